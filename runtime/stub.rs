@@ -3,16 +3,38 @@
 struct SnakeVal(u64);
 
 static TAG_MASK: u64 = 0x00_00_00_00_00_00_00_01;
+static PTR_TAG_MASK: u64 = 0x00_00_00_00_00_00_00_07;
+static TUPLE_TAG: u64 = 0x00_00_00_00_00_00_00_01;
+static CLOSURE_TAG: u64 = 0x00_00_00_00_00_00_00_03;
+static STRING_TAG: u64 = 0x00_00_00_00_00_00_00_05;
 static SNAKE_TRU: SnakeVal = SnakeVal(0xFF_FF_FF_FF_FF_FF_FF_FF);
 static SNAKE_FLS: SnakeVal = SnakeVal(0x7F_FF_FF_FF_FF_FF_FF_FF);
 
+// Size of each of the two halves of the heap in 8-byte words
+static HEAP_SIZE: usize = 1 << 20;
+
+// Objects are allocated in the from-space. When it is full the live ones
+// are copied to the to-space and the two spaces swap roles.
+static mut FROM_SPACE: *mut u64 = 0 as *mut u64;
+static mut TO_SPACE: *mut u64 = 0 as *mut u64;
+
 #[link(name = "compiled_code", kind = "static")]
 extern "sysv64" {
 
     // The \x01 here is an undocumented feature of LLVM that ensures
     // it does not add an underscore in front of the name.
     #[link_name = "\x01start_here"]
-    fn start_here() -> SnakeVal;
+    fn start_here(heap: *mut u64, heap_end: *mut u64) -> SnakeVal;
+
+    // [number of entries][return address, stack_top, slots]... for each
+    // call site of the compiled code, see FrameInfo in compile.rs
+    #[link_name = "\x01snake_frame_table"]
+    static FRAME_TABLE: u64;
+
+    // [number of sites][address of a null-terminated location]... for each
+    // site of the compiled code where a runtime error can happen
+    #[link_name = "\x01snake_site_table"]
+    static SITE_TABLE: u64;
 }
 
 // reinterprets the bytes of an unsigned number to a signed number
@@ -20,7 +42,85 @@ fn unsigned_to_signed(x: u64) -> i64 {
     i64::from_le_bytes(x.to_le_bytes())
 }
 
+// A tuple is laid out as [length][elements...], the length being a snake number
+unsafe fn tuple_elems<'a>(x: SnakeVal) -> &'a [SnakeVal] {
+    let addr = (x.0 - TUPLE_TAG) as *const u64;
+    let len = (*addr >> 1) as usize;
+    std::slice::from_raw_parts(addr.add(1) as *const SnakeVal, len)
+}
+
+fn is_tuple(x: SnakeVal) -> bool {
+    x.0 & PTR_TAG_MASK == TUPLE_TAG
+}
+
+// A string is laid out as [length][bytes...], the length being a snake
+// number and the bytes padded with zeros to whole words
+unsafe fn string_bytes<'a>(x: SnakeVal) -> &'a [u8] {
+    let addr = (x.0 - STRING_TAG) as *const u64;
+    let len = (*addr >> 1) as usize;
+    std::slice::from_raw_parts(addr.add(1) as *const u8, len)
+}
+
+fn is_string(x: SnakeVal) -> bool {
+    x.0 & PTR_TAG_MASK == STRING_TAG
+}
+
+// Where each character of a string starts, followed by where the last one
+// ends, so that strings are indexed by character and not by byte
+fn char_bounds(bytes: &[u8]) -> Vec<usize> {
+    let s = std::str::from_utf8(bytes).expect("strings are valid UTF-8");
+    s.char_indices()
+        .map(|(i, _)| i)
+        .chain(std::iter::once(s.len()))
+        .collect()
+}
+
+// A closure is laid out as [arity][code pointer][number of captured
+// values][captured values...]; returns the code pointer and the values
+unsafe fn closure_parts<'a>(x: SnakeVal) -> (u64, &'a [SnakeVal]) {
+    let addr = (x.0 - CLOSURE_TAG) as *const u64;
+    let len = (*addr.add(2) >> 1) as usize;
+    (
+        *addr.add(1),
+        std::slice::from_raw_parts(addr.add(3) as *const SnakeVal, len),
+    )
+}
+
+fn is_closure(x: SnakeVal) -> bool {
+    x.0 & PTR_TAG_MASK == CLOSURE_TAG
+}
+
+// Words taken by the bytes of a string of length [len]
+fn string_words(len: usize) -> usize {
+    (len + 7) / 8
+}
+
+// A string the way it is written in a program, in quotes and with the
+// escapes the lexer knows
+fn quote(bytes: &[u8]) -> String {
+    let mut out = String::from("\"");
+    for c in String::from_utf8_lossy(bytes).chars() {
+        match c {
+            '\n' => out.push_str("\\n"),
+            '\t' => out.push_str("\\t"),
+            '\r' => out.push_str("\\r"),
+            '\0' => out.push_str("\\0"),
+            '\\' => out.push_str("\\\\"),
+            '"' => out.push_str("\\\""),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
 fn sprint_snake_val(x: SnakeVal) -> String {
+    sprint_loop(x, &mut Vec::new())
+}
+
+// [parents] holds the tuples currently being printed so that a
+// cyclic tuple is printed as <loop> instead of forever
+fn sprint_loop(x: SnakeVal, parents: &mut Vec<SnakeVal>) -> String {
     if x.0 & TAG_MASK == 0 {
         // it's a number
         format!("{}", unsigned_to_signed(x.0) >> 1)
@@ -28,47 +128,425 @@ fn sprint_snake_val(x: SnakeVal) -> String {
         String::from("true")
     } else if x == SNAKE_FLS {
         String::from("false")
+    } else if is_tuple(x) {
+        if parents.contains(&x) {
+            return String::from("<loop>");
+        }
+        parents.push(x);
+        let elems: Vec<String> = unsafe { tuple_elems(x) }
+            .iter()
+            .map(|elem| sprint_loop(*elem, parents))
+            .collect();
+        parents.pop();
+        format!("[{}]", elems.join(", "))
+    } else if is_string(x) {
+        quote(unsafe { string_bytes(x) })
+    } else if is_closure(x) {
+        String::from("<closure>")
     } else {
         format!("Invalid snake value 0x{:x}", x.0)
     }
 }
 
+// Structural equality. Closures are equal when they run the same code on
+// equal captured values. Pairs of tuples already being compared are
+// assumed equal so that comparing cyclic tuples terminates.
+fn equal_loop(x: SnakeVal, y: SnakeVal, assumed: &mut Vec<(SnakeVal, SnakeVal)>) -> bool {
+    if x == y {
+        return true;
+    }
+    if is_string(x) && is_string(y) {
+        return unsafe { string_bytes(x) == string_bytes(y) };
+    }
+    if is_closure(x) && is_closure(y) {
+        let ((x_code, xs), (y_code, ys)) = unsafe { (closure_parts(x), closure_parts(y)) };
+        return x_code == y_code
+            && xs
+                .iter()
+                .zip(ys.iter())
+                .all(|(a, b)| equal_loop(*a, *b, assumed));
+    }
+    if !is_tuple(x) || !is_tuple(y) {
+        return false;
+    }
+    if assumed.contains(&(x, y)) {
+        return true;
+    }
+    assumed.push((x, y));
+    let (xs, ys) = unsafe { (tuple_elems(x), tuple_elems(y)) };
+    xs.len() == ys.len()
+        && xs
+            .iter()
+            .zip(ys.iter())
+            .all(|(a, b)| equal_loop(*a, *b, assumed))
+}
+
+#[export_name = "\x01snake_equal"]
+extern "sysv64" fn snake_equal(x: SnakeVal, y: SnakeVal) -> SnakeVal {
+    if equal_loop(x, y, &mut Vec::new()) {
+        SNAKE_TRU
+    } else {
+        SNAKE_FLS
+    }
+}
+
+// What print shows: a string as it is, anything else like sprint_snake_val
+fn sprint_printed(x: SnakeVal) -> String {
+    if is_string(x) {
+        String::from_utf8_lossy(unsafe { string_bytes(x) }).into_owned()
+    } else {
+        sprint_snake_val(x)
+    }
+}
+
 #[export_name = "\x01print_snake_val"]
 extern "sysv64" fn print_snake_val(v: SnakeVal) -> SnakeVal {
-    println!("{}", sprint_snake_val(v));
+    println!("{}", sprint_printed(v));
     return v;
 }
 
+// What the functions making a string return: the string and the heap
+// pointer past it, or 0 and the number of bytes the string needs when it
+// doesn't fit into the free part of the heap
+#[repr(C)]
+struct BuiltString {
+    string: SnakeVal,
+    rest: u64,
+}
+
+// Writes a string of [bytes] at [heap_ptr] if it fits below [heap_end]
+unsafe fn alloc_string(bytes: &[u8], heap_ptr: *mut u64, heap_end: *mut u64) -> BuiltString {
+    let words = 1 + string_words(bytes.len());
+    if heap_ptr as u64 + 8 * words as u64 > heap_end as u64 {
+        return BuiltString {
+            string: SnakeVal(0),
+            rest: 8 * words as u64,
+        };
+    }
+    *heap_ptr = (bytes.len() as u64) << 1;
+    // the last word is cleared first for the padding
+    *heap_ptr.add(words - 1) = 0;
+    std::ptr::copy_nonoverlapping(bytes.as_ptr(), heap_ptr.add(1) as *mut u8, bytes.len());
+    BuiltString {
+        string: SnakeVal(heap_ptr as u64 | STRING_TAG),
+        rest: heap_ptr.add(words) as u64,
+    }
+}
+
+// The index [i] into a string of length [len], which may be [len] itself
+// when [inclusive]
+fn string_index(i: SnakeVal, len: usize, inclusive: bool, site: u64) -> usize {
+    let n = unsigned_to_signed(i.0) >> 1;
+    if n < 0 || n as usize > len || (n as usize == len && !inclusive) {
+        snake_error(INDEX_BOUNDS_ERROR, i, site);
+    }
+    n as usize
+}
+
+// The compiled code has checked that the arguments are strings and numbers
+#[export_name = "\x01snake_strlen"]
+unsafe extern "sysv64" fn snake_strlen(s: SnakeVal) -> SnakeVal {
+    SnakeVal(((char_bounds(string_bytes(s)).len() - 1) as u64) << 1)
+}
+
+#[export_name = "\x01snake_concat"]
+unsafe extern "sysv64" fn snake_concat(
+    x: SnakeVal,
+    y: SnakeVal,
+    _site: u64,
+    heap_ptr: *mut u64,
+    heap_end: *mut u64,
+) -> BuiltString {
+    alloc_string(&[string_bytes(x), string_bytes(y)].concat(), heap_ptr, heap_end)
+}
+
+#[export_name = "\x01snake_char_at"]
+unsafe extern "sysv64" fn snake_char_at(
+    s: SnakeVal,
+    i: SnakeVal,
+    site: u64,
+    heap_ptr: *mut u64,
+    heap_end: *mut u64,
+) -> BuiltString {
+    let bytes = string_bytes(s);
+    let bounds = char_bounds(bytes);
+    let i = string_index(i, bounds.len() - 1, false, site);
+    alloc_string(&bytes[bounds[i]..bounds[i + 1]], heap_ptr, heap_end)
+}
+
+// The characters from [start] up to but not including [end]
+#[export_name = "\x01snake_substr"]
+unsafe extern "sysv64" fn snake_substr(
+    s: SnakeVal,
+    start: SnakeVal,
+    end: SnakeVal,
+    site: u64,
+    heap_ptr: *mut u64,
+    heap_end: *mut u64,
+) -> BuiltString {
+    let bytes = string_bytes(s);
+    let bounds = char_bounds(bytes);
+    let from = string_index(start, bounds.len() - 1, true, site);
+    let to = string_index(end, bounds.len() - 1, true, site);
+    if to < from {
+        report_error(
+            INDEX_BOUNDS_ERROR,
+            format!(
+                "substr end {} is before start {}",
+                sprint_snake_val(end),
+                sprint_snake_val(start)
+            ),
+            site,
+        );
+    }
+    alloc_string(&bytes[bounds[from]..bounds[to]], heap_ptr, heap_end)
+}
+
+#[export_name = "\x01snake_num_to_string"]
+unsafe extern "sysv64" fn snake_num_to_string(
+    n: SnakeVal,
+    _site: u64,
+    heap_ptr: *mut u64,
+    heap_end: *mut u64,
+) -> BuiltString {
+    alloc_string(sprint_snake_val(n).as_bytes(), heap_ptr, heap_end)
+}
+
+#[export_name = "\x01snake_string_to_num"]
+unsafe extern "sysv64" fn snake_string_to_num(s: SnakeVal, site: u64) -> SnakeVal {
+    let parsed = std::str::from_utf8(string_bytes(s))
+        .ok()
+        .and_then(|digits| digits.parse::<i64>().ok());
+    match parsed {
+        Some(n) if (-(1 << 62)..1 << 62).contains(&n) => SnakeVal((n << 1) as u64),
+        _ => snake_error(STRING_NUM_ERROR, s, site),
+    }
+}
+
+// Where the compiled code continues allocating after a collection
+#[repr(C)]
+struct HeapRange {
+    heap_ptr: *mut u64,
+    heap_end: *mut u64,
+}
+
+// Number of words of the heap object x points to. A tuple is
+// [length][elements...], a string [length][bytes...] and a closure
+// [arity][code][count][captured...]
+unsafe fn object_size(x: SnakeVal, addr: *const u64) -> usize {
+    if is_tuple(x) {
+        1 + (*addr >> 1) as usize
+    } else if is_string(x) {
+        1 + string_words((*addr >> 1) as usize)
+    } else {
+        3 + (*addr.add(2) >> 1) as usize
+    }
+}
+
+// The words of the object x points to that hold snake values, none for
+// a string
+unsafe fn object_fields<'a>(x: SnakeVal, addr: *mut u64) -> &'a mut [u64] {
+    if is_tuple(x) {
+        std::slice::from_raw_parts_mut(addr.add(1), (*addr >> 1) as usize)
+    } else if is_string(x) {
+        &mut []
+    } else {
+        std::slice::from_raw_parts_mut(addr.add(3), (*addr.add(2) >> 1) as usize)
+    }
+}
+
+// Copies the object x points to into the to-space unless that was already
+// done, returning the value pointing to the copy. The first word of a copied
+// object is replaced by the address of its copy with the lowest bit set,
+// which no length or arity has.
+unsafe fn forward(x: SnakeVal, alloc: &mut *mut u64, copied: &mut Vec<SnakeVal>) -> SnakeVal {
+    let tag = x.0 & PTR_TAG_MASK;
+    if tag != TUPLE_TAG && tag != CLOSURE_TAG && tag != STRING_TAG {
+        return x;
+    }
+    let addr = (x.0 - tag) as *mut u64;
+    if *addr & 1 == 1 {
+        return SnakeVal((*addr - 1) | tag);
+    }
+    let size = object_size(x, addr);
+    let copy = *alloc;
+    std::ptr::copy_nonoverlapping(addr, copy, size);
+    *alloc = copy.add(size);
+    *addr = copy as u64 | 1;
+    let moved = SnakeVal(copy as u64 | tag);
+    copied.push(moved);
+    moved
+}
+
+// Copying collector. The roots are the live variables of every Snake
+// frame: [slots] of them below [rsp] for the frame that is allocating,
+// and the frame table tells how to reach and scan each caller.
+#[export_name = "\x01snake_gc"]
+unsafe extern "sysv64" fn snake_gc(
+    needed: u64,
+    rsp: *const u64,
+    slots: u64,
+    site: u64,
+) -> HeapRange {
+    let table = &FRAME_TABLE as *const u64;
+    let frames: std::collections::HashMap<u64, (u64, u64)> = (0..*table as usize)
+        .map(|i| {
+            let entry = table.add(1 + 3 * i);
+            (*entry, (*entry.add(1), *entry.add(2)))
+        })
+        .collect();
+
+    let mut alloc = TO_SPACE;
+    let mut copied = Vec::new();
+    let (mut frame, mut slots) = (rsp as *mut u64, slots as usize);
+    loop {
+        for i in 1..=slots {
+            let slot = frame.sub(i);
+            *slot = forward(SnakeVal(*slot), &mut alloc, &mut copied).0;
+        }
+        // the top of a frame is the address its function returns to,
+        // which is only in the table when the caller is Snake code
+        match frames.get(&*frame) {
+            Some(&(stack_top, caller_slots)) => {
+                frame = frame.add(1 + stack_top as usize / 8);
+                slots = caller_slots as usize;
+            }
+            None => break,
+        }
+    }
+    while let Some(x) = copied.pop() {
+        let addr = (x.0 & !PTR_TAG_MASK) as *mut u64;
+        for field in object_fields(x, addr) {
+            *field = forward(SnakeVal(*field), &mut alloc, &mut copied).0;
+        }
+    }
+
+    let emptied = FROM_SPACE;
+    FROM_SPACE = TO_SPACE;
+    TO_SPACE = emptied;
+    let heap_end = FROM_SPACE.add(HEAP_SIZE);
+    if alloc as u64 + needed > heap_end as u64 {
+        snake_error(OUT_OF_MEMORY_ERROR, SnakeVal(needed << 1), site);
+    }
+    HeapRange {
+        heap_ptr: alloc,
+        heap_end,
+    }
+}
+
 /* Implement the following error function. You are free to change the
  * input and output types as needed for your design.
  *
 **/
+// Reported as E0200 and up, e.g. E0203 for IF_TYPE_ERROR, see error_number
 type ErrorCode = u64;
 static ARITH_TYPE_ERROR: ErrorCode = 0;
 static CMP_TYPE_ERROR: ErrorCode = 1;
 static OVERFLOW_ERROR: ErrorCode = 2;
 static IF_TYPE_ERROR: ErrorCode = 3;
 static LOGIC_TYPE_ERROR: ErrorCode = 4;
+static TUPLE_TYPE_ERROR: ErrorCode = 5;
+static INDEX_TYPE_ERROR: ErrorCode = 6;
+static INDEX_BOUNDS_ERROR: ErrorCode = 7;
+static CALL_TYPE_ERROR: ErrorCode = 8;
+static ARITY_ERROR: ErrorCode = 9;
+static OUT_OF_MEMORY_ERROR: ErrorCode = 10;
+static DIV_ZERO_ERROR: ErrorCode = 11;
+static STRING_TYPE_ERROR: ErrorCode = 12;
+static STRING_NUM_ERROR: ErrorCode = 13;
+static WHILE_TYPE_ERROR: ErrorCode = 14;
+
+// The number of the code an error is reported with
+fn error_number(err_code: ErrorCode) -> u64 {
+    // the condition of a while fails like that of an if
+    if err_code == WHILE_TYPE_ERROR {
+        200 + IF_TYPE_ERROR
+    } else {
+        200 + err_code
+    }
+}
+
+// The source location of [site], as printed for compile errors
+fn site_location(site: u64) -> String {
+    unsafe {
+        let table = &SITE_TABLE as *const u64;
+        let location = *table.add(1 + site as usize) as *const std::os::raw::c_char;
+        std::ffi::CStr::from_ptr(location)
+            .to_string_lossy()
+            .into_owned()
+    }
+}
 
 #[export_name = "\x01snake_error"]
-extern "sysv64" fn snake_error(err_code: ErrorCode, v: SnakeVal) {
-    if err_code == ARITH_TYPE_ERROR {
-        eprintln!("arithmetic expected a number {}", sprint_snake_val(v));
+extern "sysv64" fn snake_error(err_code: ErrorCode, v: SnakeVal, site: u64) -> ! {
+    let msg = if err_code == ARITH_TYPE_ERROR {
+        format!("arithmetic expected a number {}", sprint_snake_val(v))
     } else if err_code == CMP_TYPE_ERROR {
-        eprintln!("comparison expected a number {}", sprint_snake_val(v));
+        format!("comparison expected a number {}", sprint_snake_val(v))
     } else if err_code == OVERFLOW_ERROR {
-        eprintln!("overflow {}", sprint_snake_val(v));
+        format!("overflow {}", sprint_snake_val(v))
     } else if err_code == IF_TYPE_ERROR {
-        eprintln!("if expected a boolean {}", sprint_snake_val(v));
+        format!("if expected a boolean {}", sprint_snake_val(v))
+    } else if err_code == WHILE_TYPE_ERROR {
+        format!("while expected a boolean {}", sprint_snake_val(v))
     } else if err_code == LOGIC_TYPE_ERROR {
-        eprintln!("logic expected a boolean {}", sprint_snake_val(v));
+        format!("logic expected a boolean {}", sprint_snake_val(v))
+    } else if err_code == TUPLE_TYPE_ERROR {
+        format!("tuple operation expected a tuple {}", sprint_snake_val(v))
+    } else if err_code == INDEX_TYPE_ERROR {
+        format!("index expected a number {}", sprint_snake_val(v))
+    } else if err_code == INDEX_BOUNDS_ERROR {
+        format!("index out of bounds {}", sprint_snake_val(v))
+    } else if err_code == CALL_TYPE_ERROR {
+        format!("call expected a function {}", sprint_snake_val(v))
+    } else if err_code == ARITY_ERROR {
+        format!(
+            "wrong number of arguments, function expects {}",
+            sprint_snake_val(v)
+        )
+    } else if err_code == OUT_OF_MEMORY_ERROR {
+        format!("out of memory, could not allocate {} bytes", sprint_snake_val(v))
+    } else if err_code == DIV_ZERO_ERROR {
+        format!("division by zero, dividing {}", sprint_snake_val(v))
+    } else if err_code == STRING_TYPE_ERROR {
+        format!("string operation expected a string {}", sprint_snake_val(v))
+    } else if err_code == STRING_NUM_ERROR {
+        format!(
+            "string_to_num expected the digits of a number {}",
+            sprint_snake_val(v)
+        )
     } else {
         eprintln!("Unknown error {}", err_code);
+        std::process::exit(1);
+    };
+    report_error(err_code, msg, site)
+}
+
+// Errors whose message needs more than one value are reported directly.
+// The compiler's runner sets SNAKE_ERROR_RECORD to get the code and site
+// instead of the location, and reports the error like a compile error.
+fn report_error(err_code: ErrorCode, msg: String, site: u64) -> ! {
+    if std::env::var_os("SNAKE_ERROR_RECORD").is_some() {
+        // the message is last since it can span several lines
+        eprint!("snake-error E{:04} {} {}", error_number(err_code), site, msg);
+    } else {
+        eprintln!(
+            "error[E{:04}]: {} at {}",
+            error_number(err_code),
+            msg,
+            site_location(site)
+        );
     }
     std::process::exit(1);
 }
 
 fn main() {
-    let output = unsafe { start_here() };
-    println!("{}", sprint_snake_val(output));
+    let mut from_space = vec![0u64; HEAP_SIZE];
+    let mut to_space = vec![0u64; HEAP_SIZE];
+    let output = unsafe {
+        FROM_SPACE = from_space.as_mut_ptr();
+        TO_SPACE = to_space.as_mut_ptr();
+        start_here(FROM_SPACE, FROM_SPACE.add(HEAP_SIZE))
+    };
+    println!("{}", sprint_printed(output));
 }
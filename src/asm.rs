@@ -67,16 +67,25 @@ pub enum Loc {
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub enum Instr {
     Mov(MovArgs),
+    // load the address of a label, relative to rip
+    Lea(Reg, String),
 
     Add(BinArgs),
     Sub(BinArgs),
     IMul(BinArgs),
+    // sign-extend rax into rdx:rax
+    Cqo,
+    // divide rdx:rax, the quotient goes to rax and the remainder to rdx
+    IDiv(Reg),
     And(BinArgs),
     Or(BinArgs),
     Xor(BinArgs),
     Shr(BinArgs),
     Sar(BinArgs),
     Shl(BinArgs),
+    // shift by the count in cl
+    SarCl(Reg),
+    ShlCl(Reg),
     Cmp(BinArgs),
     Test(BinArgs),
 
@@ -87,9 +96,11 @@ pub enum Instr {
     Comment(String),
 
     Call(String),
+    CallReg(Reg),
     Ret,
 
     Jmp(String),
+    JmpReg(Reg),
     Je(String),
     Jne(String),
     Jl(String),
@@ -193,6 +204,9 @@ pub fn instr_to_string(i: &Instr) -> String {
         Instr::Mov(args) => {
             format!("        mov {}", mov_args_to_string(*args))
         }
+        Instr::Lea(r, label) => {
+            format!("        lea {}, [rel {}]", reg_to_string(*r), label)
+        }
         Instr::Add(args) => {
             format!("        add {}", bin_args_to_string(*args))
         }
@@ -202,6 +216,10 @@ pub fn instr_to_string(i: &Instr) -> String {
         Instr::IMul(args) => {
             format!("        imul {}", bin_args_to_string(*args))
         }
+        Instr::Cqo => "        cqo".to_string(),
+        Instr::IDiv(r) => {
+            format!("        idiv {}", reg_to_string(*r))
+        }
         Instr::And(args) => {
             format!("        and {}", bin_args_to_string(*args))
         }
@@ -220,6 +238,12 @@ pub fn instr_to_string(i: &Instr) -> String {
         Instr::Shl(args) => {
             format!("        shl {}", bin_args_to_string(*args))
         }
+        Instr::SarCl(r) => {
+            format!("        sar {}, cl", reg_to_string(*r))
+        }
+        Instr::ShlCl(r) => {
+            format!("        shl {}, cl", reg_to_string(*r))
+        }
         Instr::Cmp(args) => {
             format!("        cmp {}", bin_args_to_string(*args))
         }
@@ -243,13 +267,19 @@ pub fn instr_to_string(i: &Instr) -> String {
         Instr::Call(s) => {
             format!("        call {}", s)
         }
+        Instr::CallReg(r) => {
+            format!("        call {}", reg_to_string(*r))
+        }
         Instr::Ret => {
-            format!("        ret")
+            "        ret".to_string()
         }
 
         Instr::Jmp(s) => {
             format!("        jmp {}", s)
         }
+        Instr::JmpReg(r) => {
+            format!("        jmp {}", reg_to_string(*r))
+        }
         Instr::Je(s) => {
             format!("        je {}", s)
         }
@@ -289,8 +319,8 @@ pub fn instr_to_string(i: &Instr) -> String {
 pub fn instrs_to_string(is: &[Instr]) -> String {
     let mut buf = String::new();
     for i in is {
-        buf.push_str(&instr_to_string(&i));
-        buf.push_str("\n");
+        buf.push_str(&instr_to_string(i));
+        buf.push('\n');
     }
     buf
 }
@@ -1,143 +1,668 @@
-use std::collections::{HashMap, HashSet};
+use std::collections::HashMap;
 
-use crate::{compile::CompileErr, syntax::*};
+use crate::{
+    compile::{CompileErr, CompileWarning},
+    syntax::*,
+};
 
 static I63_MAX: i64 = 0x3F_FF_FF_FF_FF_FF_FF_FF;
 static I63_MIN: i64 = -0x40_00_00_00_00_00_00_00;
 
 #[derive(Clone, Debug, PartialEq, Eq)]
-pub enum Symbol {
-    Func(usize),
+pub enum Symbol<Span> {
+    Func {
+        parameters: Vec<String>,
+        location: Span,
+    },
     Var,
 }
 
+// The number of single character insertions, deletions and substitutions
+// that turn [a] into [b]
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut prev: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.chars().enumerate() {
+        let mut row = vec![i + 1];
+        for (j, cb) in b.iter().enumerate() {
+            let substitute = prev[j] + usize::from(ca != *cb);
+            row.push(substitute.min(prev[j + 1] + 1).min(row[j] + 1));
+        }
+        prev = row;
+    }
+    prev[b.len()]
+}
+
+// How many suggestions are shown at most
+const MAX_SUGGESTIONS: usize = 3;
+
+// The names in scope that [name] could be a typo of, closest first. A name
+// is close enough if a third of its characters need to change at most.
+fn suggestions<Span, F>(name: &str, symbols: &HashMap<String, Symbol<Span>>, fits: F) -> Vec<String>
+where
+    F: Fn(&Symbol<Span>) -> bool,
+{
+    let length = name.chars().count();
+    let max_distance = (length / 3).max(1);
+    let mut close: Vec<(usize, &String)> = symbols
+        .iter()
+        .filter(|(_, symbol)| fits(symbol))
+        .map(|(candidate, _)| (edit_distance(name, candidate), candidate))
+        // replacing every character, e.g. of a one-letter name, isn't a typo
+        .filter(|(distance, _)| *distance <= max_distance && *distance < length)
+        .collect();
+    close.sort();
+    close
+        .into_iter()
+        .take(MAX_SUGGESTIONS)
+        .map(|(_, candidate)| candidate.clone())
+        .collect()
+}
+
+/// Checks the whole program, carrying on past errors to report all of them
 pub fn check_prog<Span>(
     e: &Exp<Span>,
-    symbols: &HashMap<String, Symbol>,
-) -> Result<(), CompileErr<Span>>
+    symbols: &HashMap<String, Symbol<Span>>,
+) -> Result<(), Vec<CompileErr<Span>>>
 where
     Span: Clone,
+{
+    let mut errs = vec![];
+    check_exp(e, symbols, &mut errs);
+    check_assignments(e, &mut errs);
+    if errs.is_empty() {
+        Ok(())
+    } else {
+        Err(errs)
+    }
+}
+
+// Reports every parameter named like an earlier one, at the function
+fn check_parameters<Span>(parameters: &[String], location: &Span, errs: &mut Vec<CompileErr<Span>>)
+where
+    Span: Clone,
+{
+    for (i, param) in parameters.iter().enumerate() {
+        if parameters[..i].contains(param) {
+            errs.push(CompileErr::DuplicateArgName {
+                duplicated_name: param.clone(),
+                location: location.clone(),
+            });
+        }
+    }
+}
+
+fn check_exp<Span>(
+    e: &Exp<Span>,
+    symbols: &HashMap<String, Symbol<Span>>,
+    errs: &mut Vec<CompileErr<Span>>,
+) where
+    Span: Clone,
 {
     match e {
         Exp::Num(i, ann) => {
             if *i > I63_MAX || *i < I63_MIN {
-                return Err(CompileErr::Overflow {
+                errs.push(CompileErr::Overflow {
                     num: *i,
                     location: ann.clone(),
                 });
             }
-            Ok(())
         }
         Exp::Var(name, ann) => {
             if !symbols.contains_key(name) {
-                return Err(CompileErr::UnboundVariable {
+                errs.push(CompileErr::UnboundVariable {
                     unbound: name.clone(),
                     location: ann.clone(),
+                    suggestions: suggestions(name, symbols, |symbol| matches!(symbol, Symbol::Var)),
                 });
             }
-            if let Symbol::Func(_) = symbols[name] {
-                return Err(CompileErr::FunctionUsedAsValue {
-                    function_name: name.clone(),
-                    location: ann.clone(),
-                });
-            }
-            Ok(())
         }
         Exp::Prim(_, exps, _) => {
             for e in exps {
-                check_prog(e, symbols)?;
+                check_exp(e, symbols, errs);
             }
-            Ok(())
         }
-        Exp::Let {
-            bindings,
-            body,
-            ann,
-        } => {
+        Exp::Let { bindings, body, .. } => {
             let mut scoped_symbols = symbols.clone();
-            let mut appeared = HashSet::new();
+            let mut appeared = HashMap::<&String, &Span>::new();
             for (name, value) in bindings {
-                if appeared.contains(name) {
-                    return Err(CompileErr::DuplicateBinding {
+                match appeared.get(name) {
+                    Some(first) => errs.push(CompileErr::DuplicateBinding {
                         duplicated_name: name.clone(),
-                        location: ann.clone(),
-                    });
+                        location: value.ann().clone(),
+                        first_location: (*first).clone(),
+                    }),
+                    None => {
+                        appeared.insert(name, value.ann());
+                    }
                 }
-                appeared.insert(name);
+                // the binder is only in scope after its own value
+                check_exp(value, &scoped_symbols, errs);
                 scoped_symbols.insert(name.clone(), Symbol::Var);
-                check_prog(value, &scoped_symbols)?;
             }
-            check_prog(body, &scoped_symbols)
+            check_exp(body, &scoped_symbols, errs)
         }
-        Exp::Bool(_, _) => Ok(()),
+        Exp::Bool(_, _) | Exp::Str(_, _) => {}
         Exp::If {
             cond,
             thn,
             els,
-            ann,
+            ann: _,
         } => {
-            check_prog(cond, symbols)?;
-            check_prog(&thn, symbols)?;
-            check_prog(&els, symbols)?;
-            Ok(())
+            check_exp(cond, symbols, errs);
+            check_exp(thn, symbols, errs);
+            check_exp(els, symbols, errs);
+        }
+        Exp::Seq { first, second, .. } => {
+            check_exp(first, symbols, errs);
+            check_exp(second, symbols, errs);
         }
-        Exp::FunDefs { decls, body, ann } => {
+        Exp::While { cond, body, .. } => {
+            check_exp(cond, symbols, errs);
+            check_exp(body, symbols, errs);
+        }
+        // the loop variable is only in scope in the body, the bounds are
+        // evaluated before it exists
+        Exp::For {
+            var,
+            from,
+            to,
+            body,
+            ..
+        } => {
+            check_exp(from, symbols, errs);
+            check_exp(to, symbols, errs);
             let mut scoped_symbols = symbols.clone();
-            let mut mutual_funcs = HashSet::<String>::new();
+            scoped_symbols.insert(var.clone(), Symbol::Var);
+            check_exp(body, &scoped_symbols, errs);
+        }
+        // what may be assigned is checked by check_assignments
+        Exp::Assign { var, value, ann } => {
+            if !symbols.contains_key(var) {
+                errs.push(CompileErr::UnboundVariable {
+                    unbound: var.clone(),
+                    location: ann.clone(),
+                    suggestions: suggestions(var, symbols, |symbol| matches!(symbol, Symbol::Var)),
+                });
+            }
+            check_exp(value, symbols, errs);
+        }
+        Exp::FunDefs { decls, body, .. } => {
+            let mut scoped_symbols = symbols.clone();
+            let mut mutual_funcs = HashMap::<&String, &Span>::new();
             for decl in decls {
-                if mutual_funcs.contains(&decl.name) {
-                    return Err(CompileErr::DuplicateFunName {
+                match mutual_funcs.get(&decl.name) {
+                    Some(first) => errs.push(CompileErr::DuplicateFunName {
                         duplicated_name: decl.name.clone(),
-                        location: ann.clone(),
-                    });
+                        location: decl.ann.clone(),
+                        first_location: (*first).clone(),
+                    }),
+                    None => {
+                        mutual_funcs.insert(&decl.name, &decl.ann);
+                    }
                 }
-                mutual_funcs.insert(decl.name.clone());
-                scoped_symbols.insert(decl.name.clone(), Symbol::Func(decl.parameters.len()));
+                scoped_symbols.insert(
+                    decl.name.clone(),
+                    Symbol::Func {
+                        parameters: decl.parameters.clone(),
+                        location: decl.ann.clone(),
+                    },
+                );
             }
             for decl in decls {
+                check_parameters(&decl.parameters, &decl.ann, errs);
+                let mut fun_symbols = scoped_symbols.clone();
                 for param in &decl.parameters {
-                    scoped_symbols.insert(param.clone(), Symbol::Var);
+                    fun_symbols.insert(param.clone(), Symbol::Var);
                 }
-                check_prog(&decl.body, &scoped_symbols)?;
+                check_exp(&decl.body, &fun_symbols, errs);
             }
-            check_prog(body, &scoped_symbols)
+            check_exp(body, &scoped_symbols, errs)
+        }
+        Exp::Lambda {
+            parameters,
+            body,
+            ann,
+        } => {
+            check_parameters(parameters, ann, errs);
+            let mut scoped_symbols = symbols.clone();
+            for param in parameters {
+                scoped_symbols.insert(param.clone(), Symbol::Var);
+            }
+            check_exp(body, &scoped_symbols, errs)
         }
         Exp::Call(func, params, ann) => {
-            if !symbols.contains_key(func) {
-                return Err(CompileErr::UndefinedFunction {
+            match symbols.get(func) {
+                None => errs.push(CompileErr::UndefinedFunction {
                     undefined: func.clone(),
                     location: ann.clone(),
-                });
-            }
-            match &symbols[func] {
-                Symbol::Func(param_size) => {
-                    if params.len() != *param_size {
-                        return Err(CompileErr::FunctionCalledWrongArity {
+                    suggestions: suggestions(func, symbols, |symbol| {
+                        matches!(symbol, Symbol::Func { parameters, .. } if parameters.len() == params.len())
+                    }),
+                }),
+                // calls through a variable are checked at runtime
+                Some(Symbol::Var) => {}
+                Some(Symbol::Func {
+                    parameters,
+                    location,
+                }) => {
+                    if params.len() != parameters.len() {
+                        errs.push(CompileErr::FunctionCalledWrongArity {
                             function_name: func.clone(),
-                            correct_arity: *param_size,
+                            correct_arity: parameters.len(),
                             arity_used: params.len(),
                             location: ann.clone(),
+                            parameters: parameters.clone(),
+                            definition: location.clone(),
                         });
                     }
                 }
-                Symbol::Var => {
-                    return Err(CompileErr::ValueUsedAsFunction {
-                        variable_name: func.clone(),
-                        location: ann.clone(),
+            }
+            for p in params {
+                check_exp(p, symbols, errs);
+            }
+        }
+        Exp::InternalTailCall(_, _, _)
+        | Exp::ExternalCall { .. }
+        | Exp::MakeClosure { .. }
+        | Exp::ClosureCall { .. } => {
+            unreachable!("calls and closures of this form only exist after lambda lifting")
+        }
+    }
+}
+
+struct Assignable<Span> {
+    name: String,
+    // Why the binding can't be assigned, if it can't: it names a function
+    // or it is the counter of a for loop
+    fixed: Option<&'static str>,
+    location: Span,
+    // How many functions the binding is inside of
+    depth: usize,
+    assigned: Option<Span>,
+    // The outermost function inside the scope of the binding that refers to
+    // it
+    captured: Option<Span>,
+}
+
+// Functions copy the values of the variables they refer to when they are
+// created, so a variable that is assigned mustn't be referred to by one
+// defined in its scope. The compiled program and the interpreter would
+// disagree about its value otherwise.
+struct AssignEnv<'e, Span> {
+    // The bindings in scope, innermost last
+    binders: Vec<Assignable<Span>>,
+    // The functions and lambdas whose bodies are being checked, outermost
+    // first
+    functions: Vec<Span>,
+    errs: &'e mut Vec<CompileErr<Span>>,
+}
+
+impl<'e, Span> AssignEnv<'e, Span>
+where
+    Span: Clone,
+{
+    fn bind(&mut self, name: &str, fixed: Option<&'static str>, location: Span) {
+        self.binders.push(Assignable {
+            name: name.to_string(),
+            fixed,
+            location,
+            depth: self.functions.len(),
+            assigned: None,
+            captured: None,
+        });
+    }
+
+    fn refer(&mut self, name: &str) -> Option<usize> {
+        let ix = self.binders.iter().rposition(|b| b.name == name)?;
+        let b = &mut self.binders[ix];
+        if b.depth < self.functions.len() && b.captured.is_none() {
+            b.captured = Some(self.functions[b.depth].clone());
+        }
+        Some(ix)
+    }
+
+    // Unbound names are reported by check_exp
+    fn assign(&mut self, name: &str, location: &Span) {
+        let ix = match self.refer(name) {
+            Some(ix) => ix,
+            None => return,
+        };
+        let b = &mut self.binders[ix];
+        match b.fixed {
+            Some(what) => self.errs.push(CompileErr::InvalidAssignment {
+                name: name.to_string(),
+                what,
+                location: location.clone(),
+                definition: b.location.clone(),
+            }),
+            None => {
+                b.assigned.get_or_insert_with(|| location.clone());
+            }
+        }
+    }
+
+    // Leaves the scopes opened since there were [len] binders
+    fn unbind(&mut self, len: usize) {
+        for b in self.binders.drain(len..) {
+            if let (Some(location), Some(function)) = (b.assigned, b.captured) {
+                self.errs.push(CompileErr::CapturedAssignment {
+                    name: b.name,
+                    location,
+                    function,
+                });
+            }
+        }
+    }
+}
+
+fn check_assignments<Span>(e: &Exp<Span>, errs: &mut Vec<CompileErr<Span>>)
+where
+    Span: Clone,
+{
+    let mut env = AssignEnv {
+        binders: vec![],
+        functions: vec![],
+        errs,
+    };
+    assign_exp(e, &mut env);
+}
+
+fn assign_exp<Span>(e: &Exp<Span>, env: &mut AssignEnv<Span>)
+where
+    Span: Clone,
+{
+    match e {
+        Exp::Num(_, _) | Exp::Bool(_, _) | Exp::Str(_, _) => {}
+        Exp::Var(name, _) => {
+            env.refer(name);
+        }
+        Exp::Prim(_, exps, _) => {
+            for e in exps {
+                assign_exp(e, env);
+            }
+        }
+        Exp::Let { bindings, body, .. } => {
+            let len = env.binders.len();
+            for (name, value) in bindings {
+                assign_exp(value, env);
+                env.bind(name, None, value.ann().clone());
+            }
+            assign_exp(body, env);
+            env.unbind(len);
+        }
+        Exp::If { cond, thn, els, .. } => {
+            assign_exp(cond, env);
+            assign_exp(thn, env);
+            assign_exp(els, env);
+        }
+        Exp::Seq { first, second, .. } => {
+            assign_exp(first, env);
+            assign_exp(second, env);
+        }
+        Exp::While { cond, body, .. } => {
+            assign_exp(cond, env);
+            assign_exp(body, env);
+        }
+        // the loop counts with the place of its variable, so assigning it
+        // would change how often the loop runs
+        Exp::For {
+            var,
+            from,
+            to,
+            body,
+            ..
+        } => {
+            assign_exp(from, env);
+            assign_exp(to, env);
+            let len = env.binders.len();
+            env.bind(var, Some("loop variable"), from.ann().clone());
+            assign_exp(body, env);
+            env.unbind(len);
+        }
+        Exp::Assign { var, value, ann } => {
+            assign_exp(value, env);
+            env.assign(var, ann);
+        }
+        Exp::FunDefs { decls, body, .. } => {
+            let len = env.binders.len();
+            for decl in decls {
+                env.bind(&decl.name, Some("function"), decl.ann.clone());
+            }
+            for decl in decls {
+                env.functions.push(decl.ann.clone());
+                let params = env.binders.len();
+                for param in &decl.parameters {
+                    env.bind(param, None, decl.ann.clone());
+                }
+                assign_exp(&decl.body, env);
+                env.unbind(params);
+                env.functions.pop();
+            }
+            assign_exp(body, env);
+            env.unbind(len);
+        }
+        Exp::Lambda {
+            parameters,
+            body,
+            ann,
+        } => {
+            env.functions.push(ann.clone());
+            let len = env.binders.len();
+            for param in parameters {
+                env.bind(param, None, ann.clone());
+            }
+            assign_exp(body, env);
+            env.unbind(len);
+            env.functions.pop();
+        }
+        Exp::Call(func, args, _) => {
+            env.refer(func);
+            for arg in args {
+                assign_exp(arg, env);
+            }
+        }
+        Exp::InternalTailCall(_, _, _)
+        | Exp::ExternalCall { .. }
+        | Exp::MakeClosure { .. }
+        | Exp::ClosureCall { .. } => {
+            unreachable!("calls and closures of this form only exist after lambda lifting")
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum BinderKind {
+    Let,
+    Param,
+    Fun,
+}
+
+struct Binder<Span> {
+    name: String,
+    kind: BinderKind,
+    location: Span,
+    used: bool,
+}
+
+struct WarnEnv<Span> {
+    // The bindings in scope, innermost last
+    binders: Vec<Binder<Span>>,
+    // The functions whose bodies are being checked, as indices into binders.
+    // A function referring to itself doesn't count as a use.
+    inside: Vec<usize>,
+    warnings: Vec<CompileWarning<Span>>,
+}
+
+impl<Span> WarnEnv<Span> {
+    fn use_name(&mut self, name: &str) {
+        if let Some(ix) = self.binders.iter().rposition(|b| b.name == name) {
+            if !self.inside.contains(&ix) {
+                self.binders[ix].used = true;
+            }
+        }
+    }
+
+    fn bind(&mut self, name: &str, kind: BinderKind, location: Span) {
+        self.binders.push(Binder {
+            name: name.to_string(),
+            kind,
+            location,
+            used: false,
+        });
+    }
+
+    // Leaves the scopes opened since there were [len] binders, warning about
+    // the bindings that weren't used
+    fn unbind(&mut self, len: usize) {
+        for b in self.binders.drain(len..) {
+            if b.used {
+                continue;
+            }
+            let (name, location) = (b.name, b.location);
+            self.warnings.push(match b.kind {
+                BinderKind::Let => CompileWarning::UnusedVariable { name, location },
+                BinderKind::Param => CompileWarning::UnusedParameter { name, location },
+                BinderKind::Fun => CompileWarning::UnusedFunction { name, location },
+            });
+        }
+    }
+}
+
+/// Finds the warnings of a program that passes check_prog
+pub fn warnings<Span>(e: &Exp<Span>) -> Vec<CompileWarning<Span>>
+where
+    Span: Clone,
+{
+    let mut env = WarnEnv {
+        binders: vec![],
+        inside: vec![],
+        warnings: vec![],
+    };
+    warn_exp(e, &mut env);
+    env.warnings
+}
+
+fn warn_exp<Span>(e: &Exp<Span>, env: &mut WarnEnv<Span>)
+where
+    Span: Clone,
+{
+    match e {
+        Exp::Num(_, _) | Exp::Bool(_, _) | Exp::Str(_, _) => {}
+        Exp::Var(name, _) => env.use_name(name),
+        Exp::Prim(_, exps, _) => {
+            for e in exps {
+                warn_exp(e, env);
+            }
+        }
+        Exp::Let { bindings, body, .. } => {
+            let len = env.binders.len();
+            for (name, value) in bindings {
+                warn_exp(value, env);
+                // a name bound twice in the same let is an error already
+                if env.binders[..len].iter().any(|b| &b.name == name) {
+                    env.warnings.push(CompileWarning::Shadowing {
+                        name: name.clone(),
+                        location: value.ann().clone(),
                     });
                 }
+                env.bind(name, BinderKind::Let, value.ann().clone());
             }
-            for p in params {
-                check_prog(p, &symbols)?;
+            warn_exp(body, env);
+            env.unbind(len);
+        }
+        Exp::If { cond, thn, els, .. } => {
+            if let Exp::Bool(value, ann) = &**cond {
+                env.warnings.push(CompileWarning::ConstantCondition {
+                    who: "if",
+                    value: *value,
+                    location: ann.clone(),
+                });
             }
-            Ok(())
+            warn_exp(cond, env);
+            warn_exp(thn, env);
+            warn_exp(els, env);
+        }
+        Exp::Seq { first, second, .. } => {
+            warn_exp(first, env);
+            warn_exp(second, env);
         }
-        Exp::InternalTailCall(_, _, _) => todo!(),
-        Exp::ExternalCall {
-            fun_name,
-            args,
-            is_tail,
+        Exp::While { cond, body, .. } => {
+            if let Exp::Bool(value, ann) = &**cond {
+                env.warnings.push(CompileWarning::ConstantCondition {
+                    who: "while",
+                    value: *value,
+                    location: ann.clone(),
+                });
+            }
+            warn_exp(cond, env);
+            warn_exp(body, env);
+        }
+        // the loop variable is located at its first value, like a let-bound
+        // one at the value bound to it
+        Exp::For {
+            var,
+            from,
+            to,
+            body,
+            ..
+        } => {
+            warn_exp(from, env);
+            warn_exp(to, env);
+            let len = env.binders.len();
+            if env.binders.iter().any(|b| &b.name == var) {
+                env.warnings.push(CompileWarning::Shadowing {
+                    name: var.clone(),
+                    location: from.ann().clone(),
+                });
+            }
+            env.bind(var, BinderKind::Let, from.ann().clone());
+            warn_exp(body, env);
+            env.unbind(len);
+        }
+        // storing a value in a variable isn't a use of it
+        Exp::Assign { value, .. } => warn_exp(value, env),
+        Exp::FunDefs { decls, body, .. } => {
+            let len = env.binders.len();
+            for decl in decls {
+                env.bind(&decl.name, BinderKind::Fun, decl.ann.clone());
+            }
+            for (i, decl) in decls.iter().enumerate() {
+                env.inside.push(len + i);
+                let params = env.binders.len();
+                for param in &decl.parameters {
+                    env.bind(param, BinderKind::Param, decl.ann.clone());
+                }
+                warn_exp(&decl.body, env);
+                env.unbind(params);
+                env.inside.pop();
+            }
+            warn_exp(body, env);
+            env.unbind(len);
+        }
+        Exp::Lambda {
+            parameters,
+            body,
             ann,
-        } => todo!(),
+        } => {
+            let len = env.binders.len();
+            for param in parameters {
+                env.bind(param, BinderKind::Param, ann.clone());
+            }
+            warn_exp(body, env);
+            env.unbind(len);
+        }
+        Exp::Call(func, args, _) => {
+            env.use_name(func);
+            for arg in args {
+                warn_exp(arg, env);
+            }
+        }
+        Exp::InternalTailCall(_, _, _)
+        | Exp::ExternalCall { .. }
+        | Exp::MakeClosure { .. }
+        | Exp::ClosureCall { .. } => {
+            unreachable!("warnings are only looked for in source programs")
+        }
     }
 }
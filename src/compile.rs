@@ -1,30 +1,36 @@
 use crate::asm::instrs_to_string;
-use crate::asm::{Arg32, Arg64, BinArgs, Instr, Loc, MemRef, MovArgs, Reg, Reg32};
+use crate::asm::{Arg32, Arg64, BinArgs, Instr, MemRef, MovArgs, Reg, Reg32};
 use crate::checker;
-use crate::lambda_lift::lambda_lift;
+use crate::lambda_lift::{lambda_lift, uniquify_prog};
 use crate::sequentializer;
-use crate::syntax::{Exp, FunDecl, ImmExp, Prim, SeqExp, SeqProg, SurfFunDecl, SurfProg};
+use crate::syntax::{Exp, FunDecl, IfKind, ImmExp, Prim, SeqExp, SurfProg};
 
-use core::fmt;
-use std::collections::{HashMap, HashSet};
+use std::collections::HashMap;
 use std::convert::{TryFrom, TryInto};
-use std::f32::consts::E;
+use std::fmt::Display;
 use std::hash::Hash;
+use std::str::FromStr;
 
 #[derive(Debug, PartialEq, Eq)]
 pub enum CompileErr<Span> {
+    // The suggestions are the names in scope closest to the wrong one, best
+    // first
     UnboundVariable {
         unbound: String,
         location: Span,
+        suggestions: Vec<String>,
     },
     UndefinedFunction {
         undefined: String,
         location: Span,
+        suggestions: Vec<String>,
     },
-    // The Span here is the Span of the let-expression that has the two duplicated bindings
+    // The Spans here are the ones of the expressions bound the second and
+    // the first time
     DuplicateBinding {
         duplicated_name: String,
         location: Span,
+        first_location: Span,
     },
 
     Overflow {
@@ -35,6 +41,7 @@ pub enum CompileErr<Span> {
     DuplicateFunName {
         duplicated_name: String,
         location: Span, // the location of the 2nd function
+        first_location: Span,
     },
 
     DuplicateArgName {
@@ -42,24 +49,124 @@ pub enum CompileErr<Span> {
         location: Span,
     },
 
-    FunctionUsedAsValue {
-        function_name: String,
-        location: Span,
-    },
-
-    ValueUsedAsFunction {
-        variable_name: String,
-        location: Span,
-    },
-
     FunctionCalledWrongArity {
         function_name: String,
         correct_arity: usize,
         arity_used: usize,
         location: Span, // location of the function *call*
+        parameters: Vec<String>,
+        definition: Span,
+    },
+
+    // what is "function" or "loop variable"
+    InvalidAssignment {
+        name: String,
+        what: &'static str,
+        location: Span, // location of the assignment
+        definition: Span,
+    },
+
+    // function is the one referring to the variable
+    CapturedAssignment {
+        name: String,
+        location: Span, // location of the first assignment
+        function: Span,
     },
 }
 
+// Suspicious but valid programs. Warnings about a let binding are located at
+// the expression it binds, those about a function or its parameters at its
+// definition.
+#[derive(Debug, PartialEq, Eq)]
+pub enum CompileWarning<Span> {
+    UnusedVariable { name: String, location: Span },
+    UnusedParameter { name: String, location: Span },
+    // A function that isn't called outside of its own body
+    UnusedFunction { name: String, location: Span },
+    // A let binding with the same name as a variable or function in scope
+    Shadowing { name: String, location: Span },
+    // The condition of an if or a while, [who], is a literal. The location
+    // is the one of the condition
+    ConstantCondition {
+        who: &'static str,
+        value: bool,
+        location: Span,
+    },
+}
+
+// The names warnings are silenced by on the command line, e.g. -Wno-shadowing
+pub static WARNING_NAMES: [&str; 5] = [
+    "unused-variable",
+    "unused-parameter",
+    "unused-function",
+    "shadowing",
+    "constant-condition",
+];
+
+impl<Span> CompileWarning<Span> {
+    /// A stable identifier of the kind of warning
+    pub fn code(&self) -> &'static str {
+        match self {
+            CompileWarning::UnusedVariable { .. } => "W0001",
+            CompileWarning::UnusedParameter { .. } => "W0002",
+            CompileWarning::UnusedFunction { .. } => "W0003",
+            CompileWarning::Shadowing { .. } => "W0004",
+            CompileWarning::ConstantCondition { .. } => "W0005",
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            CompileWarning::UnusedVariable { .. } => WARNING_NAMES[0],
+            CompileWarning::UnusedParameter { .. } => WARNING_NAMES[1],
+            CompileWarning::UnusedFunction { .. } => WARNING_NAMES[2],
+            CompileWarning::Shadowing { .. } => WARNING_NAMES[3],
+            CompileWarning::ConstantCondition { .. } => WARNING_NAMES[4],
+        }
+    }
+
+    pub fn location(&self) -> &Span {
+        match self {
+            CompileWarning::UnusedVariable { location, .. }
+            | CompileWarning::UnusedParameter { location, .. }
+            | CompileWarning::UnusedFunction { location, .. }
+            | CompileWarning::Shadowing { location, .. }
+            | CompileWarning::ConstantCondition { location, .. } => location,
+        }
+    }
+}
+
+impl<Span> CompileErr<Span> {
+    /// A stable identifier of the kind of error. Codes are never reused.
+    pub fn code(&self) -> &'static str {
+        match self {
+            CompileErr::UnboundVariable { .. } => "E0001",
+            CompileErr::UndefinedFunction { .. } => "E0002",
+            CompileErr::DuplicateBinding { .. } => "E0003",
+            CompileErr::Overflow { .. } => "E0004",
+            CompileErr::DuplicateFunName { .. } => "E0005",
+            CompileErr::DuplicateArgName { .. } => "E0006",
+            CompileErr::FunctionCalledWrongArity { .. } => "E0007",
+            CompileErr::InvalidAssignment { .. } => "E0008",
+            CompileErr::CapturedAssignment { .. } => "E0009",
+        }
+    }
+
+    pub fn location(&self) -> &Span {
+        match self {
+            CompileErr::UnboundVariable { location, .. }
+            | CompileErr::UndefinedFunction { location, .. }
+            | CompileErr::DuplicateBinding { location, .. }
+            | CompileErr::Overflow { location, .. }
+            | CompileErr::DuplicateFunName { location, .. }
+            | CompileErr::DuplicateArgName { location, .. }
+            | CompileErr::FunctionCalledWrongArity { location, .. }
+            | CompileErr::InvalidAssignment { location, .. }
+            | CompileErr::CapturedAssignment { location, .. } => location,
+        }
+    }
+}
+
 // returns instruction to move imm to Rax
 fn imm_to_rax(imm: &ImmExp, vars: &HashMap<String, i32>) -> Vec<Instr> {
     vec![Instr::Mov(MovArgs::ToReg(
@@ -71,11 +178,85 @@ fn imm_to_rax(imm: &ImmExp, vars: &HashMap<String, i32>) -> Vec<Instr> {
 static SNAKE_TRU: u64 = 0xFF_FF_FF_FF_FF_FF_FF_FF;
 static SNAKE_FLS: u64 = 0x7F_FF_FF_FF_FF_FF_FF_FF;
 
+// Non-number values are distinguished by their lowest three bits.
+// Heap pointers are 8-byte aligned, so the tag can live in those bits.
+static TAG_MASK: i32 = 0b111;
+static BOOL_TAG: i32 = 0b111;
+static TUPLE_TAG: i32 = 0b001;
+static CLOSURE_TAG: i32 = 0b011;
+static STRING_TAG: i32 = 0b101;
+
+// Registers holding the next free address and the end of the heap
+static HEAP_PTR: Reg = Reg::R15;
+static HEAP_END: Reg = Reg::R14;
+
+// A call site the garbage collector walks through when looking for roots.
+// When the callee runs, [return_label] is the return address on top of its
+// frame, the caller's frame starts [stack_top] + 8 bytes above that and has
+// [slots] live variables.
+struct FrameInfo {
+    return_label: String,
+    stack_top: i32,
+    slots: i32,
+}
+
+// A check that can fail at runtime. Its [label] sets Rcx to the [site] the
+// check comes from and jumps to [handler].
+struct ErrorStub {
+    label: String,
+    handler: &'static str,
+    site: usize,
+}
+
+// Tables emitted next to the code. [locations] maps each site id to the
+// source location the runtime reports when something fails there, checks
+// at the same location share a site.
+struct DataTables<Ann> {
+    frames: Vec<FrameInfo>,
+    locations: Vec<Ann>,
+    sites: HashMap<Ann, usize>,
+    error_stubs: Vec<ErrorStub>,
+}
+
+impl<Ann: Clone + Eq + Hash> DataTables<Ann> {
+    fn site(&mut self, location: &Ann) -> usize {
+        if let Some(site) = self.sites.get(location) {
+            return *site;
+        }
+        self.locations.push(location.clone());
+        self.sites.insert(location.clone(), self.locations.len() - 1);
+        self.locations.len() - 1
+    }
+
+    /// return the label a check at [location] jumps to when it fails
+    fn error_site(&mut self, handler: &'static str, location: &Ann) -> String {
+        let site = self.site(location);
+        let label = format!("{}_{}", handler, site);
+        if self.error_stubs.iter().any(|stub| stub.label == label) {
+            return label;
+        }
+        self.error_stubs.push(ErrorStub {
+            label: label.clone(),
+            handler,
+            site,
+        });
+        label
+    }
+}
+
 static OVERFLOW: &str = "overflow_error";
 static ARITH_ERROR: &str = "arith_error";
 static CMP_ERROR: &str = "cmp_error";
 static IF_ERROR: &str = "if_error";
+static WHILE_ERROR: &str = "while_error";
 static LOGIC_ERROR: &str = "logic_error";
+static TUPLE_ERROR: &str = "tuple_error";
+static INDEX_ERROR: &str = "index_error";
+static BOUNDS_ERROR: &str = "bounds_error";
+static CALL_ERROR: &str = "call_error";
+static ARITY_ERROR: &str = "arity_error";
+static DIV_ZERO_ERROR: &str = "div_zero_error";
+static STRING_ERROR: &str = "string_error";
 static SNAKE_ERROR: &str = "snake_error";
 
 fn imm_to_arg64(imm: &ImmExp, vars: &HashMap<String, i32>) -> Arg64 {
@@ -95,7 +276,12 @@ fn imm_to_arg64(imm: &ImmExp, vars: &HashMap<String, i32>) -> Arg64 {
     }
 }
 
-fn sub_for_cmp(exps: &Vec<ImmExp>, vars: &HashMap<String, i32>, reverse: bool) -> Vec<Instr> {
+fn sub_for_cmp(
+    exps: &[ImmExp],
+    vars: &HashMap<String, i32>,
+    reverse: bool,
+    fail: String,
+) -> Vec<Instr> {
     let mut res = vec![];
     if reverse {
         // exps[1] - exps[0]
@@ -110,8 +296,8 @@ fn sub_for_cmp(exps: &Vec<ImmExp>, vars: &HashMap<String, i32>, reverse: bool) -
             Instr::Mov(MovArgs::ToReg(Reg::Rdx, imm_to_arg64(&exps[1], vars))),
         ]);
     }
-    res.append(&mut cmp_check(Reg::Rax));
-    res.append(&mut cmp_check(Reg::Rdx));
+    res.append(&mut cmp_check(Reg::Rax, fail.clone()));
+    res.append(&mut cmp_check(Reg::Rdx, fail));
 
     res.append(&mut vec![
         Instr::Sar(BinArgs::ToReg(Reg::Rax, Arg32::Signed(1))),
@@ -121,6 +307,57 @@ fn sub_for_cmp(exps: &Vec<ImmExp>, vars: &HashMap<String, i32>, reverse: bool) -
     res
 }
 
+// Checks the number in Rax and the one of [divisor], then divides them
+// untagged, leaving the quotient in Rax and the remainder in Rdx
+fn divide<Ann: Clone + Eq + Hash>(
+    divisor: &ImmExp,
+    vars: &HashMap<String, i32>,
+    tables: &mut DataTables<Ann>,
+    ann: &Ann,
+) -> Vec<Instr> {
+    let mut res = arith_check(Reg::Rax, tables.error_site(ARITH_ERROR, ann));
+    res.push(Instr::Mov(MovArgs::ToReg(Reg::Rdx, imm_to_arg64(divisor, vars))));
+    res.append(&mut arith_check(Reg::Rdx, tables.error_site(ARITH_ERROR, ann)));
+    res.append(&mut vec![
+        Instr::Cmp(BinArgs::ToReg(Reg::Rdx, Arg32::Signed(0))),
+        Instr::Je(tables.error_site(DIV_ZERO_ERROR, ann)),
+        Instr::Mov(MovArgs::ToReg(Reg::Rcx, Arg64::Reg(Reg::Rdx))),
+        Instr::Sar(BinArgs::ToReg(Reg::Rcx, Arg32::Signed(1))),
+        Instr::Sar(BinArgs::ToReg(Reg::Rax, Arg32::Signed(1))),
+        Instr::Cqo,
+        Instr::IDiv(Reg::Rcx),
+    ]);
+    res
+}
+
+// Checks the number in Rax and the shift count [by], leaving the count in
+// Rcx. Negative counts overflow and counts past 63 act like 63, which
+// shifts out every bit.
+fn shift_count<Ann: Clone + Eq + Hash>(
+    by: &ImmExp,
+    vars: &HashMap<String, i32>,
+    counter: &mut u32,
+    tables: &mut DataTables<Ann>,
+    ann: &Ann,
+) -> Vec<Instr> {
+    *counter += 1;
+    let in_range = format!("shift_count_{}", counter);
+    let mut res = arith_check(Reg::Rax, tables.error_site(ARITH_ERROR, ann));
+    res.push(Instr::Mov(MovArgs::ToReg(Reg::Rdx, imm_to_arg64(by, vars))));
+    res.append(&mut arith_check(Reg::Rdx, tables.error_site(ARITH_ERROR, ann)));
+    res.append(&mut vec![
+        Instr::Sar(BinArgs::ToReg(Reg::Rdx, Arg32::Signed(1))),
+        Instr::Cmp(BinArgs::ToReg(Reg::Rdx, Arg32::Signed(0))),
+        Instr::Jl(tables.error_site(OVERFLOW, ann)),
+        Instr::Cmp(BinArgs::ToReg(Reg::Rdx, Arg32::Signed(63))),
+        Instr::Jle(in_range.clone()),
+        Instr::Mov(MovArgs::ToReg(Reg::Rdx, Arg64::Signed(63))),
+        Instr::Label(in_range),
+        Instr::Mov(MovArgs::ToReg(Reg::Rcx, Arg64::Reg(Reg::Rdx))),
+    ]);
+    res
+}
+
 /// return instructions to convert sign in Rax to boolean value
 fn is_neg() -> Vec<Instr> {
     static NEG_MASK: u64 = 0x7F_FF_FF_FF_FF_FF_FF_FF;
@@ -140,100 +377,512 @@ fn is_non_neg() -> Vec<Instr> {
     ]
 }
 
-fn arith_check(reg: Reg) -> Vec<Instr> {
+fn arith_check(reg: Reg, fail: String) -> Vec<Instr> {
     vec![
         Instr::Mov(MovArgs::ToReg(Reg::Rcx, Arg64::Reg(reg))),
         Instr::And(BinArgs::ToReg(Reg::Rcx, Arg32::Signed(1))),
         Instr::Cmp(BinArgs::ToReg(Reg::Rcx, Arg32::Signed(1))),
-        Instr::Je(ARITH_ERROR.to_string()),
+        Instr::Je(fail),
     ]
 }
 
-fn cmp_check(reg: Reg) -> Vec<Instr> {
+fn cmp_check(reg: Reg, fail: String) -> Vec<Instr> {
     vec![
         Instr::Mov(MovArgs::ToReg(Reg::Rcx, Arg64::Reg(reg))),
         Instr::And(BinArgs::ToReg(Reg::Rcx, Arg32::Signed(1))),
         Instr::Cmp(BinArgs::ToReg(Reg::Rcx, Arg32::Signed(1))),
-        Instr::Je(CMP_ERROR.to_string()),
+        Instr::Je(fail),
     ]
 }
 
-fn logic_check(reg: Reg) -> Vec<Instr> {
+fn logic_check(reg: Reg, fail: String) -> Vec<Instr> {
     vec![
         Instr::Mov(MovArgs::ToReg(Reg::Rcx, Arg64::Reg(reg))),
-        Instr::And(BinArgs::ToReg(Reg::Rcx, Arg32::Signed(1))),
-        Instr::Cmp(BinArgs::ToReg(Reg::Rcx, Arg32::Signed(0))),
-        Instr::Je(LOGIC_ERROR.to_string()),
+        Instr::And(BinArgs::ToReg(Reg::Rcx, Arg32::Signed(TAG_MASK))),
+        Instr::Cmp(BinArgs::ToReg(Reg::Rcx, Arg32::Signed(BOOL_TAG))),
+        Instr::Jne(fail),
+    ]
+}
+
+fn if_check(reg: Reg, fail: String) -> Vec<Instr> {
+    vec![
+        Instr::Mov(MovArgs::ToReg(Reg::Rcx, Arg64::Reg(reg))),
+        Instr::And(BinArgs::ToReg(Reg::Rcx, Arg32::Signed(TAG_MASK))),
+        Instr::Cmp(BinArgs::ToReg(Reg::Rcx, Arg32::Signed(BOOL_TAG))),
+        Instr::Jne(fail),
+    ]
+}
+
+fn tuple_check(reg: Reg, fail: String) -> Vec<Instr> {
+    vec![
+        Instr::Mov(MovArgs::ToReg(Reg::Rcx, Arg64::Reg(reg))),
+        Instr::And(BinArgs::ToReg(Reg::Rcx, Arg32::Signed(TAG_MASK))),
+        Instr::Cmp(BinArgs::ToReg(Reg::Rcx, Arg32::Signed(TUPLE_TAG))),
+        Instr::Jne(fail),
+    ]
+}
+
+fn string_check(reg: Reg, fail: String) -> Vec<Instr> {
+    vec![
+        Instr::Mov(MovArgs::ToReg(Reg::Rcx, Arg64::Reg(reg))),
+        Instr::And(BinArgs::ToReg(Reg::Rcx, Arg32::Signed(TAG_MASK))),
+        Instr::Cmp(BinArgs::ToReg(Reg::Rcx, Arg32::Signed(STRING_TAG))),
+        Instr::Jne(fail),
     ]
 }
 
-fn if_check(reg: Reg) -> Vec<Instr> {
+fn index_check(reg: Reg, fail: String) -> Vec<Instr> {
     vec![
         Instr::Mov(MovArgs::ToReg(Reg::Rcx, Arg64::Reg(reg))),
         Instr::And(BinArgs::ToReg(Reg::Rcx, Arg32::Signed(1))),
-        Instr::Cmp(BinArgs::ToReg(Reg::Rcx, Arg32::Signed(0))),
-        Instr::Je(IF_ERROR.to_string()),
+        Instr::Cmp(BinArgs::ToReg(Reg::Rcx, Arg32::Signed(1))),
+        Instr::Je(fail),
+    ]
+}
+
+fn closure_check(reg: Reg, fail: String) -> Vec<Instr> {
+    vec![
+        Instr::Mov(MovArgs::ToReg(Reg::Rcx, Arg64::Reg(reg))),
+        Instr::And(BinArgs::ToReg(Reg::Rcx, Arg32::Signed(TAG_MASK))),
+        Instr::Cmp(BinArgs::ToReg(Reg::Rcx, Arg32::Signed(CLOSURE_TAG))),
+        Instr::Jne(fail),
+    ]
+}
+
+/// return instruction storing Rax in the word [offset] bytes past the heap pointer
+fn store_heap(offset: i32) -> Instr {
+    Instr::Mov(MovArgs::ToMem(
+        MemRef {
+            reg: HEAP_PTR,
+            offset,
+        },
+        Reg32::Reg(Reg::Rax),
+    ))
+}
+
+/// return instructions making sure [size] bytes are free on the heap, collecting
+/// garbage first if they are not. The size is a constant or a register other than Rax.
+/// The [stack] live variables are the current frame's roots, and [site] is reported if
+/// the heap is full even after collecting.
+fn reserve_heap(size: Arg64, stack: i32, site: usize, counter: &mut u32) -> Vec<Instr> {
+    *counter += 1;
+    let ok_label = format!("alloc_ok_{}", counter);
+    let mut res = vec![
+        Instr::Mov(MovArgs::ToReg(Reg::Rdi, size)),
+        Instr::Mov(MovArgs::ToReg(Reg::Rax, Arg64::Reg(HEAP_PTR))),
+        Instr::Add(BinArgs::ToReg(Reg::Rax, Arg32::Reg(Reg::Rdi))),
+        Instr::Cmp(BinArgs::ToReg(Reg::Rax, Arg32::Reg(HEAP_END))),
+        Instr::Jle(ok_label.clone()),
+        Instr::Mov(MovArgs::ToReg(Reg::Rsi, Arg64::Reg(Reg::Rsp))),
+        Instr::Mov(MovArgs::ToReg(Reg::Rdx, Arg64::Signed(stack.into()))),
+        Instr::Mov(MovArgs::ToReg(
+            Reg::Rcx,
+            Arg64::Signed(i64::try_from(site).unwrap()),
+        )),
+    ];
+    res.append(&mut call_runtime("snake_gc", stack));
+    // the collector returns the new heap pointer and heap end
+    res.append(&mut vec![
+        Instr::Mov(MovArgs::ToReg(HEAP_PTR, Arg64::Reg(Reg::Rax))),
+        Instr::Mov(MovArgs::ToReg(HEAP_END, Arg64::Reg(Reg::Rdx))),
+        Instr::Label(ok_label),
+    ]);
+    res
+}
+
+/// return instructions that turn the flags of a preceding cmp into a boolean in Rax.
+/// [jmp_if_false] builds the jump taken when the result should be false
+fn flags_to_bool(jmp_if_false: fn(String) -> Instr, counter: &mut u32) -> Vec<Instr> {
+    *counter += 1;
+    let fls_label = format!("false_{}", counter);
+    let done_label = format!("cmp_done_{}", counter);
+    vec![
+        jmp_if_false(fls_label.clone()),
+        Instr::Mov(MovArgs::ToReg(Reg::Rax, Arg64::Unsigned(SNAKE_TRU))),
+        Instr::Jmp(done_label.clone()),
+        Instr::Label(fls_label),
+        Instr::Mov(MovArgs::ToReg(Reg::Rax, Arg64::Unsigned(SNAKE_FLS))),
+        Instr::Label(done_label),
+    ]
+}
+
+/// return instructions to test whether the value in Rax carries [tag]
+fn has_tag(tag: i32, counter: &mut u32) -> Vec<Instr> {
+    let mut res = vec![
+        Instr::And(BinArgs::ToReg(Reg::Rax, Arg32::Signed(TAG_MASK))),
+        Instr::Cmp(BinArgs::ToReg(Reg::Rax, Arg32::Signed(tag))),
+    ];
+    res.append(&mut flags_to_bool(Instr::Jne, counter));
+    res
+}
+
+/// return instructions calling a runtime function, keeping the stack aligned
+fn call_runtime(fun: &str, stack: i32) -> Vec<Instr> {
+    vec![
+        Instr::Sub(BinArgs::ToReg(
+            Reg::Rsp,
+            Arg32::Signed(align_stack(stack) + 8),
+        )),
+        Instr::Call(fun.to_string()),
+        Instr::Add(BinArgs::ToReg(
+            Reg::Rsp,
+            Arg32::Signed(align_stack(stack) + 8),
+        )),
     ]
 }
 
+/// return instructions calling the runtime function [fun] that makes a new string out of
+/// [args], leaving the string in Rax. Besides the args, [fun] is passed the [site] and the
+/// free part of the heap. When the string doesn't fit there it returns 0 and the number of
+/// bytes it needs, so the garbage is collected and [fun] is called again.
+fn build_string(
+    fun: &str,
+    args: &[ImmExp],
+    vars: &HashMap<String, i32>,
+    stack: i32,
+    site: usize,
+    counter: &mut u32,
+) -> Vec<Instr> {
+    static ARG_REGS: [Reg; 6] = [Reg::Rdi, Reg::Rsi, Reg::Rdx, Reg::Rcx, Reg::R8, Reg::R9];
+    *counter += 1;
+    let call_label = format!("build_string_{}", counter);
+    let built_label = format!("built_string_{}", counter);
+    // the args are reloaded after a collection, which may have moved them
+    let mut res = vec![Instr::Label(call_label.clone())];
+    for (reg, arg) in ARG_REGS.iter().zip(args) {
+        res.push(Instr::Mov(MovArgs::ToReg(*reg, imm_to_arg64(arg, vars))));
+    }
+    res.append(&mut vec![
+        Instr::Mov(MovArgs::ToReg(
+            ARG_REGS[args.len()],
+            Arg64::Signed(i64::try_from(site).unwrap()),
+        )),
+        Instr::Mov(MovArgs::ToReg(ARG_REGS[args.len() + 1], Arg64::Reg(HEAP_PTR))),
+        Instr::Mov(MovArgs::ToReg(ARG_REGS[args.len() + 2], Arg64::Reg(HEAP_END))),
+    ]);
+    res.append(&mut call_runtime(fun, stack));
+    res.append(&mut vec![
+        Instr::Cmp(BinArgs::ToReg(Reg::Rax, Arg32::Signed(0))),
+        Instr::Jne(built_label.clone()),
+    ]);
+    res.append(&mut reserve_heap(Arg64::Reg(Reg::Rdx), stack, site, counter));
+    res.append(&mut vec![
+        Instr::Jmp(call_label),
+        Instr::Label(built_label),
+        Instr::Mov(MovArgs::ToReg(HEAP_PTR, Arg64::Reg(Reg::Rdx))),
+    ]);
+    res
+}
+
+/// return instructions checking that each of [exps] is a string, leaving the last one
+/// in Rax
+fn string_checks<Ann: Clone + Eq + Hash>(
+    exps: &[&ImmExp],
+    vars: &HashMap<String, i32>,
+    tables: &mut DataTables<Ann>,
+    ann: &Ann,
+) -> Vec<Instr> {
+    let mut res = vec![];
+    for exp in exps {
+        res.append(&mut imm_to_rax(exp, vars));
+        res.append(&mut string_check(Reg::Rax, tables.error_site(STRING_ERROR, ann)));
+    }
+    res
+}
+
+/// return instructions checking that each of [exps] is a number that can index
+fn index_checks<Ann: Clone + Eq + Hash>(
+    exps: &[&ImmExp],
+    vars: &HashMap<String, i32>,
+    tables: &mut DataTables<Ann>,
+    ann: &Ann,
+) -> Vec<Instr> {
+    let mut res = vec![];
+    for exp in exps {
+        res.push(Instr::Mov(MovArgs::ToReg(Reg::Rdx, imm_to_arg64(exp, vars))));
+        res.append(&mut index_check(Reg::Rdx, tables.error_site(INDEX_ERROR, ann)));
+    }
+    res
+}
+
+/// return instructions leaving in Rax the untagged address of element [index] of the
+/// tuple [tuple], after checking both of them
+fn tuple_elem_addr<Ann: Clone + Eq + Hash>(
+    tuple: &ImmExp,
+    index: &ImmExp,
+    vars: &HashMap<String, i32>,
+    tables: &mut DataTables<Ann>,
+    ann: &Ann,
+) -> Vec<Instr> {
+    let bounds_fail = tables.error_site(BOUNDS_ERROR, ann);
+    let mut res = imm_to_rax(tuple, vars);
+    res.append(&mut tuple_check(Reg::Rax, tables.error_site(TUPLE_ERROR, ann)));
+    res.push(Instr::Mov(MovArgs::ToReg(
+        Reg::Rdx,
+        imm_to_arg64(index, vars),
+    )));
+    res.append(&mut index_check(Reg::Rdx, tables.error_site(INDEX_ERROR, ann)));
+    res.append(&mut vec![
+        Instr::Sub(BinArgs::ToReg(Reg::Rax, Arg32::Signed(TUPLE_TAG))),
+        Instr::Cmp(BinArgs::ToReg(Reg::Rdx, Arg32::Signed(0))),
+        Instr::Jl(bounds_fail.clone()),
+        // the length is stored as a snake number, like the index
+        Instr::Cmp(BinArgs::ToReg(
+            Reg::Rdx,
+            Arg32::Mem(MemRef {
+                reg: Reg::Rax,
+                offset: 0,
+            }),
+        )),
+        Instr::Jge(bounds_fail),
+        // a snake number n is 2n, so shifting by 2 gives the byte offset 8n
+        Instr::Shl(BinArgs::ToReg(Reg::Rdx, Arg32::Signed(2))),
+        Instr::Add(BinArgs::ToReg(Reg::Rax, Arg32::Reg(Reg::Rdx))),
+        Instr::Add(BinArgs::ToReg(Reg::Rax, Arg32::Signed(8))),
+    ]);
+    res
+}
+
 // [vars] variable name -> offset from rsp in stack (negative number)
 // [functions] function name -> stack size when function is declared
-fn compile_to_instrs_inner<'a, 'b>(
-    e: &'a SeqExp<()>,
+fn compile_to_instrs_inner<Ann: Clone + Eq + Hash>(
+    e: &SeqExp<Ann>,
     counter: &mut u32,
     stack: i32,
-    vars: &'b mut HashMap<String, i32>,
+    vars: &mut HashMap<String, i32>,
     functions: &mut HashMap<String, i32>,
+    tables: &mut DataTables<Ann>,
 ) -> Vec<Instr> {
     match e {
         SeqExp::Imm(exp, _) => imm_to_rax(exp, vars),
-        SeqExp::Prim(p, exps, _) => {
+        SeqExp::Str(s, ann) => {
+            // layout: [length][bytes..., padded with zeros to whole words]
+            let bytes = s.as_bytes();
+            let len = i64::try_from(bytes.len()).unwrap();
+            let words: Vec<&[u8]> = bytes.chunks(8).collect();
+            let size = 8 * (i32::try_from(words.len()).unwrap() + 1);
+            let mut res = reserve_heap(Arg64::Signed(size.into()), stack, tables.site(ann), counter);
+            res.append(&mut vec![
+                Instr::Mov(MovArgs::ToReg(Reg::Rax, Arg64::Signed(len << 1))),
+                store_heap(0),
+            ]);
+            for (i, word) in words.iter().enumerate() {
+                let mut padded = [0u8; 8];
+                padded[..word.len()].copy_from_slice(word);
+                res.push(Instr::Mov(MovArgs::ToReg(
+                    Reg::Rax,
+                    Arg64::Unsigned(u64::from_le_bytes(padded)),
+                )));
+                res.push(store_heap(8 * (i32::try_from(i).unwrap() + 1)));
+            }
+            res.append(&mut vec![
+                Instr::Mov(MovArgs::ToReg(Reg::Rax, Arg64::Reg(HEAP_PTR))),
+                Instr::Add(BinArgs::ToReg(Reg::Rax, Arg32::Signed(STRING_TAG))),
+                Instr::Add(BinArgs::ToReg(HEAP_PTR, Arg32::Signed(size))),
+            ]);
+            res
+        }
+        SeqExp::Prim(Prim::Concat, exps, ann) => {
+            let mut res = string_checks(&[&exps[0], &exps[1]], vars, tables, ann);
+            res.append(&mut build_string("snake_concat", exps, vars, stack, tables.site(ann), counter));
+            res
+        }
+        SeqExp::Prim(Prim::CharAt, exps, ann) => {
+            let mut res = string_checks(&[&exps[0]], vars, tables, ann);
+            res.append(&mut index_checks(&[&exps[1]], vars, tables, ann));
+            res.append(&mut build_string("snake_char_at", exps, vars, stack, tables.site(ann), counter));
+            res
+        }
+        SeqExp::Prim(Prim::Substr, exps, ann) => {
+            let mut res = string_checks(&[&exps[0]], vars, tables, ann);
+            res.append(&mut index_checks(&[&exps[1], &exps[2]], vars, tables, ann));
+            res.append(&mut build_string("snake_substr", exps, vars, stack, tables.site(ann), counter));
+            res
+        }
+        SeqExp::Prim(Prim::NumToString, exps, ann) => {
+            let mut res = imm_to_rax(&exps[0], vars);
+            res.append(&mut arith_check(Reg::Rax, tables.error_site(ARITH_ERROR, ann)));
+            res.append(&mut build_string(
+                "snake_num_to_string",
+                exps,
+                vars,
+                stack,
+                tables.site(ann),
+                counter,
+            ));
+            res
+        }
+        SeqExp::Prim(Prim::MakeTuple, exps, ann) => {
+            // layout: [length][elements...]
+            let len = i64::try_from(exps.len()).unwrap();
+            let size = 8 * (i32::try_from(exps.len()).unwrap() + 1);
+            let mut res = reserve_heap(Arg64::Signed(size.into()), stack, tables.site(ann), counter);
+            res.append(&mut vec![
+                Instr::Mov(MovArgs::ToReg(Reg::Rax, Arg64::Signed(len << 1))),
+                store_heap(0),
+            ]);
+            for (i, exp) in exps.iter().enumerate() {
+                res.append(&mut imm_to_rax(exp, vars));
+                res.push(store_heap(8 * (i32::try_from(i).unwrap() + 1)));
+            }
+            res.append(&mut vec![
+                Instr::Mov(MovArgs::ToReg(Reg::Rax, Arg64::Reg(HEAP_PTR))),
+                Instr::Add(BinArgs::ToReg(Reg::Rax, Arg32::Signed(TUPLE_TAG))),
+                Instr::Add(BinArgs::ToReg(HEAP_PTR, Arg32::Signed(size))),
+            ]);
+            res
+        }
+        SeqExp::Prim(Prim::TupleGet, exps, ann) => {
+            let mut res = tuple_elem_addr(&exps[0], &exps[1], vars, tables, ann);
+            res.push(Instr::Mov(MovArgs::ToReg(
+                Reg::Rax,
+                Arg64::Mem(MemRef {
+                    reg: Reg::Rax,
+                    offset: 0,
+                }),
+            )));
+            res
+        }
+        SeqExp::Prim(Prim::TupleSet, exps, ann) => {
+            let mut res = tuple_elem_addr(&exps[0], &exps[1], vars, tables, ann);
+            res.append(&mut vec![
+                Instr::Mov(MovArgs::ToReg(Reg::Rdx, imm_to_arg64(&exps[2], vars))),
+                Instr::Mov(MovArgs::ToMem(
+                    MemRef {
+                        reg: Reg::Rax,
+                        offset: 0,
+                    },
+                    Reg32::Reg(Reg::Rdx),
+                )),
+            ]);
+            // the update evaluates to the tuple itself
+            res.append(&mut imm_to_rax(&exps[0], vars));
+            res
+        }
+        SeqExp::Prim(p, exps, ann) => {
             let mut res = imm_to_rax(&exps[0], vars);
-            //
             match p {
                 Prim::Add => {
-                    res.append(&mut arith_check(Reg::Rax));
+                    res.append(&mut arith_check(Reg::Rax, tables.error_site(ARITH_ERROR, ann)));
                     res.push(Instr::Mov(MovArgs::ToReg(
                         Reg::Rdx,
                         imm_to_arg64(&exps[1], vars),
                     )));
-                    res.append(&mut arith_check(Reg::Rdx));
+                    res.append(&mut arith_check(Reg::Rdx, tables.error_site(ARITH_ERROR, ann)));
                     res.push(Instr::Add(BinArgs::ToReg(Reg::Rax, Arg32::Reg(Reg::Rdx))));
-                    res.push(Instr::Jo(OVERFLOW.to_string()));
+                    res.push(Instr::Jo(tables.error_site(OVERFLOW, ann)));
                 }
                 Prim::Sub => {
-                    res.append(&mut arith_check(Reg::Rax));
+                    res.append(&mut arith_check(Reg::Rax, tables.error_site(ARITH_ERROR, ann)));
                     res.push(Instr::Mov(MovArgs::ToReg(
                         Reg::Rdx,
                         imm_to_arg64(&exps[1], vars),
                     )));
-                    res.append(&mut arith_check(Reg::Rdx));
+                    res.append(&mut arith_check(Reg::Rdx, tables.error_site(ARITH_ERROR, ann)));
                     res.push(Instr::Sub(BinArgs::ToReg(Reg::Rax, Arg32::Reg(Reg::Rdx))));
-                    res.push(Instr::Jo(OVERFLOW.to_string()));
+                    res.push(Instr::Jo(tables.error_site(OVERFLOW, ann)));
                 }
                 Prim::Mul => {
-                    res.append(&mut arith_check(Reg::Rax));
+                    res.append(&mut arith_check(Reg::Rax, tables.error_site(ARITH_ERROR, ann)));
                     res.push(Instr::Mov(MovArgs::ToReg(
                         Reg::Rdx,
                         imm_to_arg64(&exps[1], vars),
                     )));
-                    res.append(&mut arith_check(Reg::Rdx));
+                    res.append(&mut arith_check(Reg::Rdx, tables.error_site(ARITH_ERROR, ann)));
                     res.push(Instr::Sar(BinArgs::ToReg(Reg::Rdx, Arg32::Signed(1))));
                     res.push(Instr::IMul(BinArgs::ToReg(Reg::Rax, Arg32::Reg(Reg::Rdx))));
-                    res.push(Instr::Jo(OVERFLOW.to_string()));
+                    res.push(Instr::Jo(tables.error_site(OVERFLOW, ann)));
+                }
+                Prim::Div => {
+                    res.append(&mut divide(&exps[1], vars, tables, ann));
+                    res.push(Instr::Add(BinArgs::ToReg(Reg::Rax, Arg32::Reg(Reg::Rax))));
+                    res.push(Instr::Jo(tables.error_site(OVERFLOW, ann)));
+                }
+                Prim::Mod => {
+                    res.append(&mut divide(&exps[1], vars, tables, ann));
+                    res.push(Instr::Mov(MovArgs::ToReg(Reg::Rax, Arg64::Reg(Reg::Rdx))));
+                    res.push(Instr::Add(BinArgs::ToReg(Reg::Rax, Arg32::Reg(Reg::Rax))));
+                }
+                // the tag bits of two numbers are 0 either way
+                Prim::BitAnd | Prim::BitOr | Prim::BitXor => {
+                    res.append(&mut arith_check(Reg::Rax, tables.error_site(ARITH_ERROR, ann)));
+                    res.push(Instr::Mov(MovArgs::ToReg(
+                        Reg::Rdx,
+                        imm_to_arg64(&exps[1], vars),
+                    )));
+                    res.append(&mut arith_check(Reg::Rdx, tables.error_site(ARITH_ERROR, ann)));
+                    let args = BinArgs::ToReg(Reg::Rax, Arg32::Reg(Reg::Rdx));
+                    res.push(match p {
+                        Prim::BitAnd => Instr::And(args),
+                        Prim::BitOr => Instr::Or(args),
+                        _ => Instr::Xor(args),
+                    });
+                }
+                Prim::Shl => {
+                    // the shift overflows when shifting back doesn't give
+                    // the number again
+                    res.append(&mut shift_count(&exps[1], vars, counter, tables, ann));
+                    res.append(&mut vec![
+                        Instr::Mov(MovArgs::ToReg(Reg::Rdx, Arg64::Reg(Reg::Rax))),
+                        Instr::ShlCl(Reg::Rax),
+                        Instr::SarCl(Reg::Rax),
+                        Instr::Cmp(BinArgs::ToReg(Reg::Rax, Arg32::Reg(Reg::Rdx))),
+                        Instr::Jne(tables.error_site(OVERFLOW, ann)),
+                        Instr::ShlCl(Reg::Rax),
+                    ]);
+                }
+                Prim::Shr => {
+                    // the bit shifted into the tag is cleared
+                    res.append(&mut shift_count(&exps[1], vars, counter, tables, ann));
+                    res.push(Instr::SarCl(Reg::Rax));
+                    res.push(Instr::And(BinArgs::ToReg(Reg::Rax, Arg32::Signed(-2))));
+                }
+                Prim::Min | Prim::Max => {
+                    res.append(&mut arith_check(Reg::Rax, tables.error_site(ARITH_ERROR, ann)));
+                    res.push(Instr::Mov(MovArgs::ToReg(
+                        Reg::Rdx,
+                        imm_to_arg64(&exps[1], vars),
+                    )));
+                    res.append(&mut arith_check(Reg::Rdx, tables.error_site(ARITH_ERROR, ann)));
+                    *counter += 1;
+                    let keep = format!("keep_{}", counter);
+                    res.push(Instr::Cmp(BinArgs::ToReg(Reg::Rax, Arg32::Reg(Reg::Rdx))));
+                    res.push(if *p == Prim::Min {
+                        Instr::Jle(keep.clone())
+                    } else {
+                        Instr::Jge(keep.clone())
+                    });
+                    res.push(Instr::Mov(MovArgs::ToReg(Reg::Rax, Arg64::Reg(Reg::Rdx))));
+                    res.push(Instr::Label(keep));
+                }
+                Prim::Neg => {
+                    res.append(&mut arith_check(Reg::Rax, tables.error_site(ARITH_ERROR, ann)));
+                    res.push(Instr::Mov(MovArgs::ToReg(Reg::Rdx, Arg64::Reg(Reg::Rax))));
+                    res.push(Instr::Mov(MovArgs::ToReg(Reg::Rax, Arg64::Signed(0))));
+                    res.push(Instr::Sub(BinArgs::ToReg(Reg::Rax, Arg32::Reg(Reg::Rdx))));
+                    res.push(Instr::Jo(tables.error_site(OVERFLOW, ann)));
+                }
+                Prim::Abs => {
+                    // flips the bits and adds 1 when the sign is set
+                    res.append(&mut arith_check(Reg::Rax, tables.error_site(ARITH_ERROR, ann)));
+                    res.push(Instr::Mov(MovArgs::ToReg(Reg::Rdx, Arg64::Reg(Reg::Rax))));
+                    res.push(Instr::Sar(BinArgs::ToReg(Reg::Rdx, Arg32::Signed(63))));
+                    res.push(Instr::Xor(BinArgs::ToReg(Reg::Rax, Arg32::Reg(Reg::Rdx))));
+                    res.push(Instr::Sub(BinArgs::ToReg(Reg::Rax, Arg32::Reg(Reg::Rdx))));
+                    res.push(Instr::Jo(tables.error_site(OVERFLOW, ann)));
                 }
                 Prim::Add1 => {
-                    res.append(&mut arith_check(Reg::Rax));
+                    res.append(&mut arith_check(Reg::Rax, tables.error_site(ARITH_ERROR, ann)));
                     res.push(Instr::Add(BinArgs::ToReg(Reg::Rax, Arg32::Unsigned(0x2))));
-                    res.push(Instr::Jo(OVERFLOW.to_string()));
+                    res.push(Instr::Jo(tables.error_site(OVERFLOW, ann)));
                 }
                 Prim::Sub1 => {
-                    res.append(&mut arith_check(Reg::Rax));
+                    res.append(&mut arith_check(Reg::Rax, tables.error_site(ARITH_ERROR, ann)));
                     res.push(Instr::Sub(BinArgs::ToReg(Reg::Rax, Arg32::Unsigned(0x2))));
-                    res.push(Instr::Jo(OVERFLOW.to_string()));
+                    res.push(Instr::Jo(tables.error_site(OVERFLOW, ann)));
                 }
                 Prim::Not => {
-                    res.append(&mut logic_check(Reg::Rax));
+                    res.append(&mut logic_check(Reg::Rax, tables.error_site(LOGIC_ERROR, ann)));
                     static BOOL_MASK: u64 = 0x80_00_00_00_00_00_00_00;
                     res.push(Instr::Mov(MovArgs::ToReg(
                         Reg::Rdx,
@@ -242,27 +891,47 @@ fn compile_to_instrs_inner<'a, 'b>(
                     res.push(Instr::Xor(BinArgs::ToReg(Reg::Rax, Arg32::Reg(Reg::Rdx))));
                 }
                 Prim::Print => {
-                    res = vec![
-                        Instr::Mov(MovArgs::ToReg(Reg::Rdi, imm_to_arg64(&exps[0], vars))),
-                        Instr::Sub(BinArgs::ToReg(
-                            Reg::Rsp,
-                            Arg32::Signed(align_stack(stack) + 8),
-                        )),
-                        Instr::Call("print_snake_val".to_string()),
-                        Instr::Add(BinArgs::ToReg(
-                            Reg::Rsp,
-                            Arg32::Signed(align_stack(stack) + 8),
-                        )),
-                    ];
+                    res = vec![Instr::Mov(MovArgs::ToReg(
+                        Reg::Rdi,
+                        imm_to_arg64(&exps[0], vars),
+                    ))];
+                    res.append(&mut call_runtime("print_snake_val", stack));
                 }
                 Prim::IsBool => {
+                    res.append(&mut has_tag(BOOL_TAG, counter));
+                }
+                Prim::IsTuple => {
+                    res.append(&mut has_tag(TUPLE_TAG, counter));
+                }
+                Prim::IsString => {
+                    res.append(&mut has_tag(STRING_TAG, counter));
+                }
+                Prim::StrLen => {
+                    // strings store their length in bytes, the runtime counts the characters
+                    res.append(&mut string_check(Reg::Rax, tables.error_site(STRING_ERROR, ann)));
+                    res.push(Instr::Mov(MovArgs::ToReg(Reg::Rdi, Arg64::Reg(Reg::Rax))));
+                    res.append(&mut call_runtime("snake_strlen", stack));
+                }
+                Prim::StringToNum => {
+                    res.append(&mut string_check(Reg::Rax, tables.error_site(STRING_ERROR, ann)));
+                    res.append(&mut vec![
+                        Instr::Mov(MovArgs::ToReg(Reg::Rdi, Arg64::Reg(Reg::Rax))),
+                        Instr::Mov(MovArgs::ToReg(
+                            Reg::Rsi,
+                            Arg64::Signed(i64::try_from(tables.site(ann)).unwrap()),
+                        )),
+                    ]);
+                    res.append(&mut call_runtime("snake_string_to_num", stack));
+                }
+                Prim::Length => {
+                    res.append(&mut tuple_check(Reg::Rax, tables.error_site(TUPLE_ERROR, ann)));
                     res.push(Instr::Mov(MovArgs::ToReg(
-                        Reg::Rdx,
-                        Arg64::Unsigned(SNAKE_FLS),
+                        Reg::Rax,
+                        Arg64::Mem(MemRef {
+                            reg: Reg::Rax,
+                            offset: -TUPLE_TAG,
+                        }),
                     )));
-                    res.push(Instr::And(BinArgs::ToReg(Reg::Rax, Arg32::Reg(Reg::Rdx))));
-                    res.push(Instr::Shl(BinArgs::ToReg(Reg::Rax, Arg32::Signed(63))));
-                    res.push(Instr::Or(BinArgs::ToReg(Reg::Rax, Arg32::Reg(Reg::Rdx))));
                 }
                 Prim::IsNum => {
                     res.push(Instr::Mov(MovArgs::ToReg(
@@ -274,75 +943,78 @@ fn compile_to_instrs_inner<'a, 'b>(
                     res.push(Instr::Or(BinArgs::ToReg(Reg::Rax, Arg32::Reg(Reg::Rdx))));
                 }
                 Prim::And => {
-                    res.append(&mut logic_check(Reg::Rax));
+                    res.append(&mut logic_check(Reg::Rax, tables.error_site(LOGIC_ERROR, ann)));
                     res.push(Instr::Mov(MovArgs::ToReg(
                         Reg::Rdx,
                         imm_to_arg64(&exps[1], vars),
                     )));
-                    res.append(&mut logic_check(Reg::Rdx));
+                    res.append(&mut logic_check(Reg::Rdx, tables.error_site(LOGIC_ERROR, ann)));
                     res.push(Instr::And(BinArgs::ToReg(Reg::Rax, Arg32::Reg(Reg::Rdx))));
                 }
                 Prim::Or => {
-                    res.append(&mut logic_check(Reg::Rax));
+                    res.append(&mut logic_check(Reg::Rax, tables.error_site(LOGIC_ERROR, ann)));
                     res.push(Instr::Mov(MovArgs::ToReg(
                         Reg::Rdx,
                         imm_to_arg64(&exps[1], vars),
                     )));
-                    res.append(&mut logic_check(Reg::Rdx));
+                    res.append(&mut logic_check(Reg::Rdx, tables.error_site(LOGIC_ERROR, ann)));
                     res.push(Instr::Or(BinArgs::ToReg(Reg::Rax, Arg32::Reg(Reg::Rdx))));
                 }
                 Prim::Lt => {
-                    res.append(&mut sub_for_cmp(exps, vars, false));
+                    res.append(&mut sub_for_cmp(
+                        exps,
+                        vars,
+                        false,
+                        tables.error_site(CMP_ERROR, ann),
+                    ));
                     res.append(&mut is_neg());
                 }
                 Prim::Gt => {
-                    res.append(&mut sub_for_cmp(exps, vars, true));
+                    res.append(&mut sub_for_cmp(
+                        exps,
+                        vars,
+                        true,
+                        tables.error_site(CMP_ERROR, ann),
+                    ));
                     res.append(&mut is_neg());
                 }
                 Prim::Le => {
-                    res.append(&mut sub_for_cmp(exps, vars, true));
+                    res.append(&mut sub_for_cmp(
+                        exps,
+                        vars,
+                        true,
+                        tables.error_site(CMP_ERROR, ann),
+                    ));
                     res.append(&mut is_non_neg());
                 }
                 Prim::Ge => {
-                    res.append(&mut sub_for_cmp(exps, vars, false));
+                    res.append(&mut sub_for_cmp(
+                        exps,
+                        vars,
+                        false,
+                        tables.error_site(CMP_ERROR, ann),
+                    ));
                     res.append(&mut is_non_neg());
                 }
                 Prim::Eq => {
-                    res.push(Instr::Mov(MovArgs::ToReg(
-                        Reg::Rdx,
-                        imm_to_arg64(&exps[1], vars),
-                    )));
-                    *counter += 1;
-                    let fls_label = format!("false_{}", counter);
-                    let done_label = format!("cmp_done_{}", counter);
-                    res.append(&mut vec![
-                        Instr::Cmp(BinArgs::ToReg(Reg::Rax, Arg32::Reg(Reg::Rdx))),
-                        Instr::Jne(fls_label.clone()),
-                        Instr::Mov(MovArgs::ToReg(Reg::Rax, Arg64::Unsigned(SNAKE_TRU))),
-                        Instr::Jmp(done_label.clone()),
-                        Instr::Label(fls_label),
-                        Instr::Mov(MovArgs::ToReg(Reg::Rax, Arg64::Unsigned(SNAKE_FLS))),
-                        Instr::Label(done_label),
-                    ]);
+                    res.append(&mut compile_equal(&exps[1], stack, vars, counter));
                 }
                 Prim::Neq => {
+                    res.append(&mut compile_equal(&exps[1], stack, vars, counter));
+                    static BOOL_MASK: u64 = 0x80_00_00_00_00_00_00_00;
                     res.push(Instr::Mov(MovArgs::ToReg(
                         Reg::Rdx,
-                        imm_to_arg64(&exps[1], vars),
+                        Arg64::Unsigned(BOOL_MASK),
                     )));
-                    *counter += 1;
-                    let fls_label = format!("false_{}", counter);
-                    let done_label = format!("cmp_done_{}", counter);
-                    res.append(&mut vec![
-                        Instr::Cmp(BinArgs::ToReg(Reg::Rax, Arg32::Reg(Reg::Rdx))),
-                        Instr::Je(fls_label.clone()),
-                        Instr::Mov(MovArgs::ToReg(Reg::Rax, Arg64::Unsigned(SNAKE_TRU))),
-                        Instr::Jmp(done_label.clone()),
-                        Instr::Label(fls_label),
-                        Instr::Mov(MovArgs::ToReg(Reg::Rax, Arg64::Unsigned(SNAKE_FLS))),
-                        Instr::Label(done_label),
-                    ]);
+                    res.push(Instr::Xor(BinArgs::ToReg(Reg::Rax, Arg32::Reg(Reg::Rdx))));
                 }
+                Prim::MakeTuple
+                | Prim::TupleGet
+                | Prim::TupleSet
+                | Prim::Concat
+                | Prim::CharAt
+                | Prim::Substr
+                | Prim::NumToString => unreachable!(),
             }
             res
         }
@@ -350,36 +1022,146 @@ fn compile_to_instrs_inner<'a, 'b>(
             var,
             bound_exp,
             body,
-            ann,
+            ann: _,
         } => {
-            let mut res = compile_to_instrs_inner(&bound_exp, counter, stack, vars, functions);
-            let offset: i32 = ((stack + 1) * -8).try_into().unwrap();
+            let mut res =
+                compile_to_instrs_inner(bound_exp, counter, stack, vars, functions, tables);
+            let offset: i32 = (stack + 1) * -8;
             res.push(Instr::Mov(MovArgs::ToMem(
                 MemRef {
                     reg: Reg::Rsp,
-                    offset: offset,
+                    offset,
                 },
                 Reg32::Reg(Reg::Rax),
             )));
             vars.insert(var.clone(), offset);
 
             res.append(&mut compile_to_instrs_inner(
-                &body,
+                body,
                 counter,
                 stack + 1,
                 vars,
                 functions,
+                tables,
+            ));
+            res
+        }
+        // the value of first is left in rax for second to overwrite
+        SeqExp::Seq { first, second, .. } => {
+            let mut res = compile_to_instrs_inner(first, counter, stack, vars, functions, tables);
+            res.append(&mut compile_to_instrs_inner(
+                second, counter, stack, vars, functions, tables,
+            ));
+            res
+        }
+        // rax holds the false that ended the loop when it's done
+        SeqExp::While { cond, body, ann } => {
+            *counter += 1;
+            let loop_label = format!("while_{}", counter);
+            let done_label = format!("while_done_{}", counter);
+            let mut res = vec![Instr::Label(loop_label.clone())];
+            res.append(&mut compile_to_instrs_inner(
+                cond,
+                counter,
+                stack,
+                &mut vars.clone(),
+                functions,
+                tables,
+            ));
+            res.append(&mut if_check(Reg::Rax, tables.error_site(WHILE_ERROR, ann)));
+            res.append(&mut vec![
+                Instr::Mov(MovArgs::ToReg(Reg::Rdx, Arg64::Unsigned(SNAKE_FLS))),
+                Instr::Cmp(BinArgs::ToReg(Reg::Rax, Arg32::Reg(Reg::Rdx))),
+                Instr::Je(done_label.clone()),
+            ]);
+            res.append(&mut compile_to_instrs_inner(
+                body,
+                counter,
+                stack,
+                &mut vars.clone(),
+                functions,
+                tables,
+            ));
+            res.push(Instr::Jmp(loop_label));
+            res.push(Instr::Label(done_label));
+            res
+        }
+        SeqExp::For {
+            var,
+            from,
+            to,
+            body,
+            ann,
+        } => {
+            *counter += 1;
+            let loop_label = format!("for_{}", counter);
+            let done_label = format!("for_done_{}", counter);
+            let offset: i32 = (stack + 1) * -8;
+            let slot = MemRef {
+                reg: Reg::Rsp,
+                offset,
+            };
+            let mut res = imm_to_rax(from, vars);
+            res.append(&mut arith_check(Reg::Rax, tables.error_site(ARITH_ERROR, ann)));
+            res.push(Instr::Mov(MovArgs::ToMem(slot, Reg32::Reg(Reg::Rax))));
+            res.append(&mut imm_to_rax(to, vars));
+            res.append(&mut arith_check(Reg::Rax, tables.error_site(ARITH_ERROR, ann)));
+            // the body doesn't run at all when from is past to
+            res.append(&mut vec![
+                Instr::Mov(MovArgs::ToReg(Reg::Rdx, Arg64::Reg(Reg::Rax))),
+                Instr::Mov(MovArgs::ToReg(Reg::Rax, Arg64::Mem(slot))),
+                Instr::Cmp(BinArgs::ToReg(Reg::Rax, Arg32::Reg(Reg::Rdx))),
+                Instr::Jg(done_label.clone()),
+                Instr::Label(loop_label.clone()),
+            ]);
+            let mut body_vars = vars.clone();
+            body_vars.insert(var.clone(), offset);
+            res.append(&mut compile_to_instrs_inner(
+                body,
+                counter,
+                stack + 1,
+                &mut body_vars,
+                functions,
+                tables,
             ));
+            // stopping at to instead of past it can't overflow
+            res.append(&mut vec![
+                Instr::Mov(MovArgs::ToReg(Reg::Rax, Arg64::Mem(slot))),
+                Instr::Mov(MovArgs::ToReg(Reg::Rdx, imm_to_arg64(to, vars))),
+                Instr::Cmp(BinArgs::ToReg(Reg::Rax, Arg32::Reg(Reg::Rdx))),
+                Instr::Je(done_label.clone()),
+                Instr::Add(BinArgs::ToReg(Reg::Rax, Arg32::Signed(2))),
+                Instr::Mov(MovArgs::ToMem(slot, Reg32::Reg(Reg::Rax))),
+                Instr::Jmp(loop_label),
+                Instr::Label(done_label),
+                Instr::Mov(MovArgs::ToReg(Reg::Rax, Arg64::Unsigned(SNAKE_FLS))),
+            ]);
+            res
+        }
+        // the value is left in rax as the result
+        SeqExp::Assign { var, value, .. } => {
+            let mut res = imm_to_rax(value, vars);
+            res.push(Instr::Mov(MovArgs::ToMem(
+                MemRef {
+                    reg: Reg::Rsp,
+                    offset: vars[var],
+                },
+                Reg32::Reg(Reg::Rax),
+            )));
             res
         }
         SeqExp::If {
             cond,
             thn,
             els,
+            kind,
             ann,
         } => {
             let mut res = imm_to_rax(cond, vars);
-            res.append(&mut if_check(Reg::Rax));
+            res.append(&mut match kind {
+                IfKind::If => if_check(Reg::Rax, tables.error_site(IF_ERROR, ann)),
+                IfKind::Logic => logic_check(Reg::Rax, tables.error_site(LOGIC_ERROR, ann)),
+            });
             *counter += 1;
             let els_label = format!("else_{}", counter);
             let done_label = format!("done_{}", counter);
@@ -395,75 +1177,150 @@ fn compile_to_instrs_inner<'a, 'b>(
                 stack,
                 &mut vars.clone(),
                 functions,
+                tables,
             ));
             res.push(Instr::Jmp(done_label.clone()));
 
             res.push(Instr::Label(els_label));
             res.append(&mut compile_to_instrs_inner(
-                els, counter, stack, vars, functions,
+                els, counter, stack, vars, functions, tables,
             ));
             res.push(Instr::Label(done_label));
             res
         }
-        SeqExp::FunDefs { decls, body, ann } => {
+        SeqExp::FunDefs { decls, body, ann: _ } => {
             // locally defined functions
             *counter += 1;
             let body_label = format!("body_{}", counter);
             let mut res = vec![Instr::Jmp(body_label.clone())];
+            // the functions of a group can jump to each other
             for decl in decls {
                 functions.insert(decl.name.clone(), stack);
+            }
+            for decl in decls {
                 push_params(stack, vars, &decl.parameters);
                 res.push(Instr::Label(format!("func_{}", decl.name.clone())));
                 res.extend(compile_to_instrs_inner(
                     &decl.body,
                     counter,
-                    i32::try_from(decl.parameters.len()).unwrap(),
+                    stack + i32::try_from(decl.parameters.len()).unwrap(),
                     vars,
                     functions,
+                    tables,
                 ));
                 res.push(Instr::Ret);
             }
             res.push(Instr::Label(body_label));
             res.extend(compile_to_instrs_inner(
-                &body, counter, stack, vars, functions,
+                body, counter, stack, vars, functions, tables,
             ));
             res
         }
         SeqExp::InternalTailCall(func, args, _) => {
-            return compile_tail_call(func.clone(), args, stack, functions[func], vars);
+            compile_tail_call(func.clone(), args, stack, functions[func], vars)
         }
         SeqExp::ExternalCall {
             fun_name,
             args,
             is_tail,
-            ann,
+            ann: _,
         } => {
             if *is_tail {
                 return compile_tail_call(fun_name.clone(), args, stack, 0, vars);
             }
-            let mut res = vec![];
             let stack_top = align_stack(stack);
-            // record called function's parameters to [stack]
-            let mut offset = 16; // extra 8 is return address alloc
-            for arg in args {
-                res.push(Instr::Mov(MovArgs::ToReg(
-                    Reg::Rax,
-                    imm_to_arg64(arg, vars),
-                )));
-                res.push(Instr::Mov(MovArgs::ToMem(
-                    MemRef {
-                        reg: Reg::Rsp,
-                        offset: -(stack_top + offset),
-                    },
-                    Reg32::Reg(Reg::Rax),
-                )));
-                offset += 8;
-            }
+            let mut res = push_call_args(args, stack_top, vars);
             res.push(Instr::Sub(BinArgs::ToReg(
                 Reg::Rsp,
                 Arg32::Signed(stack_top),
             )));
             res.push(Instr::Call(format!("func_{}", fun_name)));
+            res.append(&mut record_frame(stack_top, stack, counter, &mut tables.frames));
+            res.push(Instr::Add(BinArgs::ToReg(
+                Reg::Rsp,
+                Arg32::Signed(stack_top),
+            )));
+            res
+        }
+        SeqExp::MakeClosure {
+            fun_name,
+            arity,
+            captured,
+            ann,
+        } => {
+            // layout: [arity][code pointer][number of captured values][captured values...]
+            let arity = i64::try_from(*arity).unwrap();
+            let len = i64::try_from(captured.len()).unwrap();
+            let size = 8 * (i32::try_from(captured.len()).unwrap() + 3);
+            let mut res = reserve_heap(Arg64::Signed(size.into()), stack, tables.site(ann), counter);
+            res.append(&mut vec![
+                Instr::Mov(MovArgs::ToReg(Reg::Rax, Arg64::Signed(arity << 1))),
+                store_heap(0),
+                Instr::Lea(Reg::Rax, format!("func_{}", fun_name)),
+                store_heap(8),
+                Instr::Mov(MovArgs::ToReg(Reg::Rax, Arg64::Signed(len << 1))),
+                store_heap(16),
+            ]);
+            for (i, exp) in captured.iter().enumerate() {
+                res.append(&mut imm_to_rax(exp, vars));
+                res.push(store_heap(8 * (i32::try_from(i).unwrap() + 3)));
+            }
+            res.append(&mut vec![
+                Instr::Mov(MovArgs::ToReg(Reg::Rax, Arg64::Reg(HEAP_PTR))),
+                Instr::Add(BinArgs::ToReg(Reg::Rax, Arg32::Signed(CLOSURE_TAG))),
+                Instr::Add(BinArgs::ToReg(HEAP_PTR, Arg32::Signed(size))),
+            ]);
+            res
+        }
+        SeqExp::ClosureCall {
+            closure,
+            args,
+            is_tail,
+            ann,
+        } => {
+            // keep the untagged closure in Rsi while the arguments are placed
+            let nargs = i32::try_from(args.len()).unwrap();
+            let mut res = imm_to_rax(closure, vars);
+            res.append(&mut closure_check(Reg::Rax, tables.error_site(CALL_ERROR, ann)));
+            res.append(&mut vec![
+                Instr::Mov(MovArgs::ToReg(Reg::Rsi, Arg64::Reg(Reg::Rax))),
+                Instr::Sub(BinArgs::ToReg(Reg::Rsi, Arg32::Signed(CLOSURE_TAG))),
+                Instr::Mov(MovArgs::ToReg(
+                    Reg::Rax,
+                    Arg64::Mem(MemRef {
+                        reg: Reg::Rsi,
+                        offset: 0,
+                    }),
+                )),
+                Instr::Cmp(BinArgs::ToReg(Reg::Rax, Arg32::Signed(nargs << 1))),
+                Instr::Jne(tables.error_site(ARITY_ERROR, ann)),
+            ]);
+            let code = Instr::Mov(MovArgs::ToReg(
+                Reg::Rax,
+                Arg64::Mem(MemRef {
+                    reg: Reg::Rsi,
+                    offset: 8,
+                }),
+            ));
+            if *is_tail {
+                res.append(&mut place_tail_args(args, stack, 0, vars));
+                res.append(&mut copy_captured(-8 * (nargs + 1), counter));
+                res.push(code);
+                res.push(Instr::JmpReg(Reg::Rax));
+                return res;
+            }
+            let stack_top = align_stack(stack);
+            res.append(&mut push_call_args(args, stack_top, vars));
+            res.append(&mut copy_captured(-(stack_top + 16 + 8 * nargs), counter));
+            res.append(&mut vec![
+                code,
+                Instr::Sub(BinArgs::ToReg(
+                    Reg::Rsp,
+                    Arg32::Signed(stack_top),
+                )),
+                Instr::CallReg(Reg::Rax),
+            ]);
+            res.append(&mut record_frame(stack_top, stack, counter, &mut tables.frames));
             res.push(Instr::Add(BinArgs::ToReg(
                 Reg::Rsp,
                 Arg32::Signed(stack_top),
@@ -473,16 +1330,162 @@ fn compile_to_instrs_inner<'a, 'b>(
     }
 }
 
+/// return the label to put right after a call, recording the caller's frame
+fn record_frame(
+    stack_top: i32,
+    stack: i32,
+    counter: &mut u32,
+    frames: &mut Vec<FrameInfo>,
+) -> Vec<Instr> {
+    *counter += 1;
+    let return_label = format!("return_{}", counter);
+    frames.push(FrameInfo {
+        return_label: return_label.clone(),
+        stack_top,
+        slots: stack,
+    });
+    vec![Instr::Label(return_label)]
+}
+
+/// return instructions writing [args] to the parameter slots of a function called with
+/// rsp lowered by [stack_top]
+fn push_call_args(args: &[ImmExp], stack_top: i32, vars: &HashMap<String, i32>) -> Vec<Instr> {
+    let mut res = vec![];
+    let mut offset = 16; // extra 8 is return address alloc
+    for arg in args {
+        res.push(Instr::Mov(MovArgs::ToReg(
+            Reg::Rax,
+            imm_to_arg64(arg, vars),
+        )));
+        res.push(Instr::Mov(MovArgs::ToMem(
+            MemRef {
+                reg: Reg::Rsp,
+                offset: -(stack_top + offset),
+            },
+            Reg32::Reg(Reg::Rax),
+        )));
+        offset += 8;
+    }
+    res
+}
+
+/// return instructions copying the values captured by the closure whose untagged
+/// address is in Rsi to the stack, starting [offset] bytes from rsp and going down
+fn copy_captured(offset: i32, counter: &mut u32) -> Vec<Instr> {
+    *counter += 1;
+    let loop_label = format!("copy_captured_{}", counter);
+    let done_label = format!("copy_captured_done_{}", counter);
+    vec![
+        // the count is a snake number, so it goes down by 2 per value
+        Instr::Mov(MovArgs::ToReg(
+            Reg::Rcx,
+            Arg64::Mem(MemRef {
+                reg: Reg::Rsi,
+                offset: 16,
+            }),
+        )),
+        Instr::Mov(MovArgs::ToReg(Reg::Rdi, Arg64::Reg(Reg::Rsp))),
+        Instr::Add(BinArgs::ToReg(Reg::Rdi, Arg32::Signed(offset))),
+        Instr::Mov(MovArgs::ToReg(Reg::R8, Arg64::Reg(Reg::Rsi))),
+        Instr::Add(BinArgs::ToReg(Reg::R8, Arg32::Signed(24))),
+        Instr::Label(loop_label.clone()),
+        Instr::Cmp(BinArgs::ToReg(Reg::Rcx, Arg32::Signed(0))),
+        Instr::Je(done_label.clone()),
+        Instr::Mov(MovArgs::ToReg(
+            Reg::Rdx,
+            Arg64::Mem(MemRef {
+                reg: Reg::R8,
+                offset: 0,
+            }),
+        )),
+        Instr::Mov(MovArgs::ToMem(
+            MemRef {
+                reg: Reg::Rdi,
+                offset: 0,
+            },
+            Reg32::Reg(Reg::Rdx),
+        )),
+        Instr::Add(BinArgs::ToReg(Reg::R8, Arg32::Signed(8))),
+        Instr::Sub(BinArgs::ToReg(Reg::Rdi, Arg32::Signed(8))),
+        Instr::Sub(BinArgs::ToReg(Reg::Rcx, Arg32::Signed(2))),
+        Instr::Jmp(loop_label),
+        Instr::Label(done_label),
+    ]
+}
+
+/// compare the value in Rax with [other]. Identical values are equal, two tuples, two
+/// strings or two closures are compared structurally by the runtime, anything else is
+/// unequal.
+fn compile_equal(
+    other: &ImmExp,
+    stack: i32,
+    vars: &HashMap<String, i32>,
+    counter: &mut u32,
+) -> Vec<Instr> {
+    *counter += 1;
+    let tru_label = format!("eq_true_{}", counter);
+    let fls_label = format!("eq_false_{}", counter);
+    let done_label = format!("eq_done_{}", counter);
+    let structural_label = format!("eq_structural_{}", counter);
+    let mut res = vec![
+        Instr::Mov(MovArgs::ToReg(Reg::Rdx, imm_to_arg64(other, vars))),
+        Instr::Cmp(BinArgs::ToReg(Reg::Rax, Arg32::Reg(Reg::Rdx))),
+        Instr::Je(tru_label.clone()),
+        // both have the same tag, the one of tuples, strings or closures
+        Instr::Mov(MovArgs::ToReg(Reg::Rcx, Arg64::Reg(Reg::Rax))),
+        Instr::And(BinArgs::ToReg(Reg::Rcx, Arg32::Signed(TAG_MASK))),
+        Instr::Mov(MovArgs::ToReg(Reg::Rdi, Arg64::Reg(Reg::Rdx))),
+        Instr::And(BinArgs::ToReg(Reg::Rdi, Arg32::Signed(TAG_MASK))),
+        Instr::Cmp(BinArgs::ToReg(Reg::Rcx, Arg32::Reg(Reg::Rdi))),
+        Instr::Jne(fls_label.clone()),
+        Instr::Cmp(BinArgs::ToReg(Reg::Rcx, Arg32::Signed(TUPLE_TAG))),
+        Instr::Je(structural_label.clone()),
+        Instr::Cmp(BinArgs::ToReg(Reg::Rcx, Arg32::Signed(STRING_TAG))),
+        Instr::Je(structural_label.clone()),
+        Instr::Cmp(BinArgs::ToReg(Reg::Rcx, Arg32::Signed(CLOSURE_TAG))),
+        Instr::Jne(fls_label.clone()),
+        Instr::Label(structural_label),
+    ];
+    res.push(Instr::Mov(MovArgs::ToReg(Reg::Rdi, Arg64::Reg(Reg::Rax))));
+    res.push(Instr::Mov(MovArgs::ToReg(Reg::Rsi, Arg64::Reg(Reg::Rdx))));
+    res.append(&mut call_runtime("snake_equal", stack));
+    res.append(&mut vec![
+        Instr::Jmp(done_label.clone()),
+        Instr::Label(tru_label),
+        Instr::Mov(MovArgs::ToReg(Reg::Rax, Arg64::Unsigned(SNAKE_TRU))),
+        Instr::Jmp(done_label.clone()),
+        Instr::Label(fls_label),
+        Instr::Mov(MovArgs::ToReg(Reg::Rax, Arg64::Unsigned(SNAKE_FLS))),
+        Instr::Label(done_label),
+    ]);
+    res
+}
+
 fn compile_tail_call(
     func: String,
     args: &[ImmExp],
     stack: i32,
     decl_stack: i32, // stack size when the called function is declared
     vars: &HashMap<String, i32>,
+) -> Vec<Instr> {
+    let mut res = place_tail_args(args, stack, decl_stack, vars);
+    res.push(Instr::Jmp(format!("func_{}", func)));
+    res
+}
+
+/// return instructions writing [args] to the parameter slots of a function declared
+/// at [decl_stack], only using Rax
+fn place_tail_args(
+    args: &[ImmExp],
+    stack: i32,
+    decl_stack: i32,
+    vars: &HashMap<String, i32>,
 ) -> Vec<Instr> {
     let mut res = vec![];
     // overwrite current stack with function arguments
-    // need to save variables to lower stack addresses to avoid overwriting them
+    // need to save variables to lower stack addresses to avoid overwriting them,
+    // below both the live variables and the slots the arguments are written to
+    let saved_base = stack.max(decl_stack + i32::try_from(args.len()).unwrap());
     let mut var_args = HashMap::<String, i32>::new();
     for arg in args {
         if let ImmExp::Var(v) = arg {
@@ -491,11 +1494,11 @@ fn compile_tail_call(
             }
             var_args.insert(
                 v.clone(),
-                8 * (i32::try_from(var_args.len()).unwrap() + stack + 1),
+                -8 * (i32::try_from(var_args.len()).unwrap() + saved_base + 1),
             );
             res.push(Instr::Mov(MovArgs::ToReg(
                 Reg::Rax,
-                imm_to_arg64(arg, &vars),
+                imm_to_arg64(arg, vars),
             )));
             res.push(Instr::Mov(MovArgs::ToMem(
                 MemRef {
@@ -519,7 +1522,7 @@ fn compile_tail_call(
             res.push(Instr::Mov(MovArgs::ToMem(
                 MemRef {
                     reg: Reg::Rsp,
-                    offset: offset,
+                    offset,
                 },
                 Reg32::Reg(Reg::Rax),
             )));
@@ -532,23 +1535,37 @@ fn compile_tail_call(
         res.push(Instr::Mov(MovArgs::ToMem(
             MemRef {
                 reg: Reg::Rsp,
-                offset: offset,
+                offset,
             },
             Reg32::Reg(Reg::Rax),
         )));
     }
-    res.push(Instr::Jmp(format!("func_{}", func)));
     res
 }
 
 /* Feel free to add any helper functions you need */
-fn compile_to_instrs(e: &SeqExp<()>, counter: &mut u32) -> Vec<Instr> {
-    let mut is = compile_to_instrs_inner(e, counter, 0, &mut HashMap::new(), &mut HashMap::new());
+fn compile_to_instrs<Ann: Clone + Eq + Hash>(
+    e: &SeqExp<Ann>,
+    counter: &mut u32,
+    tables: &mut DataTables<Ann>,
+) -> Vec<Instr> {
+    let mut is = compile_to_instrs_inner(
+        e,
+        counter,
+        0,
+        &mut HashMap::new(),
+        &mut HashMap::new(),
+        tables,
+    );
     is.push(Instr::Ret);
     is
 }
 
-fn compile_func_to_instr(f: &FunDecl<SeqExp<()>, ()>, counter: &mut u32) -> Vec<Instr> {
+fn compile_func_to_instr<Ann: Clone + Eq + Hash>(
+    f: &FunDecl<SeqExp<Ann>, Ann>,
+    counter: &mut u32,
+    tables: &mut DataTables<Ann>,
+) -> Vec<Instr> {
     let mut is = vec![Instr::Label(format!("func_{}", f.name))];
     let mut vars = HashMap::<String, i32>::new();
     push_params(0, &mut vars, &f.parameters);
@@ -558,6 +1575,7 @@ fn compile_func_to_instr(f: &FunDecl<SeqExp<()>, ()>, counter: &mut u32) -> Vec<
         f.parameters.len().try_into().unwrap(),
         &mut vars,
         &mut HashMap::new(),
+        tables,
     ));
     is.push(Instr::Ret);
     is
@@ -579,74 +1597,301 @@ fn push_params(stack: i32, vars: &mut HashMap<String, i32>, params: &[String]) {
     }
 }
 
+// Every handler is entered from an error stub, with the site id in Rcx
 fn error_handle_instr() -> Vec<Instr> {
     vec![
         Instr::Label(ARITH_ERROR.to_string()),
         Instr::Mov(MovArgs::ToReg(Reg::Rdi, Arg64::Signed(0))),
         Instr::Mov(MovArgs::ToReg(Reg::Rsi, Arg64::Reg(Reg::Rax))),
+        Instr::Mov(MovArgs::ToReg(Reg::Rdx, Arg64::Reg(Reg::Rcx))),
         Instr::Call(SNAKE_ERROR.to_string()),
         Instr::Label(CMP_ERROR.to_string()),
         Instr::Mov(MovArgs::ToReg(Reg::Rdi, Arg64::Signed(1))),
         Instr::Mov(MovArgs::ToReg(Reg::Rsi, Arg64::Reg(Reg::Rax))),
+        Instr::Mov(MovArgs::ToReg(Reg::Rdx, Arg64::Reg(Reg::Rcx))),
         Instr::Call(SNAKE_ERROR.to_string()),
         Instr::Label(OVERFLOW.to_string()),
         Instr::Mov(MovArgs::ToReg(Reg::Rdi, Arg64::Signed(2))),
         Instr::Mov(MovArgs::ToReg(Reg::Rsi, Arg64::Reg(Reg::Rax))),
+        Instr::Mov(MovArgs::ToReg(Reg::Rdx, Arg64::Reg(Reg::Rcx))),
         Instr::Call(SNAKE_ERROR.to_string()),
         Instr::Label(IF_ERROR.to_string()),
         Instr::Mov(MovArgs::ToReg(Reg::Rdi, Arg64::Signed(3))),
         Instr::Mov(MovArgs::ToReg(Reg::Rsi, Arg64::Reg(Reg::Rax))),
+        Instr::Mov(MovArgs::ToReg(Reg::Rdx, Arg64::Reg(Reg::Rcx))),
         Instr::Call(SNAKE_ERROR.to_string()),
         Instr::Label(LOGIC_ERROR.to_string()),
         Instr::Mov(MovArgs::ToReg(Reg::Rdi, Arg64::Signed(4))),
         Instr::Mov(MovArgs::ToReg(Reg::Rsi, Arg64::Reg(Reg::Rax))),
+        Instr::Mov(MovArgs::ToReg(Reg::Rdx, Arg64::Reg(Reg::Rcx))),
+        Instr::Call(SNAKE_ERROR.to_string()),
+        Instr::Label(TUPLE_ERROR.to_string()),
+        Instr::Mov(MovArgs::ToReg(Reg::Rdi, Arg64::Signed(5))),
+        Instr::Mov(MovArgs::ToReg(Reg::Rsi, Arg64::Reg(Reg::Rax))),
+        Instr::Mov(MovArgs::ToReg(Reg::Rdx, Arg64::Reg(Reg::Rcx))),
+        Instr::Call(SNAKE_ERROR.to_string()),
+        // the offending index is in Rdx for the two index errors
+        Instr::Label(INDEX_ERROR.to_string()),
+        Instr::Mov(MovArgs::ToReg(Reg::Rdi, Arg64::Signed(6))),
+        Instr::Mov(MovArgs::ToReg(Reg::Rsi, Arg64::Reg(Reg::Rdx))),
+        Instr::Mov(MovArgs::ToReg(Reg::Rdx, Arg64::Reg(Reg::Rcx))),
+        Instr::Call(SNAKE_ERROR.to_string()),
+        Instr::Label(BOUNDS_ERROR.to_string()),
+        Instr::Mov(MovArgs::ToReg(Reg::Rdi, Arg64::Signed(7))),
+        Instr::Mov(MovArgs::ToReg(Reg::Rsi, Arg64::Reg(Reg::Rdx))),
+        Instr::Mov(MovArgs::ToReg(Reg::Rdx, Arg64::Reg(Reg::Rcx))),
+        Instr::Call(SNAKE_ERROR.to_string()),
+        Instr::Label(CALL_ERROR.to_string()),
+        Instr::Mov(MovArgs::ToReg(Reg::Rdi, Arg64::Signed(8))),
+        Instr::Mov(MovArgs::ToReg(Reg::Rsi, Arg64::Reg(Reg::Rax))),
+        Instr::Mov(MovArgs::ToReg(Reg::Rdx, Arg64::Reg(Reg::Rcx))),
+        Instr::Call(SNAKE_ERROR.to_string()),
+        // Rax holds the arity of the called closure
+        Instr::Label(ARITY_ERROR.to_string()),
+        Instr::Mov(MovArgs::ToReg(Reg::Rdi, Arg64::Signed(9))),
+        Instr::Mov(MovArgs::ToReg(Reg::Rsi, Arg64::Reg(Reg::Rax))),
+        Instr::Mov(MovArgs::ToReg(Reg::Rdx, Arg64::Reg(Reg::Rcx))),
+        Instr::Call(SNAKE_ERROR.to_string()),
+        // 10 is out of memory, raised by the runtime itself. Rax holds the
+        // dividend.
+        Instr::Label(DIV_ZERO_ERROR.to_string()),
+        Instr::Mov(MovArgs::ToReg(Reg::Rdi, Arg64::Signed(11))),
+        Instr::Mov(MovArgs::ToReg(Reg::Rsi, Arg64::Reg(Reg::Rax))),
+        Instr::Mov(MovArgs::ToReg(Reg::Rdx, Arg64::Reg(Reg::Rcx))),
+        Instr::Call(SNAKE_ERROR.to_string()),
+        // 13, a string that isn't a number, is raised by the runtime too
+        Instr::Label(STRING_ERROR.to_string()),
+        Instr::Mov(MovArgs::ToReg(Reg::Rdi, Arg64::Signed(12))),
+        Instr::Mov(MovArgs::ToReg(Reg::Rsi, Arg64::Reg(Reg::Rax))),
+        Instr::Mov(MovArgs::ToReg(Reg::Rdx, Arg64::Reg(Reg::Rcx))),
+        Instr::Call(SNAKE_ERROR.to_string()),
+        Instr::Label(WHILE_ERROR.to_string()),
+        Instr::Mov(MovArgs::ToReg(Reg::Rdi, Arg64::Signed(14))),
+        Instr::Mov(MovArgs::ToReg(Reg::Rsi, Arg64::Reg(Reg::Rax))),
+        Instr::Mov(MovArgs::ToReg(Reg::Rdx, Arg64::Reg(Reg::Rcx))),
         Instr::Call(SNAKE_ERROR.to_string()),
     ]
 }
 
-pub fn check_prog<Span>(p: &SurfProg<Span>) -> Result<(), CompileErr<Span>>
+fn error_stub_instrs(stubs: &[ErrorStub]) -> Vec<Instr> {
+    let mut res = vec![];
+    for stub in stubs {
+        res.append(&mut vec![
+            Instr::Label(stub.label.clone()),
+            Instr::Mov(MovArgs::ToReg(
+                Reg::Rcx,
+                Arg64::Signed(i64::try_from(stub.site).unwrap()),
+            )),
+            Instr::Jmp(stub.handler.to_string()),
+        ]);
+    }
+    res
+}
+
+pub fn check_prog<Span>(p: &SurfProg<Span>) -> Result<(), Vec<CompileErr<Span>>>
 where
     Span: Clone,
 {
-    let res = checker::check_prog(p, &HashMap::new());
-    res
+    checker::check_prog(p, &HashMap::new())
 }
 
-pub fn compile_to_string<Span>(p: &SurfProg<Span>) -> Result<String, CompileErr<Span>>
+pub fn warnings<Span>(p: &SurfProg<Span>) -> Vec<CompileWarning<Span>>
 where
     Span: Clone,
+{
+    checker::warnings(p)
+}
+
+/// Assembly and the location of each site its runtime errors report
+pub struct Compiled<Span> {
+    pub asm: String,
+    pub sites: Vec<Span>,
+}
+
+/// Compiles the program to assembly. Runtime errors report the [Span] of the
+/// expression they come from, as formatted by its Display implementation.
+pub fn compile_to_string<Span>(p: &SurfProg<Span>) -> Result<String, Vec<CompileErr<Span>>>
+where
+    Span: Clone + Display + Eq + Hash,
+{
+    compile_prog(p).map(|compiled| compiled.asm)
+}
+
+/// Like [compile_to_string], also returning the [Span] of each site id
+pub fn compile_prog<Span>(p: &SurfProg<Span>) -> Result<Compiled<Span>, Vec<CompileErr<Span>>>
+where
+    Span: Clone + Display + Eq + Hash,
 {
     checker::check_prog(p, &HashMap::new())?;
-    let (global_functions, main) = lambda_lift(&p);
-    println!("global function size = {}", global_functions.len());
+    let (global_functions, main) = lambda_lift(p);
     let program = sequentializer::seq_prog(&global_functions, &main);
 
     let mut counter: u32 = 0;
+    let mut tables = DataTables {
+        frames: vec![],
+        locations: vec![],
+        sites: HashMap::new(),
+        error_stubs: vec![],
+    };
     let functions_is: String = program
         .funs
         .iter()
-        .map(|f| instrs_to_string(&compile_func_to_instr(&f, &mut counter)))
+        .map(|f| instrs_to_string(&compile_func_to_instr(f, &mut counter, &mut tables)))
+        .collect();
+    let main_is = instrs_to_string(&compile_to_instrs(&program.main, &mut counter, &mut tables));
+    // layout: [number of entries][return address, stack_top, slots]...
+    let frame_table: String = tables
+        .frames
+        .iter()
+        .map(|frame| {
+            format!(
+                "        dq {}, {}, {}\n",
+                frame.return_label, frame.stack_top, frame.slots
+            )
+        })
+        .collect();
+    // layout: [number of sites][address of the location string of each site]...
+    // Different locations can print the same, e.g. two spans that start at
+    // the same place, so the strings are shared as well.
+    let mut strings: Vec<String> = vec![];
+    let mut site_table = String::new();
+    for location in &tables.locations {
+        let location = location.to_string();
+        let ix = match strings.iter().position(|s| *s == location) {
+            Some(ix) => ix,
+            None => {
+                strings.push(location);
+                strings.len() - 1
+            }
+        };
+        site_table.push_str(&format!("        dq site_{}\n", ix));
+    }
+    // as bytes, since a path can contain anything
+    let site_strings: String = strings
+        .iter()
+        .enumerate()
+        .map(|(ix, location)| {
+            let bytes: Vec<String> = location.bytes().map(|b| b.to_string()).collect();
+            format!("site_{}:\n        db {}, 0\n", ix, bytes.join(", "))
+        })
         .collect();
-    let main_is = instrs_to_string(&compile_to_instrs(&program.main, &mut counter));
 
     let res = format!(
         "\
+        section .data
+        global snake_frame_table
+        global snake_site_table
+snake_frame_table:
+        dq {}
+{}
+snake_site_table:
+        dq {}
+{}
+{}
         section .text
         global start_here
         extern snake_error
+        extern snake_equal
+        extern snake_gc
+        extern snake_concat
+        extern snake_char_at
+        extern snake_substr
+        extern snake_num_to_string
+        extern snake_string_to_num
+        extern snake_strlen
         extern print_snake_val
 {}
 {}
+{}
 start_here:
+        push r15
+        push r14
+        mov r15, rdi
+        mov r14, rsi
         call main
+        pop r14
+        pop r15
         ret
 main:
 {}
 ",
+        tables.frames.len(),
+        frame_table,
+        tables.locations.len(),
+        site_table,
+        site_strings,
         instrs_to_string(&error_handle_instr()),
+        instrs_to_string(&error_stub_instrs(&tables.error_stubs)),
         functions_is,
         main_is
     );
-    println!("{}", res);
+    Ok(Compiled {
+        asm: res,
+        sites: tables.locations,
+    })
+}
+
+// A stage of the compiler whose output can be inspected
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Stage {
+    Ast,
+    Checked,
+    Uniquified,
+    Lifted,
+    Seq,
+    Asm,
+}
+
+impl FromStr for Stage {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "ast" => Ok(Stage::Ast),
+            "checked" => Ok(Stage::Checked),
+            "uniquified" => Ok(Stage::Uniquified),
+            "lifted" => Ok(Stage::Lifted),
+            "seq" => Ok(Stage::Seq),
+            "asm" => Ok(Stage::Asm),
+            _ => Err(format!("unknown stage {}", s)),
+        }
+    }
+}
+
+/// Runs the compiler up to [stage] and pretty prints what that stage
+/// produced. The AST is printed as parsed, without being checked.
+pub fn emit_stage<Span>(
+    p: &SurfProg<Span>,
+    stage: Stage,
+) -> Result<String, Vec<CompileErr<Span>>>
+where
+    Span: Clone + Display + Eq + Hash,
+{
+    if stage != Stage::Ast {
+        checker::check_prog(p, &HashMap::new())?;
+    }
+    let erased = p.map_ann(&|_| ());
+    let res = match stage {
+        Stage::Ast | Stage::Checked => format!("{}", erased),
+        Stage::Uniquified => format!("{}", uniquify_prog(&erased)),
+        Stage::Lifted => {
+            let (funs, main) = lambda_lift(&erased);
+            if funs.is_empty() {
+                format!("{}", main)
+            } else {
+                let prog = Exp::FunDefs {
+                    decls: funs,
+                    body: Box::new(main),
+                    ann: (),
+                };
+                format!("{}", prog)
+            }
+        }
+        Stage::Seq => {
+            let (funs, main) = lambda_lift(&erased);
+            format!("{}", sequentializer::seq_prog(&funs, &main))
+        }
+        Stage::Asm => return compile_to_string(p),
+    };
     Ok(res)
 }
@@ -0,0 +1,250 @@
+/* Diagnostics rendered like rustc's, with the source lines they are about:
+ *
+ * error[E0001]: Unbound variable z
+ *  --> examples/foo:2:7
+ *   |
+ * 2 |   x + z
+ *   |       ^ not found in this scope
+ */
+use crate::span::Span2;
+use std::io::IsTerminal;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Severity {
+    Error,
+    Warning,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Label {
+    pub span: Span2,
+    pub text: String,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Diagnostic {
+    // A stable identifier of the kind of diagnostic, e.g. E0001
+    pub code: Option<&'static str>,
+    pub severity: Severity,
+    pub message: String,
+    // Underlined with ^, missing for errors that aren't about the source
+    pub primary: Option<Label>,
+    // Underlined with -
+    pub secondary: Vec<Label>,
+    // Printed after the source lines
+    pub notes: Vec<String>,
+    // Printed after the notes, what could be done about it
+    pub help: Vec<String>,
+}
+
+/// Whether diagnostics printed to stderr should be colored
+pub fn stderr_is_colored() -> bool {
+    std::io::stderr().is_terminal() && std::env::var_os("NO_COLOR").is_none()
+}
+
+const BOLD: &str = "1";
+const RED: &str = "1;31";
+const YELLOW: &str = "1;33";
+const BLUE: &str = "1;34";
+
+fn paint(s: &str, style: &str, color: bool) -> String {
+    if color {
+        format!("\x1b[{}m{}\x1b[0m", style, s)
+    } else {
+        s.to_string()
+    }
+}
+
+impl Severity {
+    fn name(&self) -> &'static str {
+        match self {
+            Severity::Error => "error",
+            Severity::Warning => "warning",
+        }
+    }
+
+    fn style(&self) -> &'static str {
+        match self {
+            Severity::Error => RED,
+            Severity::Warning => YELLOW,
+        }
+    }
+}
+
+fn json_string(s: &str) -> String {
+    let mut out = String::from("\"");
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+// Columns are shifted to count from 1, like in the rendered `--> file:line:col`
+fn json_span(span: &Span2) -> String {
+    format!(
+        "{{\"start_line\":{},\"start_col\":{},\"end_line\":{},\"end_col\":{}}}",
+        span.start_line,
+        span.start_col + 1,
+        span.end_line,
+        span.end_col + 1
+    )
+}
+
+impl Diagnostic {
+    /// The first label is the primary one
+    pub fn new(
+        code: &'static str,
+        severity: Severity,
+        message: String,
+        labels: Vec<(&Span2, String)>,
+    ) -> Self {
+        let mut labels = labels
+            .into_iter()
+            .map(|(span, text)| Label { span: *span, text });
+        Diagnostic {
+            code: Some(code),
+            severity,
+            message,
+            primary: labels.next(),
+            secondary: labels.collect(),
+            notes: vec![],
+            help: vec![],
+        }
+    }
+
+    /// Serializes the diagnostic as a one line JSON object. Lines and
+    /// columns are counted from 1, end columns are exclusive.
+    pub fn to_json(&self, file: &str) -> String {
+        let labels: Vec<String> = self
+            .secondary
+            .iter()
+            .map(|l| {
+                format!(
+                    "{{\"span\":{},\"text\":{}}}",
+                    json_span(&l.span),
+                    json_string(&l.text)
+                )
+            })
+            .collect();
+        let notes: Vec<String> = self.notes.iter().map(|n| json_string(n)).collect();
+        let help: Vec<String> = self.help.iter().map(|h| json_string(h)).collect();
+        format!(
+            "{{\"code\":{},\"severity\":{},\"message\":{},\"file\":{},\"span\":{},\"label\":{},\"secondary\":[{}],\"notes\":[{}],\"help\":[{}]}}",
+            self.code.map_or("null".to_string(), json_string),
+            json_string(self.severity.name()),
+            json_string(&self.message),
+            json_string(file),
+            self.primary.as_ref().map_or("null".to_string(), |l| json_span(&l.span)),
+            self.primary.as_ref().map_or("null".to_string(), |l| json_string(&l.text)),
+            labels.join(","),
+            notes.join(","),
+            help.join(",")
+        )
+    }
+
+    // The notes, then the help lines
+    fn footer(&self) -> impl Iterator<Item = (&'static str, &String)> {
+        let notes = self.notes.iter().map(|note| ("note", note));
+        notes.chain(self.help.iter().map(|help| ("help", help)))
+    }
+
+    /// Renders the diagnostic for the file named [file] with contents [src]
+    pub fn render(&self, file: &str, src: &str, color: bool) -> String {
+        let style = self.severity.style();
+        let kind = match self.code {
+            Some(code) => format!("{}[{}]", self.severity.name(), code),
+            None => self.severity.name().to_string(),
+        };
+        let header = format!("{}: {}", paint(&kind, style, color), paint(&self.message, BOLD, color));
+        let primary = match &self.primary {
+            Some(primary) => primary,
+            None => {
+                let footer: Vec<String> = self
+                    .footer()
+                    .map(|(kind, text)| format!("{} {}: {}\n", paint("=", BLUE, color), kind, text))
+                    .collect();
+                return format!("{}\n{}", header, footer.concat());
+            }
+        };
+        let lines: Vec<&str> = src.lines().collect();
+        let mut labels: Vec<(&Label, bool)> = vec![(primary, true)];
+        labels.extend(self.secondary.iter().map(|l| (l, false)));
+        labels.sort_by_key(|(l, _)| (l.span.start_line, l.span.start_col));
+
+        let width = labels
+            .iter()
+            .map(|(l, _)| l.span.start_line.to_string().len())
+            .max()
+            .unwrap_or(1);
+        let pad = " ".repeat(width);
+        let gutter = paint("|", BLUE, color);
+
+        let mut out = format!(
+            "{}\n{}{} {}:{}:{}\n{} {}\n",
+            header,
+            pad,
+            paint("-->", BLUE, color),
+            file,
+            primary.span.start_line,
+            primary.span.start_col + 1,
+            pad,
+            gutter
+        );
+        let mut prev_line: Option<usize> = None;
+        for (i, (label, _)) in labels.iter().enumerate() {
+            let line_no = label.span.start_line;
+            if prev_line == Some(line_no) {
+                continue;
+            }
+            if prev_line.is_some_and(|prev| line_no > prev + 1) {
+                out.push_str(&format!("{}\n", paint("...", BLUE, color)));
+            }
+            prev_line = Some(line_no);
+            let line = lines.get(line_no - 1).copied().unwrap_or("");
+            let number = format!("{:>width$}", line_no, width = width);
+            out.push_str(&format!("{} {} {}\n", paint(&number, BLUE, color), gutter, line));
+            // every label starting on this line, in column order
+            for (label, primary) in labels[i..]
+                .iter()
+                .take_while(|(l, _)| l.span.start_line == line_no)
+            {
+                let start = label.span.start_col.min(line.len());
+                let end = if label.span.end_line == line_no {
+                    label.span.end_col.min(line.len())
+                } else {
+                    line.len()
+                };
+                // keep tabs so that the marks line up with the source
+                let indent: String = line
+                    .get(..start)
+                    .unwrap_or("")
+                    .chars()
+                    .map(|c| if c == '\t' { '\t' } else { ' ' })
+                    .collect();
+                let count = line.get(start..end).map_or(1, |s| s.chars().count().max(1));
+                let (mark, mark_style) = if *primary { ("^", style) } else { ("-", BLUE) };
+                let marks = format!("{} {}", mark.repeat(count), label.text);
+                out.push_str(&format!(
+                    "{} {} {}{}\n",
+                    pad,
+                    gutter,
+                    indent,
+                    paint(marks.trim_end(), mark_style, color)
+                ));
+            }
+        }
+        for (kind, text) in self.footer() {
+            out.push_str(&format!("{} {} {}: {}\n", pad, paint("=", BLUE, color), kind, text));
+        }
+        out
+    }
+}
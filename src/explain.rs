@@ -0,0 +1,363 @@
+/* The longer explanations printed by snake --explain CODE, for every code
+ * an error or warning is reported with. Codes are never reused, the ones
+ * no longer reported stay here to say what replaced them.
+ */
+
+pub struct Explanation {
+    pub code: &'static str,
+    pub title: &'static str,
+    pub text: &'static str,
+    // For the codes about programs, one with the problem and the same
+    // program fixed
+    pub examples: Option<Examples>,
+}
+
+pub struct Examples {
+    // The example programs are examples/{name}_wrong and examples/{name}_fixed
+    pub name: &'static str,
+    pub wrong: &'static str,
+    pub fixed: &'static str,
+}
+
+macro_rules! examples {
+    ($name:literal) => {
+        Some(Examples {
+            name: $name,
+            wrong: include_str!(concat!("../examples/", $name, "_wrong")),
+            fixed: include_str!(concat!("../examples/", $name, "_fixed")),
+        })
+    };
+}
+
+pub static EXPLANATIONS: &[Explanation] = &[
+    Explanation {
+        code: "E0001",
+        title: "unbound variable",
+        text: "A variable was used that isn't bound by an enclosing let, function \
+               parameter or lambda parameter. Often the name has a typo, in which case \
+               the names in scope that are close to it are suggested.",
+        examples: examples!("e0001"),
+    },
+    Explanation {
+        code: "E0002",
+        title: "undefined function",
+        text: "A function was called that isn't defined by an enclosing def and isn't a \
+               variable holding a closure either. The defined functions taking as many \
+               arguments with a name close to it are suggested.",
+        examples: examples!("e0002"),
+    },
+    Explanation {
+        code: "E0003",
+        title: "variable bound twice in one let",
+        text: "The bindings of a single let must have different names. To replace a \
+               variable with a new value, bind it in a let nested in the body.",
+        examples: examples!("e0003"),
+    },
+    Explanation {
+        code: "E0004",
+        title: "number literal out of range",
+        text: "Numbers are 63-bit signed integers, between -2^62 and 2^62 - 1, because \
+               one bit of every value tells numbers apart from other values.",
+        examples: examples!("e0004"),
+    },
+    Explanation {
+        code: "E0005",
+        title: "function defined twice",
+        text: "The functions defined together with def ... and def ... must have \
+               different names.",
+        examples: examples!("e0005"),
+    },
+    Explanation {
+        code: "E0006",
+        title: "parameter named twice",
+        text: "The parameters of a function must have different names.",
+        examples: examples!("e0006"),
+    },
+    Explanation {
+        code: "E0007",
+        title: "function called with the wrong number of arguments",
+        text: "A function defined with def must be called with exactly as many arguments \
+               as it has parameters. The error shows how the function is declared.",
+        examples: examples!("e0007"),
+    },
+    Explanation {
+        code: "E0008",
+        title: "assignment to a function or loop variable",
+        text: "Only variables bound by a let or as parameters can be assigned with :=. \
+               The variable of a for loop counts the iterations, so it can't be assigned \
+               either; use a while loop to count differently.",
+        examples: examples!("e0008"),
+    },
+    Explanation {
+        code: "E0009",
+        title: "assigned variable referred to by a function",
+        text: "A function or lambda copies the values of the variables it refers to when \
+               it is created, so it wouldn't see later assignments to them. A variable \
+               that is assigned can't be referred to by a function in its scope. To share \
+               a value that changes, keep it in a tuple and update it with t[0] := v.",
+        examples: examples!("e0009"),
+    },
+    Explanation {
+        code: "E0010",
+        title: "file can't be read",
+        text: "The program file doesn't exist, can't be read, or the formatted program \
+               can't be written back to it.",
+        examples: None,
+    },
+    Explanation {
+        code: "E0011",
+        title: "lexing failed",
+        text: "The program couldn't be split into tokens. Most such problems are reported \
+               as E0104 to E0108 instead.",
+        examples: None,
+    },
+    Explanation {
+        code: "E0012",
+        title: "syntax error (no longer reported)",
+        text: "Earlier versions reported every syntax error as E0012. Syntax errors now have \
+               codes of their own, E0101 to E0108.",
+        examples: None,
+    },
+    Explanation {
+        code: "E0013",
+        title: "linking failed",
+        text: "The generated assembly couldn't be assembled with nasm or linked with the \
+               runtime. Check that nasm is installed and that the runtime directory is \
+               where snake expects it.",
+        examples: None,
+    },
+    Explanation {
+        code: "E0014",
+        title: "interpreter failed (no longer reported)",
+        text: "Earlier versions reported every error of the interpreter as E0014. Its runtime \
+               errors now have the codes of the compiled program's, E0200 to E0213, and \
+               failing to write the output is E0016.",
+        examples: None,
+    },
+    Explanation {
+        code: "E0015",
+        title: "compiled program failed",
+        text: "The compiled program exited without reporting one of the runtime errors \
+               E0200 to E0213, e.g. because it was killed by a signal.",
+        examples: None,
+    },
+    Explanation {
+        code: "E0016",
+        title: "interpreter couldn't print",
+        text: "The interpreter couldn't write the program's output.",
+        examples: None,
+    },
+    Explanation {
+        code: "E0101",
+        title: "unexpected token",
+        text: "The parser found a token where it can't go. The error says which tokens \
+               could have come there instead, often a missing comma or keyword.",
+        examples: examples!("e0101"),
+    },
+    Explanation {
+        code: "E0102",
+        title: "unterminated construct",
+        text: "The file ended inside of a let, def, if, lambda, loop or bracket. The error \
+               points at the keyword or bracket that was opened and never closed.",
+        examples: examples!("e0102"),
+    },
+    Explanation {
+        code: "E0103",
+        title: "unexpected end of file",
+        text: "The file ended where more of the program was expected, e.g. right after an \
+               operator.",
+        examples: examples!("e0103"),
+    },
+    Explanation {
+        code: "E0104",
+        title: "unrecognized character",
+        text: "The character can't start any token of the language.",
+        examples: examples!("e0104"),
+    },
+    Explanation {
+        code: "E0105",
+        title: "invalid number literal",
+        text: "Numbers are written in decimal, in hex after 0x or in binary after 0b, \
+               with _ allowed between digits. The digits have to fit the base.",
+        examples: examples!("e0105"),
+    },
+    Explanation {
+        code: "E0106",
+        title: "unterminated block comment",
+        text: "A block comment opened with (# has to be closed with #). Block comments \
+               nest, so every (# inside of one needs its own #) too.",
+        examples: examples!("e0106"),
+    },
+    Explanation {
+        code: "E0107",
+        title: "unterminated string",
+        text: "A string opened with \" has to be closed with another \" on the same line. \
+               A quote inside of a string is written \\\" and a line break \\n.",
+        examples: examples!("e0107"),
+    },
+    Explanation {
+        code: "E0108",
+        title: "unknown escape in string",
+        text: "A \\ in a string starts one of the escapes \\n, \\t, \\r, \\0, \\\\ and \\\". \
+               To put a \\ itself into a string, write \\\\.",
+        examples: examples!("e0108"),
+    },
+    Explanation {
+        code: "E0200",
+        title: "arithmetic on a value that isn't a number",
+        text: "The operands of the arithmetic operators +, -, *, /, %, &, |, ^, << and >> \
+               have to be numbers, as do those of -e, add1, sub1, abs, min, max and \
+               num_to_string, and the bounds of a for loop.",
+        examples: examples!("e0200"),
+    },
+    Explanation {
+        code: "E0201",
+        title: "comparison of a value that isn't a number",
+        text: "The operands of <, <=, > and >= have to be numbers. Use == to compare other \
+               values.",
+        examples: examples!("e0201"),
+    },
+    Explanation {
+        code: "E0202",
+        title: "arithmetic overflow",
+        text: "The result of an arithmetic operation doesn't fit into a 63-bit signed \
+               integer, between -2^62 and 2^62 - 1. Shifting by a negative amount \
+               overflows too.",
+        examples: examples!("e0202"),
+    },
+    Explanation {
+        code: "E0203",
+        title: "if or while condition that isn't a boolean",
+        text: "The condition of an if or a while loop has to be true or false. Other \
+               values, such as 0 or empty tuples, aren't taken as either.",
+        examples: examples!("e0203"),
+    },
+    Explanation {
+        code: "E0204",
+        title: "logic on a value that isn't a boolean",
+        text: "The operands of &&, || and ! have to be booleans.",
+        examples: examples!("e0204"),
+    },
+    Explanation {
+        code: "E0205",
+        title: "tuple operation on a value that isn't a tuple",
+        text: "Only tuples can be indexed, updated or have their length taken.",
+        examples: examples!("e0205"),
+    },
+    Explanation {
+        code: "E0206",
+        title: "index that isn't a number",
+        text: "Tuples and strings are indexed with numbers, starting from 0. The start \
+               and end given to substr are numbers too.",
+        examples: examples!("e0206"),
+    },
+    Explanation {
+        code: "E0207",
+        title: "index out of bounds",
+        text: "A tuple or string of length n can be indexed from 0 to n - 1. substr takes \
+               the characters from its start up to but not including its end, which are \
+               both from 0 to n, the end not before the start.",
+        examples: examples!("e0207"),
+    },
+    Explanation {
+        code: "E0208",
+        title: "call of a value that isn't a function",
+        text: "A variable was called that holds something other than a closure.",
+        examples: examples!("e0208"),
+    },
+    Explanation {
+        code: "E0209",
+        title: "closure called with the wrong number of arguments",
+        text: "A closure must be called with exactly as many arguments as it has \
+               parameters. Unlike for functions defined with def, this is only known \
+               when the program runs.",
+        examples: examples!("e0209"),
+    },
+    Explanation {
+        code: "E0210",
+        title: "out of memory",
+        text: "The program keeps more tuples and closures alive at once than fit into the \
+               heap, even after collecting the garbage.",
+        examples: examples!("e0210"),
+    },
+    Explanation {
+        code: "E0211",
+        title: "division by zero",
+        text: "The right operand of / or % is 0. Check for it before dividing.",
+        examples: examples!("e0211"),
+    },
+    Explanation {
+        code: "E0212",
+        title: "string operation on a value that isn't a string",
+        text: "The operands of ++ and the strings given to strlen, char_at, substr and \
+               string_to_num have to be strings. Turn a number into one with \
+               num_to_string first.",
+        examples: examples!("e0212"),
+    },
+    Explanation {
+        code: "E0213",
+        title: "string that isn't a number",
+        text: "string_to_num only takes the decimal digits of a number, with a - or + in \
+               front at most, that fit into a 63-bit signed integer. Nothing else can come \
+               before or after them, not even spaces.",
+        examples: examples!("e0213"),
+    },
+    Explanation {
+        code: "W0001",
+        title: "unused variable",
+        text: "A variable is bound by a let or a for loop but never used. Silence this \
+               warning with -Wno-unused-variable.",
+        examples: examples!("w0001"),
+    },
+    Explanation {
+        code: "W0002",
+        title: "unused parameter",
+        text: "A function or lambda never uses one of its parameters. Silence this \
+               warning with -Wno-unused-parameter.",
+        examples: examples!("w0002"),
+    },
+    Explanation {
+        code: "W0003",
+        title: "unused function",
+        text: "A function is never called, except maybe by itself. Silence this warning \
+               with -Wno-unused-function.",
+        examples: examples!("w0003"),
+    },
+    Explanation {
+        code: "W0004",
+        title: "shadowing",
+        text: "A let or a for loop binds a name that is already bound outside of it, so \
+               the outer binding can't be used in its body. Silence this warning with \
+               -Wno-shadowing.",
+        examples: examples!("w0004"),
+    },
+    Explanation {
+        code: "W0005",
+        title: "constant condition",
+        text: "The condition of an if is the literal true or false, so one of the \
+               branches never runs. A while loop with the condition true only stops on an \
+               error, and its body never runs with false. Silence this warning with \
+               -Wno-constant-condition.",
+        examples: examples!("w0005"),
+    },
+];
+
+fn indent(program: &str) -> String {
+    program.lines().map(|line| format!("    {}\n", line)).collect()
+}
+
+/// The explanation of [code] as printed by snake --explain
+pub fn explain(code: &str) -> Option<String> {
+    let explanation = EXPLANATIONS.iter().find(|e| e.code == code)?;
+    let mut out = format!("{}: {}\n\n{}\n", explanation.code, explanation.title, explanation.text);
+    if let Some(examples) = &explanation.examples {
+        out.push_str(&format!(
+            "\nFor example, examples/{}_wrong:\n\n{}\nFixed, examples/{}_fixed:\n\n{}",
+            examples.name,
+            indent(examples.wrong),
+            examples.name,
+            indent(examples.fixed)
+        ));
+    }
+    Some(out)
+}
@@ -0,0 +1,359 @@
+use crate::lexer;
+use crate::lexer::{Lexer, Tok};
+use crate::span::Span1;
+use crate::syntax::{
+    binop, needs_parens_before_semicolon, operand_levels, call_name, Exp, Pretty, Prim,
+    SurfProg, BASE, EXP, SEQ,
+};
+use std::collections::VecDeque;
+
+// Expressions that would run past this column are broken over several lines
+const WIDTH: usize = 80;
+
+struct Comment {
+    start: usize,
+    text: String,
+    // Whether only whitespace precedes the comment on its line
+    own_line: bool,
+}
+
+fn comments(src: &str) -> VecDeque<Comment> {
+    lexer::comments(src)
+        .into_iter()
+        .map(|span| {
+            let line_start = src[..span.start_ix].rfind('\n').map_or(0, |ix| ix + 1);
+            Comment {
+                start: span.start_ix,
+                text: src[span.start_ix..span.end_ix].trim_end().to_string(),
+                own_line: src[line_start..span.start_ix].trim().is_empty(),
+            }
+        })
+        .collect()
+}
+
+/// Prints [prog] in the canonical layout, keeping the comments of [src] it
+/// was parsed from
+pub fn format_prog(src: &str, prog: &SurfProg<Span1>) -> String {
+    let mut f = Formatter {
+        src,
+        out: String::new(),
+        comments: comments(src),
+    };
+    while f.comment_before(prog.ann().start_ix) {
+        let c = f.comments.pop_front().unwrap();
+        f.out.push_str(&c.text);
+        f.out.push('\n');
+    }
+    f.exp(prog, SEQ);
+    f.flush_comments(0, usize::MAX);
+    f.out.push('\n');
+    f.out
+}
+
+struct Formatter<'a> {
+    src: &'a str,
+    out: String,
+    // The comments not printed yet, in source order
+    comments: VecDeque<Comment>,
+}
+
+impl<'a> Formatter<'a> {
+    fn column(&self) -> usize {
+        let line_start = self.out.rfind('\n').map_or(0, |ix| ix + 1);
+        self.out[line_start..].chars().count()
+    }
+
+    // The position of the keyword following the expression that ended at
+    // [ix], e.g. the else after a then branch
+    fn keyword_after(&self, ix: usize) -> usize {
+        Lexer::new(&self.src[ix..])
+            .map_while(Result::ok)
+            .find(|(_, tok, _)| *tok != Tok::RParen)
+            .map_or(self.src.len(), |(start, _, _)| ix + start)
+    }
+
+    fn comment_before(&self, ix: usize) -> bool {
+        self.comments.front().is_some_and(|c| c.start < ix)
+    }
+
+    fn comment_within(&self, span: &Span1) -> bool {
+        self.comments
+            .iter()
+            .any(|c| span.start_ix <= c.start && c.start < span.end_ix)
+    }
+
+    // Prints the comments that come before [next] in the source: the first
+    // at the end of the current line if it was at the end of a line, the
+    // others on their own lines
+    fn flush_comments(&mut self, indent: usize, next: usize) {
+        let mut first = true;
+        while self.comment_before(next) {
+            let c = self.comments.pop_front().unwrap();
+            if first && !c.own_line {
+                self.out.push(' ');
+            } else {
+                self.out.push('\n');
+                self.out.push_str(&" ".repeat(indent));
+            }
+            self.out.push_str(&c.text);
+            first = false;
+        }
+    }
+
+    // Starts a new line for the code at [next] in the source
+    fn newline(&mut self, indent: usize, next: usize) {
+        self.flush_comments(indent, next);
+        self.out.push('\n');
+        self.out.push_str(&" ".repeat(indent));
+    }
+
+    fn exp(&mut self, e: &Exp<Span1>, level: u8) {
+        if e.level() < level {
+            self.out.push('(');
+            self.exp(e, SEQ);
+            self.out.push(')');
+            return;
+        }
+        let col = self.column();
+        match e {
+            Exp::Let { bindings, body, .. } => {
+                self.out.push_str("let ");
+                for (i, (x, bound)) in bindings.iter().enumerate() {
+                    if i > 0 {
+                        self.out.push(',');
+                        self.newline(col + 4, bound.ann().start_ix);
+                    }
+                    self.out.push_str(&format!("{} = ", x));
+                    self.exp(bound, EXP);
+                }
+                self.out.push_str(" in");
+                self.newline(col, body.ann().start_ix);
+                self.exp(body, SEQ);
+            }
+            Exp::If { cond, thn, els, .. } => {
+                self.out.push_str("if ");
+                self.exp(cond, EXP);
+                self.out.push(':');
+                self.newline(col + 2, thn.ann().start_ix);
+                self.exp(thn, SEQ);
+                self.newline(col, self.keyword_after(thn.ann().end_ix));
+                self.out.push_str("else:");
+                self.newline(col + 2, els.ann().start_ix);
+                self.exp(els, SEQ);
+            }
+            Exp::Seq { first, second, .. } => {
+                if needs_parens_before_semicolon(&**first) {
+                    self.out.push('(');
+                    self.exp(first, SEQ);
+                    self.out.push(')');
+                } else {
+                    self.exp(first, EXP);
+                }
+                self.out.push(';');
+                self.newline(col, second.ann().start_ix);
+                self.exp(second, SEQ);
+            }
+            Exp::FunDefs { decls, body, .. } => {
+                for (i, decl) in decls.iter().enumerate() {
+                    if i > 0 {
+                        self.newline(col, decl.ann.start_ix);
+                        self.out.push_str("and ");
+                    }
+                    self.out.push_str(&format!(
+                        "def {}({}):",
+                        decl.name,
+                        decl.parameters.join(", ")
+                    ));
+                    self.newline(col + 2, decl.body.ann().start_ix);
+                    self.exp(&decl.body, SEQ);
+                }
+                let last = &decls[decls.len() - 1];
+                self.newline(col, self.keyword_after(last.ann.end_ix));
+                self.out.push_str("in");
+                self.newline(col, body.ann().start_ix);
+                self.exp(body, SEQ);
+            }
+            _ => {
+                let flat = self.respell(e.pretty(0), e);
+                if !flat.contains('\n')
+                    && col + flat.chars().count() <= WIDTH
+                    && !self.comment_within(e.ann())
+                {
+                    self.out.push_str(&flat);
+                } else {
+                    self.broken(e, col);
+                }
+            }
+        }
+    }
+
+    // Writes the literals in [flat], which is [e] printed, the way they are
+    // spelled in the source, e.g. numbers in hex
+    fn respell(&self, flat: String, e: &Exp<Span1>) -> String {
+        let mut spans = vec![];
+        literals(e, &mut spans);
+        let literal_toks = Lexer::new(&flat)
+            .map_while(Result::ok)
+            .filter(|(_, tok, _)| matches!(tok, Tok::Num(_) | Tok::Str(_)));
+        let mut out = String::new();
+        let mut last = 0;
+        for ((start, _, end), span) in literal_toks.zip(spans) {
+            out.push_str(&flat[last..start]);
+            out.push_str(&self.src[span.start_ix..span.end_ix]);
+            last = end;
+        }
+        out.push_str(&flat[last..]);
+        out
+    }
+
+    // Prints an expression that doesn't fit on the rest of the line
+    fn broken(&mut self, e: &Exp<Span1>, col: usize) {
+        match e {
+            Exp::Prim(p, es, ann) => {
+                let es: Vec<&Exp<Span1>> = es.iter().map(|e| &**e).collect();
+                if let Some(name) = call_name(*p) {
+                    self.list(&format!("{}(", name), es, ")", col, ann);
+                } else if let Some((op, level)) = binop(*p) {
+                    let (left, right) = operand_levels(level);
+                    self.exp(es[0], left);
+                    self.out.push_str(&format!(" {} ", op));
+                    self.exp(es[1], right);
+                } else {
+                    match p {
+                        Prim::Not => {
+                            self.out.push('!');
+                            self.exp(es[0], BASE);
+                        }
+                        Prim::Neg => {
+                            self.out.push('-');
+                            // -5 would be read back as a number literal
+                            if matches!(es[0], Exp::Num(n, _) if *n >= 0) {
+                                self.out.push('(');
+                                self.exp(es[0], EXP);
+                                self.out.push(')');
+                            } else {
+                                self.exp(es[0], BASE);
+                            }
+                        }
+                        Prim::TupleGet => {
+                            self.exp(es[0], BASE);
+                            self.out.push('[');
+                            self.exp(es[1], EXP);
+                            self.out.push(']');
+                        }
+                        Prim::TupleSet => {
+                            self.exp(es[0], BASE);
+                            self.out.push('[');
+                            self.exp(es[1], EXP);
+                            self.out.push_str("] := ");
+                            self.exp(es[2], EXP);
+                        }
+                        _ => self.list("[", es, "]", col, ann),
+                    }
+                }
+            }
+            Exp::Call(fun, args, ann) => {
+                self.list(&format!("{}(", fun), args.iter().collect(), ")", col, ann)
+            }
+            Exp::Lambda {
+                parameters,
+                body,
+                ann,
+            } => {
+                self.out
+                    .push_str(&format!("lambda {}:", parameters.join(", ")));
+                self.newline(col + 2, body.ann().start_ix);
+                self.exp(body, SEQ);
+                self.newline(col, ann.end_ix - "end".len());
+                self.out.push_str("end");
+            }
+            Exp::While { cond, body, ann } => {
+                self.out.push_str("while ");
+                self.exp(cond, EXP);
+                self.out.push(':');
+                self.newline(col + 2, body.ann().start_ix);
+                self.exp(body, SEQ);
+                self.newline(col, ann.end_ix - "end".len());
+                self.out.push_str("end");
+            }
+            Exp::For {
+                var,
+                from,
+                to,
+                body,
+                ann,
+            } => {
+                self.out.push_str(&format!("for {} = ", var));
+                self.exp(from, EXP);
+                self.out.push_str(" to ");
+                self.exp(to, EXP);
+                self.out.push(':');
+                self.newline(col + 2, body.ann().start_ix);
+                self.exp(body, SEQ);
+                self.newline(col, ann.end_ix - "end".len());
+                self.out.push_str("end");
+            }
+            Exp::Assign { var, value, .. } => {
+                self.out.push_str(&format!("{} := ", var));
+                self.exp(value, EXP);
+            }
+            // Only the parser's output gets formatted, so the internal forms
+            // never show up here
+            _ => self.out.push_str(&e.pretty(0)),
+        }
+    }
+
+    // Prints a comma separated list one element per line, e.g. the
+    // arguments of a call
+    fn list(&mut self, open: &str, es: Vec<&Exp<Span1>>, close: &str, col: usize, ann: &Span1) {
+        self.out.push_str(open);
+        for (i, e) in es.iter().enumerate() {
+            if i > 0 {
+                self.out.push(',');
+            }
+            self.newline(col + 2, e.ann().start_ix);
+            self.exp(e, EXP);
+        }
+        self.newline(col, ann.end_ix - close.len());
+        self.out.push_str(close);
+    }
+}
+
+// The locations of the number and string literals in [e], in the order they
+// are printed
+fn literals(e: &Exp<Span1>, spans: &mut Vec<Span1>) {
+    match e {
+        Exp::Num(_, ann) | Exp::Str(_, ann) => spans.push(*ann),
+        Exp::Prim(_, es, _) => es.iter().for_each(|e| literals(e, spans)),
+        Exp::Let { bindings, body, .. } => {
+            bindings.iter().for_each(|(_, e)| literals(e, spans));
+            literals(body, spans)
+        }
+        Exp::If { cond, thn, els, .. } => {
+            literals(cond, spans);
+            literals(thn, spans);
+            literals(els, spans)
+        }
+        Exp::Seq { first, second, .. } => {
+            literals(first, spans);
+            literals(second, spans)
+        }
+        Exp::While { cond, body, .. } => {
+            literals(cond, spans);
+            literals(body, spans)
+        }
+        Exp::For { from, to, body, .. } => {
+            literals(from, spans);
+            literals(to, spans);
+            literals(body, spans)
+        }
+        Exp::FunDefs { decls, body, .. } => {
+            decls.iter().for_each(|decl| literals(&decl.body, spans));
+            literals(body, spans)
+        }
+        Exp::Assign { value, .. } => literals(value, spans),
+        Exp::Lambda { body, .. } => literals(body, spans),
+        Exp::Call(_, es, _) => es.iter().for_each(|e| literals(e, spans)),
+        _ => {}
+    }
+}
@@ -1,11 +1,18 @@
-use crate::syntax::{Exp, Prim, SurfProg, SurfFunDecl};
+use crate::lexer::escape;
+use crate::syntax::{Exp, Prim, SurfFunDecl, SurfProg};
 
+use std::cell::RefCell;
+use std::collections::HashSet;
 use std::rc::Rc;
 
 #[derive(Debug, Clone)]
 enum SnakeVal {
     Num(i64), // should fit into 63 bits though
     Bool(bool),
+    // strings can't be changed, so they are shared instead of living on
+    // the heap
+    Str(Rc<[u8]>),
+    Tuple(usize),   // index into the heap
     Closure(usize), // index into the closure arena
 }
 
@@ -16,10 +23,12 @@ enum List<T> {
     Cons(T, Rc<List<T>>),
 }
 
-type Env = Rc<List<(String, SnakeVal)>>;
+// Variables can be assigned, so each one is a cell that the environments
+// extending the one it was bound in share
+type Env = Rc<List<(String, Rc<RefCell<SnakeVal>>)>>;
 
 fn push_local(env: &Env, name: String, v: SnakeVal) -> Env {
-    Rc::new(List::Cons((name, v), env.clone()))
+    Rc::new(List::Cons((name, Rc::new(RefCell::new(v))), env.clone()))
 }
 
 #[derive(Debug, Clone)]
@@ -29,7 +38,7 @@ struct Closure<'exp, Ann> {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
-pub enum InterpErr {
+pub enum InterpErrKind {
     ExpectedNum {
         who: String,
         got: String,
@@ -45,9 +54,37 @@ pub enum InterpErr {
         got: String,
         msg: String,
     },
+    ExpectedTuple {
+        who: String,
+        got: String,
+        msg: String,
+    },
+    ExpectedString {
+        who: String,
+        got: String,
+        msg: String,
+    },
+    // [what] is indexed, a tuple or a string
+    IndexOutOfBounds {
+        index: i64,
+        length: usize,
+        what: &'static str,
+    },
+    // The end given to substr is before its start
+    SubstrRange {
+        start: usize,
+        end: usize,
+    },
+    // A string given to string_to_num that isn't a number
+    NotANumber {
+        got: String,
+    },
     Overflow {
         msg: String,
     },
+    DivisionByZero {
+        msg: String,
+    },
     Write {
         msg: String,
     },
@@ -57,7 +94,52 @@ pub enum InterpErr {
     },
 }
 
-type Interp<T> = Result<T, InterpErr>;
+impl InterpErrKind {
+    /// A stable identifier of the kind of error, the same as the compiled
+    /// code's for the same failure
+    pub fn code(&self) -> &'static str {
+        match self {
+            InterpErrKind::ExpectedNum { who, .. } if who == "comparison" => "E0201",
+            InterpErrKind::ExpectedNum { who, .. } if who == "index" => "E0206",
+            InterpErrKind::ExpectedNum { .. } => "E0200",
+            InterpErrKind::Overflow { .. } => "E0202",
+            InterpErrKind::DivisionByZero { .. } => "E0211",
+            InterpErrKind::ExpectedBool { who, .. } if who == "if" || who == "while" => "E0203",
+            InterpErrKind::ExpectedBool { .. } => "E0204",
+            InterpErrKind::ExpectedTuple { .. } => "E0205",
+            InterpErrKind::IndexOutOfBounds { .. } | InterpErrKind::SubstrRange { .. } => "E0207",
+            InterpErrKind::ExpectedString { .. } => "E0212",
+            InterpErrKind::NotANumber { .. } => "E0213",
+            InterpErrKind::ExpectedFun { .. } => "E0208",
+            InterpErrKind::ArityErr { .. } => "E0209",
+            InterpErrKind::Write { .. } => "E0016",
+        }
+    }
+}
+
+/// A runtime error and the expression it happened in, printing the value
+/// of the whole program isn't in any expression
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InterpErr<Ann> {
+    pub kind: InterpErrKind,
+    pub location: Option<Ann>,
+}
+
+impl<Ann> InterpErr<Ann> {
+    pub fn code(&self) -> &'static str {
+        self.kind.code()
+    }
+}
+
+type Interp<T> = Result<T, InterpErrKind>;
+
+// Puts the errors of what the expression at [ann] does there
+fn at<Ann: Clone>(ann: &Ann) -> impl Fn(InterpErrKind) -> InterpErr<Ann> + '_ {
+    move |kind| InterpErr {
+        kind,
+        location: Some(ann.clone()),
+    }
+}
 
 use std::fmt;
 use std::fmt::Display;
@@ -67,26 +149,52 @@ impl Display for SnakeVal {
         match self {
             SnakeVal::Num(n) => write!(f, "{}", n),
             SnakeVal::Bool(b) => write!(f, "{}", b),
+            SnakeVal::Str(bytes) => write!(f, "{}", escape(&String::from_utf8_lossy(bytes))),
+            SnakeVal::Tuple(_) => write!(f, "tuple"),
             SnakeVal::Closure { .. } => write!(f, "closure"),
         }
     }
 }
 
-impl Display for InterpErr {
+impl Display for InterpErrKind {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
-            InterpErr::ExpectedNum { who, got: v, msg } => {
+            InterpErrKind::ExpectedNum { who, got: v, msg } => {
                 write!(f, "{} expected a number, but got {} in {}", who, v, msg)
             }
-            InterpErr::ExpectedBool { who, got: v, msg } => {
+            InterpErrKind::ExpectedBool { who, got: v, msg } => {
                 write!(f, "{} expected a boolean, but got {} in {}", who, v, msg)
             }
-            InterpErr::ExpectedFun { who, got: v, msg } => {
+            InterpErrKind::ExpectedFun { who, got: v, msg } => {
                 write!(f, "{} expected a function, but got {} in {}", who, v, msg)
             }
-            InterpErr::Overflow { msg } => write!(f, "Operation {} overflowed", msg),
-            InterpErr::Write { msg } => write!(f, "I/O Error when printing: {}", msg),
-            InterpErr::ArityErr {
+            InterpErrKind::ExpectedTuple { who, got: v, msg } => {
+                write!(f, "{} expected a tuple, but got {} in {}", who, v, msg)
+            }
+            InterpErrKind::ExpectedString { who, got: v, msg } => {
+                write!(f, "{} expected a string, but got {} in {}", who, v, msg)
+            }
+            InterpErrKind::IndexOutOfBounds {
+                index,
+                length,
+                what,
+            } => {
+                write!(
+                    f,
+                    "index out of bounds: index {} of {} of length {}",
+                    index, what, length
+                )
+            }
+            InterpErrKind::SubstrRange { start, end } => {
+                write!(f, "substr end {} is before start {}", end, start)
+            }
+            InterpErrKind::NotANumber { got } => {
+                write!(f, "string_to_num expected the digits of a number, but got {}", got)
+            }
+            InterpErrKind::Overflow { msg } => write!(f, "Operation {} overflowed", msg),
+            InterpErrKind::DivisionByZero { msg } => write!(f, "division by zero in {}", msg),
+            InterpErrKind::Write { msg } => write!(f, "I/O Error when printing: {}", msg),
+            InterpErrKind::ArityErr {
                 expected_arity,
                 num_provided,
             } => {
@@ -100,6 +208,15 @@ impl Display for InterpErr {
     }
 }
 
+impl<Ann: Display> Display for InterpErr<Ann> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match &self.location {
+            Some(location) => write!(f, "{} at {}", self.kind, location),
+            None => write!(f, "{}", self.kind),
+        }
+    }
+}
+
 fn get<'l, T>(stk: &'l List<(String, T)>, x: &str) -> Option<&'l T> {
     match stk {
         List::Empty => None,
@@ -116,7 +233,7 @@ fn get<'l, T>(stk: &'l List<(String, T)>, x: &str) -> Option<&'l T> {
 fn bool(v: SnakeVal, who: &str, msg: &str) -> Interp<bool> {
     match v {
         SnakeVal::Bool(b) => Ok(b),
-        _ => Err(InterpErr::ExpectedBool {
+        _ => Err(InterpErrKind::ExpectedBool {
             who: String::from(who),
             got: v.to_string(),
             msg: String::from(msg),
@@ -127,7 +244,18 @@ fn bool(v: SnakeVal, who: &str, msg: &str) -> Interp<bool> {
 fn num(v: SnakeVal, who: &str, msg: &str) -> Interp<i64> {
     match v {
         SnakeVal::Num(n) => Ok(n),
-        _ => Err(InterpErr::ExpectedNum {
+        _ => Err(InterpErrKind::ExpectedNum {
+            who: String::from(who),
+            got: v.to_string(),
+            msg: String::from(msg),
+        }),
+    }
+}
+
+fn tuple(v: SnakeVal, who: &str, msg: &str) -> Interp<usize> {
+    match v {
+        SnakeVal::Tuple(addr) => Ok(addr),
+        _ => Err(InterpErrKind::ExpectedTuple {
             who: String::from(who),
             got: v.to_string(),
             msg: String::from(msg),
@@ -135,16 +263,55 @@ fn num(v: SnakeVal, who: &str, msg: &str) -> Interp<i64> {
     }
 }
 
-fn print_snake_val<'e, W>(w: &mut W, v: SnakeVal, _h: &Heap) -> Interp<SnakeVal>
+fn string(v: SnakeVal, who: &str, msg: &str) -> Interp<Rc<[u8]>> {
+    match v {
+        SnakeVal::Str(bytes) => Ok(bytes),
+        _ => Err(InterpErrKind::ExpectedString {
+            who: String::from(who),
+            got: v.to_string(),
+            msg: String::from(msg),
+        }),
+    }
+}
+
+// Where each character of a string starts, followed by where the last one
+// ends, so that strings are indexed by character and not by byte
+fn char_bounds(bytes: &[u8]) -> Vec<usize> {
+    let s = std::str::from_utf8(bytes).expect("strings are valid UTF-8");
+    s.char_indices()
+        .map(|(i, _)| i)
+        .chain(std::iter::once(s.len()))
+        .collect()
+}
+
+// An index of something of length [len] that [what] is, counting from 0.
+// Up to [len] itself is allowed when [inclusive].
+fn index(v: SnakeVal, len: usize, what: &'static str, inclusive: bool, msg: &str) -> Interp<usize> {
+    let i = num(v, "index", msg)?;
+    if i < 0 || i as usize > len || (i as usize == len && !inclusive) {
+        return Err(InterpErrKind::IndexOutOfBounds {
+            index: i,
+            length: len,
+            what,
+        });
+    }
+    Ok(i as usize)
+}
+
+fn print_snake_val<W>(w: &mut W, v: SnakeVal, h: &Heap) -> Interp<SnakeVal>
 where
     W: std::io::Write,
 {
-    fn fixup_err(e: std::io::Error) -> InterpErr {
-        InterpErr::Write { msg: e.to_string() }
+    fn fixup_err(e: std::io::Error) -> InterpErrKind {
+        InterpErrKind::Write { msg: e.to_string() }
     }
-    fn print_loop<'e, W>(
+    // [parents] are the tuples currently being printed, a tuple that
+    // contains itself is printed as <loop> the second time around
+    fn print_loop<W>(
         w: &mut W,
         v: &SnakeVal,
+        h: &Heap,
+        parents: &mut Vec<usize>,
     ) -> Result<(), std::io::Error>
     where
         W: std::io::Write,
@@ -152,6 +319,23 @@ where
         match v {
             SnakeVal::Num(n) => write!(w, "{}", n)?,
             SnakeVal::Bool(b) => write!(w, "{}", b)?,
+            SnakeVal::Str(_) => write!(w, "{}", v)?,
+            SnakeVal::Tuple(addr) => {
+                if parents.contains(addr) {
+                    write!(w, "<loop>")?;
+                    return Ok(());
+                }
+                parents.push(*addr);
+                write!(w, "[")?;
+                for (i, elem) in h[*addr].iter().enumerate() {
+                    if i > 0 {
+                        write!(w, ", ")?;
+                    }
+                    print_loop(w, elem, h, parents)?;
+                }
+                write!(w, "]")?;
+                parents.pop();
+            }
             SnakeVal::Closure { .. } => {
                 write!(w, "<closure>")?;
             }
@@ -159,23 +343,154 @@ where
         Ok(())
     }
 
-    print_loop(w, &v).map_err(fixup_err)?;
+    // a string on its own is printed as it is, without quotes
+    match &v {
+        SnakeVal::Str(bytes) => write!(w, "{}", String::from_utf8_lossy(bytes)),
+        _ => print_loop(w, &v, h, &mut Vec::new()),
+    }
+    .map_err(fixup_err)?;
     writeln!(w).map_err(fixup_err)?;
     Ok(v)
 }
 
-fn equal_snake_val(v1: &SnakeVal, v2: &SnakeVal, _h: &Heap) -> bool {
-    fn eq_loop(
+// [fv] without the [names] bound around it
+fn without<'e>(mut fv: HashSet<&'e str>, names: &[String]) -> HashSet<&'e str> {
+    for name in names {
+        fv.remove(name.as_str());
+    }
+    fv
+}
+
+// The variables [e] refers to that it doesn't bind itself
+fn free_vars<Ann>(e: &Exp<Ann>) -> HashSet<&str> {
+    match e {
+        Exp::Num(_, _) | Exp::Bool(_, _) | Exp::Str(_, _) => HashSet::new(),
+        Exp::Var(x, _) => HashSet::from([x.as_str()]),
+        Exp::Prim(_, exps, _) => exps.iter().flat_map(|exp| free_vars(exp)).collect(),
+        Exp::Let { bindings, body, .. } => {
+            // each binding is in scope of the ones after it
+            let mut fv = free_vars(body);
+            for (var, value) in bindings.iter().rev() {
+                fv.remove(var.as_str());
+                fv.extend(free_vars(value));
+            }
+            fv
+        }
+        Exp::If { cond, thn, els, .. } => {
+            let mut fv = free_vars(cond);
+            fv.extend(free_vars(thn));
+            fv.extend(free_vars(els));
+            fv
+        }
+        Exp::Seq { first, second, .. } => {
+            let mut fv = free_vars(first);
+            fv.extend(free_vars(second));
+            fv
+        }
+        Exp::While { cond, body, .. } => {
+            let mut fv = free_vars(cond);
+            fv.extend(free_vars(body));
+            fv
+        }
+        Exp::For {
+            var,
+            from,
+            to,
+            body,
+            ..
+        } => {
+            let mut fv = free_vars(body);
+            fv.remove(var.as_str());
+            fv.extend(free_vars(from));
+            fv.extend(free_vars(to));
+            fv
+        }
+        Exp::Assign { var, value, .. } => {
+            let mut fv = free_vars(value);
+            fv.insert(var.as_str());
+            fv
+        }
+        Exp::FunDefs { decls, body, .. } => {
+            let mut fv = free_vars(body);
+            for decl in decls {
+                fv.extend(without(free_vars(&decl.body), &decl.parameters));
+            }
+            let names: Vec<String> = decls.iter().map(|decl| decl.name.clone()).collect();
+            without(fv, &names)
+        }
+        Exp::Lambda {
+            parameters, body, ..
+        } => without(free_vars(body), parameters),
+        Exp::Call(func, args, _) => {
+            let mut fv = HashSet::from([func.as_str()]);
+            for arg in args {
+                fv.extend(free_vars(arg));
+            }
+            fv
+        }
+        Exp::InternalTailCall(_, _, _)
+        | Exp::ExternalCall { .. }
+        | Exp::MakeClosure { .. }
+        | Exp::ClosureCall { .. } => {
+            unreachable!("calls and closures of this form only exist after lambda lifting")
+        }
+    }
+}
+
+// Tuples are compared structurally. A pair of tuples that is already
+// being compared is assumed equal so that cyclic tuples terminate.
+// Closures are equal when they have the same body and the variables it
+// refers to have equal values, which is how the compiled code compares
+// the values they capture.
+fn equal_snake_val<Ann>(v1: &SnakeVal, v2: &SnakeVal, h: &Heap, funs: &Funs<Ann>) -> bool {
+    // tuples and closures already being compared, by their indices
+    struct Assumed {
+        tuples: HashSet<(usize, usize)>,
+        closures: HashSet<(usize, usize)>,
+    }
+    fn eq_loop<Ann>(
         v1: &SnakeVal,
         v2: &SnakeVal,
+        h: &Heap,
+        funs: &Funs<Ann>,
+        assumed: &mut Assumed,
     ) -> bool {
         match (v1, v2) {
             (SnakeVal::Bool(b1), SnakeVal::Bool(b2)) => b1 == b2,
             (SnakeVal::Num(n1), SnakeVal::Num(n2)) => n1 == n2,
+            (SnakeVal::Str(s1), SnakeVal::Str(s2)) => s1 == s2,
+            (SnakeVal::Tuple(a1), SnakeVal::Tuple(a2)) => {
+                if a1 == a2 || !assumed.tuples.insert((*a1, *a2)) {
+                    return true;
+                }
+                h[*a1].len() == h[*a2].len()
+                    && h[*a1]
+                        .iter()
+                        .zip(h[*a2].iter())
+                        .all(|(e1, e2)| eq_loop(e1, e2, h, funs, assumed))
+            }
+            (SnakeVal::Closure(c1), SnakeVal::Closure(c2)) => {
+                if c1 == c2 || !assumed.closures.insert((*c1, *c2)) {
+                    return true;
+                }
+                let (env1, parameters, body) = &funs[*c1];
+                let (env2, _, body2) = &funs[*c2];
+                if !std::ptr::eq(*body, *body2) {
+                    return false;
+                }
+                without(free_vars(body), parameters).into_iter().all(|x| match (get(env1, x), get(env2, x)) {
+                    (Some(x1), Some(x2)) => eq_loop(&x1.borrow(), &x2.borrow(), h, funs, assumed),
+                    _ => unreachable!("closure of a checked program refers to unbound {}", x),
+                })
+            }
             _ => false,
         }
     }
-    eq_loop(v1, v2)
+    let mut assumed = Assumed {
+        tuples: HashSet::new(),
+        closures: HashSet::new(),
+    };
+    eq_loop(v1, v2, h, funs, &mut assumed)
 }
 
 fn interpret_prim1<W>(p: &Prim, w: &mut W, v: SnakeVal, h: &Heap) -> Interp<SnakeVal>
@@ -186,6 +501,8 @@ where
         Prim::Add1 => snake_arith(v, SnakeVal::Num(1), |n1, n2| n1.overflowing_add(n2), "add1"),
         Prim::Sub1 => snake_arith(v, SnakeVal::Num(1), |n1, n2| n1.overflowing_sub(n2), "sub1"),
         Prim::Not => Ok(SnakeVal::Bool(!bool(v, "logic", "!")?)),
+        Prim::Neg => snake_arith1(v, i64::overflowing_neg, "-"),
+        Prim::Abs => snake_arith1(v, i64::overflowing_abs, "abs"),
         Prim::Print => print_snake_val(w, v, h),
         Prim::IsBool => match v {
             SnakeVal::Bool(_) => Ok(SnakeVal::Bool(true)),
@@ -195,6 +512,30 @@ where
             SnakeVal::Num(_) => Ok(SnakeVal::Bool(true)),
             _ => Ok(SnakeVal::Bool(false)),
         },
+        Prim::IsTuple => match v {
+            SnakeVal::Tuple(_) => Ok(SnakeVal::Bool(true)),
+            _ => Ok(SnakeVal::Bool(false)),
+        },
+        Prim::Length => {
+            let addr = tuple(v, "length", "length")?;
+            Ok(SnakeVal::Num(h[addr].len() as i64))
+        }
+        Prim::IsString => Ok(SnakeVal::Bool(matches!(v, SnakeVal::Str(_)))),
+        Prim::StrLen => {
+            let s = string(v, "strlen", "strlen")?;
+            Ok(SnakeVal::Num(char_bounds(&s).len() as i64 - 1))
+        }
+        Prim::NumToString => {
+            let n = num(v, "arithmetic", "num_to_string")?;
+            Ok(SnakeVal::Str(Rc::from(n.to_string().as_bytes())))
+        }
+        Prim::StringToNum => {
+            let bytes = string(v.clone(), "string_to_num", "string_to_num")?;
+            match std::str::from_utf8(&bytes).ok().and_then(|s| s.parse::<i64>().ok()) {
+                Some(n) if !out_of_bounds(n) => Ok(SnakeVal::Num(n)),
+                _ => Err(InterpErrKind::NotANumber { got: v.to_string() }),
+            }
+        }
         _ => unreachable!(),
     }
 }
@@ -213,7 +554,7 @@ where
     let n2 = num(v2, "arithmetic", op)?;
     let (n3, overflow) = arith(n1, n2);
     if overflow || out_of_bounds(n3) {
-        Err(InterpErr::Overflow {
+        Err(InterpErrKind::Overflow {
             msg: format!("{} {} {} = {}", n1, op, n2, n3),
         })
     } else {
@@ -221,6 +562,60 @@ where
     }
 }
 
+fn snake_arith1<F>(v: SnakeVal, arith: F, op: &str) -> Interp<SnakeVal>
+where
+    F: Fn(i64) -> (i64, bool),
+{
+    let n = num(v, "arithmetic", op)?;
+    let (n2, overflow) = arith(n);
+    if overflow || out_of_bounds(n2) {
+        Err(InterpErrKind::Overflow {
+            msg: format!("{}({}) = {}", op, n, n2),
+        })
+    } else {
+        Ok(SnakeVal::Num(n2))
+    }
+}
+
+// Fails on a zero divisor, after both operands are checked to be numbers
+fn snake_div<F>(v1: SnakeVal, v2: SnakeVal, div: F, op: &str) -> Interp<SnakeVal>
+where
+    F: Fn(i64, i64) -> (i64, bool),
+{
+    let n1 = num(v1.clone(), "arithmetic", op)?;
+    if num(v2.clone(), "arithmetic", op)? == 0 {
+        return Err(InterpErrKind::DivisionByZero {
+            msg: format!("{} {} 0", n1, op),
+        });
+    }
+    snake_arith(v1, v2, div, op)
+}
+
+// A shift by a negative amount overflows, as does shifting bits out on the
+// left. Shifting right by 63 or more leaves only the sign.
+fn shift_left(n: i64, by: i64) -> (i64, bool) {
+    if by < 0 {
+        (n, true)
+    } else if by >= 63 {
+        (0, n != 0)
+    } else {
+        let shifted = (n as i128) << by;
+        (shifted as i64, out_of_bounds_i128(shifted))
+    }
+}
+
+fn shift_right(n: i64, by: i64) -> (i64, bool) {
+    if by < 0 {
+        (n, true)
+    } else {
+        (n >> by.min(63), false)
+    }
+}
+
+fn out_of_bounds_i128(n: i128) -> bool {
+    n > MAX_INT as i128 || n < MIN_INT as i128
+}
+
 fn snake_log<F>(v1: SnakeVal, v2: SnakeVal, log: F, op: &str) -> Interp<SnakeVal>
 where
     F: Fn(bool, bool) -> bool,
@@ -241,13 +636,28 @@ where
     )))
 }
 
-fn interpret_prim2(p: &Prim, v1: SnakeVal, v2: SnakeVal, heap: &Heap) -> Interp<SnakeVal>
-where
-{
+fn interpret_prim2<Ann>(
+    p: &Prim,
+    v1: SnakeVal,
+    v2: SnakeVal,
+    heap: &Heap,
+    funs: &Funs<Ann>,
+) -> Interp<SnakeVal> {
     match p {
         Prim::Add => snake_arith(v1, v2, |n1, n2| n1.overflowing_add(n2), "+"),
         Prim::Sub => snake_arith(v1, v2, |n1, n2| n1.overflowing_sub(n2), "-"),
         Prim::Mul => snake_arith(v1, v2, |n1, n2| n1.overflowing_mul(n2), "*"),
+        // both round towards zero, so the remainder has the sign of the
+        // dividend
+        Prim::Div => snake_div(v1, v2, |n1, n2| n1.overflowing_div(n2), "/"),
+        Prim::Mod => snake_div(v1, v2, |n1, n2| n1.overflowing_rem(n2), "%"),
+        Prim::BitAnd => snake_arith(v1, v2, |n1, n2| (n1 & n2, false), "&"),
+        Prim::BitOr => snake_arith(v1, v2, |n1, n2| (n1 | n2, false), "|"),
+        Prim::BitXor => snake_arith(v1, v2, |n1, n2| (n1 ^ n2, false), "^"),
+        Prim::Shl => snake_arith(v1, v2, shift_left, "<<"),
+        Prim::Shr => snake_arith(v1, v2, shift_right, ">>"),
+        Prim::Min => snake_arith(v1, v2, |n1, n2| (n1.min(n2), false), "min"),
+        Prim::Max => snake_arith(v1, v2, |n1, n2| (n1.max(n2), false), "max"),
 
         Prim::And => snake_log(v1, v2, |b1, b2| b1 && b2, "&&"),
         Prim::Or => snake_log(v1, v2, |b1, b2| b1 || b2, "||"),
@@ -257,8 +667,61 @@ where
         Prim::Gt => snake_cmp(v1, v2, |n1, n2| n1 > n2, ">"),
         Prim::Ge => snake_cmp(v1, v2, |n1, n2| n1 >= n2, ">="),
 
-        Prim::Eq => Ok(SnakeVal::Bool(equal_snake_val(&v1, &v2, heap))),
-        Prim::Neq => Ok(SnakeVal::Bool(!equal_snake_val(&v1, &v2, heap))),
+        Prim::Eq => Ok(SnakeVal::Bool(equal_snake_val(&v1, &v2, heap, funs))),
+        Prim::Neq => Ok(SnakeVal::Bool(!equal_snake_val(&v1, &v2, heap, funs))),
+
+        Prim::TupleGet => {
+            let addr = tuple(v1, "indexing", "[]")?;
+            let i = index(v2, heap[addr].len(), "tuple", false, "[]")?;
+            Ok(heap[addr][i].clone())
+        }
+        Prim::Concat => {
+            let s1 = string(v1, "++", "++")?;
+            let s2 = string(v2, "++", "++")?;
+            Ok(SnakeVal::Str(s1.iter().chain(s2.iter()).copied().collect()))
+        }
+        Prim::CharAt => {
+            let s = string(v1, "char_at", "char_at")?;
+            let bounds = char_bounds(&s);
+            let i = index(v2, bounds.len() - 1, "string", false, "char_at")?;
+            Ok(SnakeVal::Str(Rc::from(&s[bounds[i]..bounds[i + 1]])))
+        }
+        _ => unreachable!(),
+    }
+}
+
+// Prims that take a variable number of arguments
+fn interpret_prim_n(p: &Prim, mut vs: Vec<SnakeVal>, heap: &mut Heap) -> Interp<SnakeVal> {
+    match p {
+        Prim::MakeTuple => {
+            heap.push(vs);
+            Ok(SnakeVal::Tuple(heap.len() - 1))
+        }
+        Prim::TupleSet => {
+            let v = vs.pop().unwrap();
+            let i = vs.pop().unwrap();
+            let t = vs.pop().unwrap();
+            let addr = tuple(t.clone(), "indexing", ":=")?;
+            let i = index(i, heap[addr].len(), "tuple", false, ":=")?;
+            heap[addr][i] = v;
+            Ok(t)
+        }
+        // the end is past the last character taken
+        Prim::Substr => {
+            let end = vs.pop().unwrap();
+            let start = vs.pop().unwrap();
+            let s = string(vs.pop().unwrap(), "substr", "substr")?;
+            // both are numbers before either is out of bounds
+            num(start.clone(), "index", "substr")?;
+            num(end.clone(), "index", "substr")?;
+            let bounds = char_bounds(&s);
+            let start = index(start, bounds.len() - 1, "string", true, "substr")?;
+            let end = index(end, bounds.len() - 1, "string", true, "substr")?;
+            if end < start {
+                return Err(InterpErrKind::SubstrRange { start, end });
+            }
+            Ok(SnakeVal::Str(Rc::from(&s[bounds[start]..bounds[end]])))
+        }
         _ => unreachable!(),
     }
 }
@@ -291,12 +754,23 @@ enum Machine<'exp, Ann> {
 
 enum Stack<'exp, Ann> {
     Done,
-    Prim1(Prim, Box<Stack<'exp, Ann>>),
-    Prim2L(Prim, Closure<'exp, Ann>, Box<Stack<'exp, Ann>>),
-    Prim2R(Prim, SnakeVal, Box<Stack<'exp, Ann>>),
+    // the prims and everything else that can fail keep the location of
+    // their expression
+    Prim1(Prim, &'exp Ann, Box<Stack<'exp, Ann>>),
+    Prim2L(Prim, &'exp Ann, Closure<'exp, Ann>, Box<Stack<'exp, Ann>>),
+    Prim2R(Prim, &'exp Ann, SnakeVal, Box<Stack<'exp, Ann>>),
+    PrimArgs {
+        op: Prim,
+        ann: &'exp Ann,
+        evaled_args: Vec<SnakeVal>,
+        env: Env,
+        remaining_args: Vec<&'exp Exp<Ann>>,
+        stk: Box<Stack<'exp, Ann>>,
+    },
     If {
         thn: &'exp Exp<Ann>,
         els: &'exp Exp<Ann>,
+        ann: &'exp Ann,
         env: Env,
         stk: Box<Stack<'exp, Ann>>,
     },
@@ -307,8 +781,64 @@ enum Stack<'exp, Ann> {
         body: &'exp Exp<Ann>,
         stk: Box<Stack<'exp, Ann>>,
     },
+    // Drops the value of the first expression of a sequence
+    Seq {
+        second: &'exp Exp<Ann>,
+        env: Env,
+        stk: Box<Stack<'exp, Ann>>,
+    },
+    // Runs the body if the condition is true, and then this again
+    While {
+        cond: &'exp Exp<Ann>,
+        body: &'exp Exp<Ann>,
+        ann: &'exp Ann,
+        env: Env,
+        stk: Box<Stack<'exp, Ann>>,
+    },
+    WhileBody {
+        cond: &'exp Exp<Ann>,
+        body: &'exp Exp<Ann>,
+        ann: &'exp Ann,
+        env: Env,
+        stk: Box<Stack<'exp, Ann>>,
+    },
+    ForFrom {
+        var: &'exp str,
+        to: &'exp Exp<Ann>,
+        body: &'exp Exp<Ann>,
+        ann: &'exp Ann,
+        env: Env,
+        stk: Box<Stack<'exp, Ann>>,
+    },
+    // Both bounds are evaluated before either is checked, like the
+    // compiled code does
+    ForTo {
+        var: &'exp str,
+        from: SnakeVal,
+        body: &'exp Exp<Ann>,
+        ann: &'exp Ann,
+        env: Env,
+        stk: Box<Stack<'exp, Ann>>,
+    },
+    // The body just ran with var bound to i
+    ForBody {
+        var: &'exp str,
+        i: i64,
+        to: i64,
+        body: &'exp Exp<Ann>,
+        env: Env,
+        stk: Box<Stack<'exp, Ann>>,
+    },
+    // Stores the value in var
+    Assign {
+        var: &'exp str,
+        env: Env,
+        stk: Box<Stack<'exp, Ann>>,
+    },
     CallArgs {
-        fun: usize, // the closure
+        name: &'exp str,
+        ann: &'exp Ann,
+        fun: SnakeVal, // the closure
         evaled_args: Vec<SnakeVal>,
         env: Env,
         remaining_args: Vec<&'exp Exp<Ann>>,
@@ -316,9 +846,10 @@ enum Stack<'exp, Ann> {
     },
 }
 
-// No heap for now!
-type Heap = ();
-type Funs<'e, Ann> = Vec<(Env, &'e SurfFunDecl<Ann>)>;
+// Each tuple is a vector of its elements, addressed by its index in the heap
+type Heap = Vec<Vec<SnakeVal>>;
+// A function is its captured environment, parameters and body
+type Funs<'e, Ann> = Vec<(Env, &'e [String], &'e Exp<Ann>)>;
 struct State<'e, Ann> {
     funs: Funs<'e, Ann>,
     heap: Heap,
@@ -328,7 +859,7 @@ impl<'e, Ann> State<'e, Ann> {
     fn new() -> Self {
         State {
             funs: vec![],
-            heap: (),
+            heap: vec![],
         }
     }
 }
@@ -340,13 +871,18 @@ impl<'e, Ann> State<'e, Ann> {
  *  so that we don't blow the Rust stack/rely on Rust TCE.
  *
 */
-fn machine<'exp, Ann, W>(e: &'exp Exp<Ann>, buf: &mut W, store: &mut State<'exp, Ann>) -> Interp<()>
+fn machine<'exp, Ann, W>(
+    e: &'exp Exp<Ann>,
+    buf: &mut W,
+    store: &mut State<'exp, Ann>,
+) -> Result<(), InterpErr<Ann>>
 where
     W: std::io::Write,
     Ann: Clone,
 {
     fn call<'exp, Ann>(
-        fun: usize,
+        name: &str,
+        fun: SnakeVal,
         args: Vec<SnakeVal>,
         stk: Stack<'exp, Ann>,
         funs: &Funs<'exp, Ann>,
@@ -354,45 +890,50 @@ where
     where
         Ann: Clone,
     {
-        let (orig_env, d) = &funs[fun];
+        let ix = match fun {
+            SnakeVal::Closure(ix) => ix,
+            v => {
+                return Err(InterpErrKind::ExpectedFun {
+                    who: String::from("call"),
+                    got: v.to_string(),
+                    msg: name.to_string(),
+                })
+            }
+        };
+        let (orig_env, parameters, body) = &funs[ix];
 	let mut env = orig_env.clone();
 
-        if args.len() != d.parameters.len() {
-            return Err(InterpErr::ArityErr {
-                expected_arity: d.parameters.len(),
+        if args.len() != parameters.len() {
+            return Err(InterpErrKind::ArityErr {
+                expected_arity: parameters.len(),
                 num_provided: args.len(),
             });
         }
 	// environment for the body should consist of the captured env
 	// extended with the new parameters
-	for (v, x) in args.iter().zip(d.parameters.iter()) {
+	for (v, x) in args.iter().zip(parameters.iter()) {
 	    env = push_local(&env, x.to_string(), v.clone())
 	}
 	Ok(Machine::Descending {
-            e: &d.body,
+            e: body,
             env,
             stk,
         })
     }
 
-    fn mangle_fun_name(s: &str) -> String {
-	format!("{}#fun", s)
-    }
-    
     // Allocate closures for a mutually recursive sequence of function
     // declarations, returning an environment where the functions'
-    // names (mangled to avoid clashing with value variables) are
-    // associated to the address of their closure.
+    // names are associated to the address of their closure.
     fn alloc_funs<'e, Ann>(decls: &'e [SurfFunDecl<Ann>], mut env: Env, funs: &mut Funs<'e, Ann>) -> Env {
 	// Each of the closures captures the same environment: the
 	// current environment extended with all of their names
 	// i.e., the env we return.
 	let i = funs.len();
 	for (j, d) in decls.iter().enumerate() {
-	    env = push_local(&env, mangle_fun_name(&d.name), SnakeVal::Closure(i + j));
+	    env = push_local(&env, d.name.clone(), SnakeVal::Closure(i + j));
 	}
 	for d in decls.iter() {
-	    funs.push((env.clone(), &d));
+	    funs.push((env.clone(), &d.parameters, &d.body));
 	}
 	env
     }
@@ -405,7 +946,10 @@ where
     loop {
         match machine {
             Machine::Descending { e, stk, env } => match e {
-                Exp::InternalTailCall(..) | Exp::ExternalCall { .. } => {
+                Exp::InternalTailCall(..)
+                | Exp::ExternalCall { .. }
+                | Exp::MakeClosure { .. }
+                | Exp::ClosureCall { .. } => {
                     panic!("Should never happen: interpreter called with internal compiler forms")
                 }
                 Exp::Num(n, _) => {
@@ -420,32 +964,46 @@ where
                         stk,
                     }
                 }
+                Exp::Str(s, _) => {
+                    machine = Machine::Returning {
+                        v: SnakeVal::Str(Rc::from(s.as_bytes())),
+                        stk,
+                    }
+                }
                 Exp::Var(x, _) => {
                     let v = get(&*env, x).expect("Unbound variable in interpreter! You should catch this in the check function!");
-                    machine = Machine::Returning { v: v.clone(), stk }
+                    machine = Machine::Returning { v: v.borrow().clone(), stk }
                 }
-                Exp::Prim(op, es, _) => {
+                Exp::Prim(op, es, ann) => {
                     match op {
                         Prim::Add1 | Prim::Sub1 | Prim::Not |
                         Prim::Print | Prim::IsBool |
-                        Prim::IsNum => {
+                        Prim::IsNum | Prim::IsTuple | Prim::Length |
+                        Prim::Neg | Prim::Abs | Prim::IsString |
+                        Prim::StrLen | Prim::NumToString |
+                        Prim::StringToNum => {
                             let e = &es[0];
                             machine = Machine::Descending {
                                 e,
-                                stk: Stack::Prim1(*op, Box::new(stk)),
+                                stk: Stack::Prim1(*op, ann, Box::new(stk)),
                                 env,
                             };
                         },
                         Prim::Add | Prim::Sub | Prim::Mul |
+                        Prim::Div | Prim::Mod | Prim::BitAnd |
+                        Prim::BitOr | Prim::BitXor | Prim::Shl |
+                        Prim::Shr | Prim::Min | Prim::Max |
                         Prim::And | Prim::Or | Prim::Lt |
                         Prim::Gt | Prim::Le | Prim::Ge |
-                        Prim::Eq | Prim::Neq => {
+                        Prim::Eq | Prim::Neq | Prim::TupleGet |
+                        Prim::Concat | Prim::CharAt => {
                             let e1 = &es[0];
                             let e2 = &es[1];
                             machine = Machine::Descending {
                                 e: e1,
                                 stk: Stack::Prim2L(
                                     *op,
+                                    ann,
                                     Closure {
                                         exp: e2,
                                         env: env.clone(),
@@ -455,6 +1013,31 @@ where
                                 env,
                             };
                         }
+                        Prim::TupleSet | Prim::MakeTuple | Prim::Substr => {
+                            let mut remaining_args: Vec<&Exp<_>> =
+                                es.iter().map(|e| &**e).rev().collect();
+                            match remaining_args.pop() {
+                                None => {
+                                    let v = interpret_prim_n(op, Vec::new(), &mut store.heap)
+                                        .map_err(at(ann))?;
+                                    machine = Machine::Returning { v, stk };
+                                }
+                                Some(e) => {
+                                    machine = Machine::Descending {
+                                        e,
+                                        env: env.clone(),
+                                        stk: Stack::PrimArgs {
+                                            op: *op,
+                                            ann,
+                                            evaled_args: Vec::new(),
+                                            env,
+                                            remaining_args,
+                                            stk: Box::new(stk),
+                                        },
+                                    };
+                                }
+                            }
+                        }
                     }
                 }
                 Exp::Let { bindings, body, .. } => {
@@ -478,36 +1061,97 @@ where
                         }
                     }
                 }
-                Exp::If { cond, thn, els, .. } => {
+                Exp::If {
+                    cond,
+                    thn,
+                    els,
+                    ann,
+                } => {
                     machine = Machine::Descending {
                         e: cond,
                         stk: Stack::If {
                             thn,
                             els,
+                            ann,
+                            env: env.clone(),
+                            stk: Box::new(stk),
+                        },
+                        env,
+                    }
+                }
+                Exp::Seq { first, second, .. } => {
+                    machine = Machine::Descending {
+                        e: first,
+                        stk: Stack::Seq {
+                            second,
+                            env: env.clone(),
+                            stk: Box::new(stk),
+                        },
+                        env,
+                    }
+                }
+                Exp::While { cond, body, ann } => {
+                    machine = Machine::Descending {
+                        e: cond,
+                        stk: Stack::While {
+                            cond,
+                            body,
+                            ann,
                             env: env.clone(),
                             stk: Box::new(stk),
                         },
                         env,
                     }
                 }
-                Exp::Call(fun, args, _) => {
-		    let ix = match get(&*env, &mangle_fun_name(fun)) {
-			Some(SnakeVal::Closure(ix)) => *ix,
-			_ => panic!("bug in interpreter?"),
-		    };
+                Exp::For {
+                    var,
+                    from,
+                    to,
+                    body,
+                    ann,
+                } => {
+                    machine = Machine::Descending {
+                        e: from,
+                        stk: Stack::ForFrom {
+                            var,
+                            to,
+                            body,
+                            ann,
+                            env: env.clone(),
+                            stk: Box::new(stk),
+                        },
+                        env,
+                    }
+                }
+                Exp::Assign { var, value, .. } => {
+                    machine = Machine::Descending {
+                        e: value,
+                        stk: Stack::Assign {
+                            var,
+                            env: env.clone(),
+                            stk: Box::new(stk),
+                        },
+                        env,
+                    }
+                }
+                Exp::Call(fun, args, ann) => {
+                    // the function is only checked once its arguments are evaluated
+		    let f = get(&*env, fun).expect("Unbound function in interpreter! You should catch this in the check function!").borrow().clone();
                     let mut remaining_args: Vec<&Exp<_>> = args.iter().collect();
                     remaining_args.reverse();
                     match remaining_args.pop() {
                         None => {
                             machine =
-                                call(ix, Vec::new(), stk, &store.funs)?;
+                                call(fun, f, Vec::new(), stk, &store.funs).map_err(at(ann))?;
                         }
                         Some(e) => {
                             machine = Machine::Descending {
-                                e: &e,
+                                e,
                                 env: env.clone(),
                                 stk: Stack::CallArgs {
-                                    fun: ix,
+                                    name: fun,
+                                    ann,
+                                    fun: f,
                                     evaled_args: Vec::new(),
                                     env,
                                     remaining_args,
@@ -517,10 +1161,17 @@ where
                         }
                     }
                 }
+		Exp::Lambda { parameters, body, .. } => {
+		    store.funs.push((env, parameters, body));
+		    machine = Machine::Returning {
+			v: SnakeVal::Closure(store.funs.len() - 1),
+			stk,
+		    }
+		}
 		Exp::FunDefs { decls, body, .. } => {
-		    let env = alloc_funs(&decls, env, &mut store.funs);
+		    let env = alloc_funs(decls, env, &mut store.funs);
 		    machine = Machine::Descending {
-			e: &body,
+			e: body,
 			env: env.clone(),
 			stk			
 		    }
@@ -529,24 +1180,68 @@ where
             },
             Machine::Returning { v, stk } => match stk {
                 Stack::Done => {
-                    print_snake_val(buf, v, &store.heap)?;
+                    print_snake_val(buf, v, &store.heap).map_err(|kind| InterpErr {
+                        kind,
+                        location: None,
+                    })?;
                     return Ok(());
                 }
-                Stack::Prim1(op, stk) => {
-                    let v = interpret_prim1(&op, buf, v, &store.heap)?;
+                Stack::Prim1(op, ann, stk) => {
+                    let v = interpret_prim1(&op, buf, v, &store.heap).map_err(at(ann))?;
                     machine = Machine::Returning { v, stk: *stk }
                 }
-                Stack::Prim2L(op, r, stk) => {
-                    machine = Machine::Descending {
-                        e: r.exp,
-                        env: r.env,
-                        stk: Stack::Prim2R(op, v, stk),
+                Stack::Prim2L(op, ann, r, stk) => {
+                    // false && r and true || r don't evaluate r
+                    let decided = match op {
+                        Prim::And => !bool(v.clone(), "logic", "&&").map_err(at(ann))?,
+                        Prim::Or => bool(v.clone(), "logic", "||").map_err(at(ann))?,
+                        _ => false,
+                    };
+                    machine = if decided {
+                        Machine::Returning { v, stk: *stk }
+                    } else {
+                        Machine::Descending {
+                            e: r.exp,
+                            env: r.env,
+                            stk: Stack::Prim2R(op, ann, v, stk),
+                        }
                     };
                 }
-                Stack::Prim2R(op, vl, stk) => {
-                    let v = interpret_prim2(&op, vl, v, &store.heap)?;
+                Stack::Prim2R(op, ann, vl, stk) => {
+                    let v = interpret_prim2(&op, vl, v, &store.heap, &store.funs).map_err(at(ann))?;
                     machine = Machine::Returning { v, stk: *stk };
                 }
+                Stack::PrimArgs {
+                    op,
+                    ann,
+                    mut evaled_args,
+                    env,
+                    mut remaining_args,
+                    stk,
+                } => {
+                    evaled_args.push(v);
+                    match remaining_args.pop() {
+                        None => {
+                            let v = interpret_prim_n(&op, evaled_args, &mut store.heap)
+                                .map_err(at(ann))?;
+                            machine = Machine::Returning { v, stk: *stk };
+                        }
+                        Some(e) => {
+                            machine = Machine::Descending {
+                                e,
+                                env: env.clone(),
+                                stk: Stack::PrimArgs {
+                                    op,
+                                    ann,
+                                    evaled_args,
+                                    env,
+                                    remaining_args,
+                                    stk,
+                                },
+                            }
+                        }
+                    }
+                }
                 Stack::Let {
                     var,
                     mut env,
@@ -575,11 +1270,152 @@ where
                     }
                 }
 
-                Stack::If { thn, els, env, stk } => {
-                    let e = if bool(v, "if", "if")? { thn } else { els };
+                Stack::Seq { second, env, stk } => {
+                    machine = Machine::Descending {
+                        e: second,
+                        env,
+                        stk: *stk,
+                    }
+                }
+                Stack::While {
+                    cond,
+                    body,
+                    ann,
+                    env,
+                    stk,
+                } => {
+                    machine = if bool(v.clone(), "while", "while").map_err(at(ann))? {
+                        Machine::Descending {
+                            e: body,
+                            env: env.clone(),
+                            stk: Stack::WhileBody {
+                                cond,
+                                body,
+                                ann,
+                                env,
+                                stk,
+                            },
+                        }
+                    } else {
+                        Machine::Returning { v, stk: *stk }
+                    }
+                }
+                Stack::WhileBody {
+                    cond,
+                    body,
+                    ann,
+                    env,
+                    stk,
+                } => {
+                    machine = Machine::Descending {
+                        e: cond,
+                        env: env.clone(),
+                        stk: Stack::While {
+                            cond,
+                            body,
+                            ann,
+                            env,
+                            stk,
+                        },
+                    }
+                }
+                Stack::ForFrom {
+                    var,
+                    to,
+                    body,
+                    ann,
+                    env,
+                    stk,
+                } => {
+                    machine = Machine::Descending {
+                        e: to,
+                        env: env.clone(),
+                        stk: Stack::ForTo {
+                            var,
+                            from: v,
+                            body,
+                            ann,
+                            env,
+                            stk,
+                        },
+                    }
+                }
+                Stack::ForTo {
+                    var,
+                    from,
+                    body,
+                    ann,
+                    env,
+                    stk,
+                } => {
+                    let from = num(from, "arithmetic", "for").map_err(at(ann))?;
+                    let to = num(v, "arithmetic", "for").map_err(at(ann))?;
+                    machine = if from > to {
+                        Machine::Returning {
+                            v: SnakeVal::Bool(false),
+                            stk: *stk,
+                        }
+                    } else {
+                        Machine::Descending {
+                            e: body,
+                            env: push_local(&env, var.to_string(), SnakeVal::Num(from)),
+                            stk: Stack::ForBody {
+                                var,
+                                i: from,
+                                to,
+                                body,
+                                env,
+                                stk,
+                            },
+                        }
+                    }
+                }
+                Stack::ForBody {
+                    var,
+                    i,
+                    to,
+                    body,
+                    env,
+                    stk,
+                } => {
+                    machine = if i == to {
+                        Machine::Returning {
+                            v: SnakeVal::Bool(false),
+                            stk: *stk,
+                        }
+                    } else {
+                        Machine::Descending {
+                            e: body,
+                            env: push_local(&env, var.to_string(), SnakeVal::Num(i + 1)),
+                            stk: Stack::ForBody {
+                                var,
+                                i: i + 1,
+                                to,
+                                body,
+                                env,
+                                stk,
+                            },
+                        }
+                    }
+                }
+                Stack::If {
+                    thn,
+                    els,
+                    ann,
+                    env,
+                    stk,
+                } => {
+                    let e = if bool(v, "if", "if").map_err(at(ann))? { thn } else { els };
                     machine = Machine::Descending { e, env, stk: *stk }
                 }
+                Stack::Assign { var, env, stk } => {
+                    let cell = get(&*env, var).expect("Unbound variable in interpreter! You should catch this in the check function!");
+                    *cell.borrow_mut() = v.clone();
+                    machine = Machine::Returning { v, stk: *stk }
+                }
                 Stack::CallArgs {
+                    name,
+                    ann,
                     fun: fun_v,
                     mut evaled_args,
                     env,
@@ -590,17 +1426,21 @@ where
                     match remaining_args.pop() {
                         None => {
                             machine = call(
+                                name,
                                 fun_v,
                                 evaled_args,
                                 *stk,
                                 &store.funs,
-                            )?;
+                            )
+                            .map_err(at(ann))?;
                         }
                         Some(e) => {
                             machine = Machine::Descending {
                                 e,
                                 env: env.clone(),
                                 stk: Stack::CallArgs {
+                                    name,
+                                    ann,
                                     fun: fun_v,
                                     evaled_args,
                                     env,
@@ -617,7 +1457,7 @@ where
 }
 
 // Runs the reference interpreter.
-pub fn exp<Ann, W>(e: &Exp<Ann>, w: &mut W) -> Interp<()>
+pub fn exp<Ann, W>(e: &Exp<Ann>, w: &mut W) -> Result<(), InterpErr<Ann>>
 where
     Ann: Clone,
     W: std::io::Write,
@@ -625,10 +1465,10 @@ where
     machine(e, w, &mut State::new())
 }
 
-pub fn prog<Ann, W>(p: &SurfProg<Ann>, w: &mut W) -> Interp<()>
+pub fn prog<Ann, W>(p: &SurfProg<Ann>, w: &mut W) -> Result<(), InterpErr<Ann>>
 where
     W: std::io::Write,
     Ann: Clone,
 {
-    machine(&p, w, &mut State::new())
+    machine(p, w, &mut State::new())
 }
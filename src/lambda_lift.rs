@@ -1,11 +1,16 @@
-use std::{
-    collections::{HashMap, HashSet},
-    iter::FromIterator,
-};
+use std::collections::{HashMap, HashSet};
 
 use crate::syntax::*;
 
-fn uniquify<Span>(e: &Exp<Span>, mapping: &HashMap<String, String>, counter: &mut u32) -> Exp<()> {
+// Renames every binder to [name]$[n] with a unique n, so that no two share a name.
+// Functions also get the names of the functions they are defined in, e.g. loop
+// defined in fac becomes fac$loop$3; [path] is that prefix.
+fn uniquify<Ann: Clone>(
+    e: &Exp<Ann>,
+    mapping: &HashMap<String, String>,
+    path: &str,
+    counter: &mut u32,
+) -> Exp<Ann> {
     match e {
         Exp::Let {
             bindings,
@@ -17,31 +22,35 @@ fn uniquify<Span>(e: &Exp<Span>, mapping: &HashMap<String, String>, counter: &mu
                 .iter()
                 .map(|(var, value)| {
                     *counter += 1;
-                    let new_var = format!("{}", counter);
-                    let mut_exp = uniquify(value, &scoped_mapping, counter);
+                    let new_var = format!("{}${}", var, counter);
+                    let mut_exp = uniquify(value, &scoped_mapping, path, counter);
                     scoped_mapping.insert(var.to_string(), new_var.clone());
-                    return (new_var, mut_exp);
+                    (new_var, mut_exp)
                 })
                 .collect();
             Exp::Let {
                 bindings: mut_bind,
-                body: Box::new(uniquify(&body, &scoped_mapping, counter)),
-                ann: (),
+                body: Box::new(uniquify(body, &scoped_mapping, path, counter)),
+                ann: ann.clone(),
             }
         }
         Exp::FunDefs { decls, body, ann } => {
             let mut scoped_mapping = mapping.clone();
             for decl in decls {
                 *counter += 1;
-                scoped_mapping.insert(decl.name.to_string(), format!("{}", counter));
+                scoped_mapping.insert(
+                    decl.name.to_string(),
+                    format!("{}{}${}", path, decl.name, counter),
+                );
             }
             let mut uniq_decls = vec![];
             for decl in decls {
                 let mut func_scope_map = scoped_mapping.clone();
                 for param in &decl.parameters {
                     *counter += 1;
-                    func_scope_map.insert(param.to_string(), format!("{}", counter));
+                    func_scope_map.insert(param.to_string(), format!("{}${}", param, counter));
                 }
+                let decl_path = format!("{}{}$", path, decl.name);
                 uniq_decls.push(FunDecl {
                     name: scoped_mapping[&decl.name].clone(),
                     parameters: decl
@@ -49,66 +58,237 @@ fn uniquify<Span>(e: &Exp<Span>, mapping: &HashMap<String, String>, counter: &mu
                         .iter()
                         .map(|param| func_scope_map[param].clone())
                         .collect(),
-                    body: uniquify(&decl.body, &func_scope_map, counter),
-                    ann: (),
+                    body: uniquify(&decl.body, &func_scope_map, &decl_path, counter),
+                    ann: decl.ann.clone(),
                 })
             }
             Exp::FunDefs {
                 decls: uniq_decls,
-                body: Box::new(uniquify(&body, &scoped_mapping, counter)),
-                ann: (),
+                body: Box::new(uniquify(body, &scoped_mapping, path, counter)),
+                ann: ann.clone(),
             }
         }
-        Exp::Var(v, _) => Exp::Var(mapping[v].clone(), ()),
-        Exp::Num(i, _) => Exp::Num(*i, ()),
-        Exp::Bool(b, _) => Exp::Bool(*b, ()),
-        Exp::Prim(op, subjects, _) => {
+        Exp::Lambda {
+            parameters,
+            body,
+            ann,
+        } => {
+            let mut scoped_mapping = mapping.clone();
+            for param in parameters {
+                *counter += 1;
+                scoped_mapping.insert(param.to_string(), format!("{}${}", param, counter));
+            }
+            let lambda_path = format!("{}lambda$", path);
+            Exp::Lambda {
+                parameters: parameters
+                    .iter()
+                    .map(|param| scoped_mapping[param].clone())
+                    .collect(),
+                body: Box::new(uniquify(body, &scoped_mapping, &lambda_path, counter)),
+                ann: ann.clone(),
+            }
+        }
+        Exp::Var(v, ann) => Exp::Var(mapping[v].clone(), ann.clone()),
+        Exp::Num(i, ann) => Exp::Num(*i, ann.clone()),
+        Exp::Bool(b, ann) => Exp::Bool(*b, ann.clone()),
+        Exp::Str(s, ann) => Exp::Str(s.clone(), ann.clone()),
+        Exp::Prim(op, subjects, ann) => {
             let uniq_sub = subjects
                 .iter()
-                .map(|s| Box::new(uniquify(s, mapping, counter)))
+                .map(|s| Box::new(uniquify(s, mapping, path, counter)))
                 .collect();
-            Exp::Prim(*op, uniq_sub, ())
+            Exp::Prim(*op, uniq_sub, ann.clone())
         }
         Exp::If {
             cond,
             thn,
             els,
-            ann: _,
+            ann,
         } => Exp::If {
-            cond: Box::new(uniquify(&cond, mapping, counter)),
-            thn: Box::new(uniquify(&thn, mapping, counter)),
-            els: Box::new(uniquify(&els, mapping, counter)),
-            ann: (),
+            cond: Box::new(uniquify(cond, mapping, path, counter)),
+            thn: Box::new(uniquify(thn, mapping, path, counter)),
+            els: Box::new(uniquify(els, mapping, path, counter)),
+            ann: ann.clone(),
+        },
+        Exp::Seq { first, second, ann } => Exp::Seq {
+            first: Box::new(uniquify(first, mapping, path, counter)),
+            second: Box::new(uniquify(second, mapping, path, counter)),
+            ann: ann.clone(),
+        },
+        Exp::While { cond, body, ann } => Exp::While {
+            cond: Box::new(uniquify(cond, mapping, path, counter)),
+            body: Box::new(uniquify(body, mapping, path, counter)),
+            ann: ann.clone(),
+        },
+        Exp::For {
+            var,
+            from,
+            to,
+            body,
+            ann,
+        } => {
+            let from = uniquify(from, mapping, path, counter);
+            let to = uniquify(to, mapping, path, counter);
+            *counter += 1;
+            let new_var = format!("{}${}", var, counter);
+            let mut scoped_mapping = mapping.clone();
+            scoped_mapping.insert(var.to_string(), new_var.clone());
+            Exp::For {
+                var: new_var,
+                from: Box::new(from),
+                to: Box::new(to),
+                body: Box::new(uniquify(body, &scoped_mapping, path, counter)),
+                ann: ann.clone(),
+            }
+        }
+        Exp::Assign { var, value, ann } => Exp::Assign {
+            var: mapping[var].clone(),
+            value: Box::new(uniquify(value, mapping, path, counter)),
+            ann: ann.clone(),
         },
-        Exp::Call(func, params, _) => {
+        Exp::Call(func, params, ann) => {
             let uniq_params = params
                 .iter()
-                .map(|s| uniquify(s, mapping, counter))
+                .map(|s| uniquify(s, mapping, path, counter))
                 .collect();
-            Exp::Call(mapping[func].clone(), uniq_params, ())
-        }
-        Exp::InternalTailCall(_, _, _) => todo!(),
-        Exp::ExternalCall {
-            fun_name: _,
-            args: _,
-            is_tail,
-            ann: _,
-        } => todo!(),
+            Exp::Call(mapping[func].clone(), uniq_params, ann.clone())
+        }
+        // only produced by lifting, which runs after uniquify
+        Exp::InternalTailCall(_, _, _)
+        | Exp::ExternalCall { .. }
+        | Exp::MakeClosure { .. }
+        | Exp::ClosureCall { .. } => e.clone(),
+    }
+}
+
+// Variables needed to evaluate e outside of its scope. A reference to a lifted
+// function needs the variables that function captures.
+fn free_vars<Ann>(e: &Exp<Ann>, captured: &HashMap<String, Vec<String>>) -> HashSet<String> {
+    let reference = |name: &String| match captured.get(name) {
+        Some(env) => env.iter().cloned().collect(),
+        None => HashSet::from([name.clone()]),
+    };
+    // names are unique after uniquify, so binders can be removed after the fact
+    match e {
+        Exp::Num(_, _) | Exp::Bool(_, _) | Exp::Str(_, _) => HashSet::new(),
+        Exp::Var(x, _) => reference(x),
+        Exp::Prim(_, exps, _) => exps.iter().flat_map(|exp| free_vars(exp, captured)).collect(),
+        Exp::Let { bindings, body, .. } => {
+            let mut fv = free_vars(body, captured);
+            for (_, bind) in bindings {
+                fv.extend(free_vars(bind, captured));
+            }
+            for (var, _) in bindings {
+                fv.remove(var);
+            }
+            fv
+        }
+        Exp::If { cond, thn, els, .. } => {
+            let mut fv = free_vars(cond, captured);
+            fv.extend(free_vars(thn, captured));
+            fv.extend(free_vars(els, captured));
+            fv
+        }
+        Exp::Seq { first, second, .. } => {
+            let mut fv = free_vars(first, captured);
+            fv.extend(free_vars(second, captured));
+            fv
+        }
+        Exp::While { cond, body, .. } => {
+            let mut fv = free_vars(cond, captured);
+            fv.extend(free_vars(body, captured));
+            fv
+        }
+        Exp::For {
+            var,
+            from,
+            to,
+            body,
+            ..
+        } => {
+            let mut fv = free_vars(body, captured);
+            fv.remove(var);
+            fv.extend(free_vars(from, captured));
+            fv.extend(free_vars(to, captured));
+            fv
+        }
+        Exp::Assign { var, value, .. } => {
+            let mut fv = free_vars(value, captured);
+            fv.insert(var.clone());
+            fv
+        }
+        Exp::FunDefs { decls, body, .. } => {
+            let mut fv = free_vars(body, captured);
+            for decl in decls {
+                let mut body_fv = free_vars(&decl.body, captured);
+                for param in &decl.parameters {
+                    body_fv.remove(param);
+                }
+                fv.extend(body_fv);
+            }
+            for decl in decls {
+                fv.remove(&decl.name);
+            }
+            fv
+        }
+        Exp::Lambda {
+            parameters, body, ..
+        } => {
+            let mut fv = free_vars(body, captured);
+            for param in parameters {
+                fv.remove(param);
+            }
+            fv
+        }
+        Exp::Call(func, args, _) => {
+            let mut fv = reference(func);
+            for arg in args {
+                fv.extend(free_vars(arg, captured));
+            }
+            fv
+        }
+        Exp::InternalTailCall(_, args, _) | Exp::ExternalCall { args, .. } => args
+            .iter()
+            .flat_map(|arg| free_vars(arg, captured))
+            .collect(),
+        Exp::MakeClosure { captured: env, .. } => env
+            .iter()
+            .flat_map(|exp| free_vars(exp, captured))
+            .collect(),
+        Exp::ClosureCall { closure, args, .. } => {
+            let mut fv = reference(closure);
+            for arg in args {
+                fv.extend(free_vars(arg, captured));
+            }
+            fv
+        }
     }
 }
 
-fn rewrite_call_params(
-    e: &Exp<()>,
-    globals: &HashMap<String, FunDecl<Exp<()>, ()>>,
+fn rewrite_call_params<Ann: Clone>(
+    e: &Exp<Ann>,
+    arities: &HashMap<String, usize>,
+    captured: &HashMap<String, Vec<String>>,
+    locals: &HashSet<String>,
     is_tail: bool,
-) -> Exp<()> {
+) -> Exp<Ann> {
+    let rewrite = |e, is_tail| rewrite_call_params(e, arities, captured, locals, is_tail);
     match e {
-        Exp::Prim(p, exps, _) => Exp::Prim(
+        Exp::Var(func, ann) if captured.contains_key(func) => Exp::MakeClosure {
+            fun_name: func.clone(),
+            arity: arities[func],
+            captured: captured[func]
+                .iter()
+                .map(|var| Exp::Var(var.clone(), ann.clone()))
+                .collect(),
+            ann: ann.clone(),
+        },
+        Exp::Prim(p, exps, ann) => Exp::Prim(
             *p,
             exps.iter()
-                .map(|exp| Box::new(rewrite_call_params(exp, globals, false)))
+                .map(|exp| Box::new(rewrite(exp, false)))
                 .collect(),
-            (),
+            ann.clone(),
         ),
         Exp::Let {
             bindings,
@@ -117,10 +297,10 @@ fn rewrite_call_params(
         } => Exp::Let {
             bindings: bindings
                 .iter()
-                .map(|bind| (bind.0.clone(), rewrite_call_params(&bind.1, globals, false)))
+                .map(|bind| (bind.0.clone(), rewrite(&bind.1, false)))
                 .collect(),
-            body: Box::new(rewrite_call_params(body, globals, is_tail)),
-            ann: (),
+            body: Box::new(rewrite(body, is_tail)),
+            ann: ann.clone(),
         },
         Exp::If {
             cond,
@@ -128,214 +308,440 @@ fn rewrite_call_params(
             els,
             ann,
         } => Exp::If {
-            cond: Box::new(rewrite_call_params(cond, globals, false)),
-            thn: Box::new(rewrite_call_params(thn, globals, is_tail)),
-            els: Box::new(rewrite_call_params(els, globals, is_tail)),
-            ann: (),
+            cond: Box::new(rewrite(cond, false)),
+            thn: Box::new(rewrite(thn, is_tail)),
+            els: Box::new(rewrite(els, is_tail)),
+            ann: ann.clone(),
         },
-        Exp::FunDefs { decls, body, ann } => Exp::FunDefs {
-            decls: decls
-                .iter()
-                .map(|decl| FunDecl {
-                    name: decl.name.clone(),
-                    parameters: decl.parameters.clone(),
-                    body: rewrite_call_params(&decl.body, globals, is_tail),
-                    ann: (),
-                })
-                .collect(),
-            body: Box::new(rewrite_call_params(body, globals, is_tail)),
-            ann: (),
+        Exp::Seq { first, second, ann } => Exp::Seq {
+            first: Box::new(rewrite(first, false)),
+            second: Box::new(rewrite(second, is_tail)),
+            ann: ann.clone(),
+        },
+        // the loop goes on after its body, so nothing in it is a tail call
+        Exp::While { cond, body, ann } => Exp::While {
+            cond: Box::new(rewrite(cond, false)),
+            body: Box::new(rewrite(body, false)),
+            ann: ann.clone(),
         },
-        Exp::Call(func, params, _) => {
+        Exp::For {
+            var,
+            from,
+            to,
+            body,
+            ann,
+        } => Exp::For {
+            var: var.clone(),
+            from: Box::new(rewrite(from, false)),
+            to: Box::new(rewrite(to, false)),
+            body: Box::new(rewrite(body, false)),
+            ann: ann.clone(),
+        },
+        Exp::Assign { var, value, ann } => Exp::Assign {
+            var: var.clone(),
+            value: Box::new(rewrite(value, false)),
+            ann: ann.clone(),
+        },
+        Exp::FunDefs { decls, body, ann } => {
+            let mut scoped_locals = locals.clone();
+            for decl in decls {
+                scoped_locals.insert(decl.name.clone());
+            }
+            let rewrite =
+                |e, is_tail| rewrite_call_params(e, arities, captured, &scoped_locals, is_tail);
+            Exp::FunDefs {
+                decls: decls
+                    .iter()
+                    .map(|decl| FunDecl {
+                        name: decl.name.clone(),
+                        parameters: decl.parameters.clone(),
+                        body: rewrite(&decl.body, true),
+                        ann: decl.ann.clone(),
+                    })
+                    .collect(),
+                body: Box::new(rewrite(body, is_tail)),
+                ann: ann.clone(),
+            }
+        }
+        Exp::Call(func, params, ann) => {
             let mut mod_params: Vec<_> = params
                 .iter()
-                .map(|param| rewrite_call_params(param, globals, false))
+                .map(|param| rewrite(param, false))
                 .collect();
-            if !globals.contains_key(func) {
-                assert!(is_tail);
-                return Exp::InternalTailCall(func.clone(), mod_params, ());
+            // should_lift only keeps functions called in tail position local
+            if locals.contains(func) {
+                return Exp::InternalTailCall(func.clone(), mod_params, ann.clone());
+            }
+            if !captured.contains_key(func) {
+                return Exp::ClosureCall {
+                    closure: func.clone(),
+                    args: mod_params,
+                    is_tail,
+                    ann: ann.clone(),
+                };
             }
 
-            println!("return external call from call, isTail = {}", is_tail);
-            for p in globals[func].parameters.iter().skip(params.len()) {
-                mod_params.push(Exp::Var(p.clone(), ()))
+            for p in &captured[func] {
+                mod_params.push(Exp::Var(p.clone(), ann.clone()))
             }
             Exp::ExternalCall {
                 fun_name: func.to_string(),
                 args: mod_params,
-                is_tail: is_tail,
-                ann: (),
+                is_tail,
+                ann: ann.clone(),
             }
         }
         _ => e.clone(),
     }
 }
 
-fn lift_functions(
-    e: &Exp<()>,
-    vars: &HashSet<String>,
-    globals: &mut HashMap<String, FunDecl<Exp<()>, ()>>,
-    need_lift: &HashSet<String>,
-) -> Exp<()> {
-    match e {
-        Exp::Prim(p, exps, _) => {
-            let mut new_exps = vec![];
-            for exp in exps {
-                new_exps.push(Box::new(lift_functions(&exp, vars, globals, need_lift)));
+// Variables each function of a mutually recursive group captures when it is
+// lifted: the free variables of its body, plus the ones the functions of the
+// group it references need, transitively.
+fn group_free_vars<Ann>(
+    decls: &[FunDecl<Exp<Ann>, Ann>],
+    captured: &HashMap<String, Vec<String>>,
+) -> HashMap<String, Vec<String>> {
+    let names: HashSet<&String> = decls.iter().map(|decl| &decl.name).collect();
+    let mut needs: HashMap<&String, HashSet<String>> = decls
+        .iter()
+        .map(|decl| {
+            let mut fv = free_vars(&decl.body, captured);
+            for param in &decl.parameters {
+                fv.remove(param);
+            }
+            (&decl.name, fv)
+        })
+        .collect();
+    let mut changed = true;
+    while changed {
+        changed = false;
+        for decl in decls {
+            let inherited: HashSet<String> = needs[&decl.name]
+                .iter()
+                .filter(|x| names.contains(x))
+                .flat_map(|fun| needs[fun].iter().cloned())
+                .collect();
+            let fv = needs.get_mut(&decl.name).unwrap();
+            for x in inherited {
+                changed |= fv.insert(x);
             }
-            Exp::Prim(*p, new_exps, ())
         }
+    }
+    needs
+        .into_iter()
+        .map(|(name, fv)| {
+            let mut env: Vec<String> = fv.into_iter().filter(|x| !names.contains(x)).collect();
+            env.sort();
+            (name.clone(), env)
+        })
+        .collect()
+}
+
+// Lifted functions are added to [globals] in source order: each one before the
+// functions lifted out of its body. [path] is the prefix of the functions [e]
+// is defined in, as in uniquify, so lambdas are named like f$lambda$7.
+fn lift_functions<Ann: Clone>(
+    e: &Exp<Ann>,
+    globals: &mut Vec<FunDecl<Exp<Ann>, Ann>>,
+    captured: &mut HashMap<String, Vec<String>>,
+    need_lift: &HashSet<String>,
+    path: &str,
+    counter: &mut u32,
+) -> Exp<Ann> {
+    let mut lift = |e| lift_functions(e, globals, captured, need_lift, path, counter);
+    match e {
+        Exp::Prim(p, exps, ann) => Exp::Prim(
+            *p,
+            exps.iter().map(|exp| Box::new(lift(exp))).collect(),
+            ann.clone(),
+        ),
         Exp::Let {
             bindings,
             body,
             ann,
-        } => {
-            let mut scoped_vars = vars.clone();
-            Exp::Let {
-                bindings: bindings
-                    .iter()
-                    .map(|bind| {
-                        scoped_vars.insert(bind.0.clone());
-                        let new_bind = lift_functions(&bind.1, &scoped_vars, globals, need_lift);
-                        (bind.0.clone(), new_bind)
-                    })
-                    .collect(),
-                body: Box::new(lift_functions(&body, &scoped_vars, globals, need_lift)),
-                ann: (),
-            }
-        }
+        } => Exp::Let {
+            bindings: bindings
+                .iter()
+                .map(|bind| (bind.0.clone(), lift(&bind.1)))
+                .collect(),
+            body: Box::new(lift(body)),
+            ann: ann.clone(),
+        },
         Exp::If {
             cond,
             thn,
             els,
             ann,
         } => Exp::If {
-            cond: Box::new(lift_functions(&cond, vars, globals, need_lift)),
-            thn: Box::new(lift_functions(&thn, vars, globals, need_lift)),
-            els: Box::new(lift_functions(&els, vars, globals, need_lift)),
-            ann: (),
+            cond: Box::new(lift(cond)),
+            thn: Box::new(lift(thn)),
+            els: Box::new(lift(els)),
+            ann: ann.clone(),
+        },
+        Exp::Seq { first, second, ann } => Exp::Seq {
+            first: Box::new(lift(first)),
+            second: Box::new(lift(second)),
+            ann: ann.clone(),
+        },
+        Exp::While { cond, body, ann } => Exp::While {
+            cond: Box::new(lift(cond)),
+            body: Box::new(lift(body)),
+            ann: ann.clone(),
+        },
+        Exp::For {
+            var,
+            from,
+            to,
+            body,
+            ann,
+        } => Exp::For {
+            var: var.clone(),
+            from: Box::new(lift(from)),
+            to: Box::new(lift(to)),
+            body: Box::new(lift(body)),
+            ann: ann.clone(),
+        },
+        Exp::Assign { var, value, ann } => Exp::Assign {
+            var: var.clone(),
+            value: Box::new(lift(value)),
+            ann: ann.clone(),
         },
         Exp::FunDefs { decls, body, ann } => {
+            // lifted functions take the variables they capture as extra parameters,
+            // known before the bodies are lifted since they may reference each other
+            for (name, env) in group_free_vars(decls, captured) {
+                if need_lift.contains(&name) {
+                    captured.insert(name, env);
+                }
+            }
             let mut new_local = vec![];
             for decl in decls {
+                let mut nested = vec![];
+                // fac$loop$3 without its number is the path of what it defines
+                let decl_path = decl.name.trim_end_matches(|c: char| c.is_ascii_digit());
                 let mut new_decl = FunDecl {
                     name: decl.name.clone(),
                     parameters: decl.parameters.clone(),
-                    body: lift_functions(&decl.body, vars, globals, need_lift),
-                    ann: (),
+                    body: lift_functions(
+                        &decl.body,
+                        &mut nested,
+                        captured,
+                        need_lift,
+                        decl_path,
+                        counter,
+                    ),
+                    ann: decl.ann.clone(),
                 };
                 if !need_lift.contains(&decl.name) {
-                    println!("{} doesn't need lift", decl.name);
                     new_local.push(new_decl);
+                    globals.append(&mut nested);
                     continue;
                 }
                 new_decl.parameters =
-                    [decl.parameters.clone(), Vec::from_iter(vars.clone())].concat();
-                globals.insert(decl.name.clone(), new_decl);
+                    [decl.parameters.clone(), captured[&decl.name].clone()].concat();
+                globals.push(new_decl);
+                globals.append(&mut nested);
             }
-            let new_bod = lift_functions(&body, vars, globals, need_lift);
+            let new_bod = lift_functions(body, globals, captured, need_lift, path, counter);
             if !new_local.is_empty() {
                 return Exp::FunDefs {
                     decls: new_local,
                     body: Box::new(new_bod),
-                    ann: (),
+                    ann: ann.clone(),
                 };
             }
             new_bod
         }
-        Exp::Call(func, params, _) => {
-            let new_params = params
-                .iter()
-                .map(|param| lift_functions(param, vars, globals, need_lift))
-                .collect();
-            Exp::Call(func.clone(), new_params, ())
+        // A lambda becomes a lifted function that only captures its free
+        // variables, referenced by name so it is turned into a closure
+        Exp::Lambda {
+            parameters,
+            body,
+            ann,
+        } => {
+            *counter += 1;
+            let lambda_path = format!("{}lambda$", path);
+            let name = format!("{}{}", lambda_path, counter);
+            let mut env: Vec<String> = free_vars(e, captured).into_iter().collect();
+            env.sort();
+            let mut nested = vec![];
+            let new_body = lift_functions(
+                body,
+                &mut nested,
+                captured,
+                need_lift,
+                &lambda_path,
+                counter,
+            );
+            captured.insert(name.clone(), env.clone());
+            globals.push(FunDecl {
+                name: name.clone(),
+                parameters: [parameters.clone(), env].concat(),
+                body: new_body,
+                ann: ann.clone(),
+            });
+            globals.append(&mut nested);
+            Exp::Var(name, ann.clone())
         }
+        Exp::Call(func, params, ann) => Exp::Call(
+            func.clone(),
+            params.iter().map(lift).collect(),
+            ann.clone(),
+        ),
         _ => e.clone(),
     }
 }
 
-// returns name of functions to lift
-fn should_lift(p: &Exp<()>, funcs: &HashSet<String>, is_tail: bool) -> HashSet<String> {
-    let mut set = HashSet::new();
-    match p {
+// Names of the local functions that have to be lifted. A local function can
+// stay a jump target inside the frame it is defined in only when it is called
+// in tail position of that frame, so that its body returns for the frame.
+// Lifting a function moves its body to a frame of its own, which can make
+// more functions need lifting, so this runs until nothing changes.
+fn should_lift<Ann>(p: &Exp<Ann>) -> HashSet<String> {
+    let mut lifted = HashSet::new();
+    loop {
+        let mut found = HashSet::new();
+        find_lifted(p, &HashSet::new(), &HashSet::new(), true, &lifted, &mut found);
+        if found.len() == lifted.len() {
+            return lifted;
+        }
+        lifted = found;
+    }
+}
+
+// [funcs] are the local functions in scope and [jumps] the ones that can be
+// jumped to from the current frame
+fn find_lifted<Ann>(
+    e: &Exp<Ann>,
+    funcs: &HashSet<String>,
+    jumps: &HashSet<String>,
+    is_tail: bool,
+    lifted: &HashSet<String>,
+    found: &mut HashSet<String>,
+) {
+    match e {
+        Exp::Num(_, _) | Exp::Bool(_, _) | Exp::Str(_, _) => {}
+        // functions used as values become closures
+        Exp::Var(x, _) => {
+            if funcs.contains(x) {
+                found.insert(x.clone());
+            }
+        }
         Exp::Prim(_, exps, _) => {
             for exp in exps {
-                set.extend(should_lift(exp, funcs, is_tail));
+                find_lifted(exp, funcs, jumps, false, lifted, found);
             }
         }
-        Exp::Let {
-            bindings,
-            body,
-            ann,
-        } => {
-            for (v, bind) in bindings {
-                set.extend(should_lift(bind, funcs, false));
+        Exp::Let { bindings, body, .. } => {
+            for (_, bind) in bindings {
+                find_lifted(bind, funcs, jumps, false, lifted, found);
             }
-            set.extend(should_lift(body, funcs, is_tail));
+            find_lifted(body, funcs, jumps, is_tail, lifted, found);
         }
         Exp::If {
-            cond,
-            thn,
-            els,
-            ann,
+            cond, thn, els, ..
         } => {
-            set.extend(should_lift(cond, funcs, false));
-            set.extend(should_lift(thn, funcs, is_tail));
-            set.extend(should_lift(els, funcs, is_tail));
+            find_lifted(cond, funcs, jumps, false, lifted, found);
+            find_lifted(thn, funcs, jumps, is_tail, lifted, found);
+            find_lifted(els, funcs, jumps, is_tail, lifted, found);
         }
-        Exp::FunDefs { decls, body, ann } => {
+        Exp::Seq { first, second, .. } => {
+            find_lifted(first, funcs, jumps, false, lifted, found);
+            find_lifted(second, funcs, jumps, is_tail, lifted, found);
+        }
+        Exp::While { cond, body, .. } => {
+            find_lifted(cond, funcs, jumps, false, lifted, found);
+            find_lifted(body, funcs, jumps, false, lifted, found);
+        }
+        Exp::For { from, to, body, .. } => {
+            find_lifted(from, funcs, jumps, false, lifted, found);
+            find_lifted(to, funcs, jumps, false, lifted, found);
+            find_lifted(body, funcs, jumps, false, lifted, found);
+        }
+        Exp::Assign { value, .. } => find_lifted(value, funcs, jumps, false, lifted, found),
+        Exp::FunDefs { decls, body, .. } => {
             let mut scoped_funcs = funcs.clone();
+            let mut scoped_jumps = jumps.clone();
             for decl in decls {
                 scoped_funcs.insert(decl.name.clone());
+                if !lifted.contains(&decl.name) {
+                    scoped_jumps.insert(decl.name.clone());
+                }
             }
             for decl in decls {
-                set.extend(should_lift(&decl.body, &scoped_funcs, false));
+                // the body of a local function ends its frame like a tail call
+                let decl_jumps = if lifted.contains(&decl.name) {
+                    HashSet::new()
+                } else {
+                    scoped_jumps.clone()
+                };
+                find_lifted(&decl.body, &scoped_funcs, &decl_jumps, true, lifted, found);
             }
-            set.extend(should_lift(body, &scoped_funcs, is_tail));
+            find_lifted(body, &scoped_funcs, &scoped_jumps, is_tail, lifted, found);
+        }
+        // a lambda body runs in its own frame
+        Exp::Lambda { body, .. } => {
+            find_lifted(body, funcs, &HashSet::new(), true, lifted, found);
         }
         Exp::Call(func, args, _) => {
-            if !is_tail {
-                set.extend(funcs.clone());
+            if funcs.contains(func) && !(is_tail && jumps.contains(func)) {
+                found.insert(func.clone());
             }
             for arg in args {
-                set.extend(should_lift(arg, funcs, false));
+                find_lifted(arg, funcs, jumps, false, lifted, found);
             }
         }
-        Exp::InternalTailCall(_, _, _) => todo!(),
-        Exp::ExternalCall {
-            fun_name,
-            args,
-            is_tail,
-            ann,
-        } => todo!(),
-        _ => {}
+        // only produced by lifting
+        Exp::InternalTailCall(_, _, _)
+        | Exp::ExternalCall { .. }
+        | Exp::MakeClosure { .. }
+        | Exp::ClosureCall { .. } => {}
     }
-    set
+}
+
+// The program with every binder renamed, as lambda_lift sees it
+pub fn uniquify_prog<Ann: Clone>(p: &Exp<Ann>) -> Exp<Ann> {
+    uniquify(p, &HashMap::new(), "", &mut 0)
 }
 
 // Lift some functions to global definitions
-pub fn lambda_lift<Ann>(p: &Exp<Ann>) -> (Vec<FunDecl<Exp<()>, ()>>, Exp<()>) {
-    let unique_p = uniquify(&p, &mut HashMap::new(), &mut 0);
-    println!("after uniquify: {:#?}", unique_p);
-    let mut globals = HashMap::new();
-    let to_lift = should_lift(&unique_p, &HashSet::new(), true);
-    println!(
-        "should lift len = {}, content = {:?}",
-        to_lift.len(),
-        to_lift
+// Every node keeps the annotation of the source expression it comes from.
+// A lifted lambda keeps the lambda's, and the captured variables passed to
+// a function or stored in a closure take the annotation of the reference.
+pub fn lambda_lift<Ann: Clone>(p: &Exp<Ann>) -> (Vec<FunDecl<Exp<Ann>, Ann>>, Exp<Ann>) {
+    let mut counter = 0;
+    let unique_p = uniquify(p, &HashMap::new(), "", &mut counter);
+    let mut globals = vec![];
+    let to_lift = should_lift(&unique_p);
+    let mut captured = HashMap::new();
+    let main = lift_functions(
+        &unique_p,
+        &mut globals,
+        &mut captured,
+        &to_lift,
+        "",
+        &mut counter,
     );
-    let main = lift_functions(&unique_p, &HashSet::new(), &mut globals, &to_lift);
+    // the number of arguments a closure of each lifted function takes
+    let arities: HashMap<String, usize> = globals
+        .iter()
+        .map(|decl| {
+            let arity = decl.parameters.len() - captured[&decl.name].len();
+            (decl.name.clone(), arity)
+        })
+        .collect();
+    let locals = HashSet::new();
     (
         globals
-            .values()
+            .iter()
             .map(|decl| FunDecl {
                 name: decl.name.clone(),
                 parameters: decl.parameters.clone(),
-                body: rewrite_call_params(&decl.body, &globals, true),
-                ann: (),
+                body: rewrite_call_params(&decl.body, &arities, &captured, &locals, true),
+                ann: decl.ann.clone(),
             })
             .collect(),
-        rewrite_call_params(&main, &globals, true),
+        rewrite_call_params(&main, &arities, &captured, &locals, true),
     )
-    // TODO: add parameter optimization pass
 }
@@ -0,0 +1,427 @@
+/* The tokens of the language, fed to the parser.
+ *
+ * Comments are skipped: # runs to the end of the line and (# ... #) can
+ * span lines and nest. Numbers can be written in hex (0x2A) or binary
+ * (0b101010) and have _ between digits (1_000_000). A + or - right before a
+ * number is part of it unless it follows something that can end an
+ * expression, so x-1 subtracts and f(-1) passes a negative number.
+ * Strings are written between double quotes, with \n, \t, \r, \0, \\
+ * and \" for the characters that can't go there as they are.
+ */
+use crate::span::Span1;
+use std::convert::TryFrom;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Tok<'input> {
+    // The checker rejects the ones that don't fit into 63 bits
+    Num(i64),
+    // What is between the quotes, with the escapes still in it
+    Str(&'input str),
+    Id(&'input str),
+    True,
+    False,
+    Let,
+    In,
+    If,
+    Else,
+    Def,
+    And,
+    Lambda,
+    End,
+    While,
+    For,
+    To,
+    Add1,
+    Sub1,
+    Print,
+    IsNum,
+    IsBool,
+    IsTuple,
+    Length,
+    Abs,
+    Min,
+    Max,
+    StrLen,
+    Substr,
+    CharAt,
+    NumToString,
+    StringToNum,
+    IsString,
+    LParen,
+    RParen,
+    LBrack,
+    RBrack,
+    Comma,
+    Colon,
+    Semicolon,
+    Assign,
+    Equal,
+    EqEq,
+    Neq,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+    Plus,
+    PlusPlus,
+    Minus,
+    Star,
+    Slash,
+    Percent,
+    Amp,
+    Pipe,
+    Caret,
+    Shl,
+    Shr,
+    Bang,
+    AndAnd,
+    OrOr,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum LexError {
+    InvalidChar { found: char, location: Span1 },
+    InvalidNumber { location: Span1 },
+    // Digits of a number that doesn't even fit into an i64
+    NumberOutOfRange { location: Span1 },
+    // The location is the opening (#
+    UnterminatedComment { location: Span1 },
+    // The location is the opening quote
+    UnterminatedString { location: Span1 },
+    InvalidEscape { found: char, location: Span1 },
+}
+
+const KEYWORDS: [(&str, Tok); 29] = [
+    ("true", Tok::True),
+    ("false", Tok::False),
+    ("let", Tok::Let),
+    ("in", Tok::In),
+    ("if", Tok::If),
+    ("else", Tok::Else),
+    ("def", Tok::Def),
+    ("and", Tok::And),
+    ("lambda", Tok::Lambda),
+    ("end", Tok::End),
+    ("while", Tok::While),
+    ("for", Tok::For),
+    ("to", Tok::To),
+    ("add1", Tok::Add1),
+    ("sub1", Tok::Sub1),
+    ("print", Tok::Print),
+    ("isnum", Tok::IsNum),
+    ("isbool", Tok::IsBool),
+    ("istuple", Tok::IsTuple),
+    ("length", Tok::Length),
+    ("abs", Tok::Abs),
+    ("min", Tok::Min),
+    ("max", Tok::Max),
+    ("strlen", Tok::StrLen),
+    ("substr", Tok::Substr),
+    ("char_at", Tok::CharAt),
+    ("num_to_string", Tok::NumToString),
+    ("string_to_num", Tok::StringToNum),
+    ("isstring", Tok::IsString),
+];
+
+// Longest first, so that <= isn't lexed as < =
+const SYMBOLS: [(&str, Tok); 29] = [
+    (":=", Tok::Assign),
+    ("==", Tok::EqEq),
+    ("!=", Tok::Neq),
+    ("<=", Tok::Le),
+    (">=", Tok::Ge),
+    ("&&", Tok::AndAnd),
+    ("||", Tok::OrOr),
+    ("<<", Tok::Shl),
+    (">>", Tok::Shr),
+    ("++", Tok::PlusPlus),
+    ("(", Tok::LParen),
+    (")", Tok::RParen),
+    ("[", Tok::LBrack),
+    ("]", Tok::RBrack),
+    (",", Tok::Comma),
+    (":", Tok::Colon),
+    (";", Tok::Semicolon),
+    ("=", Tok::Equal),
+    ("<", Tok::Lt),
+    (">", Tok::Gt),
+    ("+", Tok::Plus),
+    ("-", Tok::Minus),
+    ("*", Tok::Star),
+    ("/", Tok::Slash),
+    ("%", Tok::Percent),
+    ("&", Tok::Amp),
+    ("|", Tok::Pipe),
+    ("^", Tok::Caret),
+    ("!", Tok::Bang),
+];
+
+impl<'input> Tok<'input> {
+    // Whether an expression can end with the token, which makes a sign
+    // after it an operator
+    fn ends_operand(&self) -> bool {
+        matches!(
+            self,
+            Tok::Num(_)
+                | Tok::Str(_)
+                | Tok::Id(_)
+                | Tok::True | Tok::False | Tok::RParen | Tok::RBrack | Tok::End
+        )
+    }
+}
+
+pub type Spanned<'input> = Result<(usize, Tok<'input>, usize), LexError>;
+
+pub struct Lexer<'input> {
+    src: &'input str,
+    pos: usize,
+    prev: Option<Tok<'input>>,
+    // Where the comments skipped so far are
+    pub comments: Vec<Span1>,
+}
+
+impl<'input> Lexer<'input> {
+    pub fn new(src: &'input str) -> Self {
+        Lexer {
+            src,
+            pos: 0,
+            prev: None,
+            comments: vec![],
+        }
+    }
+
+    fn rest(&self) -> &'input str {
+        &self.src[self.pos..]
+    }
+
+    // Skips whitespace and comments, failing on a block comment that isn't
+    // closed
+    fn skip_trivia(&mut self) -> Result<(), LexError> {
+        loop {
+            let rest = self.rest();
+            let trimmed = rest.trim_start();
+            self.pos += rest.len() - trimmed.len();
+            let start = self.pos;
+            if trimmed.starts_with("(#") {
+                let mut depth = 0;
+                while self.pos < self.src.len() {
+                    if self.rest().starts_with("(#") {
+                        depth += 1;
+                        self.pos += 2;
+                    } else if self.rest().starts_with("#)") {
+                        depth -= 1;
+                        self.pos += 2;
+                        if depth == 0 {
+                            break;
+                        }
+                    } else {
+                        self.pos += self.rest().chars().next().map_or(1, char::len_utf8);
+                    }
+                }
+                if depth > 0 {
+                    return Err(LexError::UnterminatedComment {
+                        location: Span1 {
+                            start_ix: start,
+                            end_ix: start + 2,
+                        },
+                    });
+                }
+            } else if trimmed.starts_with('#') {
+                self.pos += trimmed.find('\n').unwrap_or(trimmed.len());
+            } else {
+                return Ok(());
+            }
+            self.comments.push(Span1 {
+                start_ix: start,
+                end_ix: self.pos,
+            });
+        }
+    }
+
+    fn number(&mut self, start: usize) -> Result<Tok<'input>, LexError> {
+        let negative = self.rest().starts_with('-');
+        if self.rest().starts_with(['+', '-']) {
+            self.pos += 1;
+        }
+        let rest = self.rest();
+        let (radix, prefix) = if rest.starts_with("0x") || rest.starts_with("0X") {
+            (16, 2)
+        } else if rest.starts_with("0b") || rest.starts_with("0B") {
+            (2, 2)
+        } else {
+            (10, 0)
+        };
+        let digits_len = rest[prefix..]
+            .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_'))
+            .unwrap_or(rest.len() - prefix);
+        let digits = &rest[prefix..prefix + digits_len];
+        self.pos += prefix + digits_len;
+        let location = Span1 {
+            start_ix: start,
+            end_ix: self.pos,
+        };
+        // _ only goes between digits
+        if digits.is_empty() || digits.starts_with('_') || digits.ends_with('_') {
+            return Err(LexError::InvalidNumber { location });
+        }
+        let mut magnitude: i128 = 0;
+        for c in digits.chars().filter(|c| *c != '_') {
+            let digit = c
+                .to_digit(radix)
+                .ok_or(LexError::InvalidNumber { location })?;
+            // stays out of range once it is, and can't overflow the i128
+            magnitude = (magnitude * radix as i128 + digit as i128).min(i64::MAX as i128 + 2);
+        }
+        let value = if negative { -magnitude } else { magnitude };
+        i64::try_from(value)
+            .map(Tok::Num)
+            .map_err(|_| LexError::NumberOutOfRange { location })
+    }
+
+    // A string ends at the next quote that isn't escaped, and has to do so
+    // on the line it starts on
+    fn string(&mut self, start: usize) -> Result<Tok<'input>, LexError> {
+        self.pos += 1;
+        let contents = self.pos;
+        loop {
+            let c = match self.rest().chars().next() {
+                Some(c) if c != '\n' => c,
+                _ => {
+                    return Err(LexError::UnterminatedString {
+                        location: Span1 {
+                            start_ix: start,
+                            end_ix: start + 1,
+                        },
+                    })
+                }
+            };
+            match c {
+                '"' => {
+                    self.pos += 1;
+                    return Ok(Tok::Str(&self.src[contents..self.pos - 1]));
+                }
+                '\\' => {
+                    let escaped = self.rest()[1..].chars().next();
+                    match escaped {
+                        Some(e) if ESCAPES.iter().any(|(c, _)| *c == e) => self.pos += 2,
+                        // the missing quote is the bigger problem
+                        None | Some('\n') => self.pos += 1,
+                        Some(e) => {
+                            return Err(LexError::InvalidEscape {
+                                found: e,
+                                location: Span1 {
+                                    start_ix: self.pos,
+                                    end_ix: self.pos + 1 + e.len_utf8(),
+                                },
+                            })
+                        }
+                    }
+                }
+                c => self.pos += c.len_utf8(),
+            }
+        }
+    }
+}
+
+impl<'input> Iterator for Lexer<'input> {
+    type Item = Spanned<'input>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Err(e) = self.skip_trivia() {
+            // nothing comes after an unterminated comment
+            self.pos = self.src.len();
+            return Some(Err(e));
+        }
+        let start = self.pos;
+        let rest = self.rest();
+        let c = rest.chars().next()?;
+        let signed_number = rest.starts_with(['+', '-'])
+            && rest[1..].starts_with(|c: char| c.is_ascii_digit())
+            && !self.prev.is_some_and(|prev| prev.ends_operand());
+        let tok = if c.is_ascii_digit() || signed_number {
+            match self.number(start) {
+                Ok(tok) => tok,
+                Err(e) => return Some(Err(e)),
+            }
+        } else if c == '"' {
+            match self.string(start) {
+                Ok(tok) => tok,
+                Err(e) => return Some(Err(e)),
+            }
+        } else if c.is_ascii_alphabetic() {
+            let len = rest
+                .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_'))
+                .unwrap_or(rest.len());
+            let word = &rest[..len];
+            self.pos += len;
+            KEYWORDS
+                .iter()
+                .find(|(keyword, _)| *keyword == word)
+                .map_or(Tok::Id(word), |(_, tok)| *tok)
+        } else if let Some((symbol, tok)) = SYMBOLS.iter().find(|(s, _)| rest.starts_with(s)) {
+            self.pos += symbol.len();
+            *tok
+        } else {
+            self.pos += c.len_utf8();
+            return Some(Err(LexError::InvalidChar {
+                found: c,
+                location: Span1 {
+                    start_ix: start,
+                    end_ix: self.pos,
+                },
+            }));
+        };
+        self.prev = Some(tok);
+        Some(Ok((start, tok, self.pos)))
+    }
+}
+
+// The character after a \ in a string and the character it stands for
+const ESCAPES: [(char, char); 6] = [
+    ('n', '\n'),
+    ('t', '\t'),
+    ('r', '\r'),
+    ('0', '\0'),
+    ('\\', '\\'),
+    ('"', '"'),
+];
+
+/// The characters of a string token, with the escapes replaced
+pub fn unescape(raw: &str) -> String {
+    let mut out = String::new();
+    let mut chars = raw.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            out.push(c);
+            continue;
+        }
+        // the lexer only lets through escapes it knows
+        let escaped = chars.next().unwrap();
+        out.push(ESCAPES.iter().find(|(e, _)| *e == escaped).unwrap().1);
+    }
+    out
+}
+
+/// The string literal spelling [s], with the characters that need it
+/// escaped
+pub fn escape(s: &str) -> String {
+    let mut out = String::from("\"");
+    for c in s.chars() {
+        match ESCAPES.iter().find(|(_, stands_for)| *stands_for == c) {
+            Some((e, _)) => {
+                out.push('\\');
+                out.push(*e);
+            }
+            None => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+/// Where the comments of [src] are, up to the first error
+pub fn comments(src: &str) -> Vec<Span1> {
+    let mut lexer = Lexer::new(src);
+    while let Some(Ok(_)) = lexer.next() {}
+    lexer.comments
+}
@@ -1,8 +1,14 @@
 pub mod asm;
 mod checker;
 pub mod compile;
+pub mod diagnostic;
+pub mod explain;
+pub mod formatter;
 pub mod interp;
 mod lambda_lift;
+pub mod lexer;
+pub mod parse;
+#[allow(clippy::all, unused)]
 pub mod parser;
 pub mod runner;
 mod sequentializer;
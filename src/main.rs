@@ -1,3 +1,5 @@
+use snake::compile::{Stage, WARNING_NAMES};
+use snake::explain::explain;
 use snake::runner::*;
 use std::path::Path;
 
@@ -14,25 +16,96 @@ To run the reference interpreter use
 
     snake --interp INPUT_FILE
 
+To print the program as it is after one stage of the compiler use
+
+    snake --emit=STAGE INPUT_FILE
+
+where STAGE is one of ast, checked, uniquified, lifted, seq or asm
+
+To rewrite a program in the canonical layout use
+
+    snake fmt INPUT_FILE
+
+or to only check that it already is, failing otherwise
+
+    snake fmt --check INPUT_FILE
+
+Warnings are printed when compiling, running or interpreting a program.
+They are turned into errors with -Werror and silenced with -Wno-NAME, where
+NAME is one of unused-variable, unused-parameter, unused-function, shadowing
+or constant-condition, e.g.
+
+    snake -Werror -Wno-shadowing --run INPUT_FILE
+
+Errors and warnings are printed to stderr. To print them as one JSON object
+per line instead, pass --error-format=json, e.g.
+
+    snake --error-format=json --run INPUT_FILE
+
+Every error and warning has a code such as E0004 or W0001. To read more
+about one, with an example program that has the problem and its fix, use
+
+    snake --explain CODE
+
 To see this usage message run
 
     snake --help
 ";
 
 fn main() {
-    let args: Vec<String> = std::env::args().collect();
+    let mut warnings = WarningConfig::default();
+    let mut args: Vec<String> = vec![];
+    for arg in std::env::args() {
+        if arg == "-Werror" {
+            warnings.deny = true;
+        } else if let Some(format) = arg.strip_prefix("--error-format=") {
+            match format.parse::<ErrorFormat>() {
+                Ok(format) => set_error_format(format),
+                Err(e) => usage(Some(&e)),
+            }
+        } else if let Some(name) = arg.strip_prefix("-Wno-") {
+            if !WARNING_NAMES.contains(&name) {
+                usage(Some(&format!("unknown warning {}", name)));
+            }
+            warnings.silenced.insert(name.to_string());
+        } else {
+            args.push(arg);
+        }
+    }
     if args.len() == 2 {
         match args[1].as_str() {
             "--interp" | "--run" => usage(Some("interp and run comands require an input file")),
+            "fmt" => usage(Some("fmt requires an input file")),
+            "--explain" => usage(Some("--explain requires an error code")),
             "--help" => usage(None),
-            path => emit_assembly(Path::new(&path)),
+            path => {
+                report_warnings(Path::new(&path), &warnings);
+                emit_assembly(Path::new(&path))
+            }
         }
     } else if args.len() == 3 {
         match args[1].as_str() {
-            "--interp" => interp(Path::new(&args[2]), &mut std::io::stdout()),
-            "--run" => run(Path::new(&args[2])),
-            _ => usage(Some("Failed to parse input")),
+            "--interp" => {
+                report_warnings(Path::new(&args[2]), &warnings);
+                interp(Path::new(&args[2]), &mut std::io::stdout())
+            }
+            "--run" => {
+                report_warnings(Path::new(&args[2]), &warnings);
+                run(Path::new(&args[2]))
+            }
+            "fmt" => format(Path::new(&args[2]), false),
+            "--explain" => match explain(&args[2].to_ascii_uppercase()) {
+                Some(explanation) => print!("{}", explanation),
+                None => usage(Some(&format!("unknown error code {}", args[2]))),
+            },
+            flag => match flag.strip_prefix("--emit=").map(str::parse::<Stage>) {
+                Some(Ok(stage)) => emit(Path::new(&args[2]), stage),
+                Some(Err(e)) => usage(Some(&e)),
+                None => usage(Some("Failed to parse input")),
+            },
         }
+    } else if args.len() == 4 && args[1] == "fmt" && args[2] == "--check" {
+        format(Path::new(&args[3]), true)
     } else if args.len() <= 1 {
         usage(Some("Too few arguments"))
     } else {
@@ -0,0 +1,396 @@
+/* Parse errors that say what was expected in the words of the language
+ * instead of the parser's, e.g.
+ *
+ *   expected one of `in`, `,` after let binding, found `)`
+ */
+use crate::lexer::{LexError, Lexer, Tok};
+use crate::parser::ProgParser;
+use crate::span::Span1;
+use crate::syntax::SurfProg;
+use lalrpop_util::ParseError;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum ParseErr<Span> {
+    // A character that can't start any token
+    InvalidToken {
+        found: String,
+        location: Span,
+    },
+    // Digits that aren't a number, e.g. 0x or 1__000
+    InvalidNumber {
+        found: String,
+        location: Span,
+    },
+    // A number too large to be represented at all, [found] is how it is
+    // written
+    NumberOutOfRange {
+        found: String,
+        location: Span,
+    },
+    // The location is the opening (# of the comment
+    UnterminatedComment {
+        location: Span,
+    },
+    // The location is the opening quote of the string
+    UnterminatedString {
+        location: Span,
+    },
+    // A \ in a string followed by something other than n, t, r, 0, \ or "
+    InvalidEscape {
+        found: String,
+        location: Span,
+    },
+    UnexpectedToken {
+        found: String,
+        expected: Vec<String>,
+        context: Option<&'static str>,
+        location: Span,
+    },
+    // The file ended inside of the construct opened by [keyword], the
+    // location is the keyword's
+    Unterminated {
+        keyword: String,
+        closing: &'static str,
+        expected: Vec<String>,
+        context: Option<&'static str>,
+        location: Span,
+    },
+    // The file ended without anything left open, the location is the last
+    // token's
+    UnexpectedEof {
+        expected: Vec<String>,
+        context: Option<&'static str>,
+        location: Span,
+    },
+}
+
+impl<Span> ParseErr<Span> {
+    /// A stable identifier of the kind of error. Codes are never reused.
+    pub fn code(&self) -> &'static str {
+        match self {
+            ParseErr::UnexpectedToken { .. } => "E0101",
+            ParseErr::Unterminated { .. } => "E0102",
+            ParseErr::UnexpectedEof { .. } => "E0103",
+            ParseErr::InvalidToken { .. } => "E0104",
+            ParseErr::InvalidNumber { .. } => "E0105",
+            // the same as the checker's for literals that don't fit
+            ParseErr::NumberOutOfRange { .. } => "E0004",
+            ParseErr::UnterminatedComment { .. } => "E0106",
+            ParseErr::UnterminatedString { .. } => "E0107",
+            ParseErr::InvalidEscape { .. } => "E0108",
+        }
+    }
+
+    pub fn location(&self) -> &Span {
+        match self {
+            ParseErr::InvalidToken { location, .. }
+            | ParseErr::InvalidNumber { location, .. }
+            | ParseErr::NumberOutOfRange { location, .. }
+            | ParseErr::UnterminatedComment { location }
+            | ParseErr::UnterminatedString { location }
+            | ParseErr::InvalidEscape { location, .. }
+            | ParseErr::UnexpectedToken { location, .. }
+            | ParseErr::Unterminated { location, .. }
+            | ParseErr::UnexpectedEof { location, .. } => location,
+        }
+    }
+
+    /// What was expected, e.g. "one of `in`, `,` after let binding"
+    pub fn expectation(&self) -> Option<String> {
+        let (expected, context) = match self {
+            ParseErr::InvalidToken { .. }
+            | ParseErr::InvalidNumber { .. }
+            | ParseErr::NumberOutOfRange { .. }
+            | ParseErr::UnterminatedComment { .. }
+            | ParseErr::UnterminatedString { .. }
+            | ParseErr::InvalidEscape { .. } => return None,
+            ParseErr::UnexpectedToken {
+                expected, context, ..
+            }
+            | ParseErr::Unterminated {
+                expected, context, ..
+            }
+            | ParseErr::UnexpectedEof {
+                expected, context, ..
+            } => (expected, context),
+        };
+        let what = match expected.as_slice() {
+            [] => "end of file".to_string(),
+            [one] => one.clone(),
+            many => format!("one of {}", many.join(", ")),
+        };
+        Some(match context {
+            Some(context) => format!("{} {}", what, context),
+            None => what,
+        })
+    }
+}
+
+/// Every syntax error in a file, in the order they were found
+#[derive(Debug)]
+pub struct ParseFailure {
+    pub errors: Vec<ParseErr<Span1>>,
+    // The program with placeholders for the broken parts, when the parser
+    // could recover from all of the errors
+    pub recovered: Option<SurfProg<Span1>>,
+}
+
+pub fn parse_prog(src: &str) -> Result<SurfProg<Span1>, ParseFailure> {
+    let mut recovered = vec![];
+    let result = ProgParser::new().parse(&mut recovered, Lexer::new(src));
+    let mut errors: Vec<ParseErr<Span1>> = recovered
+        .into_iter()
+        .map(|r| parse_err(src, r.error))
+        .collect();
+    match result {
+        Ok(prog) if errors.is_empty() => Ok(prog),
+        Ok(prog) => Err(ParseFailure {
+            errors,
+            recovered: Some(prog),
+        }),
+        Err(e) => {
+            errors.push(parse_err(src, e));
+            Err(ParseFailure {
+                errors,
+                recovered: None,
+            })
+        }
+    }
+}
+
+fn parse_err(src: &str, e: ParseError<usize, Tok<'_>, LexError>) -> ParseErr<Span1> {
+    let tokens = lexemes(src);
+    // the constructs still open where the error is
+    let open_at = |ix: usize| {
+        let before = tokens.iter().take_while(|tok| tok.span.start_ix < ix);
+        open_constructs(before).pop()
+    };
+    match e {
+        ParseError::InvalidToken { location } => ParseErr::InvalidToken {
+            found: src[location..].chars().next().unwrap_or(' ').to_string(),
+            location: Span1 {
+                start_ix: location,
+                end_ix: location + 1,
+            },
+        },
+        ParseError::UnrecognizedToken {
+            token: (l, _, r),
+            expected,
+        } => {
+            let (expected, context) = describe_expected(&expected, open_at(l));
+            ParseErr::UnexpectedToken {
+                found: src[l..r].to_string(),
+                expected,
+                context,
+                location: Span1 {
+                    start_ix: l,
+                    end_ix: r,
+                },
+            }
+        }
+        ParseError::ExtraToken { token: (l, _, r) } => ParseErr::UnexpectedToken {
+            found: src[l..r].to_string(),
+            expected: vec![],
+            context: None,
+            location: Span1 {
+                start_ix: l,
+                end_ix: r,
+            },
+        },
+        ParseError::UnrecognizedEOF { expected, .. } => {
+            let open = open_at(src.len());
+            let (expected, context) = describe_expected(&expected, open);
+            match open {
+                Some(open) => ParseErr::Unterminated {
+                    keyword: open.lexeme.text.to_string(),
+                    closing: open.closing,
+                    expected,
+                    context,
+                    location: open.lexeme.span,
+                },
+                None => ParseErr::UnexpectedEof {
+                    expected,
+                    context,
+                    // an empty file ends where it starts
+                    location: tokens.last().map_or(
+                        Span1 {
+                            start_ix: src.len(),
+                            end_ix: src.len() + 1,
+                        },
+                        |tok| tok.span,
+                    ),
+                },
+            }
+        }
+        ParseError::User { error } => match error {
+            LexError::InvalidChar { found, location } => ParseErr::InvalidToken {
+                found: found.to_string(),
+                location,
+            },
+            LexError::InvalidNumber { location } => ParseErr::InvalidNumber {
+                found: src[location.start_ix..location.end_ix].to_string(),
+                location,
+            },
+            LexError::NumberOutOfRange { location } => ParseErr::NumberOutOfRange {
+                found: src[location.start_ix..location.end_ix].to_string(),
+                location,
+            },
+            LexError::UnterminatedComment { location } => {
+                ParseErr::UnterminatedComment { location }
+            }
+            LexError::UnterminatedString { location } => {
+                ParseErr::UnterminatedString { location }
+            }
+            LexError::InvalidEscape { found, location } => ParseErr::InvalidEscape {
+                found: format!("\\{}", found),
+                location,
+            },
+        },
+    }
+}
+
+// Tokens that continue an expression rather than follow it
+const OPERATORS: [&str; 23] = [
+    "+", "++", "-", "*", "/", "%", "&", "|", "^", "<<", ">>", "<", "<=", ">", ">=", "==", "!=", "&&",
+    "||", "[", "(", ":=", ";",
+];
+
+// The places a set of expected tokens can only come from, first those only
+// inside of the construct opened by a keyword
+const CONTEXTS: [(Option<&str>, &[&str], &str); 8] = [
+    (Some("for"), &["="], "after the loop variable"),
+    (None, &["in", ","], "after let binding"),
+    (None, &["="], "after let-bound name"),
+    (None, &["and", "in"], "after function definition"),
+    (None, &[":"], "before the body"),
+    (None, &["else"], "after the then branch of if"),
+    (None, &["end"], "after the body"),
+    (None, &["to"], "after the start of the loop"),
+];
+
+// Turns the parser's names for the expected terminals into backquoted
+// tokens. When the expression so far could end there the operators are
+// left out, and only the tokens that go on with the construct still [open]
+// are kept. When an expression could start there it just says so.
+fn describe_expected(expected: &[String], open: Option<Open>) -> (Vec<String>, Option<&'static str>) {
+    let mut tokens: Vec<&str> = expected
+        .iter()
+        .map(|t| t.trim_matches('"'))
+        .collect();
+    if tokens.contains(&"lambda") {
+        return (vec!["an expression".to_string()], None);
+    }
+    if tokens.contains(&"&&") {
+        tokens.retain(|t| !OPERATORS.contains(t));
+        match open {
+            Some(open) if tokens.iter().any(|t| open.follows.contains(t)) => {
+                tokens.retain(|t| open.follows.contains(t))
+            }
+            Some(_) => {}
+            None => tokens.clear(),
+        }
+    }
+    let opened = open.map(|open| open.lexeme.text);
+    let context = CONTEXTS.iter().find_map(|(keyword, set, context)| {
+        let applies = keyword.is_none() || *keyword == opened;
+        (applies && set.len() == tokens.len() && set.iter().all(|t| tokens.contains(t)))
+            .then_some(*context)
+    });
+    // keywords before punctuation
+    tokens.sort_by_key(|t| !t.starts_with(|c: char| c.is_ascii_alphabetic()));
+    let names = tokens
+        .into_iter()
+        .map(|t| match t {
+            "number" => "a number".to_string(),
+            "string" => "a string".to_string(),
+            "identifier" => "an identifier".to_string(),
+            t => format!("`{}`", t),
+        })
+        .collect();
+    (names, context)
+}
+
+#[derive(Clone, Copy)]
+struct Lexeme<'a> {
+    text: &'a str,
+    span: Span1,
+}
+
+// The tokens of [src] up to the first error, with their text
+fn lexemes(src: &str) -> Vec<Lexeme<'_>> {
+    Lexer::new(src)
+        .map_while(Result::ok)
+        .map(|(l, _, r)| Lexeme {
+            text: &src[l..r],
+            span: Span1 {
+                start_ix: l,
+                end_ix: r,
+            },
+        })
+        .collect()
+}
+
+#[derive(Clone, Copy)]
+struct Open<'a> {
+    lexeme: Lexeme<'a>,
+    // The token that closes the construct
+    closing: &'static str,
+    // The tokens that can go on with it after an expression
+    follows: &'static [&'static str],
+}
+
+// The constructs opened by [tokens] and not closed, innermost last
+fn open_constructs<'a, 'b>(tokens: impl Iterator<Item = &'b Lexeme<'a>>) -> Vec<Open<'a>>
+where
+    'a: 'b,
+{
+    let mut open: Vec<Open> = vec![];
+    for tok in tokens {
+        let (closing, follows): (_, &[&str]) = match tok.text {
+            "let" => ("in", &["="]),
+            "def" => ("in", &[":"]),
+            "if" => ("else", &[":"]),
+            "lambda" => ("end", &[",", ":"]),
+            "while" => ("end", &[":"]),
+            "for" => ("end", &["="]),
+            "(" => (")", &[",", ")"]),
+            "[" => ("]", &[",", "]"]),
+            ":" | "=" | "," | "to" => {
+                // the parts of a construct after its header
+                if let Some(top) = open.last_mut() {
+                    top.follows = match (top.lexeme.text, tok.text) {
+                        ("let", "=") => &["in", ","],
+                        ("let", ",") => &["="],
+                        ("def", ":") => &["and", "in"],
+                        ("if", ":") => &["else"],
+                        ("lambda", ":") | ("while", ":") | ("for", ":") => &["end"],
+                        ("for", "=") => &["to"],
+                        ("for", "to") => &[":"],
+                        _ => top.follows,
+                    }
+                }
+                continue;
+            }
+            // the next definition of the group takes over
+            "and" => {
+                if open.last().is_some_and(|top| top.lexeme.text == "def") {
+                    open.pop();
+                }
+                continue;
+            }
+            closer @ ("in" | "else" | "end" | ")" | "]") => {
+                if let Some(ix) = open.iter().rposition(|o| o.closing == closer) {
+                    open.truncate(ix);
+                }
+                continue;
+            }
+            _ => continue,
+        };
+        open.push(Open {
+            lexeme: *tok,
+            closing,
+            follows,
+        });
+    }
+    open
+}
@@ -1,8 +1,9 @@
 // auto-generated: "lalrpop 0.19.8"
-// sha3: aa5bdd41184737f2c0377cc3b3937308ed06f345a9108ed0ddc4ffa9f567b0c1
-use std::str::FromStr;
+// sha3: 0b50012500f7837d139eadcdf814b806d3bd0cc31547a2410ee7acb4b5dc9ea3
 use crate::syntax::{FunDecl, SurfProg, SurfFunDecl, Exp, Prim};
 use crate::span::Span1;
+use crate::lexer::{unescape, LexError, Tok};
+use lalrpop_util::ErrorRecovery;
 #[allow(unused_extern_crates)]
 extern crate lalrpop_util as __lalrpop_util;
 #[allow(unused_imports)]
@@ -14,273 +15,488 @@ extern crate alloc;
 mod __parse__Exp {
     #![allow(non_snake_case, non_camel_case_types, unused_mut, unused_variables, unused_imports, unused_parens, clippy::all)]
 
-    use std::str::FromStr;
     use crate::syntax::{FunDecl, SurfProg, SurfFunDecl, Exp, Prim};
     use crate::span::Span1;
+    use crate::lexer::{unescape, LexError, Tok};
+    use lalrpop_util::ErrorRecovery;
     #[allow(unused_extern_crates)]
     extern crate lalrpop_util as __lalrpop_util;
     #[allow(unused_imports)]
     use self::__lalrpop_util::state_machine as __state_machine;
     extern crate core;
     extern crate alloc;
-    use self::__lalrpop_util::lexer::Token;
+    use super::__ToTriple;
     #[allow(dead_code)]
     pub(crate) enum __Symbol<'input>
      {
-        Variant0(&'input str),
-        Variant1(Exp<Span1>),
-        Variant2(alloc::vec::Vec<Exp<Span1>>),
-        Variant3(String),
-        Variant4(alloc::vec::Vec<String>),
-        Variant5((SurfFunDecl<Span1>, &'input str)),
-        Variant6(alloc::vec::Vec<(SurfFunDecl<Span1>, &'input str)>),
-        Variant7(usize),
-        Variant8(Vec<(String, Exp<Span1>)>),
-        Variant9(bool),
-        Variant10(Box<Exp<Span1>>),
-        Variant11(Prim),
-        Variant12(Vec<Exp<Span1>>),
-        Variant13(Vec<String>),
-        Variant14(core::option::Option<Exp<Span1>>),
-        Variant15(SurfFunDecl<Span1>),
-        Variant16(core::option::Option<String>),
-        Variant17(i64),
-        Variant18(SurfProg<Span1>),
-    }
-    const __ACTION: &[i8] = &[
+        Variant0(Tok<'input>),
+        Variant1(&'input str),
+        Variant2(i64),
+        Variant3(__lalrpop_util::ErrorRecovery<usize, Tok<'input>, LexError>),
+        Variant4(core::option::Option<Tok<'input>>),
+        Variant5(String),
+        Variant6(alloc::vec::Vec<String>),
+        Variant7(Exp<Span1>),
+        Variant8(alloc::vec::Vec<Exp<Span1>>),
+        Variant9((SurfFunDecl<Span1>, Tok<'input>)),
+        Variant10(alloc::vec::Vec<(SurfFunDecl<Span1>, Tok<'input>)>),
+        Variant11(usize),
+        Variant12((String, Exp<Span1>)),
+        Variant13(Vec<(String, Exp<Span1>)>),
+        Variant14(Prim),
+        Variant15(bool),
+        Variant16(Box<Exp<Span1>>),
+        Variant17(Vec<String>),
+        Variant18(Vec<Exp<Span1>>),
+        Variant19(SurfFunDecl<Span1>),
+        Variant20(core::option::Option<String>),
+        Variant21(core::option::Option<Exp<Span1>>),
+        Variant22(SurfProg<Span1>),
+    }
+    const __ACTION: &[i16] = &[
         // State 0
-        7, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 46, 0, 9, 0, 47, 10, 0, 48, 49, 11, 50, 51, 52, 0, 53, 54,
+        11, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 13, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 14, 0, 0, 88, 89, 0, 90, 15, 0, 0, 91, 16, 92, 17, 0, 93, 94, 95, 96, 18, 97, 19, 98, 99, 100, 101, 102, 103, 104, 105, 106, 107, 0, 108, 20, 0, 0, 0,
         // State 1
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 15, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 2
-        0, 0, 56, 0, -81, 0, 0, -81, 0, -81, 0, 0, 0, 0, 0, 0, 0, -81, 0, -81, 0, 0, -81, 0, 0, 0, 0, 0, 0, 57, 0, 0,
+        0, 0, 0, 0, 110, 0, -134, 0, 0, 0, -134, 0, 0, -134, 0, -134, 0, 0, 0, 0, 0, 0, 0, 0, 0, -134, 0, 0, 0, -134, 0, 0, -134, -134, 0, 0, 0, 0, -134, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -134, 0, 0, 0, 111, 0,
         // State 3
-        0, 59, -36, 0, -36, 0, 0, -36, 0, -36, 60, 61, 0, 62, 63, 64, 0, -36, 0, -36, 0, 0, -36, 0, 0, 0, 0, 0, 0, -36, 0, 0,
+        0, -43, 0, 113, -43, 0, -43, 0, 0, 0, -43, 0, 0, -43, 0, -43, -43, 0, -43, 0, -43, -43, -43, 0, 0, -43, -43, 0, 0, -43, 0, 0, -43, -43, 0, 0, 0, 0, -43, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -43, 0, 0, -43, -43, 0,
         // State 4
-        0, -82, -82, 0, -82, 0, 65, -82, 66, -82, -82, -82, 0, -82, -82, -82, 0, -82, 0, -82, 0, 0, -82, 0, 0, 0, 0, 0, 0, -82, 0, 0,
+        0, -45, 0, 0, -45, 0, -45, 0, 0, 0, -45, 0, 0, -45, 0, -45, -45, 0, -45, 0, -45, -45, -45, 0, 0, -45, 0, 0, 0, -45, 0, 0, -45, -45, 0, 0, 0, 0, -45, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -45, 0, 0, 114, -45, 0,
         // State 5
-        0, -78, -78, 0, -78, 67, -78, -78, -78, -78, -78, -78, 0, -78, -78, -78, 0, -78, 0, -78, 0, 0, -78, 0, 0, 0, 0, 0, 0, -78, 0, 0,
+        0, -47, 0, 0, -47, 0, -47, 0, 0, 0, -47, 0, 0, -47, 0, -47, -47, 0, -47, 0, -47, -47, -47, 0, 0, -47, 115, 0, 0, -47, 0, 0, -47, -47, 0, 0, 0, 0, -47, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -47, 0, 0, -47, -47, 0,
         // State 6
-        0, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 46, 0, 0, 0, 47, 0, 0, 48, 49, 0, 50, 51, 52, 0, 53, 54,
+        0, 116, 0, 0, -61, 0, -61, 0, 0, 0, -61, 0, 0, -61, 0, -61, 117, 0, 118, 0, 119, 120, 121, 0, 0, -61, 0, 0, 0, -61, 0, 0, -61, -61, 0, 0, 0, 0, -61, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -61, 0, 0, 0, -61, 0,
         // State 7
-        7, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 46, 0, 9, 0, 47, 10, 0, 48, 49, 11, 50, 51, 52, 0, 53, 54,
+        0, -145, 0, -145, -145, 0, -145, 0, 122, 123, -145, 124, 0, -145, 0, -145, -145, -145, -145, 0, -145, -145, -145, -145, 0, -145, -145, 0, 0, -145, 0, 0, -145, -145, 0, 0, 0, 0, -145, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -145, 0, 0, -145, -145, 0,
         // State 8
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 54,
+        0, -143, 0, -143, -143, 0, -143, 0, 0, 0, -143, 0, 0, -143, 0, -143, -143, 125, -143, 0, -143, -143, -143, 126, 0, -143, -143, 0, 0, -143, 0, 0, -143, -143, 0, 0, 0, 0, -143, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -143, 0, 0, -143, -143, 0,
         // State 9
-        7, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 46, 0, 9, 0, 47, 10, 0, 48, 49, 11, 50, 51, 52, 0, 53, 54,
+        0, -131, 127, -131, -131, 0, -131, 128, -131, -131, -131, -131, 129, -131, 0, -131, -131, -131, -131, 0, -131, -131, -131, -131, 0, -131, -131, 0, 0, -131, 0, 0, -131, -131, 0, 0, 0, 0, -131, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -131, 0, 0, -131, -131, 0,
         // State 10
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 54,
+        0, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 14, 0, 0, 88, 89, 0, 90, 0, 0, 0, 91, 16, 92, 0, 0, 93, 94, 95, 96, 18, 97, 0, 98, 99, 100, 101, 102, 103, 104, 105, 106, 107, 0, 108, 20, 0, 0, 0,
         // State 11
-        7, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 46, 0, 0, 0, 47, 0, 0, 48, 49, 0, 50, 51, 52, 0, 53, 54,
+        11, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 13, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 14, 0, 0, 88, 89, 0, 90, 15, 0, 0, 91, 16, 92, 17, 0, 93, 94, 95, 96, 18, 97, 19, 98, 99, 100, 101, 102, 103, 104, 105, 106, 107, 0, 108, 20, 0, 0, 0,
         // State 12
-        7, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 46, 0, 9, 0, 47, 10, 0, 48, 49, 11, 50, 51, 52, 0, 53, 54,
+        0, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 14, 0, 0, 88, 89, 0, 90, 0, 0, 0, 91, 16, 92, 0, 0, 93, 94, 95, 96, 18, 97, 0, 98, 99, 100, 101, 102, 103, 104, 105, 106, 107, 0, 108, 20, 0, 0, 0,
         // State 13
-        7, 0, 0, 8, -38, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 46, 0, 9, 0, 47, 10, 0, 48, 49, 11, 50, 51, 52, 0, 53, 54,
+        11, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 13, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 14, -67, 0, 88, 89, 0, 90, 15, 0, 0, 91, 16, 92, 17, 0, 93, 94, 95, 96, 18, 97, 19, 98, 99, 100, 101, 102, 103, 104, 105, 106, 107, 0, 108, 20, 0, 0, 138,
         // State 14
-        7, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 46, 0, 0, 0, 47, 0, 0, 48, 49, 0, 50, 51, 52, 0, 53, 54,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 92, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 15
-        7, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 46, 0, 0, 0, 47, 0, 0, 48, 49, 0, 50, 51, 52, 0, 53, 54,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 92, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 16
-        7, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 46, 0, 0, 0, 47, 0, 0, 48, 49, 0, 50, 51, 52, 0, 53, 54,
+        11, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 13, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 14, 0, 0, 88, 89, 0, 90, 15, 0, 0, 91, 16, 92, 17, 0, 93, 94, 95, 96, 18, 97, 19, 98, 99, 100, 101, 102, 103, 104, 105, 106, 107, 0, 108, 20, 0, 0, 0,
         // State 17
-        7, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 46, 0, 9, 0, 47, 10, 0, 48, 49, 11, 50, 51, 52, 0, 53, 54,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -63, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 92, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 18
-        7, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 46, 0, 9, 0, 47, 10, 0, 48, 49, 11, 50, 51, 52, 0, 53, 54,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 92, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 19
-        7, 0, 0, 8, -40, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 46, 0, 9, 0, 47, 10, 0, 48, 49, 11, 50, 51, 52, 0, 53, 54,
+        11, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 13, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 14, 0, 0, 88, 89, 0, 90, 15, 0, 0, 91, 16, 92, 17, 0, 93, 94, 95, 96, 18, 97, 19, 98, 99, 100, 101, 102, 103, 104, 105, 106, 107, 0, 108, 20, 0, 0, 0,
         // State 20
-        0, 0, 0, 0, -42, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 54,
+        11, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 13, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 14, 0, 0, 88, 89, 0, 90, 15, 0, 0, 91, 16, 92, 17, 0, 93, 94, 95, 96, 18, 97, 19, 98, 99, 100, 101, 102, 103, 104, 105, 106, 107, 0, 108, 20, 0, 0, 0,
         // State 21
-        7, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 46, 0, 9, 0, 47, 10, 0, 48, 49, 11, 50, 51, 52, 0, 53, 54,
+        11, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 13, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 14, 0, 0, 88, 89, 0, 90, 15, 0, 0, 91, 16, 92, 17, 0, 93, 94, 95, 96, 18, 97, 19, 98, 99, 100, 101, 102, 103, 104, 105, 106, 107, 0, 108, 20, 0, 0, 0,
         // State 22
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 54,
+        11, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 13, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 14, 0, 0, 88, 89, 0, 90, 0, 0, 0, 91, 16, 92, 0, 0, 93, 94, 95, 96, 18, 97, 0, 98, 99, 100, 101, 102, 103, 104, 105, 106, 107, 0, 108, 20, 0, 0, 0,
         // State 23
-        7, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 46, 0, 9, 0, 47, 10, 0, 48, 49, 11, 50, 51, 52, 0, 53, 54,
+        11, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 13, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 14, 0, 0, 88, 89, 0, 90, 15, 0, 0, 91, 16, 92, 17, 0, 93, 94, 95, 96, 18, 97, 19, 98, 99, 100, 101, 102, 103, 104, 105, 106, 107, 0, 108, 20, 0, 0, 0,
         // State 24
-        7, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 46, 0, 9, 0, 47, 10, 0, 48, 49, 11, 50, 51, 52, 0, 53, 54,
+        11, 0, 0, 0, 0, 12, -67, 0, 0, 0, 0, 13, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 14, 0, 0, 88, 89, 0, 90, 15, 0, 0, 91, 16, 92, 17, 0, 93, 94, 95, 96, 18, 97, 19, 98, 99, 100, 101, 102, 103, 104, 105, 106, 107, 0, 108, 20, 0, 0, 138,
         // State 25
-        0, 0, 0, 0, -44, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 54,
+        11, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 13, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 14, 0, 0, 88, 89, 0, 90, 15, 0, 0, 91, 16, 92, 17, 0, 93, 94, 95, 96, 18, 97, 19, 98, 99, 100, 101, 102, 103, 104, 105, 106, 107, 0, 108, 20, 0, 0, 0,
         // State 26
-        7, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 46, 0, 9, 0, 47, 10, 0, 48, 49, 11, 50, 51, 52, 0, 53, 54,
+        11, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 13, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 14, 0, 0, 88, 89, 0, 90, 0, 0, 0, 91, 16, 92, 0, 0, 93, 94, 95, 96, 18, 97, 0, 98, 99, 100, 101, 102, 103, 104, 105, 106, 107, 0, 108, 20, 0, 0, 0,
         // State 27
-        7, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 46, 0, 9, 0, 47, 10, 0, 48, 49, 11, 50, 51, 52, 0, 53, 54,
+        11, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 13, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 14, 0, 0, 88, 89, 0, 90, 0, 0, 0, 91, 16, 92, 0, 0, 93, 94, 95, 96, 18, 97, 0, 98, 99, 100, 101, 102, 103, 104, 105, 106, 107, 0, 108, 20, 0, 0, 0,
         // State 28
-        7, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 46, 0, 9, 0, 47, 10, 0, 48, 49, 11, 50, 51, 52, 0, 53, 54,
+        11, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 13, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 14, 0, 0, 88, 89, 0, 90, 0, 0, 0, 91, 16, 92, 0, 0, 93, 94, 95, 96, 18, 97, 0, 98, 99, 100, 101, 102, 103, 104, 105, 106, 107, 0, 108, 20, 0, 0, 0,
         // State 29
-        0, -69, -69, 0, -69, -69, -69, -69, -69, -69, -69, -69, 0, -69, -69, -69, 0, -69, 0, -69, 0, 0, -69, 0, 0, 0, 0, 0, 0, -69, 0, 0,
+        11, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 13, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 14, 0, 0, 88, 89, 0, 90, 0, 0, 0, 91, 16, 92, 0, 0, 93, 94, 95, 96, 18, 97, 0, 98, 99, 100, 101, 102, 103, 104, 105, 106, 107, 0, 108, 20, 0, 0, 0,
         // State 30
-        0, 0, 0, 0, -48, 0, 0, -48, 0, -48, 0, 0, 0, 0, 0, 0, 0, -48, 0, -48, 0, 0, -48, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        11, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 13, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 14, 0, 0, 88, 89, 0, 90, 0, 0, 0, 91, 16, 92, 0, 0, 93, 94, 95, 96, 18, 97, 0, 98, 99, 100, 101, 102, 103, 104, 105, 106, 107, 0, 108, 20, 0, 0, 0,
         // State 31
-        0, -19, -19, 0, -19, -19, -19, -19, -19, -19, -19, -19, 0, -19, -19, -19, 0, -19, 0, -19, 0, 0, -19, 0, 0, 0, 0, 0, 0, -19, 0, 0,
+        11, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 13, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 14, 0, 0, 88, 89, 0, 90, 0, 0, 0, 91, 16, 92, 0, 0, 93, 94, 95, 96, 18, 97, 0, 98, 99, 100, 101, 102, 103, 104, 105, 106, 107, 0, 108, 20, 0, 0, 0,
         // State 32
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        11, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 13, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 14, 0, 0, 88, 89, 0, 90, 0, 0, 0, 91, 16, 92, 0, 0, 93, 94, 95, 96, 18, 97, 0, 98, 99, 100, 101, 102, 103, 104, 105, 106, 107, 0, 108, 20, 0, 0, 0,
         // State 33
-        0, 0, 0, 0, -47, 0, 0, -47, 0, -47, 0, 0, 0, 0, 0, 0, 0, -47, 0, -47, 0, 0, -47, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        11, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 13, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 14, 0, 0, 88, 89, 0, 90, 15, 0, 0, 91, 16, 92, 17, 0, 93, 94, 95, 96, 18, 97, 19, 98, 99, 100, 101, 102, 103, 104, 105, 106, 107, 0, 108, 20, 0, 0, 0,
         // State 34
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 58, 0, 0, 0, 0, 13, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        11, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 13, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 14, 0, 0, 88, 89, 0, 90, 15, 0, 0, 91, 16, 92, 17, 0, 93, 94, 95, 96, 18, 97, 19, 98, 99, 100, 101, 102, 103, 104, 105, 106, 107, 0, 108, 20, 0, 0, 0,
         // State 35
-        0, 0, 0, 0, -45, 0, 0, -45, 0, -45, 0, 0, 0, 0, 0, 0, 0, -45, 0, -45, 0, 0, -45, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        11, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 13, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 14, 0, 0, 88, 89, 0, 90, 15, 0, 0, 91, 16, 92, 17, 0, 93, 94, 95, 96, 18, 97, 19, 98, 99, 100, 101, 102, 103, 104, 105, 106, 107, 0, 108, 20, 0, 0, 0,
         // State 36
-        0, -18, -18, 14, -18, -18, -18, -18, -18, -18, -18, -18, 0, -18, -18, -18, 0, -18, 0, -18, 0, 0, -18, 0, 0, 0, 0, 0, 0, -18, 0, 0,
+        11, 0, 0, 0, 0, 12, -69, 0, 0, 0, 0, 13, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 14, -69, 0, 88, 89, 0, 90, 15, 0, 0, 91, 16, 92, 17, 0, 93, 94, 95, 96, 18, 97, 19, 98, 99, 100, 101, 102, 103, 104, 105, 106, 107, 0, 108, 20, 0, 0, 138,
         // State 37
-        0, 0, 0, 0, -46, 0, 0, -46, 0, -46, 0, 0, 0, 0, 0, 0, 0, -46, 0, -46, 0, 0, -46, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, -65, 0, 0, 0, 0, 0, 0, -65, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 92, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 38
-        0, 0, 0, 0, -24, 0, 0, -24, 0, -24, 0, 0, 0, 0, 0, 0, 0, -24, 0, -24, 0, 0, -24, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 48, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 138,
         // State 39
-        0, -63, -63, 0, -63, -63, -63, -63, -63, -63, -63, -63, 0, -63, -63, -63, 0, -63, 0, -63, 0, 0, -63, 0, 0, 0, 0, 0, 0, -63, 0, 0,
+        11, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 13, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 14, 0, 0, 88, 89, 0, 90, 15, 0, 0, 91, 16, 92, 17, 0, 93, 94, 95, 96, 18, 97, 19, 98, 99, 100, 101, 102, 103, 104, 105, 106, 107, 0, 108, 20, 0, 0, 0,
         // State 40
-        0, -20, -20, 0, -20, -20, -20, -20, -20, -20, -20, -20, 0, -20, -20, -20, 0, -20, 0, -20, 0, 0, -20, 0, 0, 0, 0, 0, 0, -20, 0, 0,
+        11, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 13, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 14, 0, 0, 88, 89, 0, 90, 15, 0, 0, 91, 16, 92, 17, 0, 93, 94, 95, 96, 18, 97, 19, 98, 99, 100, 101, 102, 103, 104, 105, 106, 107, 0, 108, 20, 0, 0, 0,
         // State 41
-        0, 0, 0, 18, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, -63, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 92, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 42
-        0, -61, -61, 0, -61, 0, -61, -61, -61, -61, -61, -61, 0, -61, -61, -61, 0, -61, 0, -61, 0, 0, -61, 0, 0, 0, 0, 0, 0, -61, 0, 0,
+        11, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 13, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 14, 0, 0, 88, 89, 0, 90, 15, 0, 0, 91, 16, 92, 17, 0, 93, 94, 95, 96, 18, 97, 19, 98, 99, 100, 101, 102, 103, 104, 105, 106, 107, 0, 108, 20, 0, 0, 0,
         // State 43
-        0, 0, 0, 0, -67, 0, 0, -67, 0, -67, 0, 0, 0, 0, 0, 0, 0, -67, 0, -67, 0, 0, -67, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        11, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 13, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 14, 0, 0, 88, 89, 0, 90, 15, 0, 0, 91, 16, 92, 17, 0, 93, 94, 95, 96, 18, 97, 19, 98, 99, 100, 101, 102, 103, 104, 105, 106, 107, 0, 108, 20, 0, 0, 0,
         // State 44
-        0, -59, -59, 0, -59, 0, 0, -59, 0, -59, -59, -59, 0, -59, -59, -59, 0, -59, 0, -59, 0, 0, -59, 0, 0, 0, 0, 0, 0, -59, 0, 0,
+        11, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 13, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 14, 0, 0, 88, 89, 0, 90, 15, 0, 0, 91, 16, 92, 17, 0, 93, 94, 95, 96, 18, 97, 19, 98, 99, 100, 101, 102, 103, 104, 105, 106, 107, 0, 108, 20, 0, 0, 0,
         // State 45
-        0, 0, 0, -73, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 92, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 46
-        0, -28, -28, 0, -28, -28, -28, -28, -28, -28, -28, -28, 0, -28, -28, -28, 0, -28, 0, -28, 0, 0, -28, 0, 0, 0, 0, 0, 0, -28, 0, 0,
+        11, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 13, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 14, 0, 0, 88, 89, 0, 90, 15, 0, 0, 91, 16, 92, 17, 0, 93, 94, 95, 96, 18, 97, 19, 98, 99, 100, 101, 102, 103, 104, 105, 106, 107, 0, 108, 20, 0, 0, 0,
         // State 47
-        0, 0, 0, -77, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        11, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 13, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 14, 0, 0, 88, 89, 0, 90, 15, 0, 0, 91, 16, 92, 17, 0, 93, 94, 95, 96, 18, 97, 19, 98, 99, 100, 101, 102, 103, 104, 105, 106, 107, 0, 108, 20, 0, 0, 138,
         // State 48
-        0, 0, 0, -76, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        11, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 13, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 14, 0, 0, 88, 89, 0, 90, 15, 0, 0, 91, 16, 92, 17, 0, 93, 94, 95, 96, 18, 97, 19, 98, 99, 100, 101, 102, 103, 104, 105, 106, 107, 0, 108, 20, 0, 0, 0,
         // State 49
-        0, 0, 0, -75, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        11, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 13, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 14, 0, 0, 88, 89, 0, 90, 15, 0, 0, 91, 16, 92, 17, 0, 93, 94, 95, 96, 18, 97, 19, 98, 99, 100, 101, 102, 103, 104, 105, 106, 107, 0, 108, 20, 0, 0, 0,
         // State 50
-        0, 0, 0, -74, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        11, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 13, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 14, 0, 0, 88, 89, 0, 90, 15, 0, 0, 91, 16, 92, 17, 0, 93, 94, 95, 96, 18, 97, 19, 98, 99, 100, 101, 102, 103, 104, 105, 106, 107, 0, 108, 20, 0, 0, 0,
         // State 51
-        0, -27, -27, 0, -27, -27, -27, -27, -27, -27, -27, -27, 0, -27, -27, -27, 0, -27, 0, -27, 0, 0, -27, 0, 0, 0, 0, 0, 0, -27, 0, 0,
+        11, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 13, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 14, 0, 0, 88, 89, 0, 90, 15, 0, 0, 91, 16, 92, 17, 0, 93, 94, 95, 96, 18, 97, 19, 98, 99, 100, 101, 102, 103, 104, 105, 106, 107, 0, 108, 20, 0, 0, 0,
         // State 52
-        0, -70, -70, 0, -70, -70, -70, -70, -70, -70, -70, -70, 0, -70, -70, -70, 0, -70, 0, -70, 0, 0, -70, 0, 0, 0, 0, 0, 0, -70, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 56, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 138,
         // State 53
-        0, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, 0, -55, 0, -55, 0, 0, -55, 0, 0, 0, 0, 0, 0, -55, 0, 0,
+        11, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 13, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 14, 0, 0, 88, 89, 0, 90, 15, 0, 0, 91, 16, 92, 17, 0, 93, 94, 95, 96, 18, 97, 19, 98, 99, 100, 101, 102, 103, 104, 105, 106, 107, 0, 108, 20, 0, 0, 0,
         // State 54
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 74, 0, 0, 0, 0, 19, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        11, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 13, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 14, 0, 0, 88, 89, 0, 90, 15, 0, 0, 91, 16, 92, 17, 0, 93, 94, 95, 96, 18, 97, 19, 98, 99, 100, 101, 102, 103, 104, 105, 106, 107, 0, 108, 20, 0, 0, 0,
         // State 55
-        -65, 0, 0, -65, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -65, 0, 0, 0, -65, 0, 0, -65, -65, 0, -65, -65, -65, 0, -65, -65,
+        11, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 13, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 14, 0, 0, 88, 89, 0, 90, 15, 0, 0, 91, 16, 92, 17, 0, 93, 94, 95, 96, 18, 97, 19, 98, 99, 100, 101, 102, 103, 104, 105, 106, 107, 0, 108, 20, 0, 0, 138,
         // State 56
-        -66, 0, 0, -66, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -66, 0, 0, 0, -66, 0, 0, -66, -66, 0, -66, -66, -66, 0, -66, -66,
+        11, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 13, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 14, 0, 0, 88, 89, 0, 90, 15, 0, 0, 91, 16, 92, 17, 0, 93, 94, 95, 96, 18, 97, 19, 98, 99, 100, 101, 102, 103, 104, 105, 106, 107, 0, 108, 20, 0, 0, 0,
         // State 57
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -14, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        11, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 13, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 14, 0, 0, 88, 89, 0, 90, 15, 0, 0, 91, 16, 92, 17, 0, 93, 94, 95, 96, 18, 97, 19, 98, 99, 100, 101, 102, 103, 104, 105, 106, 107, 0, 108, 20, 0, 0, 0,
         // State 58
-        -35, 0, 0, -35, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -35, 0, 0, 0, -35, 0, 0, -35, -35, 0, -35, -35, -35, 0, -35, -35,
+        0, -106, -106, -106, -106, 0, -106, -106, -106, -106, -106, -106, -106, -106, 0, -106, -106, -106, -106, 0, -106, -106, -106, -106, 21, -106, -106, 0, 0, -106, 0, 0, -106, -106, 0, 0, 0, 0, -106, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -106, 0, 0, -106, -106, 0,
         // State 59
-        -30, 0, 0, -30, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -30, 0, 0, 0, -30, 0, 0, -30, -30, 0, -30, -30, -30, 0, -30, -30,
+        0, 0, 0, 0, 0, 0, -54, 0, 0, 0, -54, 0, 0, -54, 0, -54, 0, 0, 0, 0, 0, 0, 0, 0, 0, -54, 0, 0, 0, -54, 0, 0, -54, -54, 0, 0, 0, 0, -54, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -54, 0, 0, 0, 0, 0,
         // State 60
-        -31, 0, 0, -31, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -31, 0, 0, 0, -31, 0, 0, -31, -31, 0, -31, -31, -31, 0, -31, -31,
+        0, -87, 0, 0, -87, 0, -87, 0, 0, 0, -87, 0, 0, -87, 0, -87, -87, 0, -87, 0, -87, -87, -87, 0, 0, -87, -87, 0, 0, -87, 0, 0, -87, -87, 0, 0, 0, 0, -87, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -87, 0, 0, -87, -87, 0,
         // State 61
-        -34, 0, 0, -34, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -34, 0, 0, 0, -34, 0, 0, -34, -34, 0, -34, -34, -34, 0, -34, -34,
+        0, -89, 0, 0, -89, 0, -89, 0, 0, 0, -89, 0, 0, -89, 0, -89, -89, 0, -89, 0, -89, -89, -89, 0, 0, -89, 0, 0, 0, -89, 0, 0, -89, -89, 0, 0, 0, 0, -89, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -89, 0, 0, 0, -89, 0,
         // State 62
-        -32, 0, 0, -32, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -32, 0, 0, 0, -32, 0, 0, -32, -32, 0, -32, -32, -32, 0, -32, -32,
+        0, -85, 0, 0, -85, 0, -85, 0, 0, 0, -85, 0, 0, -85, 0, -85, -85, 0, -85, 0, -85, -85, -85, 0, 0, -85, 0, 0, 0, -85, 0, 0, -85, -85, 0, 0, 0, 0, -85, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -85, 0, 0, -85, -85, 0,
         // State 63
-        -33, 0, 0, -33, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -33, 0, 0, 0, -33, 0, 0, -33, -33, 0, -33, -33, -33, 0, -33, -33,
+        0, -23, -23, -23, -23, 0, -23, -23, -23, -23, -23, -23, -23, -23, 0, -23, -23, -23, -23, 0, -23, -23, -23, -23, -23, -23, -23, 0, 0, -23, 0, 0, -23, -23, 0, 0, 0, 0, -23, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -23, 0, 0, -23, -23, 0,
         // State 64
-        -71, 0, 0, -71, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -71, 0, 0, 0, -71, 0, 0, -71, -71, 0, -71, -71, -71, 0, -71, -71,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 22, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 65
-        -72, 0, 0, -72, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -72, 0, 0, 0, -72, 0, 0, -72, -72, 0, -72, -72, -72, 0, -72, -72,
+        0, 0, 0, 0, 0, 0, -72, 0, 0, 0, -72, 0, 0, -72, 0, -50, 0, 0, 0, 0, 0, 0, 0, 0, 0, -72, 0, 0, 0, -72, 0, 0, -72, -72, 0, 0, 0, 0, -72, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -72, 0, 0, 0, 0, 0,
         // State 66
-        -83, 0, 0, -83, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -83, 0, 0, 0, -83, 0, 0, -83, -83, 0, -83, -83, -83, 0, -83, -83,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 67
-        0, -68, -68, 0, -68, -68, -68, -68, -68, -68, -68, -68, 0, -68, -68, -68, 0, -68, 0, -68, 0, 0, -68, 0, 0, 0, 0, 0, 0, -68, 0, 0,
+        0, 0, 0, 0, 0, 0, -110, 0, 0, 0, -110, 0, 0, -110, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -110, 0, 0, 0, -110, 0, 0, -110, -110, 0, 0, 0, 0, -110, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -110, 0, 0, 0, 0, 0,
         // State 68
-        0, 0, 0, 0, 84, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 112, 0, 0, 0, 0, 0, 0, 0, 0, 24, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 69
-        0, 0, 0, 21, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, -108, 0, 0, 0, -108, 0, 0, -108, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -108, 0, 0, 0, -108, 0, 0, -108, -108, 0, 0, 0, 0, -108, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -108, 0, 0, 0, 0, 0,
         // State 70
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 22, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, -22, -22, -22, -22, 25, -22, -22, -22, -22, -22, -22, -22, -22, 26, -22, -22, -22, -22, 0, -22, -22, -22, -22, -22, -22, -22, 0, 0, -22, 0, 0, -22, -22, 0, 0, 0, 0, -22, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -22, 0, 0, -22, -22, 0,
         // State 71
-        0, 0, 0, 0, 0, 0, 0, 23, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 24, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, -109, 0, 0, 0, -109, 0, 0, -109, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -109, 0, 0, 0, -109, 0, 0, -109, -109, 0, 0, 0, 0, -109, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -109, 0, 0, 0, 0, 0,
         // State 72
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 25, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, -36, 0, 0, 0, -36, 0, 0, -36, 0, -36, 0, 0, 0, 0, 0, 0, 0, 0, 0, -36, 0, 0, 0, -36, 0, 0, -36, -36, 0, 0, 0, 0, -36, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -36, 0, 0, 0, 0, 0,
         // State 73
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -15, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, -95, -95, -95, -95, 0, -95, -95, -95, -95, -95, -95, -95, -95, 0, -95, -95, -95, -95, 0, -95, -95, -95, -95, 0, -95, -95, 0, 0, -95, 0, 0, -95, -95, 0, 0, 0, 0, -95, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -95, 0, 0, -95, -95, 0,
         // State 74
-        0, 0, 0, 0, -80, 0, 0, -80, 0, -80, 0, 0, 0, 0, 0, 0, 0, -80, 0, -80, 0, 0, -80, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, -24, -24, -24, -24, 0, -24, -24, -24, -24, -24, -24, -24, -24, 0, -24, -24, -24, -24, 0, -24, -24, -24, -24, -24, -24, -24, 0, 0, -24, 0, 0, -24, -24, 0, 0, 0, 0, -24, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -24, 0, 0, -24, -24, 0,
         // State 75
-        0, 0, 0, 0, -51, 0, 0, -51, 0, -51, 0, 0, 0, 0, 0, 0, 0, -51, 0, -51, 0, 0, -51, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, -71, 0, 0, 0, -71, 0, 0, -71, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -71, 0, 0, 0, -71, 0, 0, -71, -71, 0, 0, 0, 0, -71, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -71, 0, 0, 0, 0, 0,
         // State 76
-        0, 0, 0, 0, -29, 0, 0, -29, 0, -29, 0, 0, 0, 0, 0, 0, 0, -29, 0, -29, 0, 0, -29, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 34, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 77
-        0, 0, 0, 0, 87, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 35, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 78
-        0, 0, 0, 0, -37, 0, 0, 88, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 36, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 79
-        0, -58, -58, 0, -58, 0, 0, -58, 0, -58, -58, -58, 0, -58, -58, -58, 0, -58, 0, -58, 0, 0, -58, 0, 0, 0, 0, 0, 0, -58, 0, 0,
+        0, -91, 0, -91, -91, 0, -91, 0, -91, -91, -91, -91, 0, -91, 0, -91, -91, -91, -91, 0, -91, -91, -91, -91, 0, -91, -91, 0, 0, -91, 0, 0, -91, -91, 0, 0, 0, 0, -91, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -91, 0, 0, -91, -91, 0,
         // State 80
-        0, -60, -60, 0, -60, 0, -60, -60, -60, -60, -60, -60, 0, -60, -60, -60, 0, -60, 0, -60, 0, 0, -60, 0, 0, 0, 0, 0, 0, -60, 0, 0,
+        0, 0, 0, 0, 0, 0, -103, 0, 0, 0, -103, 0, 0, -103, 0, -103, 0, 0, 0, 0, 0, 0, 0, 0, 0, -103, 0, 0, 0, -103, 0, 0, -103, -103, 0, 0, 0, 0, -103, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -103, 0, 0, 0, 0, 0,
         // State 81
-        0, -62, -62, 0, -62, -62, -62, -62, -62, -62, -62, -62, 0, -62, -62, -62, 0, -62, 0, -62, 0, 0, -62, 0, 0, 0, 0, 0, 0, -62, 0, 0,
+        0, 0, 0, 0, 0, 0, -70, 0, 0, 0, -70, 0, 0, -70, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -70, 0, 0, 0, -70, 0, 0, -70, -70, 0, 0, 0, 0, -70, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -70, 0, 0, 0, 0, 0,
         // State 82
-        0, 0, 0, 0, 89, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, -53, 0, 0, 0, -53, 0, 0, -53, 0, -53, 0, 0, 0, 0, 0, 0, 0, 0, 0, -53, 0, 0, 0, -53, 0, 0, -53, -53, 0, 0, 0, 0, -53, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -53, 0, 0, 0, 0, 0,
         // State 83
-        0, -22, -22, 0, -22, -22, -22, -22, -22, -22, -22, -22, 0, -22, -22, -22, 0, -22, 0, -22, 0, 0, -22, 0, 0, 0, 0, 0, 0, -22, 0, 0,
+        0, 0, 0, 0, 0, 0, -111, 0, 0, 0, -111, 0, 0, -111, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -111, 0, 0, 0, -111, 0, 0, -111, -111, 0, 0, 0, 0, -111, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -111, 0, 0, 0, 0, 0,
         // State 84
-        0, 0, 0, 0, -52, 0, 0, -52, 0, -52, 0, 0, 0, 0, 0, 0, 0, -52, 0, -52, 0, 0, -52, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, -83, 0, -83, -83, 0, -83, 0, 0, 0, -83, 0, 0, -83, 0, -83, -83, 0, -83, 0, -83, -83, -83, 0, 0, -83, -83, 0, 0, -83, 0, 0, -83, -83, 0, 0, 0, 0, -83, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -83, 0, 0, -83, -83, 0,
         // State 85
-        0, 0, 0, 0, -39, 0, 0, 96, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, -25, -25, -25, -25, 0, -25, -25, -25, -25, -25, -25, -25, -25, 0, -25, -25, -25, -25, 0, -25, -25, -25, -25, -25, -25, -25, 0, 0, -25, 0, 0, -25, -25, 0, 0, 0, 0, -25, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -25, 0, 0, -25, -25, 0,
         // State 86
-        0, -23, -23, 0, -23, -23, -23, -23, -23, -23, -23, -23, 0, -23, -23, -23, 0, -23, 0, -23, 0, 0, -23, 0, 0, 0, 0, 0, 0, -23, 0, 0,
+        0, -93, 0, -93, -93, 0, -93, 0, 0, 0, -93, 0, 0, -93, 0, -93, -93, -93, -93, 0, -93, -93, -93, -93, 0, -93, -93, 0, 0, -93, 0, 0, -93, -93, 0, 0, 0, 0, -93, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -93, 0, 0, -93, -93, 0,
         // State 87
-        -4, 0, 0, -4, -4, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -4, 0, -4, 0, -4, -4, 0, -4, -4, -4, -4, -4, -4, 0, -4, -4,
+        0, 0, 0, 0, 0, -122, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 88
-        0, -21, -21, 0, -21, -21, -21, -21, -21, -21, -21, -21, 0, -21, -21, -21, 0, -21, 0, -21, 0, 0, -21, 0, 0, 0, 0, 0, 0, -21, 0, 0,
+        0, 0, 0, 0, 0, -115, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 89
-        0, 0, 0, 0, 98, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, -129, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 90
-        0, 0, 0, 0, -41, 0, 0, 99, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, -49, -49, -49, -49, 0, -49, -49, -49, -49, -49, -49, -49, -49, 0, -49, -49, -49, -49, 0, -49, -49, -49, -49, -49, -49, -49, 0, 0, -49, 0, 0, -49, -49, 0, 0, 0, 0, -49, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -49, 0, 0, -49, -49, 0,
         // State 91
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 100, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, -79, -79, -79, -79, -79, -79, -79, -79, -79, -79, -79, -79, -79, -79, -79, -79, -79, -79, -79, -79, -79, -79, -79, -79, -79, -79, 0, 0, -79, 0, 0, -79, -79, 0, 0, 0, 0, -79, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -79, 0, 0, -79, -79, -79,
         // State 92
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 27, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, -119, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 93
-        0, 0, 0, 0, -64, 0, 0, -64, 0, -64, 0, 0, 0, 0, 0, 0, 0, -64, 0, -64, 0, 0, -64, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, -118, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 94
-        0, 0, 0, 0, 0, 0, 0, -25, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -25, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, -123, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 95
-        -5, 0, 0, -5, -5, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -5, 0, -5, 0, -5, -5, 0, -5, -5, -5, -5, -5, -5, 0, -5, -5,
+        0, 0, 0, 0, 0, -120, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 96
-        0, 0, 0, 0, -43, 0, 0, 101, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, -121, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 97
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 28, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, -128, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 98
-        0, 0, 0, 0, -9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -9,
+        0, 0, 0, 0, 0, -127, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 99
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 29, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, -125, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 100
-        0, 0, 0, 0, -10, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -10,
+        0, -107, -107, -107, -107, 0, -107, -107, -107, -107, -107, -107, -107, -107, 0, -107, -107, -107, -107, 0, -107, -107, -107, -107, -107, -107, -107, 0, 0, -107, 0, 0, -107, -107, 0, 0, 0, 0, -107, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -107, 0, 0, -107, -107, 0,
         // State 101
-        0, 0, 0, 0, 0, 0, 0, -26, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -26, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, -117, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 102
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -53, 0, 0, 0, 0, -53, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, -144, -144, -144, -144, 0, -144, -144, -144, -144, -144, -144, -144, -144, 0, -144, -144, -144, -144, 0, -144, -144, -144, -144, -144, -144, -144, 0, 0, -144, 0, 0, -144, -144, 0, 0, 0, 0, -144, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -144, 0, 0, -144, -144, 0,
         // State 103
-        0, 0, 0, 0, -54, 0, 0, -54, 0, -54, 0, 0, 0, 0, 0, 0, 0, -54, 0, -54, 0, 0, -54, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, -126, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 104
+        0, 0, 0, 0, 0, -124, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 105
+        0, 0, 0, 0, 0, -116, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 106
+        0, 0, 0, 0, 0, -130, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 107
+        0, -48, -48, -48, -48, 0, -48, -48, -48, -48, -48, -48, -48, -48, 0, -48, -48, -48, -48, 0, -48, -48, -48, -48, -48, -48, -48, 0, 0, -48, 0, 0, -48, -48, 0, 0, 0, 0, -48, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -48, 0, 0, -48, -48, 0,
+        // State 108
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 148, 0, 0, 0, 0, 0, 0, 0, 0, 40, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 109
+        -101, 0, 0, 0, 0, -101, 0, 0, 0, 0, 0, -101, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -101, 0, 0, -101, -101, 0, -101, 0, 0, 0, -101, -101, -101, 0, 0, -101, -101, -101, -101, -101, -101, 0, -101, -101, -101, -101, -101, -101, -101, -101, -101, -101, 0, -101, -101, 0, 0, 0,
+        // State 110
+        -102, 0, 0, 0, 0, -102, 0, 0, 0, 0, 0, -102, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -102, 0, 0, -102, -102, 0, -102, 0, 0, 0, -102, -102, -102, 0, 0, -102, -102, -102, -102, -102, -102, 0, -102, -102, -102, -102, -102, -102, -102, -102, -102, -102, 0, -102, -102, 0, 0, 0,
+        // State 111
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -18, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 112
+        -42, 0, 0, 0, 0, -42, 0, 0, 0, 0, 0, -42, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -42, 0, 0, -42, -42, 0, -42, 0, 0, 0, -42, -42, -42, 0, 0, -42, -42, -42, -42, -42, -42, 0, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, 0, -42, -42, 0, 0, 0,
+        // State 113
+        -44, 0, 0, 0, 0, -44, 0, 0, 0, 0, 0, -44, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -44, 0, 0, -44, -44, 0, -44, 0, 0, 0, -44, -44, -44, 0, 0, -44, -44, -44, -44, -44, -44, 0, -44, -44, -44, -44, -44, -44, -44, -44, -44, -44, 0, -44, -44, 0, 0, 0,
+        // State 114
+        -46, 0, 0, 0, 0, -46, 0, 0, 0, 0, 0, -46, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -46, 0, 0, -46, -46, 0, -46, 0, 0, 0, -46, -46, -46, 0, 0, -46, -46, -46, -46, -46, -46, 0, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, 0, -46, -46, 0, 0, 0,
+        // State 115
+        -60, 0, 0, 0, 0, -60, 0, 0, 0, 0, 0, -60, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -60, 0, 0, -60, -60, 0, -60, 0, 0, 0, -60, -60, -60, 0, 0, -60, -60, -60, -60, -60, -60, 0, -60, -60, -60, -60, -60, -60, -60, -60, -60, -60, 0, -60, -60, 0, 0, 0,
+        // State 116
+        -55, 0, 0, 0, 0, -55, 0, 0, 0, 0, 0, -55, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -55, 0, 0, -55, -55, 0, -55, 0, 0, 0, -55, -55, -55, 0, 0, -55, -55, -55, -55, -55, -55, 0, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, 0, -55, -55, 0, 0, 0,
+        // State 117
+        -56, 0, 0, 0, 0, -56, 0, 0, 0, 0, 0, -56, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -56, 0, 0, -56, -56, 0, -56, 0, 0, 0, -56, -56, -56, 0, 0, -56, -56, -56, -56, -56, -56, 0, -56, -56, -56, -56, -56, -56, -56, -56, -56, -56, 0, -56, -56, 0, 0, 0,
+        // State 118
+        -59, 0, 0, 0, 0, -59, 0, 0, 0, 0, 0, -59, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -59, 0, 0, -59, -59, 0, -59, 0, 0, 0, -59, -59, -59, 0, 0, -59, -59, -59, -59, -59, -59, 0, -59, -59, -59, -59, -59, -59, -59, -59, -59, -59, 0, -59, -59, 0, 0, 0,
+        // State 119
+        -57, 0, 0, 0, 0, -57, 0, 0, 0, 0, 0, -57, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -57, 0, 0, -57, -57, 0, -57, 0, 0, 0, -57, -57, -57, 0, 0, -57, -57, -57, -57, -57, -57, 0, -57, -57, -57, -57, -57, -57, -57, -57, -57, -57, 0, -57, -57, 0, 0, 0,
+        // State 120
+        -58, 0, 0, 0, 0, -58, 0, 0, 0, 0, 0, -58, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -58, 0, 0, -58, -58, 0, -58, 0, 0, 0, -58, -58, -58, 0, 0, -58, -58, -58, -58, -58, -58, 0, -58, -58, -58, -58, -58, -58, -58, -58, -58, -58, 0, -58, -58, 0, 0, 0,
+        // State 121
+        -112, 0, 0, 0, 0, -112, 0, 0, 0, 0, 0, -112, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -112, 0, 0, -112, -112, 0, -112, 0, 0, 0, -112, -112, -112, 0, 0, -112, -112, -112, -112, -112, -112, 0, -112, -112, -112, -112, -112, -112, -112, -112, -112, -112, 0, -112, -112, 0, 0, 0,
+        // State 122
+        -114, 0, 0, 0, 0, -114, 0, 0, 0, 0, 0, -114, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -114, 0, 0, -114, -114, 0, -114, 0, 0, 0, -114, -114, -114, 0, 0, -114, -114, -114, -114, -114, -114, 0, -114, -114, -114, -114, -114, -114, -114, -114, -114, -114, 0, -114, -114, 0, 0, 0,
+        // State 123
+        -113, 0, 0, 0, 0, -113, 0, 0, 0, 0, 0, -113, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -113, 0, 0, -113, -113, 0, -113, 0, 0, 0, -113, -113, -113, 0, 0, -113, -113, -113, -113, -113, -113, 0, -113, -113, -113, -113, -113, -113, -113, -113, -113, -113, 0, -113, -113, 0, 0, 0,
+        // State 124
+        -141, 0, 0, 0, 0, -141, 0, 0, 0, 0, 0, -141, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -141, 0, 0, -141, -141, 0, -141, 0, 0, 0, -141, -141, -141, 0, 0, -141, -141, -141, -141, -141, -141, 0, -141, -141, -141, -141, -141, -141, -141, -141, -141, -141, 0, -141, -141, 0, 0, 0,
+        // State 125
+        -142, 0, 0, 0, 0, -142, 0, 0, 0, 0, 0, -142, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -142, 0, 0, -142, -142, 0, -142, 0, 0, 0, -142, -142, -142, 0, 0, -142, -142, -142, -142, -142, -142, 0, -142, -142, -142, -142, -142, -142, -142, -142, -142, -142, 0, -142, -142, 0, 0, 0,
+        // State 126
+        -148, 0, 0, 0, 0, -148, 0, 0, 0, 0, 0, -148, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -148, 0, 0, -148, -148, 0, -148, 0, 0, 0, -148, -148, -148, 0, 0, -148, -148, -148, -148, -148, -148, 0, -148, -148, -148, -148, -148, -148, -148, -148, -148, -148, 0, -148, -148, 0, 0, 0,
+        // State 127
+        -146, 0, 0, 0, 0, -146, 0, 0, 0, 0, 0, -146, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -146, 0, 0, -146, -146, 0, -146, 0, 0, 0, -146, -146, -146, 0, 0, -146, -146, -146, -146, -146, -146, 0, -146, -146, -146, -146, -146, -146, -146, -146, -146, -146, 0, -146, -146, 0, 0, 0,
+        // State 128
+        -147, 0, 0, 0, 0, -147, 0, 0, 0, 0, 0, -147, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -147, 0, 0, -147, -147, 0, -147, 0, 0, 0, -147, -147, -147, 0, 0, -147, -147, -147, -147, -147, -147, 0, -147, -147, -147, -147, -147, -147, -147, -147, -147, -147, 0, -147, -147, 0, 0, 0,
+        // State 129
+        0, -104, -104, -104, -104, 0, -104, -104, -104, -104, -104, -104, -104, -104, 0, -104, -104, -104, -104, 0, -104, -104, -104, -104, 41, -104, -104, 0, 0, -104, 0, 0, -104, -104, 0, 0, 0, 0, -104, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -104, 0, 0, -104, -104, 0,
+        // State 130
+        0, -22, -22, -22, -22, 25, -22, -22, -22, -22, -22, -22, -22, -22, 0, -22, -22, -22, -22, 0, -22, -22, -22, -22, -22, -22, -22, 0, 0, -22, 0, 0, -22, -22, 0, 0, 0, 0, -22, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -22, 0, 0, -22, -22, 0,
+        // State 131
+        0, 0, 0, 0, 0, 0, 169, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 132
+        0, -105, -105, -105, -105, 0, -105, -105, -105, -105, -105, -105, -105, -105, 0, -105, -105, -105, -105, 0, -105, -105, -105, -105, 41, -105, -105, 0, 0, -105, 0, 0, -105, -105, 0, 0, 0, 0, -105, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -105, 0, 0, -105, -105, 0,
+        // State 133
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 171, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 134
+        0, 0, 0, 0, 0, 0, -97, 0, 0, 0, -97, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -97, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 135
+        0, 0, 0, 0, 0, 0, -66, 0, 0, 0, 172, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -66, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 136
+        0, 0, 0, 0, 0, 0, -98, 0, 0, 0, -98, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -98, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 137
+        0, 0, 0, 0, 0, 0, -135, 0, 0, 0, -135, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -135, 0, 0, 0, -135, 0, 0, 0, 0, 0, 0, 0, 0, -135, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 138
+        0, 0, 0, 0, 0, 42, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 139
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 43, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 140
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 44, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 141
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 45, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 142
+        0, 0, 0, 0, 0, 0, -62, 0, 0, 0, 174, 0, 0, -62, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 143
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -40, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -40, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 144
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 46, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 47, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 145
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 49, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 146
+        0, 0, 0, 0, 0, 0, -51, 0, 0, 0, -51, 0, 0, -51, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -51, 0, 0, 0, -51, 0, 0, -51, -51, 0, 0, 0, 0, -51, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -51, 0, 0, 0, 0, 0,
+        // State 147
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -19, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 148
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 177, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 149
+        0, 0, 0, 0, 0, 0, -136, 0, 0, 0, -136, 0, 0, -136, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -136, 0, 0, 0, -136, 0, 0, -136, -136, 0, 0, 0, 0, -136, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -136, 0, 0, 0, 0, 0,
+        // State 150
+        0, -106, -106, -106, -106, 0, -106, -106, -106, -106, -106, -106, -106, -106, 0, -106, -106, -106, -106, 0, -106, -106, -106, -106, 41, -106, -106, 0, 0, -106, 0, 0, -106, -106, 0, 0, 0, 0, -106, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -106, 0, 0, -106, -106, 0,
+        // State 151
+        0, 0, 0, 0, 0, 0, -133, 0, 0, 0, -133, 0, 0, -133, 0, -133, 0, 0, 0, 0, 0, 0, 0, 0, 0, -133, 0, 0, 0, -133, 0, 0, -133, -133, 0, 0, 0, 0, -133, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -133, 0, 0, 0, 0, 0,
+        // State 152
+        0, 0, 0, 0, 0, 0, -73, 0, 0, 0, -73, 0, 0, -73, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -73, 0, 0, 0, -73, 0, 0, -73, -73, 0, 0, 0, 0, -73, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -73, 0, 0, 0, 0, 0,
+        // State 153
+        0, 0, 0, 0, 0, 0, 178, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 154
+        0, 0, 0, 0, 0, 0, -138, 0, 0, 0, -138, 0, 0, -138, 0, -138, 0, 0, 0, 0, 0, 0, 0, 0, 0, -138, 0, 0, 0, -138, 0, 0, -138, -138, 0, 0, 0, 0, -138, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -138, 0, 0, 0, 0, 0,
+        // State 155
+        0, 0, 0, 0, 0, 0, -140, 0, 0, 0, -140, 0, 0, -140, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -140, 0, 0, 0, -140, 0, 0, -140, -140, 0, 0, 0, 0, -140, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -140, 0, 0, 0, 0, 0,
+        // State 156
+        0, 0, 0, 0, 0, 0, -50, 0, 0, 0, -50, 0, 0, -50, 0, -50, 0, 0, 0, 0, 0, 0, 0, 0, 0, -50, 0, 0, 0, -50, 0, 0, -50, -50, 0, 0, 0, 0, -50, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -50, 0, 0, 0, 0, 0,
+        // State 157
+        0, 0, 0, 0, 0, 0, -52, 0, 0, 0, -52, 0, 0, -52, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -52, 0, 0, 0, -52, 0, 0, -52, -52, 0, 0, 0, 0, -52, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -52, 0, 0, 0, 0, 0,
+        // State 158
+        0, -82, 0, -82, -82, 0, -82, 0, 0, 0, -82, 0, 0, -82, 0, -82, -82, 0, -82, 0, -82, -82, -82, 0, 0, -82, -82, 0, 0, -82, 0, 0, -82, -82, 0, 0, 0, 0, -82, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -82, 0, 0, -82, -82, 0,
+        // State 159
+        0, -84, 0, 0, -84, 0, -84, 0, 0, 0, -84, 0, 0, -84, 0, -84, -84, 0, -84, 0, -84, -84, -84, 0, 0, -84, 0, 0, 0, -84, 0, 0, -84, -84, 0, 0, 0, 0, -84, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -84, 0, 0, -84, -84, 0,
+        // State 160
+        0, -86, 0, 0, -86, 0, -86, 0, 0, 0, -86, 0, 0, -86, 0, -86, -86, 0, -86, 0, -86, -86, -86, 0, 0, -86, -86, 0, 0, -86, 0, 0, -86, -86, 0, 0, 0, 0, -86, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -86, 0, 0, -86, -86, 0,
+        // State 161
+        0, -88, 0, 0, -88, 0, -88, 0, 0, 0, -88, 0, 0, -88, 0, -88, -88, 0, -88, 0, -88, -88, -88, 0, 0, -88, 0, 0, 0, -88, 0, 0, -88, -88, 0, 0, 0, 0, -88, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -88, 0, 0, 0, -88, 0,
+        // State 162
+        0, -90, 0, -90, -90, 0, -90, 0, -90, -90, -90, -90, 0, -90, 0, -90, -90, -90, -90, 0, -90, -90, -90, -90, 0, -90, -90, 0, 0, -90, 0, 0, -90, -90, 0, 0, 0, 0, -90, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -90, 0, 0, -90, -90, 0,
+        // State 163
+        0, -92, 0, -92, -92, 0, -92, 0, 0, 0, -92, 0, 0, -92, 0, -92, -92, -92, -92, 0, -92, -92, -92, -92, 0, -92, -92, 0, 0, -92, 0, 0, -92, -92, 0, 0, 0, 0, -92, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -92, 0, 0, -92, -92, 0,
+        // State 164
+        0, -94, -94, -94, -94, 0, -94, -94, -94, -94, -94, -94, -94, -94, 0, -94, -94, -94, -94, 0, -94, -94, -94, -94, 0, -94, -94, 0, 0, -94, 0, 0, -94, -94, 0, 0, 0, 0, -94, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -94, 0, 0, -94, -94, 0,
+        // State 165
+        0, 0, 0, 0, 0, 0, 179, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 166
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 50, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 167
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 51, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 168
+        0, -29, -29, -29, -29, 0, -29, -29, -29, -29, -29, -29, -29, -29, 0, -29, -29, -29, -29, 0, -29, -29, -29, -29, -29, -29, -29, 0, 0, -29, 0, 0, -29, -29, 0, 0, 0, 0, -29, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -29, 0, 0, -29, -29, 0,
+        // State 169
+        0, 0, 0, 0, 0, 0, -68, 0, 0, 0, 181, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -68, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 170
+        0, -34, -34, -34, -34, 0, -34, -34, -34, -34, -34, -34, -34, -34, 0, -34, -34, -34, -34, 0, -34, -34, -34, -34, -34, -34, -34, 0, 0, -34, 0, 0, -34, -34, 0, 0, 0, 0, -34, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -34, 0, 0, -34, -34, 0,
+        // State 171
+        -13, 0, 0, 0, 0, -13, -13, 0, 0, 0, 0, -13, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -13, -13, 0, -13, -13, 0, -13, -13, 0, 0, -13, -13, -13, -13, 0, -13, -13, -13, -13, -13, -13, -13, -13, -13, -13, -13, -13, -13, -13, -13, -13, -13, 0, -13, -13, 0, 0, -13,
+        // State 172
+        0, 0, 0, 0, 0, 0, -64, 0, 0, 0, 185, 0, 0, -64, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 173
+        0, 0, 0, 0, 0, 0, -8, 0, 0, 0, 0, 0, 0, -8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 174
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -39, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -39, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 175
+        0, 0, 0, 0, 0, 0, -74, 0, 0, 0, -74, 0, 0, -74, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -74, 0, 0, 0, -74, 0, 0, -74, -74, 0, 0, 0, 0, -74, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -74, 0, 0, 0, 0, 0,
+        // State 176
+        0, -35, -35, -35, -35, 0, -35, -35, -35, -35, -35, -35, -35, -35, 52, -35, -35, -35, -35, 0, -35, -35, -35, -35, -35, -35, -35, 0, 0, -35, 0, 0, -35, -35, 0, 0, 0, 0, -35, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -35, 0, 0, -35, -35, 0,
+        // State 177
+        0, -30, -30, -30, -30, 0, -30, -30, -30, -30, -30, -30, -30, -30, 0, -30, -30, -30, -30, 0, -30, -30, -30, -30, -30, -30, -30, 0, 0, -30, 0, 0, -30, -30, 0, 0, 0, 0, -30, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -30, 0, 0, -30, -30, 0,
+        // State 178
+        0, -26, -26, -26, -26, 0, -26, -26, -26, -26, -26, -26, -26, -26, 0, -26, -26, -26, -26, 0, -26, -26, -26, -26, -26, -26, -26, 0, 0, -26, 0, 0, -26, -26, 0, 0, 0, 0, -26, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -26, 0, 0, -26, -26, 0,
+        // State 179
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 194, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 180
+        -14, 0, 0, 0, 0, -14, -14, 0, 0, 0, 0, -14, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -14, -14, 0, -14, -14, 0, -14, -14, 0, 0, -14, -14, -14, -14, 0, -14, -14, -14, -14, -14, -14, -14, -14, -14, -14, -14, -14, -14, -14, -14, -14, -14, 0, -14, -14, 0, 0, -14,
+        // State 181
+        0, 0, 0, 0, 0, 0, 53, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 182
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 54, 0, 0, 0, 0, 0,
+        // State 183
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 195, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 184
+        0, 0, 0, 0, 0, 0, -9, 0, 0, 0, 0, 0, 0, -9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 185
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 196, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 186
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -41, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -41, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 187
+        0, 0, 0, 0, 0, 0, -96, 0, 0, 0, -96, 0, 0, -96, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -96, 0, 0, 0, -96, 0, 0, -96, -96, 0, 0, 0, 0, -96, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -96, 0, 0, 0, 0, 0,
+        // State 188
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -37, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -37, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 189
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -38, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -38, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 190
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 197, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 191
+        0, 0, 0, 0, 0, 0, 200, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 192
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 55, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 193
+        0, -35, -35, -35, -35, 0, -35, -35, -35, -35, -35, -35, -35, -35, 0, -35, -35, -35, -35, 0, -35, -35, -35, -35, -35, -35, -35, 0, 0, -35, 0, 0, -35, -35, 0, 0, 0, 0, -35, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -35, 0, 0, -35, -35, 0,
+        // State 194
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 57, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 195
+        0, -31, -31, -31, -31, 0, -31, -31, -31, -31, -31, -31, -31, -31, 0, -31, -31, -31, -31, 0, -31, -31, -31, -31, -31, -31, -31, 0, 0, -31, 0, 0, -31, -31, 0, 0, 0, 0, -31, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -31, 0, 0, -31, -31, 0,
+        // State 196
+        0, -32, -32, -32, -32, 0, -32, -32, -32, -32, -32, -32, -32, -32, 0, -32, -32, -32, -32, 0, -32, -32, -32, -32, -32, -32, -32, 0, 0, -32, 0, 0, -32, -32, 0, 0, 0, 0, -32, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -32, 0, 0, -32, -32, 0,
+        // State 197
+        0, 0, 0, 0, 0, 0, -137, 0, 0, 0, -137, 0, 0, -137, 0, -137, 0, 0, 0, 0, 0, 0, 0, 0, 0, -137, 0, 0, 0, -137, 0, 0, -137, -137, 0, 0, 0, 0, -137, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -137, 0, 0, 0, 0, 0,
+        // State 198
+        0, 0, 0, 0, 0, 0, -139, 0, 0, 0, -139, 0, 0, -139, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -139, 0, 0, 0, -139, 0, 0, -139, -139, 0, 0, 0, 0, -139, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -139, 0, 0, 0, 0, 0,
+        // State 199
+        0, -27, -27, -27, -27, 0, -27, -27, -27, -27, -27, -27, -27, -27, 0, -27, -27, -27, -27, 0, -27, -27, -27, -27, -27, -27, -27, 0, 0, -27, 0, 0, -27, -27, 0, 0, 0, 0, -27, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -27, 0, 0, -27, -27, 0,
+        // State 200
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -77, 0, 0, 0, 0, 0, 0, 0, 0, -77, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 201
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 58, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 202
+        0, 0, 0, 0, 0, 0, 207, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 203
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -75, 0, 0, 0, 0, 0, 0, 0, 0, -75, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 204
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -76, 0, 0, 0, 0, 0, 0, 0, 0, -76, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 205
+        0, 0, 0, 0, 0, 0, -78, 0, 0, 0, -78, 0, 0, -78, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -78, 0, 0, 0, -78, 0, 0, -78, -78, 0, 0, 0, 0, -78, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -78, 0, 0, 0, 0, 0,
+        // State 206
+        0, -28, -28, -28, -28, 0, -28, -28, -28, -28, -28, -28, -28, -28, 0, -28, -28, -28, -28, 0, -28, -28, -28, -28, -28, -28, -28, 0, 0, -28, 0, 0, -28, -28, 0, 0, 0, 0, -28, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -28, 0, 0, -28, -28, 0,
+        // State 207
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 209, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 208
+        0, -33, -33, -33, -33, 0, -33, -33, -33, -33, -33, -33, -33, -33, 0, -33, -33, -33, -33, 0, -33, -33, -33, -33, -33, -33, -33, 0, 0, -33, 0, 0, -33, -33, 0, 0, 0, 0, -33, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -33, 0, 0, -33, -33, 0,
     ];
-    fn __action(state: i8, integer: usize) -> i8 {
-        __ACTION[(state as usize) * 32 + integer]
+    fn __action(state: i16, integer: usize) -> i16 {
+        __ACTION[(state as usize) * 62 + integer]
     }
-    const __EOF_ACTION: &[i8] = &[
+    const __EOF_ACTION: &[i16] = &[
         // State 0
         0,
         // State 1
         0,
         // State 2
-        -81,
+        -134,
         // State 3
-        -36,
+        -43,
         // State 4
-        -82,
+        -45,
         // State 5
-        -78,
+        -47,
         // State 6
-        0,
+        -61,
         // State 7
-        0,
+        -145,
         // State 8
-        0,
+        -143,
         // State 9
-        0,
+        -131,
         // State 10
         0,
         // State 11
@@ -320,41 +536,41 @@ mod __parse__Exp {
         // State 28
         0,
         // State 29
-        -69,
+        0,
         // State 30
-        -48,
+        0,
         // State 31
-        -19,
+        0,
         // State 32
-        -84,
+        0,
         // State 33
-        -47,
+        0,
         // State 34
         0,
         // State 35
-        -45,
+        0,
         // State 36
-        -18,
+        0,
         // State 37
-        -46,
+        0,
         // State 38
-        -24,
+        0,
         // State 39
-        -63,
+        0,
         // State 40
-        -20,
+        0,
         // State 41
         0,
         // State 42
-        -61,
+        0,
         // State 43
-        -67,
+        0,
         // State 44
-        -59,
+        0,
         // State 45
         0,
         // State 46
-        -28,
+        0,
         // State 47
         0,
         // State 48
@@ -364,11 +580,11 @@ mod __parse__Exp {
         // State 50
         0,
         // State 51
-        -27,
+        0,
         // State 52
-        -70,
+        0,
         // State 53
-        -55,
+        0,
         // State 54
         0,
         // State 55
@@ -378,77 +594,77 @@ mod __parse__Exp {
         // State 57
         0,
         // State 58
-        0,
+        -106,
         // State 59
-        0,
+        -54,
         // State 60
-        0,
+        -87,
         // State 61
-        0,
+        -89,
         // State 62
-        0,
+        -85,
         // State 63
-        0,
+        -23,
         // State 64
         0,
         // State 65
-        0,
+        -72,
         // State 66
-        0,
+        -149,
         // State 67
-        -68,
+        -110,
         // State 68
         0,
         // State 69
-        0,
+        -108,
         // State 70
-        0,
+        -22,
         // State 71
-        0,
+        -109,
         // State 72
-        0,
+        -36,
         // State 73
-        0,
+        -95,
         // State 74
-        -80,
+        -24,
         // State 75
-        -51,
+        -71,
         // State 76
-        -29,
+        0,
         // State 77
         0,
         // State 78
         0,
         // State 79
-        -58,
+        -91,
         // State 80
-        -60,
+        -103,
         // State 81
-        -62,
+        -70,
         // State 82
-        0,
+        -53,
         // State 83
-        -22,
+        -111,
         // State 84
-        -52,
+        -83,
         // State 85
-        0,
+        -25,
         // State 86
-        -23,
+        -93,
         // State 87
         0,
         // State 88
-        -21,
+        0,
         // State 89
         0,
         // State 90
-        0,
+        -49,
         // State 91
-        0,
+        -79,
         // State 92
         0,
         // State 93
-        -64,
+        0,
         // State 94
         0,
         // State 95
@@ -462,126 +678,446 @@ mod __parse__Exp {
         // State 99
         0,
         // State 100
-        0,
+        -107,
         // State 101
         0,
         // State 102
-        0,
+        -144,
         // State 103
-        -54,
+        0,
+        // State 104
+        0,
+        // State 105
+        0,
+        // State 106
+        0,
+        // State 107
+        -48,
+        // State 108
+        0,
+        // State 109
+        0,
+        // State 110
+        0,
+        // State 111
+        0,
+        // State 112
+        0,
+        // State 113
+        0,
+        // State 114
+        0,
+        // State 115
+        0,
+        // State 116
+        0,
+        // State 117
+        0,
+        // State 118
+        0,
+        // State 119
+        0,
+        // State 120
+        0,
+        // State 121
+        0,
+        // State 122
+        0,
+        // State 123
+        0,
+        // State 124
+        0,
+        // State 125
+        0,
+        // State 126
+        0,
+        // State 127
+        0,
+        // State 128
+        0,
+        // State 129
+        -104,
+        // State 130
+        -22,
+        // State 131
+        0,
+        // State 132
+        -105,
+        // State 133
+        0,
+        // State 134
+        0,
+        // State 135
+        0,
+        // State 136
+        0,
+        // State 137
+        0,
+        // State 138
+        0,
+        // State 139
+        0,
+        // State 140
+        0,
+        // State 141
+        0,
+        // State 142
+        0,
+        // State 143
+        0,
+        // State 144
+        0,
+        // State 145
+        0,
+        // State 146
+        -51,
+        // State 147
+        0,
+        // State 148
+        0,
+        // State 149
+        -136,
+        // State 150
+        -106,
+        // State 151
+        -133,
+        // State 152
+        -73,
+        // State 153
+        0,
+        // State 154
+        -138,
+        // State 155
+        -140,
+        // State 156
+        -50,
+        // State 157
+        -52,
+        // State 158
+        -82,
+        // State 159
+        -84,
+        // State 160
+        -86,
+        // State 161
+        -88,
+        // State 162
+        -90,
+        // State 163
+        -92,
+        // State 164
+        -94,
+        // State 165
+        0,
+        // State 166
+        0,
+        // State 167
+        0,
+        // State 168
+        -29,
+        // State 169
+        0,
+        // State 170
+        -34,
+        // State 171
+        0,
+        // State 172
+        0,
+        // State 173
+        0,
+        // State 174
+        0,
+        // State 175
+        -74,
+        // State 176
+        -35,
+        // State 177
+        -30,
+        // State 178
+        -26,
+        // State 179
+        0,
+        // State 180
+        0,
+        // State 181
+        0,
+        // State 182
+        0,
+        // State 183
+        0,
+        // State 184
+        0,
+        // State 185
+        0,
+        // State 186
+        0,
+        // State 187
+        -96,
+        // State 188
+        0,
+        // State 189
+        0,
+        // State 190
+        0,
+        // State 191
+        0,
+        // State 192
+        0,
+        // State 193
+        -35,
+        // State 194
+        0,
+        // State 195
+        -31,
+        // State 196
+        -32,
+        // State 197
+        -137,
+        // State 198
+        -139,
+        // State 199
+        -27,
+        // State 200
+        0,
+        // State 201
+        0,
+        // State 202
+        0,
+        // State 203
+        0,
+        // State 204
+        0,
+        // State 205
+        -78,
+        // State 206
+        -28,
+        // State 207
+        0,
+        // State 208
+        -33,
     ];
-    fn __goto(state: i8, nt: usize) -> i8 {
+    fn __goto(state: i16, nt: usize) -> i16 {
         match nt {
-            2 => 19,
-            5 => 25,
-            8 => 1,
-            11 => match state {
-                6 => 67,
-                _ => 29,
+            4 => 37,
+            7 => 36,
+            10 => 1,
+            13 => match state {
+                10 => 129,
+                12 => 132,
+                22 | 26..=32 => 150,
+                _ => 58,
             },
-            12 => 30,
-            13 => 71,
-            14 => 31,
+            14 => 59,
             15 => match state {
-                18 => 84,
-                _ => 75,
+                45 => 186,
+                _ => 143,
+            },
+            16 => 144,
+            17 => 26,
+            18 => match state {
+                28 => 160,
+                _ => 60,
             },
-            16 => 14,
-            17 => 2,
-            18 => 77,
-            19 => 89,
+            19 => 27,
             20 => match state {
-                0 => 32,
-                7 => 68,
-                9 => 70,
-                13 => 78,
-                17 => 82,
-                19 => 85,
-                21 => 91,
-                23 => 93,
-                24 => 94,
-                26 => 101,
-                27 => 102,
-                28 => 103,
-                _ => 76,
+                29 => 161,
+                _ => 61,
             },
-            22 => 33,
-            23 => match state {
-                1 => 54,
-                _ => 34,
+            21 => 28,
+            22 => match state {
+                27 => 159,
+                _ => 62,
+            },
+            23 => 63,
+            24 => match state {
+                25 => 154,
+                _ => 64,
             },
-            24 => 35,
             25 => match state {
-                8 => 69,
-                10 => 72,
-                20 => 90,
-                22 => 92,
-                25 => 96,
-                _ => 36,
+                21 => 149,
+                23 => 152,
+                39 => 175,
+                42 => 182,
+                44 => 185,
+                48 => 190,
+                53 => 201,
+                57 => 207,
+                _ => 145,
+            },
+            26 => 155,
+            27 => match state {
+                25 => 156,
+                51 => 197,
+                _ => 65,
             },
-            27 => 3,
-            28 => 4,
-            29 => 5,
-            30 => 37,
-            31 => 11,
-            32 => 38,
-            33 => match state {
-                16 => 81,
-                _ => 39,
+            28 => 29,
+            29 => 2,
+            30 => match state {
+                41 => 181,
+                _ => 141,
             },
-            34 => 40,
-            35 => 15,
-            36 => 41,
-            37 => match state {
-                15 => 80,
-                _ => 42,
+            31 => match state {
+                24 => 153,
+                _ => 133,
             },
-            39 => match state {
-                11 => 74,
-                _ => 43,
+            32 => match state {
+                0 => 66,
+                11 => 131,
+                13 | 24 | 36 => 134,
+                16 => 140,
+                20 => 148,
+                33 => 165,
+                34 => 166,
+                35 => 167,
+                40 => 179,
+                43 => 183,
+                46 => 187,
+                47 => 188,
+                49 => 191,
+                50 => 192,
+                54 => 202,
+                55 => 203,
+                56 => 205,
+                _ => 146,
             },
-            40 => match state {
-                14 => 79,
-                _ => 44,
+            33 => 67,
+            34 => match state {
+                1 => 108,
+                _ => 68,
             },
-            41 => 16,
+            35 => 69,
+            36 => match state {
+                18 | 45 => 38,
+                10 | 12 | 22 | 26..=32 => 130,
+                14 => 138,
+                15 => 139,
+                17 | 41 => 142,
+                37 => 172,
+                _ => 70,
+            },
+            38 => 3,
+            39 => 4,
+            40 => 5,
+            41 => 6,
+            42 => 7,
+            43 => 8,
+            44 => 9,
+            45 => 71,
+            46 => match state {
+                36 => 169,
+                _ => 135,
+            },
+            48 => 22,
+            49 => 72,
+            50 => match state {
+                32 => 164,
+                _ => 73,
+            },
+            51 => 74,
+            52 => match state {
+                25 => 157,
+                51 => 198,
+                _ => 75,
+            },
+            53 => 30,
+            54 => 76,
+            55 => 77,
+            56 => 78,
+            57 => match state {
+                30 => 162,
+                _ => 79,
+            },
+            59 => match state {
+                22 => 151,
+                _ => 80,
+            },
+            60 => match state {
+                38 => 174,
+                47 => 189,
+                52 => 200,
+                55 => 204,
+                _ => 136,
+            },
+            61 => 81,
+            62 => 82,
+            63 => 83,
+            64 => 31,
+            65 => match state {
+                26 => 158,
+                _ => 84,
+            },
+            66 => 85,
+            67 => match state {
+                31 => 163,
+                _ => 86,
+            },
+            68 => 32,
             _ => 0,
         }
     }
-    fn __expected_tokens(__state: i8) -> alloc::vec::Vec<alloc::string::String> {
+    fn __expected_tokens(__state: i16) -> alloc::vec::Vec<alloc::string::String> {
         const __TERMINAL: &[&str] = &[
             r###""!""###,
             r###""!=""###,
+            r###""%""###,
+            r###""&""###,
             r###""&&""###,
             r###""(""###,
             r###"")""###,
             r###""*""###,
             r###""+""###,
+            r###""++""###,
             r###"",""###,
             r###""-""###,
+            r###""/""###,
             r###"":""###,
+            r###"":=""###,
+            r###"";""###,
             r###""<""###,
+            r###""<<""###,
             r###""<=""###,
             r###""=""###,
             r###""==""###,
             r###"">""###,
             r###"">=""###,
+            r###"">>""###,
+            r###""[""###,
+            r###""]""###,
+            r###""^""###,
+            r###""abs""###,
             r###""add1""###,
             r###""and""###,
+            r###""char_at""###,
             r###""def""###,
             r###""else""###,
+            r###""end""###,
             r###""false""###,
+            r###""for""###,
+            r###""identifier""###,
             r###""if""###,
             r###""in""###,
             r###""isbool""###,
             r###""isnum""###,
+            r###""isstring""###,
+            r###""istuple""###,
+            r###""lambda""###,
+            r###""length""###,
             r###""let""###,
+            r###""max""###,
+            r###""min""###,
+            r###""num_to_string""###,
+            r###""number""###,
             r###""print""###,
+            r###""string""###,
+            r###""string_to_num""###,
+            r###""strlen""###,
             r###""sub1""###,
+            r###""substr""###,
+            r###""to""###,
             r###""true""###,
+            r###""while""###,
+            r###""|""###,
             r###""||""###,
-            r###"r#"[+-]?[0-9]+"#"###,
-            r###"r#"[a-zA-Z][a-zA-Z0-9_]*"#"###,
         ];
         __TERMINAL.iter().enumerate().filter_map(|(index, terminal)| {
             let next_state = __action(__state, index);
@@ -592,24 +1128,24 @@ mod __parse__Exp {
             }
         }).collect()
     }
-    pub(crate) struct __StateMachine<'input>
-    where 
+    pub(crate) struct __StateMachine<'input, 'err>
+    where 'input: 'err
     {
-        input: &'input str,
-        __phantom: core::marker::PhantomData<(&'input ())>,
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+        __phantom: core::marker::PhantomData<(&'input (), &'err ())>,
     }
-    impl<'input> __state_machine::ParserDefinition for __StateMachine<'input>
-    where 
+    impl<'input, 'err> __state_machine::ParserDefinition for __StateMachine<'input, 'err>
+    where 'input: 'err
     {
         type Location = usize;
-        type Error = &'static str;
-        type Token = Token<'input>;
+        type Error = LexError;
+        type Token = Tok<'input>;
         type TokenIndex = usize;
         type Symbol = __Symbol<'input>;
         type Success = Exp<Span1>;
-        type StateIndex = i8;
-        type Action = i8;
-        type ReduceIndex = i8;
+        type StateIndex = i16;
+        type Action = i16;
+        type ReduceIndex = i16;
         type NonterminalIndex = usize;
 
         #[inline]
@@ -624,40 +1160,40 @@ mod __parse__Exp {
 
         #[inline]
         fn token_to_index(&self, token: &Self::Token) -> Option<usize> {
-            __token_to_integer(token, core::marker::PhantomData::<(&())>)
+            __token_to_integer(token, core::marker::PhantomData::<(&(), &())>)
         }
 
         #[inline]
-        fn action(&self, state: i8, integer: usize) -> i8 {
+        fn action(&self, state: i16, integer: usize) -> i16 {
             __action(state, integer)
         }
 
         #[inline]
-        fn error_action(&self, state: i8) -> i8 {
-            __action(state, 32 - 1)
+        fn error_action(&self, state: i16) -> i16 {
+            __action(state, 62 - 1)
         }
 
         #[inline]
-        fn eof_action(&self, state: i8) -> i8 {
+        fn eof_action(&self, state: i16) -> i16 {
             __EOF_ACTION[state as usize]
         }
 
         #[inline]
-        fn goto(&self, state: i8, nt: usize) -> i8 {
+        fn goto(&self, state: i16, nt: usize) -> i16 {
             __goto(state, nt)
         }
 
         fn token_to_symbol(&self, token_index: usize, token: Self::Token) -> Self::Symbol {
-            __token_to_symbol(token_index, token, core::marker::PhantomData::<(&())>)
+            __token_to_symbol(token_index, token, core::marker::PhantomData::<(&(), &())>)
         }
 
-        fn expected_tokens(&self, state: i8) -> alloc::vec::Vec<alloc::string::String> {
+        fn expected_tokens(&self, state: i16) -> alloc::vec::Vec<alloc::string::String> {
             __expected_tokens(state)
         }
 
         #[inline]
         fn uses_error_recovery(&self) -> bool {
-            false
+            true
         }
 
         #[inline]
@@ -665,5023 +1201,11986 @@ mod __parse__Exp {
             &self,
             recovery: __state_machine::ErrorRecovery<Self>,
         ) -> Self::Symbol {
-            panic!("error recovery not enabled for this grammar")
+            __Symbol::Variant3(recovery)
         }
 
         fn reduce(
             &mut self,
-            action: i8,
+            action: i16,
             start_location: Option<&Self::Location>,
-            states: &mut alloc::vec::Vec<i8>,
+            states: &mut alloc::vec::Vec<i16>,
             symbols: &mut alloc::vec::Vec<__state_machine::SymbolTriple<Self>>,
         ) -> Option<__state_machine::ParseResult<Self>> {
             __reduce(
-                self.input,
+                self.errors,
                 action,
                 start_location,
                 states,
                 symbols,
-                core::marker::PhantomData::<(&())>,
+                core::marker::PhantomData::<(&(), &())>,
             )
         }
 
-        fn simulate_reduce(&self, action: i8) -> __state_machine::SimulatedReduce<Self> {
-            panic!("error recovery not enabled for this grammar")
+        fn simulate_reduce(&self, action: i16) -> __state_machine::SimulatedReduce<Self> {
+            __simulate_reduce(action, core::marker::PhantomData::<(&(), &())>)
         }
     }
     fn __token_to_integer<
         'input,
+        'err,
     >(
-        __token: &Token<'input>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __token: &Tok<'input>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> Option<usize>
     {
         match *__token {
-            Token(2, _) if true => Some(0),
-            Token(3, _) if true => Some(1),
-            Token(4, _) if true => Some(2),
-            Token(5, _) if true => Some(3),
-            Token(6, _) if true => Some(4),
-            Token(7, _) if true => Some(5),
-            Token(8, _) if true => Some(6),
-            Token(9, _) if true => Some(7),
-            Token(10, _) if true => Some(8),
-            Token(11, _) if true => Some(9),
-            Token(12, _) if true => Some(10),
-            Token(13, _) if true => Some(11),
-            Token(14, _) if true => Some(12),
-            Token(15, _) if true => Some(13),
-            Token(16, _) if true => Some(14),
-            Token(17, _) if true => Some(15),
-            Token(18, _) if true => Some(16),
-            Token(19, _) if true => Some(17),
-            Token(20, _) if true => Some(18),
-            Token(21, _) if true => Some(19),
-            Token(22, _) if true => Some(20),
-            Token(23, _) if true => Some(21),
-            Token(24, _) if true => Some(22),
-            Token(25, _) if true => Some(23),
-            Token(26, _) if true => Some(24),
-            Token(27, _) if true => Some(25),
-            Token(28, _) if true => Some(26),
-            Token(29, _) if true => Some(27),
-            Token(30, _) if true => Some(28),
-            Token(31, _) if true => Some(29),
-            Token(0, _) if true => Some(30),
-            Token(1, _) if true => Some(31),
+            Tok::Bang if true => Some(0),
+            Tok::Neq if true => Some(1),
+            Tok::Percent if true => Some(2),
+            Tok::Amp if true => Some(3),
+            Tok::AndAnd if true => Some(4),
+            Tok::LParen if true => Some(5),
+            Tok::RParen if true => Some(6),
+            Tok::Star if true => Some(7),
+            Tok::Plus if true => Some(8),
+            Tok::PlusPlus if true => Some(9),
+            Tok::Comma if true => Some(10),
+            Tok::Minus if true => Some(11),
+            Tok::Slash if true => Some(12),
+            Tok::Colon if true => Some(13),
+            Tok::Assign if true => Some(14),
+            Tok::Semicolon if true => Some(15),
+            Tok::Lt if true => Some(16),
+            Tok::Shl if true => Some(17),
+            Tok::Le if true => Some(18),
+            Tok::Equal if true => Some(19),
+            Tok::EqEq if true => Some(20),
+            Tok::Gt if true => Some(21),
+            Tok::Ge if true => Some(22),
+            Tok::Shr if true => Some(23),
+            Tok::LBrack if true => Some(24),
+            Tok::RBrack if true => Some(25),
+            Tok::Caret if true => Some(26),
+            Tok::Abs if true => Some(27),
+            Tok::Add1 if true => Some(28),
+            Tok::And if true => Some(29),
+            Tok::CharAt if true => Some(30),
+            Tok::Def if true => Some(31),
+            Tok::Else if true => Some(32),
+            Tok::End if true => Some(33),
+            Tok::False if true => Some(34),
+            Tok::For if true => Some(35),
+            Tok::Id(_) if true => Some(36),
+            Tok::If if true => Some(37),
+            Tok::In if true => Some(38),
+            Tok::IsBool if true => Some(39),
+            Tok::IsNum if true => Some(40),
+            Tok::IsString if true => Some(41),
+            Tok::IsTuple if true => Some(42),
+            Tok::Lambda if true => Some(43),
+            Tok::Length if true => Some(44),
+            Tok::Let if true => Some(45),
+            Tok::Max if true => Some(46),
+            Tok::Min if true => Some(47),
+            Tok::NumToString if true => Some(48),
+            Tok::Num(_) if true => Some(49),
+            Tok::Print if true => Some(50),
+            Tok::Str(_) if true => Some(51),
+            Tok::StringToNum if true => Some(52),
+            Tok::StrLen if true => Some(53),
+            Tok::Sub1 if true => Some(54),
+            Tok::Substr if true => Some(55),
+            Tok::To if true => Some(56),
+            Tok::True if true => Some(57),
+            Tok::While if true => Some(58),
+            Tok::Pipe if true => Some(59),
+            Tok::OrOr if true => Some(60),
             _ => None,
         }
     }
     fn __token_to_symbol<
         'input,
+        'err,
     >(
         __token_index: usize,
-        __token: Token<'input>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __token: Tok<'input>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> __Symbol<'input>
     {
         match __token_index {
-            0 | 1 | 2 | 3 | 4 | 5 | 6 | 7 | 8 | 9 | 10 | 11 | 12 | 13 | 14 | 15 | 16 | 17 | 18 | 19 | 20 | 21 | 22 | 23 | 24 | 25 | 26 | 27 | 28 | 29 | 30 | 31 => match __token {
-                Token(2, __tok0) | Token(3, __tok0) | Token(4, __tok0) | Token(5, __tok0) | Token(6, __tok0) | Token(7, __tok0) | Token(8, __tok0) | Token(9, __tok0) | Token(10, __tok0) | Token(11, __tok0) | Token(12, __tok0) | Token(13, __tok0) | Token(14, __tok0) | Token(15, __tok0) | Token(16, __tok0) | Token(17, __tok0) | Token(18, __tok0) | Token(19, __tok0) | Token(20, __tok0) | Token(21, __tok0) | Token(22, __tok0) | Token(23, __tok0) | Token(24, __tok0) | Token(25, __tok0) | Token(26, __tok0) | Token(27, __tok0) | Token(28, __tok0) | Token(29, __tok0) | Token(30, __tok0) | Token(31, __tok0) | Token(0, __tok0) | Token(1, __tok0) if true => __Symbol::Variant0(__tok0),
+            0 | 1 | 2 | 3 | 4 | 5 | 6 | 7 | 8 | 9 | 10 | 11 | 12 | 13 | 14 | 15 | 16 | 17 | 18 | 19 | 20 | 21 | 22 | 23 | 24 | 25 | 26 | 27 | 28 | 29 | 30 | 31 | 32 | 33 | 34 | 35 | 37 | 38 | 39 | 40 | 41 | 42 | 43 | 44 | 45 | 46 | 47 | 48 | 50 | 52 | 53 | 54 | 55 | 56 | 57 | 58 | 59 | 60 => __Symbol::Variant0(__token),
+            36 | 51 => match __token {
+                Tok::Id(__tok0) | Tok::Str(__tok0) if true => __Symbol::Variant1(__tok0),
+                _ => unreachable!(),
+            },
+            49 => match __token {
+                Tok::Num(__tok0) if true => __Symbol::Variant2(__tok0),
                 _ => unreachable!(),
             },
             _ => unreachable!(),
         }
     }
-    pub struct ExpParser {
-        builder: __lalrpop_util::lexer::MatcherBuilder,
-        _priv: (),
-    }
-
-    impl ExpParser {
-        pub fn new() -> ExpParser {
-            let __builder = super::__intern_token::new_builder();
-            ExpParser {
-                builder: __builder,
-                _priv: (),
-            }
-        }
-
-        #[allow(dead_code)]
-        pub fn parse<
-            'input,
-        >(
-            &self,
-            input: &'input str,
-        ) -> Result<Exp<Span1>, __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
-        {
-            let mut __tokens = self.builder.matcher(input);
-            __state_machine::Parser::drive(
-                __StateMachine {
-                    input,
-                    __phantom: core::marker::PhantomData::<(&())>,
-                },
-                __tokens,
-            )
-        }
-    }
-    pub(crate) fn __reduce<
+    fn __simulate_reduce<
         'input,
+        'err,
     >(
-        input: &'input str,
-        __action: i8,
-        __lookahead_start: Option<&usize>,
-        __states: &mut alloc::vec::Vec<i8>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
-    ) -> Option<Result<Exp<Span1>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+        __reduce_index: i16,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> __state_machine::SimulatedReduce<__StateMachine<'input, 'err>>
+    where
+        'input: 'err,
     {
-        let (__pop_states, __nonterminal) = match __action {
+        match __reduce_index {
             0 => {
-                __reduce0(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 0,
+                }
             }
             1 => {
-                __reduce1(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 0,
+                    nonterminal_produced: 0,
+                }
             }
             2 => {
-                __reduce2(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 1,
+                }
             }
             3 => {
-                __reduce3(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 0,
+                    nonterminal_produced: 1,
+                }
             }
             4 => {
-                __reduce4(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 2,
+                    nonterminal_produced: 2,
+                }
             }
             5 => {
-                __reduce5(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 0,
+                    nonterminal_produced: 3,
+                }
             }
             6 => {
-                __reduce6(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 3,
+                }
             }
             7 => {
-                __reduce7(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 2,
+                    nonterminal_produced: 4,
+                }
             }
             8 => {
-                __reduce8(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 3,
+                    nonterminal_produced: 4,
+                }
             }
             9 => {
-                __reduce9(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 2,
+                    nonterminal_produced: 5,
+                }
             }
             10 => {
-                __reduce10(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 0,
+                    nonterminal_produced: 6,
+                }
             }
             11 => {
-                __reduce11(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 6,
+                }
             }
             12 => {
-                __reduce12(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 2,
+                    nonterminal_produced: 7,
+                }
             }
             13 => {
-                __reduce13(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 3,
+                    nonterminal_produced: 7,
+                }
             }
             14 => {
-                __reduce14(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 2,
+                    nonterminal_produced: 8,
+                }
             }
             15 => {
-                __reduce15(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 0,
+                    nonterminal_produced: 9,
+                }
             }
             16 => {
-                __reduce16(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 9,
+                }
             }
             17 => {
-                __reduce17(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 2,
+                    nonterminal_produced: 10,
+                }
             }
             18 => {
-                __reduce18(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 3,
+                    nonterminal_produced: 10,
+                }
             }
             19 => {
-                __reduce19(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 0,
+                    nonterminal_produced: 11,
+                }
             }
             20 => {
-                __reduce20(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 0,
+                    nonterminal_produced: 12,
+                }
             }
             21 => {
-                __reduce21(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 13,
+                }
             }
             22 => {
-                __reduce22(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 13,
+                }
             }
             23 => {
-                __reduce23(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 13,
+                }
             }
             24 => {
-                __reduce24(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 13,
+                }
             }
             25 => {
-                __reduce25(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 4,
+                    nonterminal_produced: 13,
+                }
             }
             26 => {
-                __reduce26(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 6,
+                    nonterminal_produced: 13,
+                }
             }
             27 => {
-                __reduce27(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 8,
+                    nonterminal_produced: 13,
+                }
             }
             28 => {
-                __reduce28(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 3,
+                    nonterminal_produced: 13,
+                }
             }
             29 => {
-                __reduce29(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 4,
+                    nonterminal_produced: 13,
+                }
             }
             30 => {
-                __reduce30(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 5,
+                    nonterminal_produced: 13,
+                }
             }
             31 => {
-                __reduce31(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 5,
+                    nonterminal_produced: 13,
+                }
             }
             32 => {
-                __reduce32(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 9,
+                    nonterminal_produced: 13,
+                }
             }
             33 => {
-                __reduce33(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 3,
+                    nonterminal_produced: 13,
+                }
             }
             34 => {
-                __reduce34(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 4,
+                    nonterminal_produced: 13,
+                }
             }
             35 => {
-                __reduce35(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 14,
+                }
             }
             36 => {
-                __reduce36(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 3,
+                    nonterminal_produced: 15,
+                }
             }
             37 => {
-                __reduce37(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 3,
+                    nonterminal_produced: 15,
+                }
             }
             38 => {
-                __reduce38(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 2,
+                    nonterminal_produced: 15,
+                }
             }
             39 => {
-                __reduce39(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 16,
+                }
             }
             40 => {
-                __reduce40(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 3,
+                    nonterminal_produced: 16,
+                }
             }
             41 => {
-                __reduce41(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 17,
+                }
             }
             42 => {
-                __reduce42(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 18,
+                }
             }
             43 => {
-                __reduce43(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 19,
+                }
             }
             44 => {
-                __reduce44(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 20,
+                }
             }
             45 => {
-                __reduce45(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 21,
+                }
             }
             46 => {
-                __reduce46(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 22,
+                }
             }
             47 => {
-                __reduce47(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 23,
+                }
             }
             48 => {
-                __reduce48(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 23,
+                }
             }
             49 => {
-                __reduce49(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 24,
+                }
             }
             50 => {
-                __reduce50(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 25,
+                }
             }
             51 => {
-                __reduce51(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 26,
+                }
             }
             52 => {
-                __reduce52(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 27,
+                }
             }
             53 => {
-                __reduce53(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 27,
+                }
             }
             54 => {
-                __reduce54(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 28,
+                }
             }
             55 => {
-                __reduce55(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 28,
+                }
             }
             56 => {
-                __reduce56(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 28,
+                }
             }
             57 => {
-                __reduce57(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 28,
+                }
             }
             58 => {
-                __reduce58(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 28,
+                }
             }
             59 => {
-                __reduce59(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 28,
+                }
             }
             60 => {
-                __reduce60(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 29,
+                }
             }
             61 => {
-                __reduce61(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 30,
+                }
             }
             62 => {
-                __reduce62(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 0,
+                    nonterminal_produced: 30,
+                }
             }
             63 => {
-                __reduce63(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 2,
+                    nonterminal_produced: 30,
+                }
             }
             64 => {
-                __reduce64(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 30,
+                }
             }
             65 => {
-                __reduce65(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 31,
+                }
             }
             66 => {
-                __reduce66(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 0,
+                    nonterminal_produced: 31,
+                }
             }
             67 => {
-                __reduce67(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 2,
+                    nonterminal_produced: 31,
+                }
             }
             68 => {
-                __reduce68(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 31,
+                }
             }
             69 => {
-                __reduce69(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 32,
+                }
             }
             70 => {
-                __reduce70(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 32,
+                }
             }
             71 => {
-                __reduce71(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 32,
+                }
             }
             72 => {
-                __reduce72(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 3,
+                    nonterminal_produced: 33,
+                }
             }
             73 => {
-                __reduce73(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 4,
+                    nonterminal_produced: 33,
+                }
             }
             74 => {
-                __reduce74(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 7,
+                    nonterminal_produced: 34,
+                }
             }
             75 => {
-                __reduce75(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 7,
+                    nonterminal_produced: 34,
+                }
             }
             76 => {
-                __reduce76(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 6,
+                    nonterminal_produced: 34,
+                }
             }
             77 => {
-                __reduce77(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 7,
+                    nonterminal_produced: 35,
+                }
             }
             78 => {
-                __reduce78(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 36,
+                }
             }
             79 => {
-                __reduce79(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 37,
+                }
             }
             80 => {
-                __reduce80(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 0,
+                    nonterminal_produced: 37,
+                }
             }
             81 => {
-                __reduce81(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 3,
+                    nonterminal_produced: 38,
+                }
             }
             82 => {
-                __reduce82(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 38,
+                }
             }
             83 => {
-                // __Exp = Exp => ActionFn(1);
-                let __sym0 = __pop_Variant1(__symbols);
-                let __start = __sym0.0.clone();
-                let __end = __sym0.2.clone();
-                let __nt = super::__action1::<>(input, __sym0);
-                return Some(Ok(__nt));
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 3,
+                    nonterminal_produced: 39,
+                }
             }
             84 => {
-                __reduce84(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 39,
+                }
             }
-            _ => panic!("invalid action code {}", __action)
-        };
-        let __states_len = __states.len();
-        __states.truncate(__states_len - __pop_states);
-        let __state = *__states.last().unwrap();
-        let __next_state = __goto(__state, __nonterminal);
-        __states.push(__next_state);
-        None
-    }
-    #[inline(never)]
-    fn __symbol_type_mismatch() -> ! {
-        panic!("symbol type mismatch")
-    }
-    fn __pop_Variant5<
-      'input,
-    >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, (SurfFunDecl<Span1>, &'input str), usize)
-     {
-        match __symbols.pop() {
-            Some((__l, __Symbol::Variant5(__v), __r)) => (__l, __v, __r),
-            _ => __symbol_type_mismatch()
-        }
-    }
-    fn __pop_Variant10<
-      'input,
-    >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, Box<Exp<Span1>>, usize)
-     {
-        match __symbols.pop() {
-            Some((__l, __Symbol::Variant10(__v), __r)) => (__l, __v, __r),
-            _ => __symbol_type_mismatch()
-        }
-    }
-    fn __pop_Variant1<
-      'input,
-    >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, Exp<Span1>, usize)
-     {
-        match __symbols.pop() {
-            Some((__l, __Symbol::Variant1(__v), __r)) => (__l, __v, __r),
-            _ => __symbol_type_mismatch()
-        }
-    }
-    fn __pop_Variant11<
-      'input,
-    >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, Prim, usize)
-     {
-        match __symbols.pop() {
-            Some((__l, __Symbol::Variant11(__v), __r)) => (__l, __v, __r),
-            _ => __symbol_type_mismatch()
-        }
-    }
-    fn __pop_Variant3<
-      'input,
-    >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, String, usize)
-     {
-        match __symbols.pop() {
-            Some((__l, __Symbol::Variant3(__v), __r)) => (__l, __v, __r),
-            _ => __symbol_type_mismatch()
-        }
-    }
-    fn __pop_Variant15<
-      'input,
-    >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, SurfFunDecl<Span1>, usize)
-     {
-        match __symbols.pop() {
-            Some((__l, __Symbol::Variant15(__v), __r)) => (__l, __v, __r),
-            _ => __symbol_type_mismatch()
-        }
-    }
-    fn __pop_Variant18<
-      'input,
-    >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, SurfProg<Span1>, usize)
-     {
-        match __symbols.pop() {
-            Some((__l, __Symbol::Variant18(__v), __r)) => (__l, __v, __r),
-            _ => __symbol_type_mismatch()
-        }
-    }
-    fn __pop_Variant8<
-      'input,
-    >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, Vec<(String, Exp<Span1>)>, usize)
-     {
-        match __symbols.pop() {
-            Some((__l, __Symbol::Variant8(__v), __r)) => (__l, __v, __r),
-            _ => __symbol_type_mismatch()
-        }
-    }
-    fn __pop_Variant12<
-      'input,
-    >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, Vec<Exp<Span1>>, usize)
-     {
-        match __symbols.pop() {
-            Some((__l, __Symbol::Variant12(__v), __r)) => (__l, __v, __r),
-            _ => __symbol_type_mismatch()
-        }
-    }
-    fn __pop_Variant13<
-      'input,
-    >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, Vec<String>, usize)
-     {
-        match __symbols.pop() {
-            Some((__l, __Symbol::Variant13(__v), __r)) => (__l, __v, __r),
-            _ => __symbol_type_mismatch()
-        }
-    }
-    fn __pop_Variant6<
-      'input,
-    >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, alloc::vec::Vec<(SurfFunDecl<Span1>, &'input str)>, usize)
-     {
-        match __symbols.pop() {
-            Some((__l, __Symbol::Variant6(__v), __r)) => (__l, __v, __r),
-            _ => __symbol_type_mismatch()
-        }
-    }
-    fn __pop_Variant2<
-      'input,
-    >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, alloc::vec::Vec<Exp<Span1>>, usize)
-     {
-        match __symbols.pop() {
-            Some((__l, __Symbol::Variant2(__v), __r)) => (__l, __v, __r),
-            _ => __symbol_type_mismatch()
-        }
-    }
-    fn __pop_Variant4<
-      'input,
-    >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, alloc::vec::Vec<String>, usize)
-     {
-        match __symbols.pop() {
-            Some((__l, __Symbol::Variant4(__v), __r)) => (__l, __v, __r),
-            _ => __symbol_type_mismatch()
-        }
-    }
-    fn __pop_Variant9<
-      'input,
-    >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, bool, usize)
-     {
-        match __symbols.pop() {
-            Some((__l, __Symbol::Variant9(__v), __r)) => (__l, __v, __r),
-            _ => __symbol_type_mismatch()
-        }
-    }
-    fn __pop_Variant14<
-      'input,
-    >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, core::option::Option<Exp<Span1>>, usize)
-     {
-        match __symbols.pop() {
-            Some((__l, __Symbol::Variant14(__v), __r)) => (__l, __v, __r),
-            _ => __symbol_type_mismatch()
+            85 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 3,
+                    nonterminal_produced: 40,
+                }
+            }
+            86 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 40,
+                }
+            }
+            87 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 3,
+                    nonterminal_produced: 41,
+                }
+            }
+            88 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 41,
+                }
+            }
+            89 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 3,
+                    nonterminal_produced: 42,
+                }
+            }
+            90 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 42,
+                }
+            }
+            91 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 3,
+                    nonterminal_produced: 43,
+                }
+            }
+            92 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 43,
+                }
+            }
+            93 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 3,
+                    nonterminal_produced: 44,
+                }
+            }
+            94 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 44,
+                }
+            }
+            95 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 4,
+                    nonterminal_produced: 45,
+                }
+            }
+            96 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 46,
+                }
+            }
+            97 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 46,
+                }
+            }
+            98 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 47,
+                }
+            }
+            99 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 0,
+                    nonterminal_produced: 47,
+                }
+            }
+            100 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 48,
+                }
+            }
+            101 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 48,
+                }
+            }
+            102 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 49,
+                }
+            }
+            103 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 2,
+                    nonterminal_produced: 50,
+                }
+            }
+            104 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 2,
+                    nonterminal_produced: 50,
+                }
+            }
+            105 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 50,
+                }
+            }
+            106 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 51,
+                }
+            }
+            107 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 52,
+                }
+            }
+            108 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 52,
+                }
+            }
+            109 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 52,
+                }
+            }
+            110 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 52,
+                }
+            }
+            111 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 53,
+                }
+            }
+            112 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 53,
+                }
+            }
+            113 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 53,
+                }
+            }
+            114 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 54,
+                }
+            }
+            115 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 54,
+                }
+            }
+            116 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 54,
+                }
+            }
+            117 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 54,
+                }
+            }
+            118 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 54,
+                }
+            }
+            119 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 54,
+                }
+            }
+            120 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 54,
+                }
+            }
+            121 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 54,
+                }
+            }
+            122 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 54,
+                }
+            }
+            123 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 54,
+                }
+            }
+            124 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 54,
+                }
+            }
+            125 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 54,
+                }
+            }
+            126 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 55,
+                }
+            }
+            127 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 55,
+                }
+            }
+            128 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 55,
+                }
+            }
+            129 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 56,
+                }
+            }
+            130 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 57,
+                }
+            }
+            131 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 58,
+                }
+            }
+            132 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 3,
+                    nonterminal_produced: 59,
+                }
+            }
+            133 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 59,
+                }
+            }
+            134 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 60,
+                }
+            }
+            135 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 3,
+                    nonterminal_produced: 61,
+                }
+            }
+            136 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 6,
+                    nonterminal_produced: 62,
+                }
+            }
+            137 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 3,
+                    nonterminal_produced: 62,
+                }
+            }
+            138 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 6,
+                    nonterminal_produced: 63,
+                }
+            }
+            139 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 3,
+                    nonterminal_produced: 63,
+                }
+            }
+            140 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 64,
+                }
+            }
+            141 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 64,
+                }
+            }
+            142 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 65,
+                }
+            }
+            143 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 66,
+                }
+            }
+            144 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 67,
+                }
+            }
+            145 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 68,
+                }
+            }
+            146 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 68,
+                }
+            }
+            147 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 68,
+                }
+            }
+            148 => __state_machine::SimulatedReduce::Accept,
+            149 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 70,
+                }
+            }
+            _ => panic!("invalid reduction index {}", __reduce_index)
         }
     }
-    fn __pop_Variant16<
-      'input,
-    >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, core::option::Option<String>, usize)
-     {
-        match __symbols.pop() {
-            Some((__l, __Symbol::Variant16(__v), __r)) => (__l, __v, __r),
-            _ => __symbol_type_mismatch()
-        }
+    pub struct ExpParser {
+        _priv: (),
     }
-    fn __pop_Variant17<
-      'input,
-    >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, i64, usize)
-     {
-        match __symbols.pop() {
-            Some((__l, __Symbol::Variant17(__v), __r)) => (__l, __v, __r),
-            _ => __symbol_type_mismatch()
+
+    impl ExpParser {
+        pub fn new() -> ExpParser {
+            ExpParser {
+                _priv: (),
+            }
         }
-    }
-    fn __pop_Variant7<
-      'input,
-    >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, usize, usize)
-     {
-        match __symbols.pop() {
-            Some((__l, __Symbol::Variant7(__v), __r)) => (__l, __v, __r),
-            _ => __symbol_type_mismatch()
+
+        #[allow(dead_code)]
+        pub fn parse<
+            'input,
+            'err,
+            __TOKEN: __ToTriple<'input, 'err, >,
+            __TOKENS: IntoIterator<Item=__TOKEN>,
+        >(
+            &self,
+            errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+            __tokens0: __TOKENS,
+        ) -> Result<Exp<Span1>, __lalrpop_util::ParseError<usize, Tok<'input>, LexError>>
+        {
+            let __tokens = __tokens0.into_iter();
+            let mut __tokens = __tokens.map(|t| __ToTriple::to_triple(t));
+            __state_machine::Parser::drive(
+                __StateMachine {
+                    errors,
+                    __phantom: core::marker::PhantomData::<(&(), &())>,
+                },
+                __tokens,
+            )
         }
     }
-    fn __pop_Variant0<
-      'input,
+    fn __accepts<
+        'input,
+        'err,
     >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, &'input str, usize)
-     {
-        match __symbols.pop() {
-            Some((__l, __Symbol::Variant0(__v), __r)) => (__l, __v, __r),
-            _ => __symbol_type_mismatch()
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+        __error_state: i16,
+        __states: & [i16],
+        __opt_integer: Option<usize>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> bool
+    {
+        let mut __states = __states.to_vec();
+        __states.push(__error_state);
+        loop {
+            let mut __states_len = __states.len();
+            let __top = __states[__states_len - 1];
+            let __action = match __opt_integer {
+                None => __EOF_ACTION[__top as usize],
+                Some(__integer) => __action(__top, __integer),
+            };
+            if __action == 0 { return false; }
+            if __action > 0 { return true; }
+            let (__to_pop, __nt) = match __simulate_reduce(-(__action + 1), core::marker::PhantomData::<(&(), &())>) {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop, nonterminal_produced
+                } => (states_to_pop, nonterminal_produced),
+                __state_machine::SimulatedReduce::Accept => return true,
+            };
+            __states_len -= __to_pop;
+            __states.truncate(__states_len);
+            let __top = __states[__states_len - 1];
+            let __next_state = __goto(__top, __nt);
+            __states.push(__next_state);
         }
     }
-    pub(crate) fn __reduce0<
+    pub(crate) fn __reduce<
         'input,
+        'err,
     >(
-        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+        __action: i16,
         __lookahead_start: Option<&usize>,
+        __states: &mut alloc::vec::Vec<i16>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
-    ) -> (usize, usize)
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> Option<Result<Exp<Span1>,__lalrpop_util::ParseError<usize, Tok<'input>, LexError>>>
     {
-        // (<Exp> ",") = Exp, "," => ActionFn(73);
-        assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant1(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym1.2.clone();
-        let __nt = super::__action73::<>(input, __sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (2, 0)
-    }
-    pub(crate) fn __reduce1<
-        'input,
+        let (__pop_states, __nonterminal) = match __action {
+            0 => {
+                __reduce0(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            1 => {
+                __reduce1(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            2 => {
+                __reduce2(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            3 => {
+                __reduce3(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            4 => {
+                __reduce4(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            5 => {
+                __reduce5(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            6 => {
+                __reduce6(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            7 => {
+                __reduce7(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            8 => {
+                __reduce8(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            9 => {
+                __reduce9(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            10 => {
+                __reduce10(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            11 => {
+                __reduce11(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            12 => {
+                __reduce12(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            13 => {
+                __reduce13(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            14 => {
+                __reduce14(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            15 => {
+                __reduce15(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            16 => {
+                __reduce16(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            17 => {
+                __reduce17(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            18 => {
+                __reduce18(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            19 => {
+                __reduce19(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            20 => {
+                __reduce20(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            21 => {
+                __reduce21(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            22 => {
+                __reduce22(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            23 => {
+                __reduce23(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            24 => {
+                __reduce24(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            25 => {
+                __reduce25(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            26 => {
+                __reduce26(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            27 => {
+                __reduce27(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            28 => {
+                __reduce28(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            29 => {
+                __reduce29(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            30 => {
+                __reduce30(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            31 => {
+                __reduce31(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            32 => {
+                __reduce32(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            33 => {
+                __reduce33(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            34 => {
+                __reduce34(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            35 => {
+                __reduce35(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            36 => {
+                __reduce36(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            37 => {
+                __reduce37(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            38 => {
+                __reduce38(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            39 => {
+                __reduce39(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            40 => {
+                __reduce40(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            41 => {
+                __reduce41(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            42 => {
+                __reduce42(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            43 => {
+                __reduce43(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            44 => {
+                __reduce44(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            45 => {
+                __reduce45(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            46 => {
+                __reduce46(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            47 => {
+                __reduce47(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            48 => {
+                __reduce48(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            49 => {
+                __reduce49(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            50 => {
+                __reduce50(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            51 => {
+                __reduce51(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            52 => {
+                __reduce52(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            53 => {
+                __reduce53(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            54 => {
+                __reduce54(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            55 => {
+                __reduce55(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            56 => {
+                __reduce56(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            57 => {
+                __reduce57(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            58 => {
+                __reduce58(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            59 => {
+                __reduce59(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            60 => {
+                __reduce60(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            61 => {
+                __reduce61(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            62 => {
+                __reduce62(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            63 => {
+                __reduce63(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            64 => {
+                __reduce64(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            65 => {
+                __reduce65(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            66 => {
+                __reduce66(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            67 => {
+                __reduce67(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            68 => {
+                __reduce68(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            69 => {
+                __reduce69(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            70 => {
+                __reduce70(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            71 => {
+                __reduce71(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            72 => {
+                __reduce72(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            73 => {
+                __reduce73(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            74 => {
+                __reduce74(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            75 => {
+                __reduce75(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            76 => {
+                __reduce76(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            77 => {
+                __reduce77(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            78 => {
+                __reduce78(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            79 => {
+                __reduce79(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            80 => {
+                __reduce80(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            81 => {
+                __reduce81(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            82 => {
+                __reduce82(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            83 => {
+                __reduce83(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            84 => {
+                __reduce84(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            85 => {
+                __reduce85(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            86 => {
+                __reduce86(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            87 => {
+                __reduce87(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            88 => {
+                __reduce88(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            89 => {
+                __reduce89(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            90 => {
+                __reduce90(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            91 => {
+                __reduce91(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            92 => {
+                __reduce92(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            93 => {
+                __reduce93(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            94 => {
+                __reduce94(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            95 => {
+                __reduce95(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            96 => {
+                __reduce96(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            97 => {
+                __reduce97(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            98 => {
+                __reduce98(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            99 => {
+                __reduce99(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            100 => {
+                __reduce100(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            101 => {
+                __reduce101(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            102 => {
+                __reduce102(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            103 => {
+                __reduce103(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            104 => {
+                __reduce104(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            105 => {
+                __reduce105(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            106 => {
+                __reduce106(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            107 => {
+                __reduce107(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            108 => {
+                __reduce108(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            109 => {
+                __reduce109(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            110 => {
+                __reduce110(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            111 => {
+                __reduce111(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            112 => {
+                __reduce112(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            113 => {
+                __reduce113(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            114 => {
+                __reduce114(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            115 => {
+                __reduce115(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            116 => {
+                __reduce116(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            117 => {
+                __reduce117(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            118 => {
+                __reduce118(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            119 => {
+                __reduce119(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            120 => {
+                __reduce120(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            121 => {
+                __reduce121(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            122 => {
+                __reduce122(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            123 => {
+                __reduce123(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            124 => {
+                __reduce124(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            125 => {
+                __reduce125(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            126 => {
+                __reduce126(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            127 => {
+                __reduce127(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            128 => {
+                __reduce128(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            129 => {
+                __reduce129(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            130 => {
+                __reduce130(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            131 => {
+                __reduce131(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            132 => {
+                __reduce132(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            133 => {
+                __reduce133(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            134 => {
+                __reduce134(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            135 => {
+                __reduce135(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            136 => {
+                __reduce136(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            137 => {
+                __reduce137(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            138 => {
+                __reduce138(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            139 => {
+                __reduce139(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            140 => {
+                __reduce140(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            141 => {
+                __reduce141(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            142 => {
+                __reduce142(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            143 => {
+                __reduce143(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            144 => {
+                __reduce144(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            145 => {
+                __reduce145(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            146 => {
+                __reduce146(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            147 => {
+                __reduce147(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            148 => {
+                // __Exp = Exp => ActionFn(1);
+                let __sym0 = __pop_Variant7(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action1::<>(errors, __sym0);
+                return Some(Ok(__nt));
+            }
+            149 => {
+                __reduce149(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            _ => panic!("invalid action code {}", __action)
+        };
+        let __states_len = __states.len();
+        __states.truncate(__states_len - __pop_states);
+        let __state = *__states.last().unwrap();
+        let __next_state = __goto(__state, __nonterminal);
+        __states.push(__next_state);
+        None
+    }
+    #[inline(never)]
+    fn __symbol_type_mismatch() -> ! {
+        panic!("symbol type mismatch")
+    }
+    fn __pop_Variant12<
+      'input,
     >(
-        input: &'input str,
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
-    ) -> (usize, usize)
-    {
-        // (<Exp> ",")* =  => ActionFn(71);
-        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
-        let __end = __start.clone();
-        let __nt = super::__action71::<>(input, &__start, &__end);
-        __symbols.push((__start, __Symbol::Variant2(__nt), __end));
-        (0, 1)
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, (String, Exp<Span1>), usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant12(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
     }
-    pub(crate) fn __reduce2<
-        'input,
+    fn __pop_Variant9<
+      'input,
     >(
-        input: &'input str,
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
-    ) -> (usize, usize)
-    {
-        // (<Exp> ",")* = (<Exp> ",")+ => ActionFn(72);
-        let __sym0 = __pop_Variant2(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action72::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant2(__nt), __end));
-        (1, 1)
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, (SurfFunDecl<Span1>, Tok<'input>), usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant9(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
     }
-    pub(crate) fn __reduce3<
-        'input,
+    fn __pop_Variant16<
+      'input,
     >(
-        input: &'input str,
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
-    ) -> (usize, usize)
-    {
-        // (<Exp> ",")+ = Exp, "," => ActionFn(78);
-        assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant1(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym1.2.clone();
-        let __nt = super::__action78::<>(input, __sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant2(__nt), __end));
-        (2, 2)
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, Box<Exp<Span1>>, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant16(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
     }
-    pub(crate) fn __reduce4<
-        'input,
+    fn __pop_Variant7<
+      'input,
     >(
-        input: &'input str,
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
-    ) -> (usize, usize)
-    {
-        // (<Exp> ",")+ = (<Exp> ",")+, Exp, "," => ActionFn(79);
-        assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant0(__symbols);
-        let __sym1 = __pop_Variant1(__symbols);
-        let __sym0 = __pop_Variant2(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym2.2.clone();
-        let __nt = super::__action79::<>(input, __sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant2(__nt), __end));
-        (3, 2)
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, Exp<Span1>, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant7(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
     }
-    pub(crate) fn __reduce5<
-        'input,
+    fn __pop_Variant14<
+      'input,
     >(
-        input: &'input str,
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
-    ) -> (usize, usize)
-    {
-        // (<Id> ",") = Id, "," => ActionFn(68);
-        assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant3(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym1.2.clone();
-        let __nt = super::__action68::<>(input, __sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
-        (2, 3)
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, Prim, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant14(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
     }
-    pub(crate) fn __reduce6<
-        'input,
+    fn __pop_Variant5<
+      'input,
     >(
-        input: &'input str,
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
-    ) -> (usize, usize)
-    {
-        // (<Id> ",")* =  => ActionFn(66);
-        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
-        let __end = __start.clone();
-        let __nt = super::__action66::<>(input, &__start, &__end);
-        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
-        (0, 4)
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, String, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant5(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
     }
-    pub(crate) fn __reduce7<
-        'input,
+    fn __pop_Variant19<
+      'input,
     >(
-        input: &'input str,
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
-    ) -> (usize, usize)
-    {
-        // (<Id> ",")* = (<Id> ",")+ => ActionFn(67);
-        let __sym0 = __pop_Variant4(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action67::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
-        (1, 4)
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, SurfFunDecl<Span1>, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant19(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
     }
-    pub(crate) fn __reduce8<
-        'input,
+    fn __pop_Variant22<
+      'input,
     >(
-        input: &'input str,
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
-    ) -> (usize, usize)
-    {
-        // (<Id> ",")+ = Id, "," => ActionFn(82);
-        assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant3(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym1.2.clone();
-        let __nt = super::__action82::<>(input, __sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
-        (2, 5)
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, SurfProg<Span1>, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant22(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
     }
-    pub(crate) fn __reduce9<
-        'input,
+    fn __pop_Variant0<
+      'input,
     >(
-        input: &'input str,
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
-    ) -> (usize, usize)
-    {
-        // (<Id> ",")+ = (<Id> ",")+, Id, "," => ActionFn(83);
-        assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant0(__symbols);
-        let __sym1 = __pop_Variant3(__symbols);
-        let __sym0 = __pop_Variant4(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym2.2.clone();
-        let __nt = super::__action83::<>(input, __sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
-        (3, 5)
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, Tok<'input>, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant0(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
     }
-    pub(crate) fn __reduce10<
-        'input,
+    fn __pop_Variant13<
+      'input,
     >(
-        input: &'input str,
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
-    ) -> (usize, usize)
-    {
-        // (FunDecl "and") = FunDecl, "and" => ActionFn(60);
-        assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant15(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym1.2.clone();
-        let __nt = super::__action60::<>(input, __sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
-        (2, 6)
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, Vec<(String, Exp<Span1>)>, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant13(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
     }
-    pub(crate) fn __reduce11<
-        'input,
+    fn __pop_Variant18<
+      'input,
     >(
-        input: &'input str,
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
-    ) -> (usize, usize)
-    {
-        // (FunDecl "and")* =  => ActionFn(58);
-        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
-        let __end = __start.clone();
-        let __nt = super::__action58::<>(input, &__start, &__end);
-        __symbols.push((__start, __Symbol::Variant6(__nt), __end));
-        (0, 7)
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, Vec<Exp<Span1>>, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant18(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
     }
-    pub(crate) fn __reduce12<
-        'input,
+    fn __pop_Variant17<
+      'input,
     >(
-        input: &'input str,
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
-    ) -> (usize, usize)
-    {
-        // (FunDecl "and")* = (FunDecl "and")+ => ActionFn(59);
-        let __sym0 = __pop_Variant6(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action59::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant6(__nt), __end));
-        (1, 7)
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, Vec<String>, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant17(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
     }
-    pub(crate) fn __reduce13<
-        'input,
+    fn __pop_Variant3<
+      'input,
     >(
-        input: &'input str,
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
-    ) -> (usize, usize)
-    {
-        // (FunDecl "and")+ = FunDecl, "and" => ActionFn(86);
-        assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant15(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym1.2.clone();
-        let __nt = super::__action86::<>(input, __sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant6(__nt), __end));
-        (2, 8)
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, __lalrpop_util::ErrorRecovery<usize, Tok<'input>, LexError>, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant3(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
     }
-    pub(crate) fn __reduce14<
-        'input,
+    fn __pop_Variant10<
+      'input,
     >(
-        input: &'input str,
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
-    ) -> (usize, usize)
-    {
-        // (FunDecl "and")+ = (FunDecl "and")+, FunDecl, "and" => ActionFn(87);
-        assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant0(__symbols);
-        let __sym1 = __pop_Variant15(__symbols);
-        let __sym0 = __pop_Variant6(__symbols);
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, alloc::vec::Vec<(SurfFunDecl<Span1>, Tok<'input>)>, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant10(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant8<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, alloc::vec::Vec<Exp<Span1>>, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant8(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant6<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, alloc::vec::Vec<String>, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant6(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant15<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, bool, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant15(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant21<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, core::option::Option<Exp<Span1>>, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant21(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant20<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, core::option::Option<String>, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant20(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant4<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, core::option::Option<Tok<'input>>, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant4(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant2<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, i64, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant2(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant11<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, usize, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant11(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant1<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant1(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    pub(crate) fn __reduce0<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // ":"? = ":" => ActionFn(115);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym2.2.clone();
-        let __nt = super::__action87::<>(input, __sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant6(__nt), __end));
-        (3, 8)
+        let __end = __sym0.2.clone();
+        let __nt = super::__action115::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (1, 0)
     }
-    pub(crate) fn __reduce15<
+    pub(crate) fn __reduce1<
         'input,
+        'err,
     >(
-        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // @L =  => ActionFn(61);
+        // ":"? =  => ActionFn(116);
         let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
         let __end = __start.clone();
-        let __nt = super::__action61::<>(input, &__start, &__end);
-        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
-        (0, 9)
+        let __nt = super::__action116::<>(errors, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (0, 0)
     }
-    pub(crate) fn __reduce16<
+    pub(crate) fn __reduce2<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // "="? = "=" => ActionFn(91);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action91::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (1, 1)
+    }
+    pub(crate) fn __reduce3<
         'input,
+        'err,
     >(
-        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // @R =  => ActionFn(56);
+        // "="? =  => ActionFn(92);
         let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
         let __end = __start.clone();
-        let __nt = super::__action56::<>(input, &__start, &__end);
-        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
-        (0, 10)
+        let __nt = super::__action92::<>(errors, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (0, 1)
     }
-    pub(crate) fn __reduce17<
+    pub(crate) fn __reduce4<
         'input,
+        'err,
     >(
-        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // BaseExp = Id => ActionFn(105);
-        let __sym0 = __pop_Variant3(__symbols);
+        // (<Id> ",") = Id, "," => ActionFn(130);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant5(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action105::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 11)
+        let __end = __sym1.2.clone();
+        let __nt = super::__action130::<>(errors, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
+        (2, 2)
     }
-    pub(crate) fn __reduce18<
+    pub(crate) fn __reduce5<
         'input,
+        'err,
     >(
-        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // BaseExp = Bool => ActionFn(106);
-        let __sym0 = __pop_Variant9(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action106::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 11)
+        // (<Id> ",")* =  => ActionFn(128);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
+        let __end = __start.clone();
+        let __nt = super::__action128::<>(errors, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant6(__nt), __end));
+        (0, 3)
     }
-    pub(crate) fn __reduce19<
+    pub(crate) fn __reduce6<
         'input,
+        'err,
     >(
-        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // BaseExp = Num => ActionFn(107);
-        let __sym0 = __pop_Variant17(__symbols);
+        // (<Id> ",")* = (<Id> ",")+ => ActionFn(129);
+        let __sym0 = __pop_Variant6(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action107::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 11)
+        let __nt = super::__action129::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant6(__nt), __end));
+        (1, 3)
     }
-    pub(crate) fn __reduce20<
+    pub(crate) fn __reduce7<
         'input,
+        'err,
     >(
-        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // BaseExp = Prim1, "(", Exp, ")" => ActionFn(108);
-        assert!(__symbols.len() >= 4);
-        let __sym3 = __pop_Variant0(__symbols);
-        let __sym2 = __pop_Variant1(__symbols);
+        // (<Id> ",")+ = Id, "," => ActionFn(145);
+        assert!(__symbols.len() >= 2);
         let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant11(__symbols);
+        let __sym0 = __pop_Variant5(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym3.2.clone();
-        let __nt = super::__action108::<>(input, __sym0, __sym1, __sym2, __sym3);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (4, 11)
+        let __end = __sym1.2.clone();
+        let __nt = super::__action145::<>(errors, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant6(__nt), __end));
+        (2, 4)
     }
-    pub(crate) fn __reduce21<
+    pub(crate) fn __reduce8<
         'input,
+        'err,
     >(
-        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // BaseExp = "(", Exp, ")" => ActionFn(33);
+        // (<Id> ",")+ = (<Id> ",")+, Id, "," => ActionFn(146);
         assert!(__symbols.len() >= 3);
         let __sym2 = __pop_Variant0(__symbols);
-        let __sym1 = __pop_Variant1(__symbols);
-        let __sym0 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant5(__symbols);
+        let __sym0 = __pop_Variant6(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym2.2.clone();
-        let __nt = super::__action33::<>(input, __sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (3, 11)
+        let __nt = super::__action146::<>(errors, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant6(__nt), __end));
+        (3, 4)
     }
-    pub(crate) fn __reduce22<
+    pub(crate) fn __reduce9<
         'input,
+        'err,
     >(
-        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // BaseExp = Id, "(", Comma<Exp>, ")" => ActionFn(109);
-        assert!(__symbols.len() >= 4);
-        let __sym3 = __pop_Variant0(__symbols);
-        let __sym2 = __pop_Variant12(__symbols);
+        // (<ListExp> ",") = ListExp, "," => ActionFn(136);
+        assert!(__symbols.len() >= 2);
         let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant3(__symbols);
+        let __sym0 = __pop_Variant7(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym3.2.clone();
-        let __nt = super::__action109::<>(input, __sym0, __sym1, __sym2, __sym3);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (4, 11)
+        let __end = __sym1.2.clone();
+        let __nt = super::__action136::<>(errors, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
+        (2, 5)
     }
-    pub(crate) fn __reduce23<
+    pub(crate) fn __reduce10<
         'input,
+        'err,
     >(
-        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // BinOps = LogExp => ActionFn(11);
-        let __sym0 = __pop_Variant1(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action11::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 12)
+        // (<ListExp> ",")* =  => ActionFn(134);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
+        let __end = __start.clone();
+        let __nt = super::__action134::<>(errors, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant8(__nt), __end));
+        (0, 6)
     }
-    pub(crate) fn __reduce24<
+    pub(crate) fn __reduce11<
         'input,
+        'err,
     >(
-        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // Bindings = Id, "=", Exp => ActionFn(40);
-        assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant1(__symbols);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant3(__symbols);
+        // (<ListExp> ",")* = (<ListExp> ",")+ => ActionFn(135);
+        let __sym0 = __pop_Variant8(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym2.2.clone();
-        let __nt = super::__action40::<>(input, __sym0, __sym1, __sym2);
+        let __end = __sym0.2.clone();
+        let __nt = super::__action135::<>(errors, __sym0);
         __symbols.push((__start, __Symbol::Variant8(__nt), __end));
-        (3, 13)
+        (1, 6)
     }
-    pub(crate) fn __reduce25<
+    pub(crate) fn __reduce12<
         'input,
+        'err,
     >(
-        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // Bindings = Bindings, ",", Id, "=", Exp => ActionFn(41);
-        assert!(__symbols.len() >= 5);
-        let __sym4 = __pop_Variant1(__symbols);
-        let __sym3 = __pop_Variant0(__symbols);
-        let __sym2 = __pop_Variant3(__symbols);
+        // (<ListExp> ",")+ = ListExp, "," => ActionFn(149);
+        assert!(__symbols.len() >= 2);
         let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant8(__symbols);
+        let __sym0 = __pop_Variant7(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym4.2.clone();
-        let __nt = super::__action41::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4);
+        let __end = __sym1.2.clone();
+        let __nt = super::__action149::<>(errors, __sym0, __sym1);
         __symbols.push((__start, __Symbol::Variant8(__nt), __end));
-        (5, 13)
+        (2, 7)
     }
-    pub(crate) fn __reduce26<
+    pub(crate) fn __reduce13<
         'input,
+        'err,
     >(
-        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // Bool = "true" => ActionFn(44);
-        let __sym0 = __pop_Variant0(__symbols);
+        // (<ListExp> ",")+ = (<ListExp> ",")+, ListExp, "," => ActionFn(150);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant7(__symbols);
+        let __sym0 = __pop_Variant8(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action44::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant9(__nt), __end));
-        (1, 14)
+        let __end = __sym2.2.clone();
+        let __nt = super::__action150::<>(errors, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant8(__nt), __end));
+        (3, 7)
     }
-    pub(crate) fn __reduce27<
+    pub(crate) fn __reduce14<
         'input,
+        'err,
     >(
-        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // Bool = "false" => ActionFn(45);
-        let __sym0 = __pop_Variant0(__symbols);
+        // (FunDecl "and") = FunDecl, "and" => ActionFn(122);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant19(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action45::<>(input, __sym0);
+        let __end = __sym1.2.clone();
+        let __nt = super::__action122::<>(errors, __sym0, __sym1);
         __symbols.push((__start, __Symbol::Variant9(__nt), __end));
-        (1, 14)
+        (2, 8)
     }
-    pub(crate) fn __reduce28<
+    pub(crate) fn __reduce15<
         'input,
+        'err,
     >(
-        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // Boxed<Exp> = Exp => ActionFn(57);
-        let __sym0 = __pop_Variant1(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action57::<>(input, __sym0);
+        // (FunDecl "and")* =  => ActionFn(120);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
+        let __end = __start.clone();
+        let __nt = super::__action120::<>(errors, &__start, &__end);
         __symbols.push((__start, __Symbol::Variant10(__nt), __end));
-        (1, 15)
+        (0, 9)
     }
-    pub(crate) fn __reduce29<
+    pub(crate) fn __reduce16<
         'input,
+        'err,
     >(
-        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // Cmp = "<" => ActionFn(21);
-        let __sym0 = __pop_Variant0(__symbols);
+        // (FunDecl "and")* = (FunDecl "and")+ => ActionFn(121);
+        let __sym0 = __pop_Variant10(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action21::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
-        (1, 16)
+        let __nt = super::__action121::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant10(__nt), __end));
+        (1, 9)
     }
-    pub(crate) fn __reduce30<
+    pub(crate) fn __reduce17<
         'input,
+        'err,
     >(
-        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // Cmp = "<=" => ActionFn(22);
-        let __sym0 = __pop_Variant0(__symbols);
+        // (FunDecl "and")+ = FunDecl, "and" => ActionFn(153);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant19(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action22::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
-        (1, 16)
+        let __end = __sym1.2.clone();
+        let __nt = super::__action153::<>(errors, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant10(__nt), __end));
+        (2, 10)
     }
-    pub(crate) fn __reduce31<
+    pub(crate) fn __reduce18<
         'input,
+        'err,
     >(
-        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // Cmp = ">" => ActionFn(23);
-        let __sym0 = __pop_Variant0(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action23::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
-        (1, 16)
+        // (FunDecl "and")+ = (FunDecl "and")+, FunDecl, "and" => ActionFn(154);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant19(__symbols);
+        let __sym0 = __pop_Variant10(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action154::<>(errors, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant10(__nt), __end));
+        (3, 10)
     }
-    pub(crate) fn __reduce32<
+    pub(crate) fn __reduce19<
         'input,
+        'err,
     >(
-        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // Cmp = ">=" => ActionFn(24);
-        let __sym0 = __pop_Variant0(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action24::<>(input, __sym0);
+        // @L =  => ActionFn(123);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
+        let __end = __start.clone();
+        let __nt = super::__action123::<>(errors, &__start, &__end);
         __symbols.push((__start, __Symbol::Variant11(__nt), __end));
-        (1, 16)
+        (0, 11)
     }
-    pub(crate) fn __reduce33<
+    pub(crate) fn __reduce20<
         'input,
+        'err,
     >(
-        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // Cmp = "==" => ActionFn(25);
-        let __sym0 = __pop_Variant0(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action25::<>(input, __sym0);
+        // @R =  => ActionFn(118);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
+        let __end = __start.clone();
+        let __nt = super::__action118::<>(errors, &__start, &__end);
         __symbols.push((__start, __Symbol::Variant11(__nt), __end));
-        (1, 16)
+        (0, 12)
     }
-    pub(crate) fn __reduce34<
+    pub(crate) fn __reduce21<
         'input,
+        'err,
     >(
-        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // Cmp = "!=" => ActionFn(26);
-        let __sym0 = __pop_Variant0(__symbols);
+        // BaseExp = Id => ActionFn(193);
+        let __sym0 = __pop_Variant5(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action26::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
-        (1, 16)
+        let __nt = super::__action193::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
+        (1, 13)
     }
-    pub(crate) fn __reduce35<
+    pub(crate) fn __reduce22<
         'input,
+        'err,
     >(
-        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // CmpExp = LAssoc<Cmp, Sum> => ActionFn(13);
-        let __sym0 = __pop_Variant1(__symbols);
+        // BaseExp = Bool => ActionFn(194);
+        let __sym0 = __pop_Variant15(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action13::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 17)
+        let __nt = super::__action194::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
+        (1, 13)
     }
-    pub(crate) fn __reduce36<
+    pub(crate) fn __reduce23<
         'input,
+        'err,
     >(
-        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // Comma<Exp> = Exp => ActionFn(120);
-        let __sym0 = __pop_Variant1(__symbols);
+        // BaseExp = Num => ActionFn(195);
+        let __sym0 = __pop_Variant2(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action120::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant12(__nt), __end));
-        (1, 18)
+        let __nt = super::__action195::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
+        (1, 13)
     }
-    pub(crate) fn __reduce37<
+    pub(crate) fn __reduce24<
         'input,
+        'err,
     >(
-        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // Comma<Exp> =  => ActionFn(121);
-        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
-        let __end = __start.clone();
-        let __nt = super::__action121::<>(input, &__start, &__end);
-        __symbols.push((__start, __Symbol::Variant12(__nt), __end));
-        (0, 18)
+        // BaseExp = Str => ActionFn(196);
+        let __sym0 = __pop_Variant5(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action196::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
+        (1, 13)
     }
-    pub(crate) fn __reduce38<
+    pub(crate) fn __reduce25<
         'input,
+        'err,
     >(
-        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // Comma<Exp> = (<Exp> ",")+, Exp => ActionFn(122);
-        assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant1(__symbols);
-        let __sym0 = __pop_Variant2(__symbols);
+        // BaseExp = Prim1, "(", Exp, ")" => ActionFn(197);
+        assert!(__symbols.len() >= 4);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant7(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant14(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym1.2.clone();
-        let __nt = super::__action122::<>(input, __sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant12(__nt), __end));
-        (2, 18)
+        let __end = __sym3.2.clone();
+        let __nt = super::__action197::<>(errors, __sym0, __sym1, __sym2, __sym3);
+        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
+        (4, 13)
     }
-    pub(crate) fn __reduce39<
+    pub(crate) fn __reduce26<
         'input,
+        'err,
     >(
-        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // Comma<Exp> = (<Exp> ",")+ => ActionFn(123);
-        let __sym0 = __pop_Variant2(__symbols);
+        // BaseExp = Prim2, "(", Exp, ",", Exp, ")" => ActionFn(198);
+        assert!(__symbols.len() >= 6);
+        let __sym5 = __pop_Variant0(__symbols);
+        let __sym4 = __pop_Variant7(__symbols);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant7(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant14(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action123::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant12(__nt), __end));
-        (1, 18)
+        let __end = __sym5.2.clone();
+        let __nt = super::__action198::<>(errors, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5);
+        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
+        (6, 13)
     }
-    pub(crate) fn __reduce40<
+    pub(crate) fn __reduce27<
         'input,
+        'err,
     >(
-        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // Comma<Id> = Id => ActionFn(124);
-        let __sym0 = __pop_Variant3(__symbols);
+        // BaseExp = Prim3, "(", Exp, ",", Exp, ",", Exp, ")" => ActionFn(199);
+        assert!(__symbols.len() >= 8);
+        let __sym7 = __pop_Variant0(__symbols);
+        let __sym6 = __pop_Variant7(__symbols);
+        let __sym5 = __pop_Variant0(__symbols);
+        let __sym4 = __pop_Variant7(__symbols);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant7(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant14(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action124::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant13(__nt), __end));
-        (1, 19)
+        let __end = __sym7.2.clone();
+        let __nt = super::__action199::<>(errors, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5, __sym6, __sym7);
+        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
+        (8, 13)
     }
-    pub(crate) fn __reduce41<
+    pub(crate) fn __reduce28<
         'input,
+        'err,
     >(
-        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // Comma<Id> =  => ActionFn(125);
-        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
-        let __end = __start.clone();
-        let __nt = super::__action125::<>(input, &__start, &__end);
-        __symbols.push((__start, __Symbol::Variant13(__nt), __end));
-        (0, 19)
+        // BaseExp = "(", Exp, ")" => ActionFn(57);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant7(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action57::<>(errors, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
+        (3, 13)
     }
-    pub(crate) fn __reduce42<
+    pub(crate) fn __reduce29<
         'input,
+        'err,
     >(
-        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // Comma<Id> = (<Id> ",")+, Id => ActionFn(126);
-        assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant3(__symbols);
-        let __sym0 = __pop_Variant4(__symbols);
+        // BaseExp = Id, "(", Comma<ListExp>, ")" => ActionFn(200);
+        assert!(__symbols.len() >= 4);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant18(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant5(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym1.2.clone();
-        let __nt = super::__action126::<>(input, __sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant13(__nt), __end));
-        (2, 19)
+        let __end = __sym3.2.clone();
+        let __nt = super::__action200::<>(errors, __sym0, __sym1, __sym2, __sym3);
+        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
+        (4, 13)
     }
-    pub(crate) fn __reduce43<
+    pub(crate) fn __reduce30<
         'input,
+        'err,
     >(
-        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // Comma<Id> = (<Id> ",")+ => ActionFn(127);
-        let __sym0 = __pop_Variant4(__symbols);
+        // BaseExp = "lambda", Comma<Id>, ":", Boxed<Exp>, "end" => ActionFn(201);
+        assert!(__symbols.len() >= 5);
+        let __sym4 = __pop_Variant0(__symbols);
+        let __sym3 = __pop_Variant16(__symbols);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant17(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action127::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant13(__nt), __end));
-        (1, 19)
+        let __end = __sym4.2.clone();
+        let __nt = super::__action201::<>(errors, __sym0, __sym1, __sym2, __sym3, __sym4);
+        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
+        (5, 13)
     }
-    pub(crate) fn __reduce44<
+    pub(crate) fn __reduce31<
         'input,
+        'err,
     >(
-        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // Exp = IF => ActionFn(5);
-        let __sym0 = __pop_Variant1(__symbols);
+        // BaseExp = "while", Boxed<Exp>, ":", Boxed<Exp>, "end" => ActionFn(202);
+        assert!(__symbols.len() >= 5);
+        let __sym4 = __pop_Variant0(__symbols);
+        let __sym3 = __pop_Variant16(__symbols);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant16(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action5::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 20)
+        let __end = __sym4.2.clone();
+        let __nt = super::__action202::<>(errors, __sym0, __sym1, __sym2, __sym3, __sym4);
+        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
+        (5, 13)
     }
-    pub(crate) fn __reduce45<
+    pub(crate) fn __reduce32<
         'input,
+        'err,
     >(
-        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // Exp = LET => ActionFn(6);
-        let __sym0 = __pop_Variant1(__symbols);
+        // BaseExp = "for", Id, "=", Boxed<Exp>, "to", Boxed<Exp>, ":", Boxed<Exp>, "end" => ActionFn(203);
+        assert!(__symbols.len() >= 9);
+        let __sym8 = __pop_Variant0(__symbols);
+        let __sym7 = __pop_Variant16(__symbols);
+        let __sym6 = __pop_Variant0(__symbols);
+        let __sym5 = __pop_Variant16(__symbols);
+        let __sym4 = __pop_Variant0(__symbols);
+        let __sym3 = __pop_Variant16(__symbols);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant5(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action6::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 20)
+        let __end = __sym8.2.clone();
+        let __nt = super::__action203::<>(errors, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5, __sym6, __sym7, __sym8);
+        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
+        (9, 13)
     }
-    pub(crate) fn __reduce46<
+    pub(crate) fn __reduce33<
         'input,
+        'err,
     >(
-        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // Exp = FUNDEFS => ActionFn(7);
-        let __sym0 = __pop_Variant1(__symbols);
+        // BaseExp = "[", Comma<ListExp>, "]" => ActionFn(204);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant18(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action7::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 20)
+        let __end = __sym2.2.clone();
+        let __nt = super::__action204::<>(errors, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
+        (3, 13)
     }
-    pub(crate) fn __reduce47<
+    pub(crate) fn __reduce34<
         'input,
+        'err,
     >(
-        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // Exp = BinOps => ActionFn(8);
-        let __sym0 = __pop_Variant1(__symbols);
+        // BaseExp = BaseExp, "[", Exp, "]" => ActionFn(205);
+        assert!(__symbols.len() >= 4);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant7(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant7(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action8::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 20)
+        let __end = __sym3.2.clone();
+        let __nt = super::__action205::<>(errors, __sym0, __sym1, __sym2, __sym3);
+        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
+        (4, 13)
     }
-    pub(crate) fn __reduce48<
+    pub(crate) fn __reduce35<
         'input,
+        'err,
     >(
-        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // Exp? = Exp => ActionFn(69);
-        let __sym0 = __pop_Variant1(__symbols);
+        // BinOps = LogExp => ActionFn(19);
+        let __sym0 = __pop_Variant7(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action69::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant14(__nt), __end));
-        (1, 21)
+        let __nt = super::__action19::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
+        (1, 14)
     }
-    pub(crate) fn __reduce49<
+    pub(crate) fn __reduce36<
         'input,
+        'err,
     >(
-        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // Exp? =  => ActionFn(70);
-        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
-        let __end = __start.clone();
-        let __nt = super::__action70::<>(input, &__start, &__end);
-        __symbols.push((__start, __Symbol::Variant14(__nt), __end));
-        (0, 21)
+        // Binding = Id, "=", Exp => ActionFn(84);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant7(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant5(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action84::<>(errors, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant12(__nt), __end));
+        (3, 15)
     }
-    pub(crate) fn __reduce50<
+    pub(crate) fn __reduce37<
         'input,
+        'err,
     >(
-        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // FUNDEFS = FunDecl, "in", Boxed<Exp> => ActionFn(110);
+        // Binding = Id, "=", Recovered => ActionFn(143);
         assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant10(__symbols);
+        let __sym2 = __pop_Variant7(__symbols);
         let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant15(__symbols);
+        let __sym0 = __pop_Variant5(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym2.2.clone();
-        let __nt = super::__action110::<>(input, __sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (3, 22)
+        let __nt = super::__action143::<>(errors, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant12(__nt), __end));
+        (3, 15)
     }
-    pub(crate) fn __reduce51<
+    pub(crate) fn __reduce38<
         'input,
+        'err,
     >(
-        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // FUNDEFS = (FunDecl "and")+, FunDecl, "in", Boxed<Exp> => ActionFn(111);
-        assert!(__symbols.len() >= 4);
-        let __sym3 = __pop_Variant10(__symbols);
-        let __sym2 = __pop_Variant0(__symbols);
-        let __sym1 = __pop_Variant15(__symbols);
-        let __sym0 = __pop_Variant6(__symbols);
+        // Binding = Id, Recovered => ActionFn(144);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant7(__symbols);
+        let __sym0 = __pop_Variant5(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym3.2.clone();
-        let __nt = super::__action111::<>(input, __sym0, __sym1, __sym2, __sym3);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (4, 22)
+        let __end = __sym1.2.clone();
+        let __nt = super::__action144::<>(errors, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant12(__nt), __end));
+        (2, 15)
     }
-    pub(crate) fn __reduce52<
+    pub(crate) fn __reduce39<
         'input,
+        'err,
     >(
-        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // FunDecl = "def", Id, "(", Comma<Id>, ")", ":", Exp => ActionFn(112);
-        assert!(__symbols.len() >= 7);
-        let __sym6 = __pop_Variant1(__symbols);
-        let __sym5 = __pop_Variant0(__symbols);
-        let __sym4 = __pop_Variant0(__symbols);
-        let __sym3 = __pop_Variant13(__symbols);
-        let __sym2 = __pop_Variant0(__symbols);
-        let __sym1 = __pop_Variant3(__symbols);
-        let __sym0 = __pop_Variant0(__symbols);
+        // Bindings = Binding => ActionFn(82);
+        let __sym0 = __pop_Variant12(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym6.2.clone();
-        let __nt = super::__action112::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5, __sym6);
-        __symbols.push((__start, __Symbol::Variant15(__nt), __end));
-        (7, 23)
+        let __end = __sym0.2.clone();
+        let __nt = super::__action82::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant13(__nt), __end));
+        (1, 16)
     }
-    pub(crate) fn __reduce53<
+    pub(crate) fn __reduce40<
         'input,
+        'err,
     >(
-        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // IF = "if", Exp, ":", Exp, "else", ":", Exp => ActionFn(113);
-        assert!(__symbols.len() >= 7);
-        let __sym6 = __pop_Variant1(__symbols);
-        let __sym5 = __pop_Variant0(__symbols);
-        let __sym4 = __pop_Variant0(__symbols);
-        let __sym3 = __pop_Variant1(__symbols);
-        let __sym2 = __pop_Variant0(__symbols);
-        let __sym1 = __pop_Variant1(__symbols);
-        let __sym0 = __pop_Variant0(__symbols);
+        // Bindings = Bindings, ",", Binding => ActionFn(83);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant12(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant13(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym6.2.clone();
-        let __nt = super::__action113::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5, __sym6);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (7, 24)
+        let __end = __sym2.2.clone();
+        let __nt = super::__action83::<>(errors, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant13(__nt), __end));
+        (3, 16)
     }
-    pub(crate) fn __reduce54<
+    pub(crate) fn __reduce41<
         'input,
+        'err,
     >(
-        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // Id = r#"[a-zA-Z][a-zA-Z0-9_]*"# => ActionFn(43);
+        // BitAnd = "&" => ActionFn(36);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action43::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
-        (1, 25)
+        let __nt = super::__action36::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant14(__nt), __end));
+        (1, 17)
     }
-    pub(crate) fn __reduce55<
+    pub(crate) fn __reduce42<
         'input,
+        'err,
     >(
-        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // Id? = Id => ActionFn(64);
-        let __sym0 = __pop_Variant3(__symbols);
+        // BitAndExp = LAssoc<BitAnd, ShiftExp> => ActionFn(24);
+        let __sym0 = __pop_Variant7(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action64::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant16(__nt), __end));
-        (1, 26)
+        let __nt = super::__action24::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
+        (1, 18)
     }
-    pub(crate) fn __reduce56<
+    pub(crate) fn __reduce43<
         'input,
+        'err,
     >(
-        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // Id? =  => ActionFn(65);
-        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
-        let __end = __start.clone();
-        let __nt = super::__action65::<>(input, &__start, &__end);
-        __symbols.push((__start, __Symbol::Variant16(__nt), __end));
-        (0, 26)
+        // BitOr = "|" => ActionFn(34);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action34::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant14(__nt), __end));
+        (1, 19)
     }
-    pub(crate) fn __reduce57<
+    pub(crate) fn __reduce44<
         'input,
+        'err,
     >(
-        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // LAssoc<Cmp, Sum> = LAssoc<Cmp, Sum>, Cmp, Sum => ActionFn(114);
-        assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant1(__symbols);
-        let __sym1 = __pop_Variant11(__symbols);
-        let __sym0 = __pop_Variant1(__symbols);
+        // BitOrExp = LAssoc<BitOr, BitXorExp> => ActionFn(22);
+        let __sym0 = __pop_Variant7(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym2.2.clone();
-        let __nt = super::__action114::<>(input, __sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (3, 27)
+        let __end = __sym0.2.clone();
+        let __nt = super::__action22::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
+        (1, 20)
     }
-    pub(crate) fn __reduce58<
+    pub(crate) fn __reduce45<
         'input,
+        'err,
     >(
-        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // LAssoc<Cmp, Sum> = Sum => ActionFn(52);
-        let __sym0 = __pop_Variant1(__symbols);
+        // BitXor = "^" => ActionFn(35);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action52::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 27)
+        let __nt = super::__action35::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant14(__nt), __end));
+        (1, 21)
     }
-    pub(crate) fn __reduce59<
+    pub(crate) fn __reduce46<
         'input,
+        'err,
     >(
-        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // LAssoc<PlusMinus, Product> = LAssoc<PlusMinus, Product>, PlusMinus, Product => ActionFn(115);
-        assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant1(__symbols);
-        let __sym1 = __pop_Variant11(__symbols);
-        let __sym0 = __pop_Variant1(__symbols);
+        // BitXorExp = LAssoc<BitXor, BitAndExp> => ActionFn(23);
+        let __sym0 = __pop_Variant7(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym2.2.clone();
-        let __nt = super::__action115::<>(input, __sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (3, 28)
-    }
-    pub(crate) fn __reduce60<
+        let __end = __sym0.2.clone();
+        let __nt = super::__action23::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
+        (1, 22)
+    }
+    pub(crate) fn __reduce47<
         'input,
+        'err,
     >(
-        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // LAssoc<PlusMinus, Product> = Product => ActionFn(50);
-        let __sym0 = __pop_Variant1(__symbols);
+        // Bool = "true" => ActionFn(89);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action50::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 28)
+        let __nt = super::__action89::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant15(__nt), __end));
+        (1, 23)
     }
-    pub(crate) fn __reduce61<
+    pub(crate) fn __reduce48<
         'input,
+        'err,
     >(
-        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // LAssoc<Times, NotExp> = LAssoc<Times, NotExp>, Times, NotExp => ActionFn(116);
-        assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant1(__symbols);
-        let __sym1 = __pop_Variant11(__symbols);
-        let __sym0 = __pop_Variant1(__symbols);
+        // Bool = "false" => ActionFn(90);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym2.2.clone();
-        let __nt = super::__action116::<>(input, __sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (3, 29)
+        let __end = __sym0.2.clone();
+        let __nt = super::__action90::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant15(__nt), __end));
+        (1, 23)
     }
-    pub(crate) fn __reduce62<
+    pub(crate) fn __reduce49<
         'input,
+        'err,
     >(
-        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // LAssoc<Times, NotExp> = NotExp => ActionFn(48);
-        let __sym0 = __pop_Variant1(__symbols);
+        // Boxed<ClosedExp> = ClosedExp => ActionFn(114);
+        let __sym0 = __pop_Variant7(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action48::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 29)
+        let __nt = super::__action114::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant16(__nt), __end));
+        (1, 24)
     }
-    pub(crate) fn __reduce63<
+    pub(crate) fn __reduce50<
         'input,
+        'err,
     >(
-        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // LET = "let", Bindings, "in", Exp => ActionFn(117);
-        assert!(__symbols.len() >= 4);
-        let __sym3 = __pop_Variant1(__symbols);
-        let __sym2 = __pop_Variant0(__symbols);
-        let __sym1 = __pop_Variant8(__symbols);
-        let __sym0 = __pop_Variant0(__symbols);
+        // Boxed<Exp> = Exp => ActionFn(119);
+        let __sym0 = __pop_Variant7(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym3.2.clone();
-        let __nt = super::__action117::<>(input, __sym0, __sym1, __sym2, __sym3);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (4, 30)
+        let __end = __sym0.2.clone();
+        let __nt = super::__action119::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant16(__nt), __end));
+        (1, 25)
     }
-    pub(crate) fn __reduce64<
+    pub(crate) fn __reduce51<
         'input,
+        'err,
     >(
-        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // Log = "&&" => ActionFn(19);
-        let __sym0 = __pop_Variant0(__symbols);
+        // Boxed<OpenExp> = OpenExp => ActionFn(131);
+        let __sym0 = __pop_Variant7(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action19::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
-        (1, 31)
+        let __nt = super::__action131::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant16(__nt), __end));
+        (1, 26)
     }
-    pub(crate) fn __reduce65<
+    pub(crate) fn __reduce52<
         'input,
+        'err,
     >(
-        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // Log = "||" => ActionFn(20);
-        let __sym0 = __pop_Variant0(__symbols);
+        // ClosedExp = SET<ClosedExp> => ActionFn(15);
+        let __sym0 = __pop_Variant7(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action20::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
-        (1, 31)
+        let __nt = super::__action15::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
+        (1, 27)
     }
-    pub(crate) fn __reduce66<
+    pub(crate) fn __reduce53<
         'input,
+        'err,
     >(
-        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // LogExp = RAssoc<Log, CmpExp> => ActionFn(12);
-        let __sym0 = __pop_Variant1(__symbols);
+        // ClosedExp = BinOps => ActionFn(16);
+        let __sym0 = __pop_Variant7(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action12::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 32)
+        let __nt = super::__action16::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
+        (1, 27)
     }
-    pub(crate) fn __reduce67<
+    pub(crate) fn __reduce54<
         'input,
+        'err,
     >(
-        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // NotExp = "!", BaseExp => ActionFn(118);
-        assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant1(__symbols);
+        // Cmp = "<" => ActionFn(41);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym1.2.clone();
-        let __nt = super::__action118::<>(input, __sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (2, 33)
+        let __end = __sym0.2.clone();
+        let __nt = super::__action41::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant14(__nt), __end));
+        (1, 28)
     }
-    pub(crate) fn __reduce68<
+    pub(crate) fn __reduce55<
         'input,
+        'err,
     >(
-        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // NotExp = BaseExp => ActionFn(28);
-        let __sym0 = __pop_Variant1(__symbols);
+        // Cmp = "<=" => ActionFn(42);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action28::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 33)
+        let __nt = super::__action42::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant14(__nt), __end));
+        (1, 28)
     }
-    pub(crate) fn __reduce69<
+    pub(crate) fn __reduce56<
         'input,
+        'err,
     >(
-        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // Num = r#"[+-]?[0-9]+"# => ActionFn(42);
+        // Cmp = ">" => ActionFn(43);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action42::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant17(__nt), __end));
-        (1, 34)
+        let __nt = super::__action43::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant14(__nt), __end));
+        (1, 28)
     }
-    pub(crate) fn __reduce70<
+    pub(crate) fn __reduce57<
         'input,
+        'err,
     >(
-        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // PlusMinus = "+" => ActionFn(16);
+        // Cmp = ">=" => ActionFn(44);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action16::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
-        (1, 35)
+        let __nt = super::__action44::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant14(__nt), __end));
+        (1, 28)
     }
-    pub(crate) fn __reduce71<
+    pub(crate) fn __reduce58<
         'input,
+        'err,
     >(
-        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // PlusMinus = "-" => ActionFn(17);
+        // Cmp = "==" => ActionFn(45);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action17::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
-        (1, 35)
+        let __nt = super::__action45::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant14(__nt), __end));
+        (1, 28)
     }
-    pub(crate) fn __reduce72<
+    pub(crate) fn __reduce59<
         'input,
+        'err,
     >(
-        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // Prim1 = "add1" => ActionFn(35);
+        // Cmp = "!=" => ActionFn(46);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action35::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
-        (1, 36)
+        let __nt = super::__action46::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant14(__nt), __end));
+        (1, 28)
     }
-    pub(crate) fn __reduce73<
+    pub(crate) fn __reduce60<
         'input,
+        'err,
     >(
-        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // Prim1 = "sub1" => ActionFn(36);
-        let __sym0 = __pop_Variant0(__symbols);
+        // CmpExp = LAssoc<Cmp, BitOrExp> => ActionFn(21);
+        let __sym0 = __pop_Variant7(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action36::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
-        (1, 36)
+        let __nt = super::__action21::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
+        (1, 29)
     }
-    pub(crate) fn __reduce74<
+    pub(crate) fn __reduce61<
         'input,
+        'err,
     >(
-        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // Prim1 = "print" => ActionFn(37);
-        let __sym0 = __pop_Variant0(__symbols);
+        // Comma<Id> = Id => ActionFn(229);
+        let __sym0 = __pop_Variant5(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action37::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
-        (1, 36)
+        let __nt = super::__action229::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant17(__nt), __end));
+        (1, 30)
     }
-    pub(crate) fn __reduce75<
+    pub(crate) fn __reduce62<
         'input,
+        'err,
     >(
-        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // Prim1 = "isnum" => ActionFn(38);
-        let __sym0 = __pop_Variant0(__symbols);
+        // Comma<Id> =  => ActionFn(230);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
+        let __end = __start.clone();
+        let __nt = super::__action230::<>(errors, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant17(__nt), __end));
+        (0, 30)
+    }
+    pub(crate) fn __reduce63<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // Comma<Id> = (<Id> ",")+, Id => ActionFn(231);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant5(__symbols);
+        let __sym0 = __pop_Variant6(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action38::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
-        (1, 36)
+        let __end = __sym1.2.clone();
+        let __nt = super::__action231::<>(errors, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant17(__nt), __end));
+        (2, 30)
     }
-    pub(crate) fn __reduce76<
+    pub(crate) fn __reduce64<
         'input,
+        'err,
     >(
-        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // Prim1 = "isbool" => ActionFn(39);
-        let __sym0 = __pop_Variant0(__symbols);
+        // Comma<Id> = (<Id> ",")+ => ActionFn(232);
+        let __sym0 = __pop_Variant6(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action39::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
-        (1, 36)
+        let __nt = super::__action232::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant17(__nt), __end));
+        (1, 30)
     }
-    pub(crate) fn __reduce77<
+    pub(crate) fn __reduce65<
         'input,
+        'err,
     >(
-        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // Product = LAssoc<Times, NotExp> => ActionFn(15);
-        let __sym0 = __pop_Variant1(__symbols);
+        // Comma<ListExp> = ListExp => ActionFn(233);
+        let __sym0 = __pop_Variant7(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action15::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 37)
+        let __nt = super::__action233::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant18(__nt), __end));
+        (1, 31)
     }
-    pub(crate) fn __reduce78<
+    pub(crate) fn __reduce66<
         'input,
+        'err,
     >(
-        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // Prog = Exp => ActionFn(2);
-        let __sym0 = __pop_Variant1(__symbols);
+        // Comma<ListExp> =  => ActionFn(234);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
+        let __end = __start.clone();
+        let __nt = super::__action234::<>(errors, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant18(__nt), __end));
+        (0, 31)
+    }
+    pub(crate) fn __reduce67<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // Comma<ListExp> = (<ListExp> ",")+, ListExp => ActionFn(235);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant7(__symbols);
+        let __sym0 = __pop_Variant8(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym1.2.clone();
+        let __nt = super::__action235::<>(errors, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant18(__nt), __end));
+        (2, 31)
+    }
+    pub(crate) fn __reduce68<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // Comma<ListExp> = (<ListExp> ",")+ => ActionFn(236);
+        let __sym0 = __pop_Variant8(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action2::<>(input, __sym0);
+        let __nt = super::__action236::<>(errors, __sym0);
         __symbols.push((__start, __Symbol::Variant18(__nt), __end));
-        (1, 38)
+        (1, 31)
     }
-    pub(crate) fn __reduce79<
+    pub(crate) fn __reduce69<
         'input,
+        'err,
     >(
-        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // RAssoc<Log, CmpExp> = CmpExp, Log, RAssoc<Log, CmpExp> => ActionFn(119);
-        assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant1(__symbols);
-        let __sym1 = __pop_Variant11(__symbols);
-        let __sym0 = __pop_Variant1(__symbols);
+        // Exp = SEQ => ActionFn(7);
+        let __sym0 = __pop_Variant7(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym2.2.clone();
-        let __nt = super::__action119::<>(input, __sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (3, 39)
+        let __end = __sym0.2.clone();
+        let __nt = super::__action7::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
+        (1, 32)
     }
-    pub(crate) fn __reduce80<
+    pub(crate) fn __reduce70<
         'input,
+        'err,
     >(
-        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // RAssoc<Log, CmpExp> = CmpExp => ActionFn(54);
-        let __sym0 = __pop_Variant1(__symbols);
+        // Exp = OpenExp => ActionFn(8);
+        let __sym0 = __pop_Variant7(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action54::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 39)
+        let __nt = super::__action8::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
+        (1, 32)
     }
-    pub(crate) fn __reduce81<
+    pub(crate) fn __reduce71<
         'input,
+        'err,
     >(
-        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // Sum = LAssoc<PlusMinus, Product> => ActionFn(14);
-        let __sym0 = __pop_Variant1(__symbols);
+        // Exp = ClosedExp => ActionFn(9);
+        let __sym0 = __pop_Variant7(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action14::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 40)
+        let __nt = super::__action9::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
+        (1, 32)
     }
-    pub(crate) fn __reduce82<
+    pub(crate) fn __reduce72<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // FUNDEFS = FunDecl, "in", Boxed<Exp> => ActionFn(206);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant16(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant19(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action206::<>(errors, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
+        (3, 33)
+    }
+    pub(crate) fn __reduce73<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // FUNDEFS = (FunDecl "and")+, FunDecl, "in", Boxed<Exp> => ActionFn(207);
+        assert!(__symbols.len() >= 4);
+        let __sym3 = __pop_Variant16(__symbols);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant19(__symbols);
+        let __sym0 = __pop_Variant10(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym3.2.clone();
+        let __nt = super::__action207::<>(errors, __sym0, __sym1, __sym2, __sym3);
+        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
+        (4, 33)
+    }
+    pub(crate) fn __reduce74<
         'input,
+        'err,
     >(
-        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // Times = "*" => ActionFn(18);
+        // FunDecl = "def", Id, "(", Comma<Id>, ")", ":", Exp => ActionFn(208);
+        assert!(__symbols.len() >= 7);
+        let __sym6 = __pop_Variant7(__symbols);
+        let __sym5 = __pop_Variant0(__symbols);
+        let __sym4 = __pop_Variant0(__symbols);
+        let __sym3 = __pop_Variant17(__symbols);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant5(__symbols);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action18::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
-        (1, 41)
+        let __end = __sym6.2.clone();
+        let __nt = super::__action208::<>(errors, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5, __sym6);
+        __symbols.push((__start, __Symbol::Variant19(__nt), __end));
+        (7, 34)
     }
-    pub(crate) fn __reduce84<
+    pub(crate) fn __reduce75<
         'input,
+        'err,
     >(
-        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // __Prog = Prog => ActionFn(0);
-        let __sym0 = __pop_Variant18(__symbols);
+        // FunDecl = "def", Id, "(", Comma<Id>, ")", ":", Recovered => ActionFn(209);
+        assert!(__symbols.len() >= 7);
+        let __sym6 = __pop_Variant7(__symbols);
+        let __sym5 = __pop_Variant0(__symbols);
+        let __sym4 = __pop_Variant0(__symbols);
+        let __sym3 = __pop_Variant17(__symbols);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant5(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action0::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant18(__nt), __end));
-        (1, 43)
+        let __end = __sym6.2.clone();
+        let __nt = super::__action209::<>(errors, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5, __sym6);
+        __symbols.push((__start, __Symbol::Variant19(__nt), __end));
+        (7, 34)
     }
-}
-pub use self::__parse__Exp::ExpParser;
-
-#[cfg_attr(rustfmt, rustfmt_skip)]
-mod __parse__Prog {
-    #![allow(non_snake_case, non_camel_case_types, unused_mut, unused_variables, unused_imports, unused_parens, clippy::all)]
-
-    use std::str::FromStr;
-    use crate::syntax::{FunDecl, SurfProg, SurfFunDecl, Exp, Prim};
-    use crate::span::Span1;
-    #[allow(unused_extern_crates)]
-    extern crate lalrpop_util as __lalrpop_util;
-    #[allow(unused_imports)]
-    use self::__lalrpop_util::state_machine as __state_machine;
-    extern crate core;
-    extern crate alloc;
-    use self::__lalrpop_util::lexer::Token;
-    #[allow(dead_code)]
-    pub(crate) enum __Symbol<'input>
-     {
-        Variant0(&'input str),
-        Variant1(Exp<Span1>),
-        Variant2(alloc::vec::Vec<Exp<Span1>>),
-        Variant3(String),
-        Variant4(alloc::vec::Vec<String>),
-        Variant5((SurfFunDecl<Span1>, &'input str)),
-        Variant6(alloc::vec::Vec<(SurfFunDecl<Span1>, &'input str)>),
-        Variant7(usize),
-        Variant8(Vec<(String, Exp<Span1>)>),
-        Variant9(bool),
-        Variant10(Box<Exp<Span1>>),
-        Variant11(Prim),
-        Variant12(Vec<Exp<Span1>>),
-        Variant13(Vec<String>),
-        Variant14(core::option::Option<Exp<Span1>>),
-        Variant15(SurfFunDecl<Span1>),
-        Variant16(core::option::Option<String>),
-        Variant17(i64),
-        Variant18(SurfProg<Span1>),
-    }
-    const __ACTION: &[i8] = &[
-        // State 0
-        7, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 47, 0, 9, 0, 48, 10, 0, 49, 50, 11, 51, 52, 53, 0, 54, 55,
-        // State 1
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 2
-        0, 0, 57, 0, -81, 0, 0, -81, 0, -81, 0, 0, 0, 0, 0, 0, 0, -81, 0, -81, 0, 0, -81, 0, 0, 0, 0, 0, 0, 58, 0, 0,
-        // State 3
-        0, 60, -36, 0, -36, 0, 0, -36, 0, -36, 61, 62, 0, 63, 64, 65, 0, -36, 0, -36, 0, 0, -36, 0, 0, 0, 0, 0, 0, -36, 0, 0,
-        // State 4
-        0, -82, -82, 0, -82, 0, 66, -82, 67, -82, -82, -82, 0, -82, -82, -82, 0, -82, 0, -82, 0, 0, -82, 0, 0, 0, 0, 0, 0, -82, 0, 0,
-        // State 5
-        0, -78, -78, 0, -78, 68, -78, -78, -78, -78, -78, -78, 0, -78, -78, -78, 0, -78, 0, -78, 0, 0, -78, 0, 0, 0, 0, 0, 0, -78, 0, 0,
-        // State 6
-        0, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 47, 0, 0, 0, 48, 0, 0, 49, 50, 0, 51, 52, 53, 0, 54, 55,
-        // State 7
-        7, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 47, 0, 9, 0, 48, 10, 0, 49, 50, 11, 51, 52, 53, 0, 54, 55,
-        // State 8
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 55,
-        // State 9
-        7, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 47, 0, 9, 0, 48, 10, 0, 49, 50, 11, 51, 52, 53, 0, 54, 55,
-        // State 10
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 55,
-        // State 11
-        7, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 47, 0, 0, 0, 48, 0, 0, 49, 50, 0, 51, 52, 53, 0, 54, 55,
-        // State 12
-        7, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 47, 0, 9, 0, 48, 10, 0, 49, 50, 11, 51, 52, 53, 0, 54, 55,
-        // State 13
-        7, 0, 0, 8, -38, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 47, 0, 9, 0, 48, 10, 0, 49, 50, 11, 51, 52, 53, 0, 54, 55,
-        // State 14
-        7, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 47, 0, 0, 0, 48, 0, 0, 49, 50, 0, 51, 52, 53, 0, 54, 55,
-        // State 15
-        7, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 47, 0, 0, 0, 48, 0, 0, 49, 50, 0, 51, 52, 53, 0, 54, 55,
-        // State 16
-        7, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 47, 0, 0, 0, 48, 0, 0, 49, 50, 0, 51, 52, 53, 0, 54, 55,
-        // State 17
-        7, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 47, 0, 9, 0, 48, 10, 0, 49, 50, 11, 51, 52, 53, 0, 54, 55,
-        // State 18
-        7, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 47, 0, 9, 0, 48, 10, 0, 49, 50, 11, 51, 52, 53, 0, 54, 55,
-        // State 19
-        7, 0, 0, 8, -40, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 47, 0, 9, 0, 48, 10, 0, 49, 50, 11, 51, 52, 53, 0, 54, 55,
-        // State 20
-        0, 0, 0, 0, -42, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 55,
-        // State 21
-        7, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 47, 0, 9, 0, 48, 10, 0, 49, 50, 11, 51, 52, 53, 0, 54, 55,
-        // State 22
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 55,
-        // State 23
-        7, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 47, 0, 9, 0, 48, 10, 0, 49, 50, 11, 51, 52, 53, 0, 54, 55,
-        // State 24
-        7, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 47, 0, 9, 0, 48, 10, 0, 49, 50, 11, 51, 52, 53, 0, 54, 55,
-        // State 25
-        0, 0, 0, 0, -44, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 55,
-        // State 26
-        7, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 47, 0, 9, 0, 48, 10, 0, 49, 50, 11, 51, 52, 53, 0, 54, 55,
-        // State 27
-        7, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 47, 0, 9, 0, 48, 10, 0, 49, 50, 11, 51, 52, 53, 0, 54, 55,
-        // State 28
-        7, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 47, 0, 9, 0, 48, 10, 0, 49, 50, 11, 51, 52, 53, 0, 54, 55,
-        // State 29
-        0, -69, -69, 0, -69, -69, -69, -69, -69, -69, -69, -69, 0, -69, -69, -69, 0, -69, 0, -69, 0, 0, -69, 0, 0, 0, 0, 0, 0, -69, 0, 0,
-        // State 30
-        0, 0, 0, 0, -48, 0, 0, -48, 0, -48, 0, 0, 0, 0, 0, 0, 0, -48, 0, -48, 0, 0, -48, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 31
-        0, -19, -19, 0, -19, -19, -19, -19, -19, -19, -19, -19, 0, -19, -19, -19, 0, -19, 0, -19, 0, 0, -19, 0, 0, 0, 0, 0, 0, -19, 0, 0,
-        // State 32
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 33
-        0, 0, 0, 0, -47, 0, 0, -47, 0, -47, 0, 0, 0, 0, 0, 0, 0, -47, 0, -47, 0, 0, -47, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 34
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 59, 0, 0, 0, 0, 13, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 35
-        0, 0, 0, 0, -45, 0, 0, -45, 0, -45, 0, 0, 0, 0, 0, 0, 0, -45, 0, -45, 0, 0, -45, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 36
-        0, -18, -18, 14, -18, -18, -18, -18, -18, -18, -18, -18, 0, -18, -18, -18, 0, -18, 0, -18, 0, 0, -18, 0, 0, 0, 0, 0, 0, -18, 0, 0,
-        // State 37
-        0, 0, 0, 0, -46, 0, 0, -46, 0, -46, 0, 0, 0, 0, 0, 0, 0, -46, 0, -46, 0, 0, -46, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 38
-        0, 0, 0, 0, -24, 0, 0, -24, 0, -24, 0, 0, 0, 0, 0, 0, 0, -24, 0, -24, 0, 0, -24, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 39
-        0, -63, -63, 0, -63, -63, -63, -63, -63, -63, -63, -63, 0, -63, -63, -63, 0, -63, 0, -63, 0, 0, -63, 0, 0, 0, 0, 0, 0, -63, 0, 0,
-        // State 40
-        0, -20, -20, 0, -20, -20, -20, -20, -20, -20, -20, -20, 0, -20, -20, -20, 0, -20, 0, -20, 0, 0, -20, 0, 0, 0, 0, 0, 0, -20, 0, 0,
-        // State 41
-        0, 0, 0, 18, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 42
-        0, -61, -61, 0, -61, 0, -61, -61, -61, -61, -61, -61, 0, -61, -61, -61, 0, -61, 0, -61, 0, 0, -61, 0, 0, 0, 0, 0, 0, -61, 0, 0,
-        // State 43
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 44
-        0, 0, 0, 0, -67, 0, 0, -67, 0, -67, 0, 0, 0, 0, 0, 0, 0, -67, 0, -67, 0, 0, -67, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 45
-        0, -59, -59, 0, -59, 0, 0, -59, 0, -59, -59, -59, 0, -59, -59, -59, 0, -59, 0, -59, 0, 0, -59, 0, 0, 0, 0, 0, 0, -59, 0, 0,
-        // State 46
-        0, 0, 0, -73, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 47
-        0, -28, -28, 0, -28, -28, -28, -28, -28, -28, -28, -28, 0, -28, -28, -28, 0, -28, 0, -28, 0, 0, -28, 0, 0, 0, 0, 0, 0, -28, 0, 0,
-        // State 48
-        0, 0, 0, -77, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 49
-        0, 0, 0, -76, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 50
-        0, 0, 0, -75, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 51
-        0, 0, 0, -74, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 52
-        0, -27, -27, 0, -27, -27, -27, -27, -27, -27, -27, -27, 0, -27, -27, -27, 0, -27, 0, -27, 0, 0, -27, 0, 0, 0, 0, 0, 0, -27, 0, 0,
-        // State 53
-        0, -70, -70, 0, -70, -70, -70, -70, -70, -70, -70, -70, 0, -70, -70, -70, 0, -70, 0, -70, 0, 0, -70, 0, 0, 0, 0, 0, 0, -70, 0, 0,
-        // State 54
-        0, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, 0, -55, 0, -55, 0, 0, -55, 0, 0, 0, 0, 0, 0, -55, 0, 0,
-        // State 55
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 75, 0, 0, 0, 0, 19, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 56
-        -65, 0, 0, -65, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -65, 0, 0, 0, -65, 0, 0, -65, -65, 0, -65, -65, -65, 0, -65, -65,
-        // State 57
-        -66, 0, 0, -66, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -66, 0, 0, 0, -66, 0, 0, -66, -66, 0, -66, -66, -66, 0, -66, -66,
-        // State 58
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -14, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 59
-        -35, 0, 0, -35, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -35, 0, 0, 0, -35, 0, 0, -35, -35, 0, -35, -35, -35, 0, -35, -35,
-        // State 60
-        -30, 0, 0, -30, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -30, 0, 0, 0, -30, 0, 0, -30, -30, 0, -30, -30, -30, 0, -30, -30,
-        // State 61
-        -31, 0, 0, -31, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -31, 0, 0, 0, -31, 0, 0, -31, -31, 0, -31, -31, -31, 0, -31, -31,
-        // State 62
-        -34, 0, 0, -34, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -34, 0, 0, 0, -34, 0, 0, -34, -34, 0, -34, -34, -34, 0, -34, -34,
-        // State 63
-        -32, 0, 0, -32, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -32, 0, 0, 0, -32, 0, 0, -32, -32, 0, -32, -32, -32, 0, -32, -32,
-        // State 64
-        -33, 0, 0, -33, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -33, 0, 0, 0, -33, 0, 0, -33, -33, 0, -33, -33, -33, 0, -33, -33,
-        // State 65
-        -71, 0, 0, -71, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -71, 0, 0, 0, -71, 0, 0, -71, -71, 0, -71, -71, -71, 0, -71, -71,
-        // State 66
-        -72, 0, 0, -72, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -72, 0, 0, 0, -72, 0, 0, -72, -72, 0, -72, -72, -72, 0, -72, -72,
-        // State 67
-        -83, 0, 0, -83, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -83, 0, 0, 0, -83, 0, 0, -83, -83, 0, -83, -83, -83, 0, -83, -83,
-        // State 68
-        0, -68, -68, 0, -68, -68, -68, -68, -68, -68, -68, -68, 0, -68, -68, -68, 0, -68, 0, -68, 0, 0, -68, 0, 0, 0, 0, 0, 0, -68, 0, 0,
-        // State 69
-        0, 0, 0, 0, 85, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 70
-        0, 0, 0, 21, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 71
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 22, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 72
-        0, 0, 0, 0, 0, 0, 0, 23, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 24, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 73
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 25, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 74
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -15, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 75
-        0, 0, 0, 0, -80, 0, 0, -80, 0, -80, 0, 0, 0, 0, 0, 0, 0, -80, 0, -80, 0, 0, -80, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 76
-        0, 0, 0, 0, -51, 0, 0, -51, 0, -51, 0, 0, 0, 0, 0, 0, 0, -51, 0, -51, 0, 0, -51, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 77
-        0, 0, 0, 0, -29, 0, 0, -29, 0, -29, 0, 0, 0, 0, 0, 0, 0, -29, 0, -29, 0, 0, -29, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 78
-        0, 0, 0, 0, 88, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 79
-        0, 0, 0, 0, -37, 0, 0, 89, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 80
-        0, -58, -58, 0, -58, 0, 0, -58, 0, -58, -58, -58, 0, -58, -58, -58, 0, -58, 0, -58, 0, 0, -58, 0, 0, 0, 0, 0, 0, -58, 0, 0,
-        // State 81
-        0, -60, -60, 0, -60, 0, -60, -60, -60, -60, -60, -60, 0, -60, -60, -60, 0, -60, 0, -60, 0, 0, -60, 0, 0, 0, 0, 0, 0, -60, 0, 0,
-        // State 82
-        0, -62, -62, 0, -62, -62, -62, -62, -62, -62, -62, -62, 0, -62, -62, -62, 0, -62, 0, -62, 0, 0, -62, 0, 0, 0, 0, 0, 0, -62, 0, 0,
-        // State 83
-        0, 0, 0, 0, 90, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 84
-        0, -22, -22, 0, -22, -22, -22, -22, -22, -22, -22, -22, 0, -22, -22, -22, 0, -22, 0, -22, 0, 0, -22, 0, 0, 0, 0, 0, 0, -22, 0, 0,
-        // State 85
-        0, 0, 0, 0, -52, 0, 0, -52, 0, -52, 0, 0, 0, 0, 0, 0, 0, -52, 0, -52, 0, 0, -52, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 86
-        0, 0, 0, 0, -39, 0, 0, 97, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 87
-        0, -23, -23, 0, -23, -23, -23, -23, -23, -23, -23, -23, 0, -23, -23, -23, 0, -23, 0, -23, 0, 0, -23, 0, 0, 0, 0, 0, 0, -23, 0, 0,
-        // State 88
-        -4, 0, 0, -4, -4, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -4, 0, -4, 0, -4, -4, 0, -4, -4, -4, -4, -4, -4, 0, -4, -4,
-        // State 89
-        0, -21, -21, 0, -21, -21, -21, -21, -21, -21, -21, -21, 0, -21, -21, -21, 0, -21, 0, -21, 0, 0, -21, 0, 0, 0, 0, 0, 0, -21, 0, 0,
-        // State 90
-        0, 0, 0, 0, 99, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 91
-        0, 0, 0, 0, -41, 0, 0, 100, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 92
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 101, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 93
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 27, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 94
-        0, 0, 0, 0, -64, 0, 0, -64, 0, -64, 0, 0, 0, 0, 0, 0, 0, -64, 0, -64, 0, 0, -64, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 95
-        0, 0, 0, 0, 0, 0, 0, -25, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -25, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 96
-        -5, 0, 0, -5, -5, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -5, 0, -5, 0, -5, -5, 0, -5, -5, -5, -5, -5, -5, 0, -5, -5,
-        // State 97
-        0, 0, 0, 0, -43, 0, 0, 102, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 98
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 28, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 99
-        0, 0, 0, 0, -9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -9,
-        // State 100
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 29, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 101
-        0, 0, 0, 0, -10, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -10,
-        // State 102
-        0, 0, 0, 0, 0, 0, 0, -26, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -26, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 103
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -53, 0, 0, 0, 0, -53, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 104
-        0, 0, 0, 0, -54, 0, 0, -54, 0, -54, 0, 0, 0, 0, 0, 0, 0, -54, 0, -54, 0, 0, -54, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    ];
-    fn __action(state: i8, integer: usize) -> i8 {
-        __ACTION[(state as usize) * 32 + integer]
+    pub(crate) fn __reduce76<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // FunDecl = "def", Id, "(", Comma<Id>, ")", Recovered => ActionFn(210);
+        assert!(__symbols.len() >= 6);
+        let __sym5 = __pop_Variant7(__symbols);
+        let __sym4 = __pop_Variant0(__symbols);
+        let __sym3 = __pop_Variant17(__symbols);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant5(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym5.2.clone();
+        let __nt = super::__action210::<>(errors, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5);
+        __symbols.push((__start, __Symbol::Variant19(__nt), __end));
+        (6, 34)
     }
-    const __EOF_ACTION: &[i8] = &[
-        // State 0
-        0,
-        // State 1
-        0,
-        // State 2
-        -81,
-        // State 3
-        -36,
-        // State 4
-        -82,
-        // State 5
-        -78,
-        // State 6
-        0,
-        // State 7
-        0,
-        // State 8
-        0,
-        // State 9
-        0,
-        // State 10
-        0,
-        // State 11
-        0,
-        // State 12
-        0,
-        // State 13
-        0,
-        // State 14
-        0,
-        // State 15
-        0,
-        // State 16
-        0,
-        // State 17
-        0,
-        // State 18
-        0,
-        // State 19
-        0,
-        // State 20
-        0,
-        // State 21
-        0,
-        // State 22
-        0,
-        // State 23
-        0,
-        // State 24
-        0,
-        // State 25
-        0,
-        // State 26
-        0,
-        // State 27
-        0,
-        // State 28
-        0,
-        // State 29
-        -69,
-        // State 30
-        -48,
-        // State 31
-        -19,
-        // State 32
-        -79,
-        // State 33
-        -47,
-        // State 34
-        0,
-        // State 35
-        -45,
-        // State 36
-        -18,
-        // State 37
-        -46,
-        // State 38
-        -24,
-        // State 39
-        -63,
-        // State 40
-        -20,
-        // State 41
-        0,
-        // State 42
-        -61,
-        // State 43
-        -85,
-        // State 44
-        -67,
-        // State 45
-        -59,
-        // State 46
-        0,
-        // State 47
-        -28,
-        // State 48
-        0,
-        // State 49
-        0,
-        // State 50
-        0,
-        // State 51
-        0,
-        // State 52
-        -27,
-        // State 53
-        -70,
-        // State 54
-        -55,
-        // State 55
-        0,
-        // State 56
-        0,
-        // State 57
-        0,
-        // State 58
-        0,
-        // State 59
-        0,
-        // State 60
-        0,
-        // State 61
-        0,
-        // State 62
-        0,
-        // State 63
-        0,
-        // State 64
-        0,
-        // State 65
-        0,
-        // State 66
-        0,
-        // State 67
-        0,
-        // State 68
-        -68,
-        // State 69
-        0,
-        // State 70
-        0,
-        // State 71
-        0,
-        // State 72
-        0,
-        // State 73
-        0,
-        // State 74
-        0,
-        // State 75
-        -80,
-        // State 76
-        -51,
-        // State 77
-        -29,
-        // State 78
-        0,
-        // State 79
-        0,
-        // State 80
-        -58,
-        // State 81
-        -60,
-        // State 82
-        -62,
-        // State 83
-        0,
-        // State 84
-        -22,
-        // State 85
-        -52,
-        // State 86
-        0,
-        // State 87
-        -23,
-        // State 88
-        0,
-        // State 89
-        -21,
-        // State 90
-        0,
-        // State 91
-        0,
-        // State 92
-        0,
-        // State 93
-        0,
-        // State 94
-        -64,
-        // State 95
-        0,
-        // State 96
-        0,
-        // State 97
-        0,
-        // State 98
-        0,
-        // State 99
-        0,
-        // State 100
-        0,
-        // State 101
-        0,
-        // State 102
-        0,
-        // State 103
-        0,
-        // State 104
-        -54,
-    ];
-    fn __goto(state: i8, nt: usize) -> i8 {
-        match nt {
-            2 => 19,
-            5 => 25,
-            8 => 1,
-            11 => match state {
-                6 => 68,
-                _ => 29,
-            },
-            12 => 30,
-            13 => 72,
-            14 => 31,
-            15 => match state {
-                18 => 85,
-                _ => 76,
-            },
-            16 => 14,
-            17 => 2,
-            18 => 78,
-            19 => 90,
-            20 => match state {
-                0 => 32,
-                7 => 69,
-                9 => 71,
-                13 => 79,
-                17 => 83,
-                19 => 86,
-                21 => 92,
-                23 => 94,
-                24 => 95,
-                26 => 102,
-                27 => 103,
-                28 => 104,
-                _ => 77,
-            },
-            22 => 33,
-            23 => match state {
-                1 => 55,
-                _ => 34,
-            },
-            24 => 35,
-            25 => match state {
-                8 => 70,
-                10 => 73,
-                20 => 91,
-                22 => 93,
-                25 => 97,
-                _ => 36,
-            },
-            27 => 3,
-            28 => 4,
-            29 => 5,
-            30 => 37,
-            31 => 11,
-            32 => 38,
-            33 => match state {
-                16 => 82,
-                _ => 39,
-            },
-            34 => 40,
-            35 => 15,
-            36 => 41,
-            37 => match state {
-                15 => 81,
-                _ => 42,
-            },
-            38 => 43,
-            39 => match state {
-                11 => 75,
-                _ => 44,
-            },
-            40 => match state {
-                14 => 80,
-                _ => 45,
-            },
-            41 => 16,
-            _ => 0,
-        }
-    }
-    fn __expected_tokens(__state: i8) -> alloc::vec::Vec<alloc::string::String> {
-        const __TERMINAL: &[&str] = &[
-            r###""!""###,
-            r###""!=""###,
-            r###""&&""###,
-            r###""(""###,
-            r###"")""###,
-            r###""*""###,
-            r###""+""###,
-            r###"",""###,
-            r###""-""###,
-            r###"":""###,
-            r###""<""###,
-            r###""<=""###,
-            r###""=""###,
-            r###""==""###,
-            r###"">""###,
-            r###"">=""###,
-            r###""add1""###,
-            r###""and""###,
-            r###""def""###,
-            r###""else""###,
-            r###""false""###,
-            r###""if""###,
-            r###""in""###,
-            r###""isbool""###,
-            r###""isnum""###,
-            r###""let""###,
-            r###""print""###,
-            r###""sub1""###,
-            r###""true""###,
-            r###""||""###,
-            r###"r#"[+-]?[0-9]+"#"###,
-            r###"r#"[a-zA-Z][a-zA-Z0-9_]*"#"###,
-        ];
-        __TERMINAL.iter().enumerate().filter_map(|(index, terminal)| {
-            let next_state = __action(__state, index);
-            if next_state == 0 {
-                None
-            } else {
-                Some(alloc::string::ToString::to_string(terminal))
-            }
-        }).collect()
-    }
-    pub(crate) struct __StateMachine<'input>
-    where 
+    pub(crate) fn __reduce77<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
     {
-        input: &'input str,
-        __phantom: core::marker::PhantomData<(&'input ())>,
+        // IF = "if", Exp, ":", Exp, "else", ":", Exp => ActionFn(211);
+        assert!(__symbols.len() >= 7);
+        let __sym6 = __pop_Variant7(__symbols);
+        let __sym5 = __pop_Variant0(__symbols);
+        let __sym4 = __pop_Variant0(__symbols);
+        let __sym3 = __pop_Variant7(__symbols);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant7(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym6.2.clone();
+        let __nt = super::__action211::<>(errors, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5, __sym6);
+        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
+        (7, 35)
     }
-    impl<'input> __state_machine::ParserDefinition for __StateMachine<'input>
-    where 
+    pub(crate) fn __reduce78<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
     {
-        type Location = usize;
-        type Error = &'static str;
-        type Token = Token<'input>;
-        type TokenIndex = usize;
-        type Symbol = __Symbol<'input>;
-        type Success = SurfProg<Span1>;
-        type StateIndex = i8;
-        type Action = i8;
-        type ReduceIndex = i8;
-        type NonterminalIndex = usize;
-
-        #[inline]
-        fn start_location(&self) -> Self::Location {
-              Default::default()
-        }
-
-        #[inline]
-        fn start_state(&self) -> Self::StateIndex {
-              0
-        }
-
-        #[inline]
-        fn token_to_index(&self, token: &Self::Token) -> Option<usize> {
-            __token_to_integer(token, core::marker::PhantomData::<(&())>)
-        }
-
-        #[inline]
-        fn action(&self, state: i8, integer: usize) -> i8 {
-            __action(state, integer)
-        }
-
-        #[inline]
-        fn error_action(&self, state: i8) -> i8 {
-            __action(state, 32 - 1)
-        }
-
-        #[inline]
-        fn eof_action(&self, state: i8) -> i8 {
-            __EOF_ACTION[state as usize]
-        }
-
-        #[inline]
-        fn goto(&self, state: i8, nt: usize) -> i8 {
-            __goto(state, nt)
-        }
-
-        fn token_to_symbol(&self, token_index: usize, token: Self::Token) -> Self::Symbol {
-            __token_to_symbol(token_index, token, core::marker::PhantomData::<(&())>)
-        }
-
-        fn expected_tokens(&self, state: i8) -> alloc::vec::Vec<alloc::string::String> {
-            __expected_tokens(state)
-        }
-
-        #[inline]
-        fn uses_error_recovery(&self) -> bool {
-            false
-        }
-
-        #[inline]
-        fn error_recovery_symbol(
-            &self,
-            recovery: __state_machine::ErrorRecovery<Self>,
-        ) -> Self::Symbol {
-            panic!("error recovery not enabled for this grammar")
-        }
-
-        fn reduce(
-            &mut self,
-            action: i8,
-            start_location: Option<&Self::Location>,
-            states: &mut alloc::vec::Vec<i8>,
-            symbols: &mut alloc::vec::Vec<__state_machine::SymbolTriple<Self>>,
-        ) -> Option<__state_machine::ParseResult<Self>> {
-            __reduce(
-                self.input,
-                action,
-                start_location,
-                states,
-                symbols,
-                core::marker::PhantomData::<(&())>,
-            )
-        }
-
-        fn simulate_reduce(&self, action: i8) -> __state_machine::SimulatedReduce<Self> {
-            panic!("error recovery not enabled for this grammar")
-        }
+        // Id = "identifier" => ActionFn(88);
+        let __sym0 = __pop_Variant1(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action88::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
+        (1, 36)
     }
-    fn __token_to_integer<
+    pub(crate) fn __reduce79<
         'input,
+        'err,
     >(
-        __token: &Token<'input>,
-        _: core::marker::PhantomData<(&'input ())>,
-    ) -> Option<usize>
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
     {
-        match *__token {
-            Token(2, _) if true => Some(0),
-            Token(3, _) if true => Some(1),
-            Token(4, _) if true => Some(2),
-            Token(5, _) if true => Some(3),
-            Token(6, _) if true => Some(4),
-            Token(7, _) if true => Some(5),
-            Token(8, _) if true => Some(6),
-            Token(9, _) if true => Some(7),
-            Token(10, _) if true => Some(8),
-            Token(11, _) if true => Some(9),
-            Token(12, _) if true => Some(10),
-            Token(13, _) if true => Some(11),
-            Token(14, _) if true => Some(12),
-            Token(15, _) if true => Some(13),
-            Token(16, _) if true => Some(14),
-            Token(17, _) if true => Some(15),
-            Token(18, _) if true => Some(16),
-            Token(19, _) if true => Some(17),
-            Token(20, _) if true => Some(18),
-            Token(21, _) if true => Some(19),
-            Token(22, _) if true => Some(20),
-            Token(23, _) if true => Some(21),
-            Token(24, _) if true => Some(22),
-            Token(25, _) if true => Some(23),
-            Token(26, _) if true => Some(24),
-            Token(27, _) if true => Some(25),
-            Token(28, _) if true => Some(26),
-            Token(29, _) if true => Some(27),
-            Token(30, _) if true => Some(28),
-            Token(31, _) if true => Some(29),
-            Token(0, _) if true => Some(30),
-            Token(1, _) if true => Some(31),
-            _ => None,
-        }
+        // Id? = Id => ActionFn(126);
+        let __sym0 = __pop_Variant5(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action126::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant20(__nt), __end));
+        (1, 37)
     }
-    fn __token_to_symbol<
+    pub(crate) fn __reduce80<
         'input,
+        'err,
     >(
-        __token_index: usize,
-        __token: Token<'input>,
-        _: core::marker::PhantomData<(&'input ())>,
-    ) -> __Symbol<'input>
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
     {
-        match __token_index {
-            0 | 1 | 2 | 3 | 4 | 5 | 6 | 7 | 8 | 9 | 10 | 11 | 12 | 13 | 14 | 15 | 16 | 17 | 18 | 19 | 20 | 21 | 22 | 23 | 24 | 25 | 26 | 27 | 28 | 29 | 30 | 31 => match __token {
-                Token(2, __tok0) | Token(3, __tok0) | Token(4, __tok0) | Token(5, __tok0) | Token(6, __tok0) | Token(7, __tok0) | Token(8, __tok0) | Token(9, __tok0) | Token(10, __tok0) | Token(11, __tok0) | Token(12, __tok0) | Token(13, __tok0) | Token(14, __tok0) | Token(15, __tok0) | Token(16, __tok0) | Token(17, __tok0) | Token(18, __tok0) | Token(19, __tok0) | Token(20, __tok0) | Token(21, __tok0) | Token(22, __tok0) | Token(23, __tok0) | Token(24, __tok0) | Token(25, __tok0) | Token(26, __tok0) | Token(27, __tok0) | Token(28, __tok0) | Token(29, __tok0) | Token(30, __tok0) | Token(31, __tok0) | Token(0, __tok0) | Token(1, __tok0) if true => __Symbol::Variant0(__tok0),
-                _ => unreachable!(),
-            },
-            _ => unreachable!(),
-        }
-    }
-    pub struct ProgParser {
-        builder: __lalrpop_util::lexer::MatcherBuilder,
-        _priv: (),
-    }
-
-    impl ProgParser {
-        pub fn new() -> ProgParser {
-            let __builder = super::__intern_token::new_builder();
-            ProgParser {
-                builder: __builder,
-                _priv: (),
-            }
-        }
-
-        #[allow(dead_code)]
-        pub fn parse<
-            'input,
-        >(
-            &self,
-            input: &'input str,
-        ) -> Result<SurfProg<Span1>, __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
-        {
-            let mut __tokens = self.builder.matcher(input);
-            __state_machine::Parser::drive(
-                __StateMachine {
-                    input,
-                    __phantom: core::marker::PhantomData::<(&())>,
-                },
-                __tokens,
-            )
-        }
+        // Id? =  => ActionFn(127);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
+        let __end = __start.clone();
+        let __nt = super::__action127::<>(errors, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant20(__nt), __end));
+        (0, 37)
     }
-    pub(crate) fn __reduce<
+    pub(crate) fn __reduce81<
         'input,
+        'err,
     >(
-        input: &'input str,
-        __action: i8,
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
         __lookahead_start: Option<&usize>,
-        __states: &mut alloc::vec::Vec<i8>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
-    ) -> Option<Result<SurfProg<Span1>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
     {
-        let (__pop_states, __nonterminal) = match __action {
-            0 => {
-                __reduce0(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
-            }
-            1 => {
-                __reduce1(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
-            }
-            2 => {
-                __reduce2(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
-            }
-            3 => {
-                __reduce3(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
-            }
-            4 => {
-                __reduce4(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
-            }
-            5 => {
-                __reduce5(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
-            }
-            6 => {
-                __reduce6(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
-            }
-            7 => {
-                __reduce7(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
-            }
-            8 => {
-                __reduce8(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
-            }
-            9 => {
-                __reduce9(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
-            }
-            10 => {
-                __reduce10(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
-            }
-            11 => {
-                __reduce11(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
-            }
-            12 => {
-                __reduce12(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
-            }
-            13 => {
-                __reduce13(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
-            }
-            14 => {
-                __reduce14(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
-            }
-            15 => {
-                __reduce15(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
-            }
-            16 => {
-                __reduce16(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
-            }
-            17 => {
-                __reduce17(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
-            }
-            18 => {
-                __reduce18(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
-            }
-            19 => {
-                __reduce19(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
-            }
-            20 => {
-                __reduce20(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
-            }
-            21 => {
-                __reduce21(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
-            }
-            22 => {
-                __reduce22(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
-            }
-            23 => {
-                __reduce23(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
-            }
-            24 => {
-                __reduce24(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
-            }
-            25 => {
-                __reduce25(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
-            }
-            26 => {
-                __reduce26(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
-            }
-            27 => {
-                __reduce27(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
-            }
-            28 => {
-                __reduce28(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
-            }
-            29 => {
-                __reduce29(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
-            }
-            30 => {
-                __reduce30(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
-            }
-            31 => {
-                __reduce31(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
-            }
-            32 => {
-                __reduce32(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
-            }
-            33 => {
-                __reduce33(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
-            }
-            34 => {
-                __reduce34(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
-            }
-            35 => {
-                __reduce35(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
-            }
-            36 => {
-                __reduce36(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
-            }
-            37 => {
-                __reduce37(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
-            }
-            38 => {
-                __reduce38(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
-            }
-            39 => {
-                __reduce39(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
-            }
-            40 => {
-                __reduce40(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
-            }
-            41 => {
-                __reduce41(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
-            }
-            42 => {
-                __reduce42(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
-            }
-            43 => {
-                __reduce43(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
-            }
-            44 => {
-                __reduce44(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
-            }
-            45 => {
-                __reduce45(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
-            }
-            46 => {
-                __reduce46(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
-            }
-            47 => {
-                __reduce47(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
-            }
-            48 => {
-                __reduce48(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
-            }
-            49 => {
-                __reduce49(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
-            }
-            50 => {
-                __reduce50(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
-            }
-            51 => {
-                __reduce51(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
-            }
-            52 => {
-                __reduce52(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
-            }
-            53 => {
-                __reduce53(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
-            }
-            54 => {
-                __reduce54(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
-            }
-            55 => {
-                __reduce55(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
-            }
-            56 => {
-                __reduce56(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
-            }
-            57 => {
-                __reduce57(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
-            }
-            58 => {
-                __reduce58(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
-            }
-            59 => {
-                __reduce59(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
-            }
-            60 => {
-                __reduce60(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
-            }
-            61 => {
-                __reduce61(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
-            }
-            62 => {
-                __reduce62(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
-            }
-            63 => {
-                __reduce63(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
-            }
-            64 => {
-                __reduce64(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
-            }
-            65 => {
-                __reduce65(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
-            }
-            66 => {
-                __reduce66(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
-            }
-            67 => {
-                __reduce67(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
-            }
-            68 => {
-                __reduce68(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
-            }
-            69 => {
-                __reduce69(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
-            }
-            70 => {
-                __reduce70(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
-            }
-            71 => {
-                __reduce71(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
-            }
-            72 => {
-                __reduce72(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
-            }
-            73 => {
-                __reduce73(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
-            }
-            74 => {
-                __reduce74(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
-            }
-            75 => {
-                __reduce75(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
-            }
-            76 => {
-                __reduce76(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
-            }
-            77 => {
-                __reduce77(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
-            }
-            78 => {
-                __reduce78(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
-            }
-            79 => {
-                __reduce79(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
-            }
-            80 => {
-                __reduce80(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
-            }
-            81 => {
-                __reduce81(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
-            }
-            82 => {
-                __reduce82(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
-            }
-            83 => {
-                __reduce83(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
-            }
-            84 => {
-                // __Prog = Prog => ActionFn(0);
-                let __sym0 = __pop_Variant18(__symbols);
-                let __start = __sym0.0.clone();
-                let __end = __sym0.2.clone();
-                let __nt = super::__action0::<>(input, __sym0);
-                return Some(Ok(__nt));
-            }
-            _ => panic!("invalid action code {}", __action)
-        };
-        let __states_len = __states.len();
-        __states.truncate(__states_len - __pop_states);
-        let __state = *__states.last().unwrap();
-        let __next_state = __goto(__state, __nonterminal);
-        __states.push(__next_state);
-        None
+        // LAssoc<BitAnd, ShiftExp> = LAssoc<BitAnd, ShiftExp>, BitAnd, ShiftExp => ActionFn(212);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant7(__symbols);
+        let __sym1 = __pop_Variant14(__symbols);
+        let __sym0 = __pop_Variant7(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action212::<>(errors, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
+        (3, 38)
     }
-    #[inline(never)]
-    fn __symbol_type_mismatch() -> ! {
-        panic!("symbol type mismatch")
+    pub(crate) fn __reduce82<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // LAssoc<BitAnd, ShiftExp> = ShiftExp => ActionFn(101);
+        let __sym0 = __pop_Variant7(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action101::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
+        (1, 38)
     }
-    fn __pop_Variant5<
-      'input,
+    pub(crate) fn __reduce83<
+        'input,
+        'err,
     >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, (SurfFunDecl<Span1>, &'input str), usize)
-     {
-        match __symbols.pop() {
-            Some((__l, __Symbol::Variant5(__v), __r)) => (__l, __v, __r),
-            _ => __symbol_type_mismatch()
-        }
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // LAssoc<BitOr, BitXorExp> = LAssoc<BitOr, BitXorExp>, BitOr, BitXorExp => ActionFn(213);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant7(__symbols);
+        let __sym1 = __pop_Variant14(__symbols);
+        let __sym0 = __pop_Variant7(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action213::<>(errors, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
+        (3, 39)
     }
-    fn __pop_Variant10<
-      'input,
+    pub(crate) fn __reduce84<
+        'input,
+        'err,
     >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, Box<Exp<Span1>>, usize)
-     {
-        match __symbols.pop() {
-            Some((__l, __Symbol::Variant10(__v), __r)) => (__l, __v, __r),
-            _ => __symbol_type_mismatch()
-        }
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // LAssoc<BitOr, BitXorExp> = BitXorExp => ActionFn(105);
+        let __sym0 = __pop_Variant7(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action105::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
+        (1, 39)
     }
-    fn __pop_Variant1<
-      'input,
+    pub(crate) fn __reduce85<
+        'input,
+        'err,
     >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, Exp<Span1>, usize)
-     {
-        match __symbols.pop() {
-            Some((__l, __Symbol::Variant1(__v), __r)) => (__l, __v, __r),
-            _ => __symbol_type_mismatch()
-        }
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // LAssoc<BitXor, BitAndExp> = LAssoc<BitXor, BitAndExp>, BitXor, BitAndExp => ActionFn(214);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant7(__symbols);
+        let __sym1 = __pop_Variant14(__symbols);
+        let __sym0 = __pop_Variant7(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action214::<>(errors, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
+        (3, 40)
     }
-    fn __pop_Variant11<
-      'input,
+    pub(crate) fn __reduce86<
+        'input,
+        'err,
     >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, Prim, usize)
-     {
-        match __symbols.pop() {
-            Some((__l, __Symbol::Variant11(__v), __r)) => (__l, __v, __r),
-            _ => __symbol_type_mismatch()
-        }
-    }
-    fn __pop_Variant3<
-      'input,
-    >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, String, usize)
-     {
-        match __symbols.pop() {
-            Some((__l, __Symbol::Variant3(__v), __r)) => (__l, __v, __r),
-            _ => __symbol_type_mismatch()
-        }
-    }
-    fn __pop_Variant15<
-      'input,
-    >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, SurfFunDecl<Span1>, usize)
-     {
-        match __symbols.pop() {
-            Some((__l, __Symbol::Variant15(__v), __r)) => (__l, __v, __r),
-            _ => __symbol_type_mismatch()
-        }
-    }
-    fn __pop_Variant18<
-      'input,
-    >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, SurfProg<Span1>, usize)
-     {
-        match __symbols.pop() {
-            Some((__l, __Symbol::Variant18(__v), __r)) => (__l, __v, __r),
-            _ => __symbol_type_mismatch()
-        }
-    }
-    fn __pop_Variant8<
-      'input,
-    >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, Vec<(String, Exp<Span1>)>, usize)
-     {
-        match __symbols.pop() {
-            Some((__l, __Symbol::Variant8(__v), __r)) => (__l, __v, __r),
-            _ => __symbol_type_mismatch()
-        }
-    }
-    fn __pop_Variant12<
-      'input,
-    >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, Vec<Exp<Span1>>, usize)
-     {
-        match __symbols.pop() {
-            Some((__l, __Symbol::Variant12(__v), __r)) => (__l, __v, __r),
-            _ => __symbol_type_mismatch()
-        }
-    }
-    fn __pop_Variant13<
-      'input,
-    >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, Vec<String>, usize)
-     {
-        match __symbols.pop() {
-            Some((__l, __Symbol::Variant13(__v), __r)) => (__l, __v, __r),
-            _ => __symbol_type_mismatch()
-        }
-    }
-    fn __pop_Variant6<
-      'input,
-    >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, alloc::vec::Vec<(SurfFunDecl<Span1>, &'input str)>, usize)
-     {
-        match __symbols.pop() {
-            Some((__l, __Symbol::Variant6(__v), __r)) => (__l, __v, __r),
-            _ => __symbol_type_mismatch()
-        }
-    }
-    fn __pop_Variant2<
-      'input,
-    >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, alloc::vec::Vec<Exp<Span1>>, usize)
-     {
-        match __symbols.pop() {
-            Some((__l, __Symbol::Variant2(__v), __r)) => (__l, __v, __r),
-            _ => __symbol_type_mismatch()
-        }
-    }
-    fn __pop_Variant4<
-      'input,
-    >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, alloc::vec::Vec<String>, usize)
-     {
-        match __symbols.pop() {
-            Some((__l, __Symbol::Variant4(__v), __r)) => (__l, __v, __r),
-            _ => __symbol_type_mismatch()
-        }
-    }
-    fn __pop_Variant9<
-      'input,
-    >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, bool, usize)
-     {
-        match __symbols.pop() {
-            Some((__l, __Symbol::Variant9(__v), __r)) => (__l, __v, __r),
-            _ => __symbol_type_mismatch()
-        }
-    }
-    fn __pop_Variant14<
-      'input,
-    >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, core::option::Option<Exp<Span1>>, usize)
-     {
-        match __symbols.pop() {
-            Some((__l, __Symbol::Variant14(__v), __r)) => (__l, __v, __r),
-            _ => __symbol_type_mismatch()
-        }
-    }
-    fn __pop_Variant16<
-      'input,
-    >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, core::option::Option<String>, usize)
-     {
-        match __symbols.pop() {
-            Some((__l, __Symbol::Variant16(__v), __r)) => (__l, __v, __r),
-            _ => __symbol_type_mismatch()
-        }
-    }
-    fn __pop_Variant17<
-      'input,
-    >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, i64, usize)
-     {
-        match __symbols.pop() {
-            Some((__l, __Symbol::Variant17(__v), __r)) => (__l, __v, __r),
-            _ => __symbol_type_mismatch()
-        }
-    }
-    fn __pop_Variant7<
-      'input,
-    >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, usize, usize)
-     {
-        match __symbols.pop() {
-            Some((__l, __Symbol::Variant7(__v), __r)) => (__l, __v, __r),
-            _ => __symbol_type_mismatch()
-        }
-    }
-    fn __pop_Variant0<
-      'input,
-    >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, &'input str, usize)
-     {
-        match __symbols.pop() {
-            Some((__l, __Symbol::Variant0(__v), __r)) => (__l, __v, __r),
-            _ => __symbol_type_mismatch()
-        }
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // LAssoc<BitXor, BitAndExp> = BitAndExp => ActionFn(103);
+        let __sym0 = __pop_Variant7(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action103::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
+        (1, 40)
     }
-    pub(crate) fn __reduce0<
+    pub(crate) fn __reduce87<
         'input,
+        'err,
     >(
-        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // (<Exp> ",") = Exp, "," => ActionFn(73);
-        assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant1(__symbols);
+        // LAssoc<Cmp, BitOrExp> = LAssoc<Cmp, BitOrExp>, Cmp, BitOrExp => ActionFn(215);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant7(__symbols);
+        let __sym1 = __pop_Variant14(__symbols);
+        let __sym0 = __pop_Variant7(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym1.2.clone();
-        let __nt = super::__action73::<>(input, __sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (2, 0)
+        let __end = __sym2.2.clone();
+        let __nt = super::__action215::<>(errors, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
+        (3, 41)
     }
-    pub(crate) fn __reduce1<
+    pub(crate) fn __reduce88<
         'input,
+        'err,
     >(
-        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // (<Exp> ",")* =  => ActionFn(71);
-        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
-        let __end = __start.clone();
-        let __nt = super::__action71::<>(input, &__start, &__end);
-        __symbols.push((__start, __Symbol::Variant2(__nt), __end));
-        (0, 1)
+        // LAssoc<Cmp, BitOrExp> = BitOrExp => ActionFn(107);
+        let __sym0 = __pop_Variant7(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action107::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
+        (1, 41)
     }
-    pub(crate) fn __reduce2<
+    pub(crate) fn __reduce89<
         'input,
+        'err,
     >(
-        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // (<Exp> ",")* = (<Exp> ",")+ => ActionFn(72);
-        let __sym0 = __pop_Variant2(__symbols);
+        // LAssoc<PlusMinus, Product> = LAssoc<PlusMinus, Product>, PlusMinus, Product => ActionFn(216);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant7(__symbols);
+        let __sym1 = __pop_Variant14(__symbols);
+        let __sym0 = __pop_Variant7(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action72::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant2(__nt), __end));
-        (1, 1)
+        let __end = __sym2.2.clone();
+        let __nt = super::__action216::<>(errors, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
+        (3, 42)
     }
-    pub(crate) fn __reduce3<
+    pub(crate) fn __reduce90<
         'input,
+        'err,
     >(
-        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // (<Exp> ",")+ = Exp, "," => ActionFn(78);
-        assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant1(__symbols);
+        // LAssoc<PlusMinus, Product> = Product => ActionFn(97);
+        let __sym0 = __pop_Variant7(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym1.2.clone();
-        let __nt = super::__action78::<>(input, __sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant2(__nt), __end));
-        (2, 2)
+        let __end = __sym0.2.clone();
+        let __nt = super::__action97::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
+        (1, 42)
     }
-    pub(crate) fn __reduce4<
+    pub(crate) fn __reduce91<
         'input,
+        'err,
     >(
-        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // (<Exp> ",")+ = (<Exp> ",")+, Exp, "," => ActionFn(79);
+        // LAssoc<Shift, Sum> = LAssoc<Shift, Sum>, Shift, Sum => ActionFn(217);
         assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant0(__symbols);
-        let __sym1 = __pop_Variant1(__symbols);
-        let __sym0 = __pop_Variant2(__symbols);
+        let __sym2 = __pop_Variant7(__symbols);
+        let __sym1 = __pop_Variant14(__symbols);
+        let __sym0 = __pop_Variant7(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym2.2.clone();
-        let __nt = super::__action79::<>(input, __sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant2(__nt), __end));
-        (3, 2)
+        let __nt = super::__action217::<>(errors, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
+        (3, 43)
     }
-    pub(crate) fn __reduce5<
+    pub(crate) fn __reduce92<
         'input,
+        'err,
     >(
-        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // (<Id> ",") = Id, "," => ActionFn(68);
-        assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant3(__symbols);
+        // LAssoc<Shift, Sum> = Sum => ActionFn(99);
+        let __sym0 = __pop_Variant7(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym1.2.clone();
-        let __nt = super::__action68::<>(input, __sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
-        (2, 3)
+        let __end = __sym0.2.clone();
+        let __nt = super::__action99::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
+        (1, 43)
     }
-    pub(crate) fn __reduce6<
+    pub(crate) fn __reduce93<
         'input,
+        'err,
     >(
-        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // (<Id> ",")* =  => ActionFn(66);
-        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
-        let __end = __start.clone();
-        let __nt = super::__action66::<>(input, &__start, &__end);
-        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
-        (0, 4)
+        // LAssoc<Times, NotExp> = LAssoc<Times, NotExp>, Times, NotExp => ActionFn(218);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant7(__symbols);
+        let __sym1 = __pop_Variant14(__symbols);
+        let __sym0 = __pop_Variant7(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action218::<>(errors, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
+        (3, 44)
     }
-    pub(crate) fn __reduce7<
+    pub(crate) fn __reduce94<
         'input,
+        'err,
     >(
-        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // (<Id> ",")* = (<Id> ",")+ => ActionFn(67);
-        let __sym0 = __pop_Variant4(__symbols);
+        // LAssoc<Times, NotExp> = NotExp => ActionFn(95);
+        let __sym0 = __pop_Variant7(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action67::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
-        (1, 4)
+        let __nt = super::__action95::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
+        (1, 44)
     }
-    pub(crate) fn __reduce8<
+    pub(crate) fn __reduce95<
         'input,
+        'err,
     >(
-        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // (<Id> ",")+ = Id, "," => ActionFn(82);
-        assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant3(__symbols);
+        // LET = "let", Bindings, "in", Exp => ActionFn(219);
+        assert!(__symbols.len() >= 4);
+        let __sym3 = __pop_Variant7(__symbols);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant13(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym1.2.clone();
-        let __nt = super::__action82::<>(input, __sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
-        (2, 5)
+        let __end = __sym3.2.clone();
+        let __nt = super::__action219::<>(errors, __sym0, __sym1, __sym2, __sym3);
+        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
+        (4, 45)
     }
-    pub(crate) fn __reduce9<
+    pub(crate) fn __reduce96<
         'input,
+        'err,
     >(
-        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // (<Id> ",")+ = (<Id> ",")+, Id, "," => ActionFn(83);
-        assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant0(__symbols);
-        let __sym1 = __pop_Variant3(__symbols);
-        let __sym0 = __pop_Variant4(__symbols);
+        // ListExp = Exp => ActionFn(64);
+        let __sym0 = __pop_Variant7(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym2.2.clone();
-        let __nt = super::__action83::<>(input, __sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
-        (3, 5)
+        let __end = __sym0.2.clone();
+        let __nt = super::__action64::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
+        (1, 46)
     }
-    pub(crate) fn __reduce10<
+    pub(crate) fn __reduce97<
         'input,
+        'err,
     >(
-        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // (FunDecl "and") = FunDecl, "and" => ActionFn(60);
-        assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant15(__symbols);
+        // ListExp = Recovered => ActionFn(65);
+        let __sym0 = __pop_Variant7(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym1.2.clone();
-        let __nt = super::__action60::<>(input, __sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
-        (2, 6)
+        let __end = __sym0.2.clone();
+        let __nt = super::__action65::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
+        (1, 46)
     }
-    pub(crate) fn __reduce11<
+    pub(crate) fn __reduce98<
         'input,
+        'err,
     >(
-        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // (FunDecl "and")* =  => ActionFn(58);
-        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
-        let __end = __start.clone();
-        let __nt = super::__action58::<>(input, &__start, &__end);
-        __symbols.push((__start, __Symbol::Variant6(__nt), __end));
-        (0, 7)
+        // ListExp? = ListExp => ActionFn(132);
+        let __sym0 = __pop_Variant7(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action132::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant21(__nt), __end));
+        (1, 47)
     }
-    pub(crate) fn __reduce12<
+    pub(crate) fn __reduce99<
         'input,
+        'err,
     >(
-        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // (FunDecl "and")* = (FunDecl "and")+ => ActionFn(59);
-        let __sym0 = __pop_Variant6(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action59::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant6(__nt), __end));
-        (1, 7)
+        // ListExp? =  => ActionFn(133);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
+        let __end = __start.clone();
+        let __nt = super::__action133::<>(errors, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant21(__nt), __end));
+        (0, 47)
     }
-    pub(crate) fn __reduce13<
+    pub(crate) fn __reduce100<
         'input,
+        'err,
     >(
-        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // (FunDecl "and")+ = FunDecl, "and" => ActionFn(86);
-        assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant15(__symbols);
+        // Log = "&&" => ActionFn(39);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym1.2.clone();
-        let __nt = super::__action86::<>(input, __sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant6(__nt), __end));
-        (2, 8)
+        let __end = __sym0.2.clone();
+        let __nt = super::__action39::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant14(__nt), __end));
+        (1, 48)
     }
-    pub(crate) fn __reduce14<
+    pub(crate) fn __reduce101<
         'input,
+        'err,
     >(
-        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // (FunDecl "and")+ = (FunDecl "and")+, FunDecl, "and" => ActionFn(87);
-        assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant0(__symbols);
-        let __sym1 = __pop_Variant15(__symbols);
-        let __sym0 = __pop_Variant6(__symbols);
+        // Log = "||" => ActionFn(40);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym2.2.clone();
-        let __nt = super::__action87::<>(input, __sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant6(__nt), __end));
-        (3, 8)
+        let __end = __sym0.2.clone();
+        let __nt = super::__action40::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant14(__nt), __end));
+        (1, 48)
     }
-    pub(crate) fn __reduce15<
+    pub(crate) fn __reduce102<
         'input,
+        'err,
     >(
-        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // @L =  => ActionFn(61);
-        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
-        let __end = __start.clone();
-        let __nt = super::__action61::<>(input, &__start, &__end);
+        // LogExp = RAssoc<Log, CmpExp> => ActionFn(20);
+        let __sym0 = __pop_Variant7(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action20::<>(errors, __sym0);
         __symbols.push((__start, __Symbol::Variant7(__nt), __end));
-        (0, 9)
+        (1, 49)
     }
-    pub(crate) fn __reduce16<
+    pub(crate) fn __reduce103<
         'input,
+        'err,
     >(
-        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // @R =  => ActionFn(56);
-        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
-        let __end = __start.clone();
-        let __nt = super::__action56::<>(input, &__start, &__end);
+        // NotExp = "!", BaseExp => ActionFn(220);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant7(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym1.2.clone();
+        let __nt = super::__action220::<>(errors, __sym0, __sym1);
         __symbols.push((__start, __Symbol::Variant7(__nt), __end));
-        (0, 10)
+        (2, 50)
     }
-    pub(crate) fn __reduce17<
+    pub(crate) fn __reduce104<
         'input,
+        'err,
     >(
-        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // BaseExp = Id => ActionFn(105);
-        let __sym0 = __pop_Variant3(__symbols);
+        // NotExp = "-", BaseExp => ActionFn(221);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant7(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action105::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 11)
+        let __end = __sym1.2.clone();
+        let __nt = super::__action221::<>(errors, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
+        (2, 50)
     }
-    pub(crate) fn __reduce18<
+    pub(crate) fn __reduce105<
         'input,
+        'err,
     >(
-        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // BaseExp = Bool => ActionFn(106);
-        let __sym0 = __pop_Variant9(__symbols);
+        // NotExp = BaseExp => ActionFn(49);
+        let __sym0 = __pop_Variant7(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action106::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 11)
+        let __nt = super::__action49::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
+        (1, 50)
     }
-    pub(crate) fn __reduce19<
+    pub(crate) fn __reduce106<
         'input,
+        'err,
     >(
-        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // BaseExp = Num => ActionFn(107);
-        let __sym0 = __pop_Variant17(__symbols);
+        // Num = "number" => ActionFn(86);
+        let __sym0 = __pop_Variant2(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action107::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 11)
+        let __nt = super::__action86::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant2(__nt), __end));
+        (1, 51)
     }
-    pub(crate) fn __reduce20<
+    pub(crate) fn __reduce107<
         'input,
+        'err,
     >(
-        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // BaseExp = Prim1, "(", Exp, ")" => ActionFn(108);
-        assert!(__symbols.len() >= 4);
-        let __sym3 = __pop_Variant0(__symbols);
-        let __sym2 = __pop_Variant1(__symbols);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant11(__symbols);
+        // OpenExp = IF => ActionFn(11);
+        let __sym0 = __pop_Variant7(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym3.2.clone();
-        let __nt = super::__action108::<>(input, __sym0, __sym1, __sym2, __sym3);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (4, 11)
+        let __end = __sym0.2.clone();
+        let __nt = super::__action11::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
+        (1, 52)
     }
-    pub(crate) fn __reduce21<
+    pub(crate) fn __reduce108<
         'input,
+        'err,
     >(
-        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // BaseExp = "(", Exp, ")" => ActionFn(33);
-        assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant0(__symbols);
-        let __sym1 = __pop_Variant1(__symbols);
-        let __sym0 = __pop_Variant0(__symbols);
+        // OpenExp = LET => ActionFn(12);
+        let __sym0 = __pop_Variant7(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym2.2.clone();
-        let __nt = super::__action33::<>(input, __sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (3, 11)
+        let __end = __sym0.2.clone();
+        let __nt = super::__action12::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
+        (1, 52)
     }
-    pub(crate) fn __reduce22<
+    pub(crate) fn __reduce109<
         'input,
+        'err,
     >(
-        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // BaseExp = Id, "(", Comma<Exp>, ")" => ActionFn(109);
-        assert!(__symbols.len() >= 4);
-        let __sym3 = __pop_Variant0(__symbols);
-        let __sym2 = __pop_Variant12(__symbols);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant3(__symbols);
+        // OpenExp = FUNDEFS => ActionFn(13);
+        let __sym0 = __pop_Variant7(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym3.2.clone();
-        let __nt = super::__action109::<>(input, __sym0, __sym1, __sym2, __sym3);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (4, 11)
+        let __end = __sym0.2.clone();
+        let __nt = super::__action13::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
+        (1, 52)
     }
-    pub(crate) fn __reduce23<
+    pub(crate) fn __reduce110<
         'input,
+        'err,
     >(
-        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // BinOps = LogExp => ActionFn(11);
-        let __sym0 = __pop_Variant1(__symbols);
+        // OpenExp = SET<OpenExp> => ActionFn(14);
+        let __sym0 = __pop_Variant7(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action11::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 12)
+        let __nt = super::__action14::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
+        (1, 52)
     }
-    pub(crate) fn __reduce24<
+    pub(crate) fn __reduce111<
         'input,
+        'err,
     >(
-        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // Bindings = Id, "=", Exp => ActionFn(40);
-        assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant1(__symbols);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant3(__symbols);
+        // PlusMinus = "+" => ActionFn(28);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym2.2.clone();
-        let __nt = super::__action40::<>(input, __sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant8(__nt), __end));
-        (3, 13)
+        let __end = __sym0.2.clone();
+        let __nt = super::__action28::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant14(__nt), __end));
+        (1, 53)
     }
-    pub(crate) fn __reduce25<
+    pub(crate) fn __reduce112<
         'input,
+        'err,
     >(
-        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // Bindings = Bindings, ",", Id, "=", Exp => ActionFn(41);
-        assert!(__symbols.len() >= 5);
-        let __sym4 = __pop_Variant1(__symbols);
-        let __sym3 = __pop_Variant0(__symbols);
-        let __sym2 = __pop_Variant3(__symbols);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant8(__symbols);
+        // PlusMinus = "-" => ActionFn(29);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym4.2.clone();
-        let __nt = super::__action41::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4);
-        __symbols.push((__start, __Symbol::Variant8(__nt), __end));
-        (5, 13)
+        let __end = __sym0.2.clone();
+        let __nt = super::__action29::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant14(__nt), __end));
+        (1, 53)
     }
-    pub(crate) fn __reduce26<
+    pub(crate) fn __reduce113<
         'input,
+        'err,
     >(
-        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // Bool = "true" => ActionFn(44);
+        // PlusMinus = "++" => ActionFn(30);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action44::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant9(__nt), __end));
-        (1, 14)
+        let __nt = super::__action30::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant14(__nt), __end));
+        (1, 53)
     }
-    pub(crate) fn __reduce27<
+    pub(crate) fn __reduce114<
         'input,
+        'err,
     >(
-        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // Bool = "false" => ActionFn(45);
+        // Prim1 = "add1" => ActionFn(66);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action45::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant9(__nt), __end));
-        (1, 14)
+        let __nt = super::__action66::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant14(__nt), __end));
+        (1, 54)
     }
-    pub(crate) fn __reduce28<
+    pub(crate) fn __reduce115<
         'input,
+        'err,
     >(
-        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // Boxed<Exp> = Exp => ActionFn(57);
-        let __sym0 = __pop_Variant1(__symbols);
+        // Prim1 = "sub1" => ActionFn(67);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action57::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant10(__nt), __end));
-        (1, 15)
+        let __nt = super::__action67::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant14(__nt), __end));
+        (1, 54)
     }
-    pub(crate) fn __reduce29<
+    pub(crate) fn __reduce116<
         'input,
+        'err,
     >(
-        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // Cmp = "<" => ActionFn(21);
+        // Prim1 = "print" => ActionFn(68);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action21::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
-        (1, 16)
+        let __nt = super::__action68::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant14(__nt), __end));
+        (1, 54)
     }
-    pub(crate) fn __reduce30<
+    pub(crate) fn __reduce117<
         'input,
+        'err,
     >(
-        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // Cmp = "<=" => ActionFn(22);
+        // Prim1 = "isnum" => ActionFn(69);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action22::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
-        (1, 16)
+        let __nt = super::__action69::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant14(__nt), __end));
+        (1, 54)
     }
-    pub(crate) fn __reduce31<
+    pub(crate) fn __reduce118<
         'input,
+        'err,
     >(
-        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // Cmp = ">" => ActionFn(23);
+        // Prim1 = "isbool" => ActionFn(70);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action23::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
-        (1, 16)
+        let __nt = super::__action70::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant14(__nt), __end));
+        (1, 54)
     }
-    pub(crate) fn __reduce32<
+    pub(crate) fn __reduce119<
         'input,
+        'err,
     >(
-        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // Cmp = ">=" => ActionFn(24);
+        // Prim1 = "istuple" => ActionFn(71);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action24::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
-        (1, 16)
+        let __nt = super::__action71::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant14(__nt), __end));
+        (1, 54)
     }
-    pub(crate) fn __reduce33<
+    pub(crate) fn __reduce120<
         'input,
+        'err,
     >(
-        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // Cmp = "==" => ActionFn(25);
+        // Prim1 = "length" => ActionFn(72);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action25::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
-        (1, 16)
+        let __nt = super::__action72::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant14(__nt), __end));
+        (1, 54)
     }
-    pub(crate) fn __reduce34<
+    pub(crate) fn __reduce121<
         'input,
+        'err,
     >(
-        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // Cmp = "!=" => ActionFn(26);
+        // Prim1 = "abs" => ActionFn(73);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action26::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
-        (1, 16)
+        let __nt = super::__action73::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant14(__nt), __end));
+        (1, 54)
     }
-    pub(crate) fn __reduce35<
+    pub(crate) fn __reduce122<
         'input,
+        'err,
     >(
-        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // CmpExp = LAssoc<Cmp, Sum> => ActionFn(13);
-        let __sym0 = __pop_Variant1(__symbols);
+        // Prim1 = "isstring" => ActionFn(74);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action13::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 17)
+        let __nt = super::__action74::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant14(__nt), __end));
+        (1, 54)
     }
-    pub(crate) fn __reduce36<
+    pub(crate) fn __reduce123<
         'input,
+        'err,
     >(
-        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // Comma<Exp> = Exp => ActionFn(120);
-        let __sym0 = __pop_Variant1(__symbols);
+        // Prim1 = "strlen" => ActionFn(75);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action120::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant12(__nt), __end));
-        (1, 18)
+        let __nt = super::__action75::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant14(__nt), __end));
+        (1, 54)
     }
-    pub(crate) fn __reduce37<
+    pub(crate) fn __reduce124<
         'input,
+        'err,
     >(
-        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // Comma<Exp> =  => ActionFn(121);
-        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
-        let __end = __start.clone();
-        let __nt = super::__action121::<>(input, &__start, &__end);
-        __symbols.push((__start, __Symbol::Variant12(__nt), __end));
-        (0, 18)
+        // Prim1 = "num_to_string" => ActionFn(76);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action76::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant14(__nt), __end));
+        (1, 54)
     }
-    pub(crate) fn __reduce38<
+    pub(crate) fn __reduce125<
         'input,
+        'err,
     >(
-        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // Comma<Exp> = (<Exp> ",")+, Exp => ActionFn(122);
-        assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant1(__symbols);
-        let __sym0 = __pop_Variant2(__symbols);
+        // Prim1 = "string_to_num" => ActionFn(77);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym1.2.clone();
-        let __nt = super::__action122::<>(input, __sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant12(__nt), __end));
-        (2, 18)
+        let __end = __sym0.2.clone();
+        let __nt = super::__action77::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant14(__nt), __end));
+        (1, 54)
     }
-    pub(crate) fn __reduce39<
+    pub(crate) fn __reduce126<
         'input,
+        'err,
     >(
-        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // Comma<Exp> = (<Exp> ",")+ => ActionFn(123);
-        let __sym0 = __pop_Variant2(__symbols);
+        // Prim2 = "min" => ActionFn(78);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action123::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant12(__nt), __end));
-        (1, 18)
+        let __nt = super::__action78::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant14(__nt), __end));
+        (1, 55)
     }
-    pub(crate) fn __reduce40<
+    pub(crate) fn __reduce127<
         'input,
+        'err,
     >(
-        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // Comma<Id> = Id => ActionFn(124);
-        let __sym0 = __pop_Variant3(__symbols);
+        // Prim2 = "max" => ActionFn(79);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action124::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant13(__nt), __end));
-        (1, 19)
+        let __nt = super::__action79::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant14(__nt), __end));
+        (1, 55)
     }
-    pub(crate) fn __reduce41<
+    pub(crate) fn __reduce128<
         'input,
+        'err,
     >(
-        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // Comma<Id> =  => ActionFn(125);
-        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
-        let __end = __start.clone();
-        let __nt = super::__action125::<>(input, &__start, &__end);
-        __symbols.push((__start, __Symbol::Variant13(__nt), __end));
-        (0, 19)
+        // Prim2 = "char_at" => ActionFn(80);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action80::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant14(__nt), __end));
+        (1, 55)
     }
-    pub(crate) fn __reduce42<
+    pub(crate) fn __reduce129<
         'input,
+        'err,
     >(
-        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // Comma<Id> = (<Id> ",")+, Id => ActionFn(126);
-        assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant3(__symbols);
-        let __sym0 = __pop_Variant4(__symbols);
+        // Prim3 = "substr" => ActionFn(81);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym1.2.clone();
-        let __nt = super::__action126::<>(input, __sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant13(__nt), __end));
-        (2, 19)
+        let __end = __sym0.2.clone();
+        let __nt = super::__action81::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant14(__nt), __end));
+        (1, 56)
     }
-    pub(crate) fn __reduce43<
+    pub(crate) fn __reduce130<
         'input,
+        'err,
     >(
-        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // Comma<Id> = (<Id> ",")+ => ActionFn(127);
-        let __sym0 = __pop_Variant4(__symbols);
+        // Product = LAssoc<Times, NotExp> => ActionFn(27);
+        let __sym0 = __pop_Variant7(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action127::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant13(__nt), __end));
-        (1, 19)
+        let __nt = super::__action27::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
+        (1, 57)
     }
-    pub(crate) fn __reduce44<
+    pub(crate) fn __reduce131<
         'input,
+        'err,
     >(
-        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // Exp = IF => ActionFn(5);
-        let __sym0 = __pop_Variant1(__symbols);
+        // Prog = Exp => ActionFn(2);
+        let __sym0 = __pop_Variant7(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action5::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 20)
+        let __nt = super::__action2::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant22(__nt), __end));
+        (1, 58)
     }
-    pub(crate) fn __reduce45<
+    pub(crate) fn __reduce132<
         'input,
+        'err,
     >(
-        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // Exp = LET => ActionFn(6);
-        let __sym0 = __pop_Variant1(__symbols);
+        // RAssoc<Log, CmpExp> = CmpExp, Log, RAssoc<Log, CmpExp> => ActionFn(222);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant7(__symbols);
+        let __sym1 = __pop_Variant14(__symbols);
+        let __sym0 = __pop_Variant7(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action6::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 20)
+        let __end = __sym2.2.clone();
+        let __nt = super::__action222::<>(errors, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
+        (3, 59)
     }
-    pub(crate) fn __reduce46<
+    pub(crate) fn __reduce133<
         'input,
+        'err,
     >(
-        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // Exp = FUNDEFS => ActionFn(7);
-        let __sym0 = __pop_Variant1(__symbols);
+        // RAssoc<Log, CmpExp> = CmpExp => ActionFn(109);
+        let __sym0 = __pop_Variant7(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action7::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 20)
+        let __nt = super::__action109::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
+        (1, 59)
     }
-    pub(crate) fn __reduce47<
+    pub(crate) fn __reduce134<
         'input,
+        'err,
     >(
-        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // Exp = BinOps => ActionFn(8);
-        let __sym0 = __pop_Variant1(__symbols);
+        // Recovered = error => ActionFn(223);
+        let __sym0 = __pop_Variant3(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action8::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 20)
+        let __nt = super::__action223::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
+        (1, 60)
     }
-    pub(crate) fn __reduce48<
+    pub(crate) fn __reduce135<
         'input,
+        'err,
     >(
-        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // Exp? = Exp => ActionFn(69);
-        let __sym0 = __pop_Variant1(__symbols);
+        // SEQ = Boxed<ClosedExp>, ";", Boxed<Exp> => ActionFn(224);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant16(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant16(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action69::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant14(__nt), __end));
-        (1, 21)
+        let __end = __sym2.2.clone();
+        let __nt = super::__action224::<>(errors, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
+        (3, 61)
     }
-    pub(crate) fn __reduce49<
+    pub(crate) fn __reduce136<
         'input,
+        'err,
     >(
-        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // Exp? =  => ActionFn(70);
-        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
-        let __end = __start.clone();
-        let __nt = super::__action70::<>(input, &__start, &__end);
-        __symbols.push((__start, __Symbol::Variant14(__nt), __end));
-        (0, 21)
+        // SET<ClosedExp> = BaseExp, "[", Exp, "]", ":=", ClosedExp => ActionFn(225);
+        assert!(__symbols.len() >= 6);
+        let __sym5 = __pop_Variant7(__symbols);
+        let __sym4 = __pop_Variant0(__symbols);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant7(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant7(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym5.2.clone();
+        let __nt = super::__action225::<>(errors, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5);
+        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
+        (6, 62)
     }
-    pub(crate) fn __reduce50<
+    pub(crate) fn __reduce137<
         'input,
+        'err,
     >(
-        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // FUNDEFS = FunDecl, "in", Boxed<Exp> => ActionFn(110);
+        // SET<ClosedExp> = Id, ":=", Boxed<ClosedExp> => ActionFn(226);
         assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant10(__symbols);
+        let __sym2 = __pop_Variant16(__symbols);
         let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant15(__symbols);
+        let __sym0 = __pop_Variant5(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym2.2.clone();
-        let __nt = super::__action110::<>(input, __sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (3, 22)
+        let __nt = super::__action226::<>(errors, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
+        (3, 62)
     }
-    pub(crate) fn __reduce51<
+    pub(crate) fn __reduce138<
         'input,
+        'err,
     >(
-        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // FUNDEFS = (FunDecl "and")+, FunDecl, "in", Boxed<Exp> => ActionFn(111);
-        assert!(__symbols.len() >= 4);
-        let __sym3 = __pop_Variant10(__symbols);
-        let __sym2 = __pop_Variant0(__symbols);
-        let __sym1 = __pop_Variant15(__symbols);
-        let __sym0 = __pop_Variant6(__symbols);
+        // SET<OpenExp> = BaseExp, "[", Exp, "]", ":=", OpenExp => ActionFn(227);
+        assert!(__symbols.len() >= 6);
+        let __sym5 = __pop_Variant7(__symbols);
+        let __sym4 = __pop_Variant0(__symbols);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant7(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant7(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym3.2.clone();
-        let __nt = super::__action111::<>(input, __sym0, __sym1, __sym2, __sym3);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (4, 22)
+        let __end = __sym5.2.clone();
+        let __nt = super::__action227::<>(errors, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5);
+        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
+        (6, 63)
     }
-    pub(crate) fn __reduce52<
-        'input,
-    >(
-        input: &'input str,
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
-    ) -> (usize, usize)
-    {
-        // FunDecl = "def", Id, "(", Comma<Id>, ")", ":", Exp => ActionFn(112);
-        assert!(__symbols.len() >= 7);
-        let __sym6 = __pop_Variant1(__symbols);
-        let __sym5 = __pop_Variant0(__symbols);
-        let __sym4 = __pop_Variant0(__symbols);
-        let __sym3 = __pop_Variant13(__symbols);
-        let __sym2 = __pop_Variant0(__symbols);
-        let __sym1 = __pop_Variant3(__symbols);
-        let __sym0 = __pop_Variant0(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym6.2.clone();
-        let __nt = super::__action112::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5, __sym6);
-        __symbols.push((__start, __Symbol::Variant15(__nt), __end));
-        (7, 23)
-    }
-    pub(crate) fn __reduce53<
-        'input,
-    >(
-        input: &'input str,
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
-    ) -> (usize, usize)
-    {
-        // IF = "if", Exp, ":", Exp, "else", ":", Exp => ActionFn(113);
-        assert!(__symbols.len() >= 7);
-        let __sym6 = __pop_Variant1(__symbols);
-        let __sym5 = __pop_Variant0(__symbols);
-        let __sym4 = __pop_Variant0(__symbols);
-        let __sym3 = __pop_Variant1(__symbols);
-        let __sym2 = __pop_Variant0(__symbols);
-        let __sym1 = __pop_Variant1(__symbols);
-        let __sym0 = __pop_Variant0(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym6.2.clone();
-        let __nt = super::__action113::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5, __sym6);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (7, 24)
-    }
-    pub(crate) fn __reduce54<
-        'input,
-    >(
-        input: &'input str,
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
-    ) -> (usize, usize)
-    {
-        // Id = r#"[a-zA-Z][a-zA-Z0-9_]*"# => ActionFn(43);
-        let __sym0 = __pop_Variant0(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action43::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
-        (1, 25)
-    }
-    pub(crate) fn __reduce55<
-        'input,
-    >(
-        input: &'input str,
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
-    ) -> (usize, usize)
-    {
-        // Id? = Id => ActionFn(64);
-        let __sym0 = __pop_Variant3(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action64::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant16(__nt), __end));
-        (1, 26)
-    }
-    pub(crate) fn __reduce56<
-        'input,
-    >(
-        input: &'input str,
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
-    ) -> (usize, usize)
-    {
-        // Id? =  => ActionFn(65);
-        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
-        let __end = __start.clone();
-        let __nt = super::__action65::<>(input, &__start, &__end);
-        __symbols.push((__start, __Symbol::Variant16(__nt), __end));
-        (0, 26)
-    }
-    pub(crate) fn __reduce57<
-        'input,
-    >(
-        input: &'input str,
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
-    ) -> (usize, usize)
-    {
-        // LAssoc<Cmp, Sum> = LAssoc<Cmp, Sum>, Cmp, Sum => ActionFn(114);
-        assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant1(__symbols);
-        let __sym1 = __pop_Variant11(__symbols);
-        let __sym0 = __pop_Variant1(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym2.2.clone();
-        let __nt = super::__action114::<>(input, __sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (3, 27)
-    }
-    pub(crate) fn __reduce58<
-        'input,
-    >(
-        input: &'input str,
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
-    ) -> (usize, usize)
-    {
-        // LAssoc<Cmp, Sum> = Sum => ActionFn(52);
-        let __sym0 = __pop_Variant1(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action52::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 27)
-    }
-    pub(crate) fn __reduce59<
-        'input,
-    >(
-        input: &'input str,
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
-    ) -> (usize, usize)
-    {
-        // LAssoc<PlusMinus, Product> = LAssoc<PlusMinus, Product>, PlusMinus, Product => ActionFn(115);
-        assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant1(__symbols);
-        let __sym1 = __pop_Variant11(__symbols);
-        let __sym0 = __pop_Variant1(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym2.2.clone();
-        let __nt = super::__action115::<>(input, __sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (3, 28)
-    }
-    pub(crate) fn __reduce60<
-        'input,
-    >(
-        input: &'input str,
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
-    ) -> (usize, usize)
-    {
-        // LAssoc<PlusMinus, Product> = Product => ActionFn(50);
-        let __sym0 = __pop_Variant1(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action50::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 28)
-    }
-    pub(crate) fn __reduce61<
+    pub(crate) fn __reduce139<
         'input,
+        'err,
     >(
-        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // LAssoc<Times, NotExp> = LAssoc<Times, NotExp>, Times, NotExp => ActionFn(116);
+        // SET<OpenExp> = Id, ":=", Boxed<OpenExp> => ActionFn(228);
         assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant1(__symbols);
-        let __sym1 = __pop_Variant11(__symbols);
-        let __sym0 = __pop_Variant1(__symbols);
+        let __sym2 = __pop_Variant16(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant5(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym2.2.clone();
-        let __nt = super::__action116::<>(input, __sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (3, 29)
-    }
-    pub(crate) fn __reduce62<
-        'input,
-    >(
-        input: &'input str,
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
-    ) -> (usize, usize)
-    {
-        // LAssoc<Times, NotExp> = NotExp => ActionFn(48);
-        let __sym0 = __pop_Variant1(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action48::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 29)
-    }
-    pub(crate) fn __reduce63<
-        'input,
-    >(
-        input: &'input str,
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
-    ) -> (usize, usize)
-    {
-        // LET = "let", Bindings, "in", Exp => ActionFn(117);
-        assert!(__symbols.len() >= 4);
-        let __sym3 = __pop_Variant1(__symbols);
-        let __sym2 = __pop_Variant0(__symbols);
-        let __sym1 = __pop_Variant8(__symbols);
-        let __sym0 = __pop_Variant0(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym3.2.clone();
-        let __nt = super::__action117::<>(input, __sym0, __sym1, __sym2, __sym3);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (4, 30)
+        let __nt = super::__action228::<>(errors, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
+        (3, 63)
     }
-    pub(crate) fn __reduce64<
+    pub(crate) fn __reduce140<
         'input,
+        'err,
     >(
-        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // Log = "&&" => ActionFn(19);
+        // Shift = "<<" => ActionFn(37);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action19::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
-        (1, 31)
+        let __nt = super::__action37::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant14(__nt), __end));
+        (1, 64)
     }
-    pub(crate) fn __reduce65<
+    pub(crate) fn __reduce141<
         'input,
+        'err,
     >(
-        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // Log = "||" => ActionFn(20);
+        // Shift = ">>" => ActionFn(38);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action20::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
-        (1, 31)
+        let __nt = super::__action38::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant14(__nt), __end));
+        (1, 64)
     }
-    pub(crate) fn __reduce66<
+    pub(crate) fn __reduce142<
         'input,
+        'err,
     >(
-        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // LogExp = RAssoc<Log, CmpExp> => ActionFn(12);
-        let __sym0 = __pop_Variant1(__symbols);
+        // ShiftExp = LAssoc<Shift, Sum> => ActionFn(25);
+        let __sym0 = __pop_Variant7(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action12::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 32)
-    }
-    pub(crate) fn __reduce67<
-        'input,
-    >(
-        input: &'input str,
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
-    ) -> (usize, usize)
-    {
-        // NotExp = "!", BaseExp => ActionFn(118);
-        assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant1(__symbols);
-        let __sym0 = __pop_Variant0(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym1.2.clone();
-        let __nt = super::__action118::<>(input, __sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (2, 33)
+        let __nt = super::__action25::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
+        (1, 65)
     }
-    pub(crate) fn __reduce68<
+    pub(crate) fn __reduce143<
         'input,
+        'err,
     >(
-        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // NotExp = BaseExp => ActionFn(28);
+        // Str = "string" => ActionFn(87);
         let __sym0 = __pop_Variant1(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action28::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 33)
-    }
-    pub(crate) fn __reduce69<
-        'input,
-    >(
-        input: &'input str,
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
-    ) -> (usize, usize)
-    {
-        // Num = r#"[+-]?[0-9]+"# => ActionFn(42);
-        let __sym0 = __pop_Variant0(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action42::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant17(__nt), __end));
-        (1, 34)
-    }
-    pub(crate) fn __reduce70<
-        'input,
-    >(
-        input: &'input str,
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
-    ) -> (usize, usize)
-    {
-        // PlusMinus = "+" => ActionFn(16);
-        let __sym0 = __pop_Variant0(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action16::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
-        (1, 35)
+        let __nt = super::__action87::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
+        (1, 66)
     }
-    pub(crate) fn __reduce71<
+    pub(crate) fn __reduce144<
         'input,
+        'err,
     >(
-        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // PlusMinus = "-" => ActionFn(17);
-        let __sym0 = __pop_Variant0(__symbols);
+        // Sum = LAssoc<PlusMinus, Product> => ActionFn(26);
+        let __sym0 = __pop_Variant7(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action17::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
-        (1, 35)
+        let __nt = super::__action26::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
+        (1, 67)
     }
-    pub(crate) fn __reduce72<
+    pub(crate) fn __reduce145<
         'input,
+        'err,
     >(
-        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // Prim1 = "add1" => ActionFn(35);
+        // Times = "*" => ActionFn(31);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action35::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
-        (1, 36)
+        let __nt = super::__action31::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant14(__nt), __end));
+        (1, 68)
     }
-    pub(crate) fn __reduce73<
+    pub(crate) fn __reduce146<
         'input,
+        'err,
     >(
-        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // Prim1 = "sub1" => ActionFn(36);
+        // Times = "/" => ActionFn(32);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action36::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
-        (1, 36)
+        let __nt = super::__action32::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant14(__nt), __end));
+        (1, 68)
     }
-    pub(crate) fn __reduce74<
+    pub(crate) fn __reduce147<
         'input,
+        'err,
     >(
-        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // Prim1 = "print" => ActionFn(37);
+        // Times = "%" => ActionFn(33);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action37::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
-        (1, 36)
+        let __nt = super::__action33::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant14(__nt), __end));
+        (1, 68)
     }
-    pub(crate) fn __reduce75<
+    pub(crate) fn __reduce149<
         'input,
+        'err,
     >(
-        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
     ) -> (usize, usize)
     {
-        // Prim1 = "isnum" => ActionFn(38);
-        let __sym0 = __pop_Variant0(__symbols);
+        // __Prog = Prog => ActionFn(0);
+        let __sym0 = __pop_Variant22(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action38::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
-        (1, 36)
+        let __nt = super::__action0::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant22(__nt), __end));
+        (1, 70)
     }
-    pub(crate) fn __reduce76<
-        'input,
-    >(
-        input: &'input str,
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
-    ) -> (usize, usize)
-    {
-        // Prim1 = "isbool" => ActionFn(39);
-        let __sym0 = __pop_Variant0(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action39::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
-        (1, 36)
+}
+pub use self::__parse__Exp::ExpParser;
+
+#[cfg_attr(rustfmt, rustfmt_skip)]
+mod __parse__Prog {
+    #![allow(non_snake_case, non_camel_case_types, unused_mut, unused_variables, unused_imports, unused_parens, clippy::all)]
+
+    use crate::syntax::{FunDecl, SurfProg, SurfFunDecl, Exp, Prim};
+    use crate::span::Span1;
+    use crate::lexer::{unescape, LexError, Tok};
+    use lalrpop_util::ErrorRecovery;
+    #[allow(unused_extern_crates)]
+    extern crate lalrpop_util as __lalrpop_util;
+    #[allow(unused_imports)]
+    use self::__lalrpop_util::state_machine as __state_machine;
+    extern crate core;
+    extern crate alloc;
+    use super::__ToTriple;
+    #[allow(dead_code)]
+    pub(crate) enum __Symbol<'input>
+     {
+        Variant0(Tok<'input>),
+        Variant1(&'input str),
+        Variant2(i64),
+        Variant3(__lalrpop_util::ErrorRecovery<usize, Tok<'input>, LexError>),
+        Variant4(core::option::Option<Tok<'input>>),
+        Variant5(String),
+        Variant6(alloc::vec::Vec<String>),
+        Variant7(Exp<Span1>),
+        Variant8(alloc::vec::Vec<Exp<Span1>>),
+        Variant9((SurfFunDecl<Span1>, Tok<'input>)),
+        Variant10(alloc::vec::Vec<(SurfFunDecl<Span1>, Tok<'input>)>),
+        Variant11(usize),
+        Variant12((String, Exp<Span1>)),
+        Variant13(Vec<(String, Exp<Span1>)>),
+        Variant14(Prim),
+        Variant15(bool),
+        Variant16(Box<Exp<Span1>>),
+        Variant17(Vec<String>),
+        Variant18(Vec<Exp<Span1>>),
+        Variant19(SurfFunDecl<Span1>),
+        Variant20(core::option::Option<String>),
+        Variant21(core::option::Option<Exp<Span1>>),
+        Variant22(SurfProg<Span1>),
     }
-    pub(crate) fn __reduce77<
-        'input,
-    >(
-        input: &'input str,
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
-    ) -> (usize, usize)
-    {
-        // Product = LAssoc<Times, NotExp> => ActionFn(15);
-        let __sym0 = __pop_Variant1(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action15::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 37)
-    }
-    pub(crate) fn __reduce78<
-        'input,
-    >(
-        input: &'input str,
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
-    ) -> (usize, usize)
-    {
-        // Prog = Exp => ActionFn(2);
-        let __sym0 = __pop_Variant1(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action2::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant18(__nt), __end));
-        (1, 38)
-    }
-    pub(crate) fn __reduce79<
-        'input,
-    >(
-        input: &'input str,
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
-    ) -> (usize, usize)
-    {
-        // RAssoc<Log, CmpExp> = CmpExp, Log, RAssoc<Log, CmpExp> => ActionFn(119);
-        assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant1(__symbols);
-        let __sym1 = __pop_Variant11(__symbols);
-        let __sym0 = __pop_Variant1(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym2.2.clone();
-        let __nt = super::__action119::<>(input, __sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (3, 39)
-    }
-    pub(crate) fn __reduce80<
-        'input,
-    >(
-        input: &'input str,
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
-    ) -> (usize, usize)
-    {
-        // RAssoc<Log, CmpExp> = CmpExp => ActionFn(54);
-        let __sym0 = __pop_Variant1(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action54::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 39)
-    }
-    pub(crate) fn __reduce81<
-        'input,
-    >(
-        input: &'input str,
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
-    ) -> (usize, usize)
-    {
-        // Sum = LAssoc<PlusMinus, Product> => ActionFn(14);
-        let __sym0 = __pop_Variant1(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action14::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 40)
+    const __ACTION: &[i16] = &[
+        // State 0
+        11, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 13, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 14, 0, 0, 89, 90, 0, 91, 15, 0, 0, 92, 16, 93, 17, 0, 94, 95, 96, 97, 18, 98, 19, 99, 100, 101, 102, 103, 104, 105, 106, 107, 108, 0, 109, 20, 0, 0, 0,
+        // State 1
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 15, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 2
+        0, 0, 0, 0, 111, 0, -134, 0, 0, 0, -134, 0, 0, -134, 0, -134, 0, 0, 0, 0, 0, 0, 0, 0, 0, -134, 0, 0, 0, -134, 0, 0, -134, -134, 0, 0, 0, 0, -134, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -134, 0, 0, 0, 112, 0,
+        // State 3
+        0, -43, 0, 114, -43, 0, -43, 0, 0, 0, -43, 0, 0, -43, 0, -43, -43, 0, -43, 0, -43, -43, -43, 0, 0, -43, -43, 0, 0, -43, 0, 0, -43, -43, 0, 0, 0, 0, -43, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -43, 0, 0, -43, -43, 0,
+        // State 4
+        0, -45, 0, 0, -45, 0, -45, 0, 0, 0, -45, 0, 0, -45, 0, -45, -45, 0, -45, 0, -45, -45, -45, 0, 0, -45, 0, 0, 0, -45, 0, 0, -45, -45, 0, 0, 0, 0, -45, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -45, 0, 0, 115, -45, 0,
+        // State 5
+        0, -47, 0, 0, -47, 0, -47, 0, 0, 0, -47, 0, 0, -47, 0, -47, -47, 0, -47, 0, -47, -47, -47, 0, 0, -47, 116, 0, 0, -47, 0, 0, -47, -47, 0, 0, 0, 0, -47, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -47, 0, 0, -47, -47, 0,
+        // State 6
+        0, 117, 0, 0, -61, 0, -61, 0, 0, 0, -61, 0, 0, -61, 0, -61, 118, 0, 119, 0, 120, 121, 122, 0, 0, -61, 0, 0, 0, -61, 0, 0, -61, -61, 0, 0, 0, 0, -61, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -61, 0, 0, 0, -61, 0,
+        // State 7
+        0, -145, 0, -145, -145, 0, -145, 0, 123, 124, -145, 125, 0, -145, 0, -145, -145, -145, -145, 0, -145, -145, -145, -145, 0, -145, -145, 0, 0, -145, 0, 0, -145, -145, 0, 0, 0, 0, -145, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -145, 0, 0, -145, -145, 0,
+        // State 8
+        0, -143, 0, -143, -143, 0, -143, 0, 0, 0, -143, 0, 0, -143, 0, -143, -143, 126, -143, 0, -143, -143, -143, 127, 0, -143, -143, 0, 0, -143, 0, 0, -143, -143, 0, 0, 0, 0, -143, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -143, 0, 0, -143, -143, 0,
+        // State 9
+        0, -131, 128, -131, -131, 0, -131, 129, -131, -131, -131, -131, 130, -131, 0, -131, -131, -131, -131, 0, -131, -131, -131, -131, 0, -131, -131, 0, 0, -131, 0, 0, -131, -131, 0, 0, 0, 0, -131, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -131, 0, 0, -131, -131, 0,
+        // State 10
+        0, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 14, 0, 0, 89, 90, 0, 91, 0, 0, 0, 92, 16, 93, 0, 0, 94, 95, 96, 97, 18, 98, 0, 99, 100, 101, 102, 103, 104, 105, 106, 107, 108, 0, 109, 20, 0, 0, 0,
+        // State 11
+        11, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 13, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 14, 0, 0, 89, 90, 0, 91, 15, 0, 0, 92, 16, 93, 17, 0, 94, 95, 96, 97, 18, 98, 19, 99, 100, 101, 102, 103, 104, 105, 106, 107, 108, 0, 109, 20, 0, 0, 0,
+        // State 12
+        0, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 14, 0, 0, 89, 90, 0, 91, 0, 0, 0, 92, 16, 93, 0, 0, 94, 95, 96, 97, 18, 98, 0, 99, 100, 101, 102, 103, 104, 105, 106, 107, 108, 0, 109, 20, 0, 0, 0,
+        // State 13
+        11, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 13, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 14, -67, 0, 89, 90, 0, 91, 15, 0, 0, 92, 16, 93, 17, 0, 94, 95, 96, 97, 18, 98, 19, 99, 100, 101, 102, 103, 104, 105, 106, 107, 108, 0, 109, 20, 0, 0, 139,
+        // State 14
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 93, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 15
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 93, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 16
+        11, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 13, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 14, 0, 0, 89, 90, 0, 91, 15, 0, 0, 92, 16, 93, 17, 0, 94, 95, 96, 97, 18, 98, 19, 99, 100, 101, 102, 103, 104, 105, 106, 107, 108, 0, 109, 20, 0, 0, 0,
+        // State 17
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -63, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 93, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 18
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 93, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 19
+        11, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 13, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 14, 0, 0, 89, 90, 0, 91, 15, 0, 0, 92, 16, 93, 17, 0, 94, 95, 96, 97, 18, 98, 19, 99, 100, 101, 102, 103, 104, 105, 106, 107, 108, 0, 109, 20, 0, 0, 0,
+        // State 20
+        11, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 13, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 14, 0, 0, 89, 90, 0, 91, 15, 0, 0, 92, 16, 93, 17, 0, 94, 95, 96, 97, 18, 98, 19, 99, 100, 101, 102, 103, 104, 105, 106, 107, 108, 0, 109, 20, 0, 0, 0,
+        // State 21
+        11, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 13, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 14, 0, 0, 89, 90, 0, 91, 15, 0, 0, 92, 16, 93, 17, 0, 94, 95, 96, 97, 18, 98, 19, 99, 100, 101, 102, 103, 104, 105, 106, 107, 108, 0, 109, 20, 0, 0, 0,
+        // State 22
+        11, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 13, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 14, 0, 0, 89, 90, 0, 91, 0, 0, 0, 92, 16, 93, 0, 0, 94, 95, 96, 97, 18, 98, 0, 99, 100, 101, 102, 103, 104, 105, 106, 107, 108, 0, 109, 20, 0, 0, 0,
+        // State 23
+        11, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 13, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 14, 0, 0, 89, 90, 0, 91, 15, 0, 0, 92, 16, 93, 17, 0, 94, 95, 96, 97, 18, 98, 19, 99, 100, 101, 102, 103, 104, 105, 106, 107, 108, 0, 109, 20, 0, 0, 0,
+        // State 24
+        11, 0, 0, 0, 0, 12, -67, 0, 0, 0, 0, 13, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 14, 0, 0, 89, 90, 0, 91, 15, 0, 0, 92, 16, 93, 17, 0, 94, 95, 96, 97, 18, 98, 19, 99, 100, 101, 102, 103, 104, 105, 106, 107, 108, 0, 109, 20, 0, 0, 139,
+        // State 25
+        11, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 13, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 14, 0, 0, 89, 90, 0, 91, 15, 0, 0, 92, 16, 93, 17, 0, 94, 95, 96, 97, 18, 98, 19, 99, 100, 101, 102, 103, 104, 105, 106, 107, 108, 0, 109, 20, 0, 0, 0,
+        // State 26
+        11, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 13, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 14, 0, 0, 89, 90, 0, 91, 0, 0, 0, 92, 16, 93, 0, 0, 94, 95, 96, 97, 18, 98, 0, 99, 100, 101, 102, 103, 104, 105, 106, 107, 108, 0, 109, 20, 0, 0, 0,
+        // State 27
+        11, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 13, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 14, 0, 0, 89, 90, 0, 91, 0, 0, 0, 92, 16, 93, 0, 0, 94, 95, 96, 97, 18, 98, 0, 99, 100, 101, 102, 103, 104, 105, 106, 107, 108, 0, 109, 20, 0, 0, 0,
+        // State 28
+        11, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 13, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 14, 0, 0, 89, 90, 0, 91, 0, 0, 0, 92, 16, 93, 0, 0, 94, 95, 96, 97, 18, 98, 0, 99, 100, 101, 102, 103, 104, 105, 106, 107, 108, 0, 109, 20, 0, 0, 0,
+        // State 29
+        11, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 13, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 14, 0, 0, 89, 90, 0, 91, 0, 0, 0, 92, 16, 93, 0, 0, 94, 95, 96, 97, 18, 98, 0, 99, 100, 101, 102, 103, 104, 105, 106, 107, 108, 0, 109, 20, 0, 0, 0,
+        // State 30
+        11, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 13, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 14, 0, 0, 89, 90, 0, 91, 0, 0, 0, 92, 16, 93, 0, 0, 94, 95, 96, 97, 18, 98, 0, 99, 100, 101, 102, 103, 104, 105, 106, 107, 108, 0, 109, 20, 0, 0, 0,
+        // State 31
+        11, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 13, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 14, 0, 0, 89, 90, 0, 91, 0, 0, 0, 92, 16, 93, 0, 0, 94, 95, 96, 97, 18, 98, 0, 99, 100, 101, 102, 103, 104, 105, 106, 107, 108, 0, 109, 20, 0, 0, 0,
+        // State 32
+        11, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 13, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 14, 0, 0, 89, 90, 0, 91, 0, 0, 0, 92, 16, 93, 0, 0, 94, 95, 96, 97, 18, 98, 0, 99, 100, 101, 102, 103, 104, 105, 106, 107, 108, 0, 109, 20, 0, 0, 0,
+        // State 33
+        11, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 13, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 14, 0, 0, 89, 90, 0, 91, 15, 0, 0, 92, 16, 93, 17, 0, 94, 95, 96, 97, 18, 98, 19, 99, 100, 101, 102, 103, 104, 105, 106, 107, 108, 0, 109, 20, 0, 0, 0,
+        // State 34
+        11, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 13, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 14, 0, 0, 89, 90, 0, 91, 15, 0, 0, 92, 16, 93, 17, 0, 94, 95, 96, 97, 18, 98, 19, 99, 100, 101, 102, 103, 104, 105, 106, 107, 108, 0, 109, 20, 0, 0, 0,
+        // State 35
+        11, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 13, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 14, 0, 0, 89, 90, 0, 91, 15, 0, 0, 92, 16, 93, 17, 0, 94, 95, 96, 97, 18, 98, 19, 99, 100, 101, 102, 103, 104, 105, 106, 107, 108, 0, 109, 20, 0, 0, 0,
+        // State 36
+        11, 0, 0, 0, 0, 12, -69, 0, 0, 0, 0, 13, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 14, -69, 0, 89, 90, 0, 91, 15, 0, 0, 92, 16, 93, 17, 0, 94, 95, 96, 97, 18, 98, 19, 99, 100, 101, 102, 103, 104, 105, 106, 107, 108, 0, 109, 20, 0, 0, 139,
+        // State 37
+        0, 0, 0, 0, 0, 0, -65, 0, 0, 0, 0, 0, 0, -65, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 93, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 38
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 48, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 139,
+        // State 39
+        11, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 13, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 14, 0, 0, 89, 90, 0, 91, 15, 0, 0, 92, 16, 93, 17, 0, 94, 95, 96, 97, 18, 98, 19, 99, 100, 101, 102, 103, 104, 105, 106, 107, 108, 0, 109, 20, 0, 0, 0,
+        // State 40
+        11, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 13, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 14, 0, 0, 89, 90, 0, 91, 15, 0, 0, 92, 16, 93, 17, 0, 94, 95, 96, 97, 18, 98, 19, 99, 100, 101, 102, 103, 104, 105, 106, 107, 108, 0, 109, 20, 0, 0, 0,
+        // State 41
+        0, 0, 0, 0, 0, 0, -63, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 93, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 42
+        11, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 13, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 14, 0, 0, 89, 90, 0, 91, 15, 0, 0, 92, 16, 93, 17, 0, 94, 95, 96, 97, 18, 98, 19, 99, 100, 101, 102, 103, 104, 105, 106, 107, 108, 0, 109, 20, 0, 0, 0,
+        // State 43
+        11, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 13, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 14, 0, 0, 89, 90, 0, 91, 15, 0, 0, 92, 16, 93, 17, 0, 94, 95, 96, 97, 18, 98, 19, 99, 100, 101, 102, 103, 104, 105, 106, 107, 108, 0, 109, 20, 0, 0, 0,
+        // State 44
+        11, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 13, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 14, 0, 0, 89, 90, 0, 91, 15, 0, 0, 92, 16, 93, 17, 0, 94, 95, 96, 97, 18, 98, 19, 99, 100, 101, 102, 103, 104, 105, 106, 107, 108, 0, 109, 20, 0, 0, 0,
+        // State 45
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 93, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 46
+        11, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 13, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 14, 0, 0, 89, 90, 0, 91, 15, 0, 0, 92, 16, 93, 17, 0, 94, 95, 96, 97, 18, 98, 19, 99, 100, 101, 102, 103, 104, 105, 106, 107, 108, 0, 109, 20, 0, 0, 0,
+        // State 47
+        11, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 13, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 14, 0, 0, 89, 90, 0, 91, 15, 0, 0, 92, 16, 93, 17, 0, 94, 95, 96, 97, 18, 98, 19, 99, 100, 101, 102, 103, 104, 105, 106, 107, 108, 0, 109, 20, 0, 0, 139,
+        // State 48
+        11, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 13, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 14, 0, 0, 89, 90, 0, 91, 15, 0, 0, 92, 16, 93, 17, 0, 94, 95, 96, 97, 18, 98, 19, 99, 100, 101, 102, 103, 104, 105, 106, 107, 108, 0, 109, 20, 0, 0, 0,
+        // State 49
+        11, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 13, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 14, 0, 0, 89, 90, 0, 91, 15, 0, 0, 92, 16, 93, 17, 0, 94, 95, 96, 97, 18, 98, 19, 99, 100, 101, 102, 103, 104, 105, 106, 107, 108, 0, 109, 20, 0, 0, 0,
+        // State 50
+        11, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 13, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 14, 0, 0, 89, 90, 0, 91, 15, 0, 0, 92, 16, 93, 17, 0, 94, 95, 96, 97, 18, 98, 19, 99, 100, 101, 102, 103, 104, 105, 106, 107, 108, 0, 109, 20, 0, 0, 0,
+        // State 51
+        11, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 13, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 14, 0, 0, 89, 90, 0, 91, 15, 0, 0, 92, 16, 93, 17, 0, 94, 95, 96, 97, 18, 98, 19, 99, 100, 101, 102, 103, 104, 105, 106, 107, 108, 0, 109, 20, 0, 0, 0,
+        // State 52
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 56, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 139,
+        // State 53
+        11, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 13, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 14, 0, 0, 89, 90, 0, 91, 15, 0, 0, 92, 16, 93, 17, 0, 94, 95, 96, 97, 18, 98, 19, 99, 100, 101, 102, 103, 104, 105, 106, 107, 108, 0, 109, 20, 0, 0, 0,
+        // State 54
+        11, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 13, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 14, 0, 0, 89, 90, 0, 91, 15, 0, 0, 92, 16, 93, 17, 0, 94, 95, 96, 97, 18, 98, 19, 99, 100, 101, 102, 103, 104, 105, 106, 107, 108, 0, 109, 20, 0, 0, 0,
+        // State 55
+        11, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 13, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 14, 0, 0, 89, 90, 0, 91, 15, 0, 0, 92, 16, 93, 17, 0, 94, 95, 96, 97, 18, 98, 19, 99, 100, 101, 102, 103, 104, 105, 106, 107, 108, 0, 109, 20, 0, 0, 139,
+        // State 56
+        11, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 13, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 14, 0, 0, 89, 90, 0, 91, 15, 0, 0, 92, 16, 93, 17, 0, 94, 95, 96, 97, 18, 98, 19, 99, 100, 101, 102, 103, 104, 105, 106, 107, 108, 0, 109, 20, 0, 0, 0,
+        // State 57
+        11, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 13, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 14, 0, 0, 89, 90, 0, 91, 15, 0, 0, 92, 16, 93, 17, 0, 94, 95, 96, 97, 18, 98, 19, 99, 100, 101, 102, 103, 104, 105, 106, 107, 108, 0, 109, 20, 0, 0, 0,
+        // State 58
+        0, -106, -106, -106, -106, 0, -106, -106, -106, -106, -106, -106, -106, -106, 0, -106, -106, -106, -106, 0, -106, -106, -106, -106, 21, -106, -106, 0, 0, -106, 0, 0, -106, -106, 0, 0, 0, 0, -106, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -106, 0, 0, -106, -106, 0,
+        // State 59
+        0, 0, 0, 0, 0, 0, -54, 0, 0, 0, -54, 0, 0, -54, 0, -54, 0, 0, 0, 0, 0, 0, 0, 0, 0, -54, 0, 0, 0, -54, 0, 0, -54, -54, 0, 0, 0, 0, -54, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -54, 0, 0, 0, 0, 0,
+        // State 60
+        0, -87, 0, 0, -87, 0, -87, 0, 0, 0, -87, 0, 0, -87, 0, -87, -87, 0, -87, 0, -87, -87, -87, 0, 0, -87, -87, 0, 0, -87, 0, 0, -87, -87, 0, 0, 0, 0, -87, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -87, 0, 0, -87, -87, 0,
+        // State 61
+        0, -89, 0, 0, -89, 0, -89, 0, 0, 0, -89, 0, 0, -89, 0, -89, -89, 0, -89, 0, -89, -89, -89, 0, 0, -89, 0, 0, 0, -89, 0, 0, -89, -89, 0, 0, 0, 0, -89, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -89, 0, 0, 0, -89, 0,
+        // State 62
+        0, -85, 0, 0, -85, 0, -85, 0, 0, 0, -85, 0, 0, -85, 0, -85, -85, 0, -85, 0, -85, -85, -85, 0, 0, -85, 0, 0, 0, -85, 0, 0, -85, -85, 0, 0, 0, 0, -85, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -85, 0, 0, -85, -85, 0,
+        // State 63
+        0, -23, -23, -23, -23, 0, -23, -23, -23, -23, -23, -23, -23, -23, 0, -23, -23, -23, -23, 0, -23, -23, -23, -23, -23, -23, -23, 0, 0, -23, 0, 0, -23, -23, 0, 0, 0, 0, -23, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -23, 0, 0, -23, -23, 0,
+        // State 64
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 22, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 65
+        0, 0, 0, 0, 0, 0, -72, 0, 0, 0, -72, 0, 0, -72, 0, -50, 0, 0, 0, 0, 0, 0, 0, 0, 0, -72, 0, 0, 0, -72, 0, 0, -72, -72, 0, 0, 0, 0, -72, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -72, 0, 0, 0, 0, 0,
+        // State 66
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 67
+        0, 0, 0, 0, 0, 0, -110, 0, 0, 0, -110, 0, 0, -110, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -110, 0, 0, 0, -110, 0, 0, -110, -110, 0, 0, 0, 0, -110, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -110, 0, 0, 0, 0, 0,
+        // State 68
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 113, 0, 0, 0, 0, 0, 0, 0, 0, 24, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 69
+        0, 0, 0, 0, 0, 0, -108, 0, 0, 0, -108, 0, 0, -108, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -108, 0, 0, 0, -108, 0, 0, -108, -108, 0, 0, 0, 0, -108, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -108, 0, 0, 0, 0, 0,
+        // State 70
+        0, -22, -22, -22, -22, 25, -22, -22, -22, -22, -22, -22, -22, -22, 26, -22, -22, -22, -22, 0, -22, -22, -22, -22, -22, -22, -22, 0, 0, -22, 0, 0, -22, -22, 0, 0, 0, 0, -22, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -22, 0, 0, -22, -22, 0,
+        // State 71
+        0, 0, 0, 0, 0, 0, -109, 0, 0, 0, -109, 0, 0, -109, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -109, 0, 0, 0, -109, 0, 0, -109, -109, 0, 0, 0, 0, -109, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -109, 0, 0, 0, 0, 0,
+        // State 72
+        0, 0, 0, 0, 0, 0, -36, 0, 0, 0, -36, 0, 0, -36, 0, -36, 0, 0, 0, 0, 0, 0, 0, 0, 0, -36, 0, 0, 0, -36, 0, 0, -36, -36, 0, 0, 0, 0, -36, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -36, 0, 0, 0, 0, 0,
+        // State 73
+        0, -95, -95, -95, -95, 0, -95, -95, -95, -95, -95, -95, -95, -95, 0, -95, -95, -95, -95, 0, -95, -95, -95, -95, 0, -95, -95, 0, 0, -95, 0, 0, -95, -95, 0, 0, 0, 0, -95, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -95, 0, 0, -95, -95, 0,
+        // State 74
+        0, -24, -24, -24, -24, 0, -24, -24, -24, -24, -24, -24, -24, -24, 0, -24, -24, -24, -24, 0, -24, -24, -24, -24, -24, -24, -24, 0, 0, -24, 0, 0, -24, -24, 0, 0, 0, 0, -24, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -24, 0, 0, -24, -24, 0,
+        // State 75
+        0, 0, 0, 0, 0, 0, -71, 0, 0, 0, -71, 0, 0, -71, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -71, 0, 0, 0, -71, 0, 0, -71, -71, 0, 0, 0, 0, -71, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -71, 0, 0, 0, 0, 0,
+        // State 76
+        0, 0, 0, 0, 0, 34, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 77
+        0, 0, 0, 0, 0, 35, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 78
+        0, 0, 0, 0, 0, 36, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 79
+        0, -91, 0, -91, -91, 0, -91, 0, -91, -91, -91, -91, 0, -91, 0, -91, -91, -91, -91, 0, -91, -91, -91, -91, 0, -91, -91, 0, 0, -91, 0, 0, -91, -91, 0, 0, 0, 0, -91, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -91, 0, 0, -91, -91, 0,
+        // State 80
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 81
+        0, 0, 0, 0, 0, 0, -103, 0, 0, 0, -103, 0, 0, -103, 0, -103, 0, 0, 0, 0, 0, 0, 0, 0, 0, -103, 0, 0, 0, -103, 0, 0, -103, -103, 0, 0, 0, 0, -103, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -103, 0, 0, 0, 0, 0,
+        // State 82
+        0, 0, 0, 0, 0, 0, -70, 0, 0, 0, -70, 0, 0, -70, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -70, 0, 0, 0, -70, 0, 0, -70, -70, 0, 0, 0, 0, -70, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -70, 0, 0, 0, 0, 0,
+        // State 83
+        0, 0, 0, 0, 0, 0, -53, 0, 0, 0, -53, 0, 0, -53, 0, -53, 0, 0, 0, 0, 0, 0, 0, 0, 0, -53, 0, 0, 0, -53, 0, 0, -53, -53, 0, 0, 0, 0, -53, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -53, 0, 0, 0, 0, 0,
+        // State 84
+        0, 0, 0, 0, 0, 0, -111, 0, 0, 0, -111, 0, 0, -111, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -111, 0, 0, 0, -111, 0, 0, -111, -111, 0, 0, 0, 0, -111, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -111, 0, 0, 0, 0, 0,
+        // State 85
+        0, -83, 0, -83, -83, 0, -83, 0, 0, 0, -83, 0, 0, -83, 0, -83, -83, 0, -83, 0, -83, -83, -83, 0, 0, -83, -83, 0, 0, -83, 0, 0, -83, -83, 0, 0, 0, 0, -83, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -83, 0, 0, -83, -83, 0,
+        // State 86
+        0, -25, -25, -25, -25, 0, -25, -25, -25, -25, -25, -25, -25, -25, 0, -25, -25, -25, -25, 0, -25, -25, -25, -25, -25, -25, -25, 0, 0, -25, 0, 0, -25, -25, 0, 0, 0, 0, -25, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -25, 0, 0, -25, -25, 0,
+        // State 87
+        0, -93, 0, -93, -93, 0, -93, 0, 0, 0, -93, 0, 0, -93, 0, -93, -93, -93, -93, 0, -93, -93, -93, -93, 0, -93, -93, 0, 0, -93, 0, 0, -93, -93, 0, 0, 0, 0, -93, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -93, 0, 0, -93, -93, 0,
+        // State 88
+        0, 0, 0, 0, 0, -122, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 89
+        0, 0, 0, 0, 0, -115, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 90
+        0, 0, 0, 0, 0, -129, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 91
+        0, -49, -49, -49, -49, 0, -49, -49, -49, -49, -49, -49, -49, -49, 0, -49, -49, -49, -49, 0, -49, -49, -49, -49, -49, -49, -49, 0, 0, -49, 0, 0, -49, -49, 0, 0, 0, 0, -49, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -49, 0, 0, -49, -49, 0,
+        // State 92
+        0, -79, -79, -79, -79, -79, -79, -79, -79, -79, -79, -79, -79, -79, -79, -79, -79, -79, -79, -79, -79, -79, -79, -79, -79, -79, -79, 0, 0, -79, 0, 0, -79, -79, 0, 0, 0, 0, -79, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -79, 0, 0, -79, -79, -79,
+        // State 93
+        0, 0, 0, 0, 0, -119, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 94
+        0, 0, 0, 0, 0, -118, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 95
+        0, 0, 0, 0, 0, -123, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 96
+        0, 0, 0, 0, 0, -120, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 97
+        0, 0, 0, 0, 0, -121, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 98
+        0, 0, 0, 0, 0, -128, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 99
+        0, 0, 0, 0, 0, -127, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 100
+        0, 0, 0, 0, 0, -125, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 101
+        0, -107, -107, -107, -107, 0, -107, -107, -107, -107, -107, -107, -107, -107, 0, -107, -107, -107, -107, 0, -107, -107, -107, -107, -107, -107, -107, 0, 0, -107, 0, 0, -107, -107, 0, 0, 0, 0, -107, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -107, 0, 0, -107, -107, 0,
+        // State 102
+        0, 0, 0, 0, 0, -117, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 103
+        0, -144, -144, -144, -144, 0, -144, -144, -144, -144, -144, -144, -144, -144, 0, -144, -144, -144, -144, 0, -144, -144, -144, -144, -144, -144, -144, 0, 0, -144, 0, 0, -144, -144, 0, 0, 0, 0, -144, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -144, 0, 0, -144, -144, 0,
+        // State 104
+        0, 0, 0, 0, 0, -126, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 105
+        0, 0, 0, 0, 0, -124, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 106
+        0, 0, 0, 0, 0, -116, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 107
+        0, 0, 0, 0, 0, -130, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 108
+        0, -48, -48, -48, -48, 0, -48, -48, -48, -48, -48, -48, -48, -48, 0, -48, -48, -48, -48, 0, -48, -48, -48, -48, -48, -48, -48, 0, 0, -48, 0, 0, -48, -48, 0, 0, 0, 0, -48, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -48, 0, 0, -48, -48, 0,
+        // State 109
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 149, 0, 0, 0, 0, 0, 0, 0, 0, 40, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 110
+        -101, 0, 0, 0, 0, -101, 0, 0, 0, 0, 0, -101, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -101, 0, 0, -101, -101, 0, -101, 0, 0, 0, -101, -101, -101, 0, 0, -101, -101, -101, -101, -101, -101, 0, -101, -101, -101, -101, -101, -101, -101, -101, -101, -101, 0, -101, -101, 0, 0, 0,
+        // State 111
+        -102, 0, 0, 0, 0, -102, 0, 0, 0, 0, 0, -102, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -102, 0, 0, -102, -102, 0, -102, 0, 0, 0, -102, -102, -102, 0, 0, -102, -102, -102, -102, -102, -102, 0, -102, -102, -102, -102, -102, -102, -102, -102, -102, -102, 0, -102, -102, 0, 0, 0,
+        // State 112
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -18, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 113
+        -42, 0, 0, 0, 0, -42, 0, 0, 0, 0, 0, -42, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -42, 0, 0, -42, -42, 0, -42, 0, 0, 0, -42, -42, -42, 0, 0, -42, -42, -42, -42, -42, -42, 0, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, 0, -42, -42, 0, 0, 0,
+        // State 114
+        -44, 0, 0, 0, 0, -44, 0, 0, 0, 0, 0, -44, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -44, 0, 0, -44, -44, 0, -44, 0, 0, 0, -44, -44, -44, 0, 0, -44, -44, -44, -44, -44, -44, 0, -44, -44, -44, -44, -44, -44, -44, -44, -44, -44, 0, -44, -44, 0, 0, 0,
+        // State 115
+        -46, 0, 0, 0, 0, -46, 0, 0, 0, 0, 0, -46, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -46, 0, 0, -46, -46, 0, -46, 0, 0, 0, -46, -46, -46, 0, 0, -46, -46, -46, -46, -46, -46, 0, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, 0, -46, -46, 0, 0, 0,
+        // State 116
+        -60, 0, 0, 0, 0, -60, 0, 0, 0, 0, 0, -60, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -60, 0, 0, -60, -60, 0, -60, 0, 0, 0, -60, -60, -60, 0, 0, -60, -60, -60, -60, -60, -60, 0, -60, -60, -60, -60, -60, -60, -60, -60, -60, -60, 0, -60, -60, 0, 0, 0,
+        // State 117
+        -55, 0, 0, 0, 0, -55, 0, 0, 0, 0, 0, -55, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -55, 0, 0, -55, -55, 0, -55, 0, 0, 0, -55, -55, -55, 0, 0, -55, -55, -55, -55, -55, -55, 0, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, 0, -55, -55, 0, 0, 0,
+        // State 118
+        -56, 0, 0, 0, 0, -56, 0, 0, 0, 0, 0, -56, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -56, 0, 0, -56, -56, 0, -56, 0, 0, 0, -56, -56, -56, 0, 0, -56, -56, -56, -56, -56, -56, 0, -56, -56, -56, -56, -56, -56, -56, -56, -56, -56, 0, -56, -56, 0, 0, 0,
+        // State 119
+        -59, 0, 0, 0, 0, -59, 0, 0, 0, 0, 0, -59, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -59, 0, 0, -59, -59, 0, -59, 0, 0, 0, -59, -59, -59, 0, 0, -59, -59, -59, -59, -59, -59, 0, -59, -59, -59, -59, -59, -59, -59, -59, -59, -59, 0, -59, -59, 0, 0, 0,
+        // State 120
+        -57, 0, 0, 0, 0, -57, 0, 0, 0, 0, 0, -57, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -57, 0, 0, -57, -57, 0, -57, 0, 0, 0, -57, -57, -57, 0, 0, -57, -57, -57, -57, -57, -57, 0, -57, -57, -57, -57, -57, -57, -57, -57, -57, -57, 0, -57, -57, 0, 0, 0,
+        // State 121
+        -58, 0, 0, 0, 0, -58, 0, 0, 0, 0, 0, -58, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -58, 0, 0, -58, -58, 0, -58, 0, 0, 0, -58, -58, -58, 0, 0, -58, -58, -58, -58, -58, -58, 0, -58, -58, -58, -58, -58, -58, -58, -58, -58, -58, 0, -58, -58, 0, 0, 0,
+        // State 122
+        -112, 0, 0, 0, 0, -112, 0, 0, 0, 0, 0, -112, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -112, 0, 0, -112, -112, 0, -112, 0, 0, 0, -112, -112, -112, 0, 0, -112, -112, -112, -112, -112, -112, 0, -112, -112, -112, -112, -112, -112, -112, -112, -112, -112, 0, -112, -112, 0, 0, 0,
+        // State 123
+        -114, 0, 0, 0, 0, -114, 0, 0, 0, 0, 0, -114, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -114, 0, 0, -114, -114, 0, -114, 0, 0, 0, -114, -114, -114, 0, 0, -114, -114, -114, -114, -114, -114, 0, -114, -114, -114, -114, -114, -114, -114, -114, -114, -114, 0, -114, -114, 0, 0, 0,
+        // State 124
+        -113, 0, 0, 0, 0, -113, 0, 0, 0, 0, 0, -113, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -113, 0, 0, -113, -113, 0, -113, 0, 0, 0, -113, -113, -113, 0, 0, -113, -113, -113, -113, -113, -113, 0, -113, -113, -113, -113, -113, -113, -113, -113, -113, -113, 0, -113, -113, 0, 0, 0,
+        // State 125
+        -141, 0, 0, 0, 0, -141, 0, 0, 0, 0, 0, -141, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -141, 0, 0, -141, -141, 0, -141, 0, 0, 0, -141, -141, -141, 0, 0, -141, -141, -141, -141, -141, -141, 0, -141, -141, -141, -141, -141, -141, -141, -141, -141, -141, 0, -141, -141, 0, 0, 0,
+        // State 126
+        -142, 0, 0, 0, 0, -142, 0, 0, 0, 0, 0, -142, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -142, 0, 0, -142, -142, 0, -142, 0, 0, 0, -142, -142, -142, 0, 0, -142, -142, -142, -142, -142, -142, 0, -142, -142, -142, -142, -142, -142, -142, -142, -142, -142, 0, -142, -142, 0, 0, 0,
+        // State 127
+        -148, 0, 0, 0, 0, -148, 0, 0, 0, 0, 0, -148, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -148, 0, 0, -148, -148, 0, -148, 0, 0, 0, -148, -148, -148, 0, 0, -148, -148, -148, -148, -148, -148, 0, -148, -148, -148, -148, -148, -148, -148, -148, -148, -148, 0, -148, -148, 0, 0, 0,
+        // State 128
+        -146, 0, 0, 0, 0, -146, 0, 0, 0, 0, 0, -146, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -146, 0, 0, -146, -146, 0, -146, 0, 0, 0, -146, -146, -146, 0, 0, -146, -146, -146, -146, -146, -146, 0, -146, -146, -146, -146, -146, -146, -146, -146, -146, -146, 0, -146, -146, 0, 0, 0,
+        // State 129
+        -147, 0, 0, 0, 0, -147, 0, 0, 0, 0, 0, -147, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -147, 0, 0, -147, -147, 0, -147, 0, 0, 0, -147, -147, -147, 0, 0, -147, -147, -147, -147, -147, -147, 0, -147, -147, -147, -147, -147, -147, -147, -147, -147, -147, 0, -147, -147, 0, 0, 0,
+        // State 130
+        0, -104, -104, -104, -104, 0, -104, -104, -104, -104, -104, -104, -104, -104, 0, -104, -104, -104, -104, 0, -104, -104, -104, -104, 41, -104, -104, 0, 0, -104, 0, 0, -104, -104, 0, 0, 0, 0, -104, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -104, 0, 0, -104, -104, 0,
+        // State 131
+        0, -22, -22, -22, -22, 25, -22, -22, -22, -22, -22, -22, -22, -22, 0, -22, -22, -22, -22, 0, -22, -22, -22, -22, -22, -22, -22, 0, 0, -22, 0, 0, -22, -22, 0, 0, 0, 0, -22, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -22, 0, 0, -22, -22, 0,
+        // State 132
+        0, 0, 0, 0, 0, 0, 170, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 133
+        0, -105, -105, -105, -105, 0, -105, -105, -105, -105, -105, -105, -105, -105, 0, -105, -105, -105, -105, 0, -105, -105, -105, -105, 41, -105, -105, 0, 0, -105, 0, 0, -105, -105, 0, 0, 0, 0, -105, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -105, 0, 0, -105, -105, 0,
+        // State 134
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 172, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 135
+        0, 0, 0, 0, 0, 0, -97, 0, 0, 0, -97, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -97, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 136
+        0, 0, 0, 0, 0, 0, -66, 0, 0, 0, 173, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -66, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 137
+        0, 0, 0, 0, 0, 0, -98, 0, 0, 0, -98, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -98, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 138
+        0, 0, 0, 0, 0, 0, -135, 0, 0, 0, -135, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -135, 0, 0, 0, -135, 0, 0, 0, 0, 0, 0, 0, 0, -135, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 139
+        0, 0, 0, 0, 0, 42, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 140
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 43, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 141
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 44, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 142
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 45, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 143
+        0, 0, 0, 0, 0, 0, -62, 0, 0, 0, 175, 0, 0, -62, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 144
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -40, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -40, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 145
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 46, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 47, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 146
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 49, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 147
+        0, 0, 0, 0, 0, 0, -51, 0, 0, 0, -51, 0, 0, -51, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -51, 0, 0, 0, -51, 0, 0, -51, -51, 0, 0, 0, 0, -51, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -51, 0, 0, 0, 0, 0,
+        // State 148
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -19, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 149
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 178, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 150
+        0, 0, 0, 0, 0, 0, -136, 0, 0, 0, -136, 0, 0, -136, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -136, 0, 0, 0, -136, 0, 0, -136, -136, 0, 0, 0, 0, -136, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -136, 0, 0, 0, 0, 0,
+        // State 151
+        0, -106, -106, -106, -106, 0, -106, -106, -106, -106, -106, -106, -106, -106, 0, -106, -106, -106, -106, 0, -106, -106, -106, -106, 41, -106, -106, 0, 0, -106, 0, 0, -106, -106, 0, 0, 0, 0, -106, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -106, 0, 0, -106, -106, 0,
+        // State 152
+        0, 0, 0, 0, 0, 0, -133, 0, 0, 0, -133, 0, 0, -133, 0, -133, 0, 0, 0, 0, 0, 0, 0, 0, 0, -133, 0, 0, 0, -133, 0, 0, -133, -133, 0, 0, 0, 0, -133, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -133, 0, 0, 0, 0, 0,
+        // State 153
+        0, 0, 0, 0, 0, 0, -73, 0, 0, 0, -73, 0, 0, -73, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -73, 0, 0, 0, -73, 0, 0, -73, -73, 0, 0, 0, 0, -73, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -73, 0, 0, 0, 0, 0,
+        // State 154
+        0, 0, 0, 0, 0, 0, 179, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 155
+        0, 0, 0, 0, 0, 0, -138, 0, 0, 0, -138, 0, 0, -138, 0, -138, 0, 0, 0, 0, 0, 0, 0, 0, 0, -138, 0, 0, 0, -138, 0, 0, -138, -138, 0, 0, 0, 0, -138, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -138, 0, 0, 0, 0, 0,
+        // State 156
+        0, 0, 0, 0, 0, 0, -140, 0, 0, 0, -140, 0, 0, -140, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -140, 0, 0, 0, -140, 0, 0, -140, -140, 0, 0, 0, 0, -140, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -140, 0, 0, 0, 0, 0,
+        // State 157
+        0, 0, 0, 0, 0, 0, -50, 0, 0, 0, -50, 0, 0, -50, 0, -50, 0, 0, 0, 0, 0, 0, 0, 0, 0, -50, 0, 0, 0, -50, 0, 0, -50, -50, 0, 0, 0, 0, -50, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -50, 0, 0, 0, 0, 0,
+        // State 158
+        0, 0, 0, 0, 0, 0, -52, 0, 0, 0, -52, 0, 0, -52, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -52, 0, 0, 0, -52, 0, 0, -52, -52, 0, 0, 0, 0, -52, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -52, 0, 0, 0, 0, 0,
+        // State 159
+        0, -82, 0, -82, -82, 0, -82, 0, 0, 0, -82, 0, 0, -82, 0, -82, -82, 0, -82, 0, -82, -82, -82, 0, 0, -82, -82, 0, 0, -82, 0, 0, -82, -82, 0, 0, 0, 0, -82, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -82, 0, 0, -82, -82, 0,
+        // State 160
+        0, -84, 0, 0, -84, 0, -84, 0, 0, 0, -84, 0, 0, -84, 0, -84, -84, 0, -84, 0, -84, -84, -84, 0, 0, -84, 0, 0, 0, -84, 0, 0, -84, -84, 0, 0, 0, 0, -84, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -84, 0, 0, -84, -84, 0,
+        // State 161
+        0, -86, 0, 0, -86, 0, -86, 0, 0, 0, -86, 0, 0, -86, 0, -86, -86, 0, -86, 0, -86, -86, -86, 0, 0, -86, -86, 0, 0, -86, 0, 0, -86, -86, 0, 0, 0, 0, -86, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -86, 0, 0, -86, -86, 0,
+        // State 162
+        0, -88, 0, 0, -88, 0, -88, 0, 0, 0, -88, 0, 0, -88, 0, -88, -88, 0, -88, 0, -88, -88, -88, 0, 0, -88, 0, 0, 0, -88, 0, 0, -88, -88, 0, 0, 0, 0, -88, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -88, 0, 0, 0, -88, 0,
+        // State 163
+        0, -90, 0, -90, -90, 0, -90, 0, -90, -90, -90, -90, 0, -90, 0, -90, -90, -90, -90, 0, -90, -90, -90, -90, 0, -90, -90, 0, 0, -90, 0, 0, -90, -90, 0, 0, 0, 0, -90, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -90, 0, 0, -90, -90, 0,
+        // State 164
+        0, -92, 0, -92, -92, 0, -92, 0, 0, 0, -92, 0, 0, -92, 0, -92, -92, -92, -92, 0, -92, -92, -92, -92, 0, -92, -92, 0, 0, -92, 0, 0, -92, -92, 0, 0, 0, 0, -92, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -92, 0, 0, -92, -92, 0,
+        // State 165
+        0, -94, -94, -94, -94, 0, -94, -94, -94, -94, -94, -94, -94, -94, 0, -94, -94, -94, -94, 0, -94, -94, -94, -94, 0, -94, -94, 0, 0, -94, 0, 0, -94, -94, 0, 0, 0, 0, -94, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -94, 0, 0, -94, -94, 0,
+        // State 166
+        0, 0, 0, 0, 0, 0, 180, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 167
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 50, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 168
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 51, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 169
+        0, -29, -29, -29, -29, 0, -29, -29, -29, -29, -29, -29, -29, -29, 0, -29, -29, -29, -29, 0, -29, -29, -29, -29, -29, -29, -29, 0, 0, -29, 0, 0, -29, -29, 0, 0, 0, 0, -29, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -29, 0, 0, -29, -29, 0,
+        // State 170
+        0, 0, 0, 0, 0, 0, -68, 0, 0, 0, 182, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -68, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 171
+        0, -34, -34, -34, -34, 0, -34, -34, -34, -34, -34, -34, -34, -34, 0, -34, -34, -34, -34, 0, -34, -34, -34, -34, -34, -34, -34, 0, 0, -34, 0, 0, -34, -34, 0, 0, 0, 0, -34, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -34, 0, 0, -34, -34, 0,
+        // State 172
+        -13, 0, 0, 0, 0, -13, -13, 0, 0, 0, 0, -13, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -13, -13, 0, -13, -13, 0, -13, -13, 0, 0, -13, -13, -13, -13, 0, -13, -13, -13, -13, -13, -13, -13, -13, -13, -13, -13, -13, -13, -13, -13, -13, -13, 0, -13, -13, 0, 0, -13,
+        // State 173
+        0, 0, 0, 0, 0, 0, -64, 0, 0, 0, 186, 0, 0, -64, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 174
+        0, 0, 0, 0, 0, 0, -8, 0, 0, 0, 0, 0, 0, -8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 175
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -39, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -39, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 176
+        0, 0, 0, 0, 0, 0, -74, 0, 0, 0, -74, 0, 0, -74, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -74, 0, 0, 0, -74, 0, 0, -74, -74, 0, 0, 0, 0, -74, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -74, 0, 0, 0, 0, 0,
+        // State 177
+        0, -35, -35, -35, -35, 0, -35, -35, -35, -35, -35, -35, -35, -35, 52, -35, -35, -35, -35, 0, -35, -35, -35, -35, -35, -35, -35, 0, 0, -35, 0, 0, -35, -35, 0, 0, 0, 0, -35, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -35, 0, 0, -35, -35, 0,
+        // State 178
+        0, -30, -30, -30, -30, 0, -30, -30, -30, -30, -30, -30, -30, -30, 0, -30, -30, -30, -30, 0, -30, -30, -30, -30, -30, -30, -30, 0, 0, -30, 0, 0, -30, -30, 0, 0, 0, 0, -30, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -30, 0, 0, -30, -30, 0,
+        // State 179
+        0, -26, -26, -26, -26, 0, -26, -26, -26, -26, -26, -26, -26, -26, 0, -26, -26, -26, -26, 0, -26, -26, -26, -26, -26, -26, -26, 0, 0, -26, 0, 0, -26, -26, 0, 0, 0, 0, -26, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -26, 0, 0, -26, -26, 0,
+        // State 180
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 195, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 181
+        -14, 0, 0, 0, 0, -14, -14, 0, 0, 0, 0, -14, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -14, -14, 0, -14, -14, 0, -14, -14, 0, 0, -14, -14, -14, -14, 0, -14, -14, -14, -14, -14, -14, -14, -14, -14, -14, -14, -14, -14, -14, -14, -14, -14, 0, -14, -14, 0, 0, -14,
+        // State 182
+        0, 0, 0, 0, 0, 0, 53, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 183
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 54, 0, 0, 0, 0, 0,
+        // State 184
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 196, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 185
+        0, 0, 0, 0, 0, 0, -9, 0, 0, 0, 0, 0, 0, -9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 186
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 197, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 187
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -41, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -41, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 188
+        0, 0, 0, 0, 0, 0, -96, 0, 0, 0, -96, 0, 0, -96, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -96, 0, 0, 0, -96, 0, 0, -96, -96, 0, 0, 0, 0, -96, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -96, 0, 0, 0, 0, 0,
+        // State 189
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -37, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -37, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 190
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -38, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -38, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 191
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 198, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 192
+        0, 0, 0, 0, 0, 0, 201, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 193
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 55, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 194
+        0, -35, -35, -35, -35, 0, -35, -35, -35, -35, -35, -35, -35, -35, 0, -35, -35, -35, -35, 0, -35, -35, -35, -35, -35, -35, -35, 0, 0, -35, 0, 0, -35, -35, 0, 0, 0, 0, -35, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -35, 0, 0, -35, -35, 0,
+        // State 195
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 57, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 196
+        0, -31, -31, -31, -31, 0, -31, -31, -31, -31, -31, -31, -31, -31, 0, -31, -31, -31, -31, 0, -31, -31, -31, -31, -31, -31, -31, 0, 0, -31, 0, 0, -31, -31, 0, 0, 0, 0, -31, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -31, 0, 0, -31, -31, 0,
+        // State 197
+        0, -32, -32, -32, -32, 0, -32, -32, -32, -32, -32, -32, -32, -32, 0, -32, -32, -32, -32, 0, -32, -32, -32, -32, -32, -32, -32, 0, 0, -32, 0, 0, -32, -32, 0, 0, 0, 0, -32, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -32, 0, 0, -32, -32, 0,
+        // State 198
+        0, 0, 0, 0, 0, 0, -137, 0, 0, 0, -137, 0, 0, -137, 0, -137, 0, 0, 0, 0, 0, 0, 0, 0, 0, -137, 0, 0, 0, -137, 0, 0, -137, -137, 0, 0, 0, 0, -137, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -137, 0, 0, 0, 0, 0,
+        // State 199
+        0, 0, 0, 0, 0, 0, -139, 0, 0, 0, -139, 0, 0, -139, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -139, 0, 0, 0, -139, 0, 0, -139, -139, 0, 0, 0, 0, -139, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -139, 0, 0, 0, 0, 0,
+        // State 200
+        0, -27, -27, -27, -27, 0, -27, -27, -27, -27, -27, -27, -27, -27, 0, -27, -27, -27, -27, 0, -27, -27, -27, -27, -27, -27, -27, 0, 0, -27, 0, 0, -27, -27, 0, 0, 0, 0, -27, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -27, 0, 0, -27, -27, 0,
+        // State 201
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -77, 0, 0, 0, 0, 0, 0, 0, 0, -77, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 202
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 58, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 203
+        0, 0, 0, 0, 0, 0, 208, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 204
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -75, 0, 0, 0, 0, 0, 0, 0, 0, -75, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 205
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -76, 0, 0, 0, 0, 0, 0, 0, 0, -76, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 206
+        0, 0, 0, 0, 0, 0, -78, 0, 0, 0, -78, 0, 0, -78, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -78, 0, 0, 0, -78, 0, 0, -78, -78, 0, 0, 0, 0, -78, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -78, 0, 0, 0, 0, 0,
+        // State 207
+        0, -28, -28, -28, -28, 0, -28, -28, -28, -28, -28, -28, -28, -28, 0, -28, -28, -28, -28, 0, -28, -28, -28, -28, -28, -28, -28, 0, 0, -28, 0, 0, -28, -28, 0, 0, 0, 0, -28, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -28, 0, 0, -28, -28, 0,
+        // State 208
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 210, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 209
+        0, -33, -33, -33, -33, 0, -33, -33, -33, -33, -33, -33, -33, -33, 0, -33, -33, -33, -33, 0, -33, -33, -33, -33, -33, -33, -33, 0, 0, -33, 0, 0, -33, -33, 0, 0, 0, 0, -33, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -33, 0, 0, -33, -33, 0,
+    ];
+    fn __action(state: i16, integer: usize) -> i16 {
+        __ACTION[(state as usize) * 62 + integer]
     }
-    pub(crate) fn __reduce82<
-        'input,
-    >(
-        input: &'input str,
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
-    ) -> (usize, usize)
+    const __EOF_ACTION: &[i16] = &[
+        // State 0
+        0,
+        // State 1
+        0,
+        // State 2
+        -134,
+        // State 3
+        -43,
+        // State 4
+        -45,
+        // State 5
+        -47,
+        // State 6
+        -61,
+        // State 7
+        -145,
+        // State 8
+        -143,
+        // State 9
+        -131,
+        // State 10
+        0,
+        // State 11
+        0,
+        // State 12
+        0,
+        // State 13
+        0,
+        // State 14
+        0,
+        // State 15
+        0,
+        // State 16
+        0,
+        // State 17
+        0,
+        // State 18
+        0,
+        // State 19
+        0,
+        // State 20
+        0,
+        // State 21
+        0,
+        // State 22
+        0,
+        // State 23
+        0,
+        // State 24
+        0,
+        // State 25
+        0,
+        // State 26
+        0,
+        // State 27
+        0,
+        // State 28
+        0,
+        // State 29
+        0,
+        // State 30
+        0,
+        // State 31
+        0,
+        // State 32
+        0,
+        // State 33
+        0,
+        // State 34
+        0,
+        // State 35
+        0,
+        // State 36
+        0,
+        // State 37
+        0,
+        // State 38
+        0,
+        // State 39
+        0,
+        // State 40
+        0,
+        // State 41
+        0,
+        // State 42
+        0,
+        // State 43
+        0,
+        // State 44
+        0,
+        // State 45
+        0,
+        // State 46
+        0,
+        // State 47
+        0,
+        // State 48
+        0,
+        // State 49
+        0,
+        // State 50
+        0,
+        // State 51
+        0,
+        // State 52
+        0,
+        // State 53
+        0,
+        // State 54
+        0,
+        // State 55
+        0,
+        // State 56
+        0,
+        // State 57
+        0,
+        // State 58
+        -106,
+        // State 59
+        -54,
+        // State 60
+        -87,
+        // State 61
+        -89,
+        // State 62
+        -85,
+        // State 63
+        -23,
+        // State 64
+        0,
+        // State 65
+        -72,
+        // State 66
+        -132,
+        // State 67
+        -110,
+        // State 68
+        0,
+        // State 69
+        -108,
+        // State 70
+        -22,
+        // State 71
+        -109,
+        // State 72
+        -36,
+        // State 73
+        -95,
+        // State 74
+        -24,
+        // State 75
+        -71,
+        // State 76
+        0,
+        // State 77
+        0,
+        // State 78
+        0,
+        // State 79
+        -91,
+        // State 80
+        -150,
+        // State 81
+        -103,
+        // State 82
+        -70,
+        // State 83
+        -53,
+        // State 84
+        -111,
+        // State 85
+        -83,
+        // State 86
+        -25,
+        // State 87
+        -93,
+        // State 88
+        0,
+        // State 89
+        0,
+        // State 90
+        0,
+        // State 91
+        -49,
+        // State 92
+        -79,
+        // State 93
+        0,
+        // State 94
+        0,
+        // State 95
+        0,
+        // State 96
+        0,
+        // State 97
+        0,
+        // State 98
+        0,
+        // State 99
+        0,
+        // State 100
+        0,
+        // State 101
+        -107,
+        // State 102
+        0,
+        // State 103
+        -144,
+        // State 104
+        0,
+        // State 105
+        0,
+        // State 106
+        0,
+        // State 107
+        0,
+        // State 108
+        -48,
+        // State 109
+        0,
+        // State 110
+        0,
+        // State 111
+        0,
+        // State 112
+        0,
+        // State 113
+        0,
+        // State 114
+        0,
+        // State 115
+        0,
+        // State 116
+        0,
+        // State 117
+        0,
+        // State 118
+        0,
+        // State 119
+        0,
+        // State 120
+        0,
+        // State 121
+        0,
+        // State 122
+        0,
+        // State 123
+        0,
+        // State 124
+        0,
+        // State 125
+        0,
+        // State 126
+        0,
+        // State 127
+        0,
+        // State 128
+        0,
+        // State 129
+        0,
+        // State 130
+        -104,
+        // State 131
+        -22,
+        // State 132
+        0,
+        // State 133
+        -105,
+        // State 134
+        0,
+        // State 135
+        0,
+        // State 136
+        0,
+        // State 137
+        0,
+        // State 138
+        0,
+        // State 139
+        0,
+        // State 140
+        0,
+        // State 141
+        0,
+        // State 142
+        0,
+        // State 143
+        0,
+        // State 144
+        0,
+        // State 145
+        0,
+        // State 146
+        0,
+        // State 147
+        -51,
+        // State 148
+        0,
+        // State 149
+        0,
+        // State 150
+        -136,
+        // State 151
+        -106,
+        // State 152
+        -133,
+        // State 153
+        -73,
+        // State 154
+        0,
+        // State 155
+        -138,
+        // State 156
+        -140,
+        // State 157
+        -50,
+        // State 158
+        -52,
+        // State 159
+        -82,
+        // State 160
+        -84,
+        // State 161
+        -86,
+        // State 162
+        -88,
+        // State 163
+        -90,
+        // State 164
+        -92,
+        // State 165
+        -94,
+        // State 166
+        0,
+        // State 167
+        0,
+        // State 168
+        0,
+        // State 169
+        -29,
+        // State 170
+        0,
+        // State 171
+        -34,
+        // State 172
+        0,
+        // State 173
+        0,
+        // State 174
+        0,
+        // State 175
+        0,
+        // State 176
+        -74,
+        // State 177
+        -35,
+        // State 178
+        -30,
+        // State 179
+        -26,
+        // State 180
+        0,
+        // State 181
+        0,
+        // State 182
+        0,
+        // State 183
+        0,
+        // State 184
+        0,
+        // State 185
+        0,
+        // State 186
+        0,
+        // State 187
+        0,
+        // State 188
+        -96,
+        // State 189
+        0,
+        // State 190
+        0,
+        // State 191
+        0,
+        // State 192
+        0,
+        // State 193
+        0,
+        // State 194
+        -35,
+        // State 195
+        0,
+        // State 196
+        -31,
+        // State 197
+        -32,
+        // State 198
+        -137,
+        // State 199
+        -139,
+        // State 200
+        -27,
+        // State 201
+        0,
+        // State 202
+        0,
+        // State 203
+        0,
+        // State 204
+        0,
+        // State 205
+        0,
+        // State 206
+        -78,
+        // State 207
+        -28,
+        // State 208
+        0,
+        // State 209
+        -33,
+    ];
+    fn __goto(state: i16, nt: usize) -> i16 {
+        match nt {
+            4 => 37,
+            7 => 36,
+            10 => 1,
+            13 => match state {
+                10 => 130,
+                12 => 133,
+                22 | 26..=32 => 151,
+                _ => 58,
+            },
+            14 => 59,
+            15 => match state {
+                45 => 187,
+                _ => 144,
+            },
+            16 => 145,
+            17 => 26,
+            18 => match state {
+                28 => 161,
+                _ => 60,
+            },
+            19 => 27,
+            20 => match state {
+                29 => 162,
+                _ => 61,
+            },
+            21 => 28,
+            22 => match state {
+                27 => 160,
+                _ => 62,
+            },
+            23 => 63,
+            24 => match state {
+                25 => 155,
+                _ => 64,
+            },
+            25 => match state {
+                21 => 150,
+                23 => 153,
+                39 => 176,
+                42 => 183,
+                44 => 186,
+                48 => 191,
+                53 => 202,
+                57 => 208,
+                _ => 146,
+            },
+            26 => 156,
+            27 => match state {
+                25 => 157,
+                51 => 198,
+                _ => 65,
+            },
+            28 => 29,
+            29 => 2,
+            30 => match state {
+                41 => 182,
+                _ => 142,
+            },
+            31 => match state {
+                24 => 154,
+                _ => 134,
+            },
+            32 => match state {
+                0 => 66,
+                11 => 132,
+                13 | 24 | 36 => 135,
+                16 => 141,
+                20 => 149,
+                33 => 166,
+                34 => 167,
+                35 => 168,
+                40 => 180,
+                43 => 184,
+                46 => 188,
+                47 => 189,
+                49 => 192,
+                50 => 193,
+                54 => 203,
+                55 => 204,
+                56 => 206,
+                _ => 147,
+            },
+            33 => 67,
+            34 => match state {
+                1 => 109,
+                _ => 68,
+            },
+            35 => 69,
+            36 => match state {
+                18 | 45 => 38,
+                10 | 12 | 22 | 26..=32 => 131,
+                14 => 139,
+                15 => 140,
+                17 | 41 => 143,
+                37 => 173,
+                _ => 70,
+            },
+            38 => 3,
+            39 => 4,
+            40 => 5,
+            41 => 6,
+            42 => 7,
+            43 => 8,
+            44 => 9,
+            45 => 71,
+            46 => match state {
+                36 => 170,
+                _ => 136,
+            },
+            48 => 22,
+            49 => 72,
+            50 => match state {
+                32 => 165,
+                _ => 73,
+            },
+            51 => 74,
+            52 => match state {
+                25 => 158,
+                51 => 199,
+                _ => 75,
+            },
+            53 => 30,
+            54 => 76,
+            55 => 77,
+            56 => 78,
+            57 => match state {
+                30 => 163,
+                _ => 79,
+            },
+            58 => 80,
+            59 => match state {
+                22 => 152,
+                _ => 81,
+            },
+            60 => match state {
+                38 => 175,
+                47 => 190,
+                52 => 201,
+                55 => 205,
+                _ => 137,
+            },
+            61 => 82,
+            62 => 83,
+            63 => 84,
+            64 => 31,
+            65 => match state {
+                26 => 159,
+                _ => 85,
+            },
+            66 => 86,
+            67 => match state {
+                31 => 164,
+                _ => 87,
+            },
+            68 => 32,
+            _ => 0,
+        }
+    }
+    fn __expected_tokens(__state: i16) -> alloc::vec::Vec<alloc::string::String> {
+        const __TERMINAL: &[&str] = &[
+            r###""!""###,
+            r###""!=""###,
+            r###""%""###,
+            r###""&""###,
+            r###""&&""###,
+            r###""(""###,
+            r###"")""###,
+            r###""*""###,
+            r###""+""###,
+            r###""++""###,
+            r###"",""###,
+            r###""-""###,
+            r###""/""###,
+            r###"":""###,
+            r###"":=""###,
+            r###"";""###,
+            r###""<""###,
+            r###""<<""###,
+            r###""<=""###,
+            r###""=""###,
+            r###""==""###,
+            r###"">""###,
+            r###"">=""###,
+            r###"">>""###,
+            r###""[""###,
+            r###""]""###,
+            r###""^""###,
+            r###""abs""###,
+            r###""add1""###,
+            r###""and""###,
+            r###""char_at""###,
+            r###""def""###,
+            r###""else""###,
+            r###""end""###,
+            r###""false""###,
+            r###""for""###,
+            r###""identifier""###,
+            r###""if""###,
+            r###""in""###,
+            r###""isbool""###,
+            r###""isnum""###,
+            r###""isstring""###,
+            r###""istuple""###,
+            r###""lambda""###,
+            r###""length""###,
+            r###""let""###,
+            r###""max""###,
+            r###""min""###,
+            r###""num_to_string""###,
+            r###""number""###,
+            r###""print""###,
+            r###""string""###,
+            r###""string_to_num""###,
+            r###""strlen""###,
+            r###""sub1""###,
+            r###""substr""###,
+            r###""to""###,
+            r###""true""###,
+            r###""while""###,
+            r###""|""###,
+            r###""||""###,
+        ];
+        __TERMINAL.iter().enumerate().filter_map(|(index, terminal)| {
+            let next_state = __action(__state, index);
+            if next_state == 0 {
+                None
+            } else {
+                Some(alloc::string::ToString::to_string(terminal))
+            }
+        }).collect()
+    }
+    pub(crate) struct __StateMachine<'input, 'err>
+    where 'input: 'err
+    {
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+        __phantom: core::marker::PhantomData<(&'input (), &'err ())>,
+    }
+    impl<'input, 'err> __state_machine::ParserDefinition for __StateMachine<'input, 'err>
+    where 'input: 'err
+    {
+        type Location = usize;
+        type Error = LexError;
+        type Token = Tok<'input>;
+        type TokenIndex = usize;
+        type Symbol = __Symbol<'input>;
+        type Success = SurfProg<Span1>;
+        type StateIndex = i16;
+        type Action = i16;
+        type ReduceIndex = i16;
+        type NonterminalIndex = usize;
+
+        #[inline]
+        fn start_location(&self) -> Self::Location {
+              Default::default()
+        }
+
+        #[inline]
+        fn start_state(&self) -> Self::StateIndex {
+              0
+        }
+
+        #[inline]
+        fn token_to_index(&self, token: &Self::Token) -> Option<usize> {
+            __token_to_integer(token, core::marker::PhantomData::<(&(), &())>)
+        }
+
+        #[inline]
+        fn action(&self, state: i16, integer: usize) -> i16 {
+            __action(state, integer)
+        }
+
+        #[inline]
+        fn error_action(&self, state: i16) -> i16 {
+            __action(state, 62 - 1)
+        }
+
+        #[inline]
+        fn eof_action(&self, state: i16) -> i16 {
+            __EOF_ACTION[state as usize]
+        }
+
+        #[inline]
+        fn goto(&self, state: i16, nt: usize) -> i16 {
+            __goto(state, nt)
+        }
+
+        fn token_to_symbol(&self, token_index: usize, token: Self::Token) -> Self::Symbol {
+            __token_to_symbol(token_index, token, core::marker::PhantomData::<(&(), &())>)
+        }
+
+        fn expected_tokens(&self, state: i16) -> alloc::vec::Vec<alloc::string::String> {
+            __expected_tokens(state)
+        }
+
+        #[inline]
+        fn uses_error_recovery(&self) -> bool {
+            true
+        }
+
+        #[inline]
+        fn error_recovery_symbol(
+            &self,
+            recovery: __state_machine::ErrorRecovery<Self>,
+        ) -> Self::Symbol {
+            __Symbol::Variant3(recovery)
+        }
+
+        fn reduce(
+            &mut self,
+            action: i16,
+            start_location: Option<&Self::Location>,
+            states: &mut alloc::vec::Vec<i16>,
+            symbols: &mut alloc::vec::Vec<__state_machine::SymbolTriple<Self>>,
+        ) -> Option<__state_machine::ParseResult<Self>> {
+            __reduce(
+                self.errors,
+                action,
+                start_location,
+                states,
+                symbols,
+                core::marker::PhantomData::<(&(), &())>,
+            )
+        }
+
+        fn simulate_reduce(&self, action: i16) -> __state_machine::SimulatedReduce<Self> {
+            __simulate_reduce(action, core::marker::PhantomData::<(&(), &())>)
+        }
+    }
+    fn __token_to_integer<
+        'input,
+        'err,
+    >(
+        __token: &Tok<'input>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> Option<usize>
+    {
+        match *__token {
+            Tok::Bang if true => Some(0),
+            Tok::Neq if true => Some(1),
+            Tok::Percent if true => Some(2),
+            Tok::Amp if true => Some(3),
+            Tok::AndAnd if true => Some(4),
+            Tok::LParen if true => Some(5),
+            Tok::RParen if true => Some(6),
+            Tok::Star if true => Some(7),
+            Tok::Plus if true => Some(8),
+            Tok::PlusPlus if true => Some(9),
+            Tok::Comma if true => Some(10),
+            Tok::Minus if true => Some(11),
+            Tok::Slash if true => Some(12),
+            Tok::Colon if true => Some(13),
+            Tok::Assign if true => Some(14),
+            Tok::Semicolon if true => Some(15),
+            Tok::Lt if true => Some(16),
+            Tok::Shl if true => Some(17),
+            Tok::Le if true => Some(18),
+            Tok::Equal if true => Some(19),
+            Tok::EqEq if true => Some(20),
+            Tok::Gt if true => Some(21),
+            Tok::Ge if true => Some(22),
+            Tok::Shr if true => Some(23),
+            Tok::LBrack if true => Some(24),
+            Tok::RBrack if true => Some(25),
+            Tok::Caret if true => Some(26),
+            Tok::Abs if true => Some(27),
+            Tok::Add1 if true => Some(28),
+            Tok::And if true => Some(29),
+            Tok::CharAt if true => Some(30),
+            Tok::Def if true => Some(31),
+            Tok::Else if true => Some(32),
+            Tok::End if true => Some(33),
+            Tok::False if true => Some(34),
+            Tok::For if true => Some(35),
+            Tok::Id(_) if true => Some(36),
+            Tok::If if true => Some(37),
+            Tok::In if true => Some(38),
+            Tok::IsBool if true => Some(39),
+            Tok::IsNum if true => Some(40),
+            Tok::IsString if true => Some(41),
+            Tok::IsTuple if true => Some(42),
+            Tok::Lambda if true => Some(43),
+            Tok::Length if true => Some(44),
+            Tok::Let if true => Some(45),
+            Tok::Max if true => Some(46),
+            Tok::Min if true => Some(47),
+            Tok::NumToString if true => Some(48),
+            Tok::Num(_) if true => Some(49),
+            Tok::Print if true => Some(50),
+            Tok::Str(_) if true => Some(51),
+            Tok::StringToNum if true => Some(52),
+            Tok::StrLen if true => Some(53),
+            Tok::Sub1 if true => Some(54),
+            Tok::Substr if true => Some(55),
+            Tok::To if true => Some(56),
+            Tok::True if true => Some(57),
+            Tok::While if true => Some(58),
+            Tok::Pipe if true => Some(59),
+            Tok::OrOr if true => Some(60),
+            _ => None,
+        }
+    }
+    fn __token_to_symbol<
+        'input,
+        'err,
+    >(
+        __token_index: usize,
+        __token: Tok<'input>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> __Symbol<'input>
+    {
+        match __token_index {
+            0 | 1 | 2 | 3 | 4 | 5 | 6 | 7 | 8 | 9 | 10 | 11 | 12 | 13 | 14 | 15 | 16 | 17 | 18 | 19 | 20 | 21 | 22 | 23 | 24 | 25 | 26 | 27 | 28 | 29 | 30 | 31 | 32 | 33 | 34 | 35 | 37 | 38 | 39 | 40 | 41 | 42 | 43 | 44 | 45 | 46 | 47 | 48 | 50 | 52 | 53 | 54 | 55 | 56 | 57 | 58 | 59 | 60 => __Symbol::Variant0(__token),
+            36 | 51 => match __token {
+                Tok::Id(__tok0) | Tok::Str(__tok0) if true => __Symbol::Variant1(__tok0),
+                _ => unreachable!(),
+            },
+            49 => match __token {
+                Tok::Num(__tok0) if true => __Symbol::Variant2(__tok0),
+                _ => unreachable!(),
+            },
+            _ => unreachable!(),
+        }
+    }
+    fn __simulate_reduce<
+        'input,
+        'err,
+    >(
+        __reduce_index: i16,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> __state_machine::SimulatedReduce<__StateMachine<'input, 'err>>
+    where
+        'input: 'err,
+    {
+        match __reduce_index {
+            0 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 0,
+                }
+            }
+            1 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 0,
+                    nonterminal_produced: 0,
+                }
+            }
+            2 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 1,
+                }
+            }
+            3 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 0,
+                    nonterminal_produced: 1,
+                }
+            }
+            4 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 2,
+                    nonterminal_produced: 2,
+                }
+            }
+            5 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 0,
+                    nonterminal_produced: 3,
+                }
+            }
+            6 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 3,
+                }
+            }
+            7 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 2,
+                    nonterminal_produced: 4,
+                }
+            }
+            8 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 3,
+                    nonterminal_produced: 4,
+                }
+            }
+            9 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 2,
+                    nonterminal_produced: 5,
+                }
+            }
+            10 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 0,
+                    nonterminal_produced: 6,
+                }
+            }
+            11 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 6,
+                }
+            }
+            12 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 2,
+                    nonterminal_produced: 7,
+                }
+            }
+            13 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 3,
+                    nonterminal_produced: 7,
+                }
+            }
+            14 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 2,
+                    nonterminal_produced: 8,
+                }
+            }
+            15 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 0,
+                    nonterminal_produced: 9,
+                }
+            }
+            16 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 9,
+                }
+            }
+            17 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 2,
+                    nonterminal_produced: 10,
+                }
+            }
+            18 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 3,
+                    nonterminal_produced: 10,
+                }
+            }
+            19 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 0,
+                    nonterminal_produced: 11,
+                }
+            }
+            20 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 0,
+                    nonterminal_produced: 12,
+                }
+            }
+            21 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 13,
+                }
+            }
+            22 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 13,
+                }
+            }
+            23 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 13,
+                }
+            }
+            24 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 13,
+                }
+            }
+            25 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 4,
+                    nonterminal_produced: 13,
+                }
+            }
+            26 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 6,
+                    nonterminal_produced: 13,
+                }
+            }
+            27 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 8,
+                    nonterminal_produced: 13,
+                }
+            }
+            28 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 3,
+                    nonterminal_produced: 13,
+                }
+            }
+            29 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 4,
+                    nonterminal_produced: 13,
+                }
+            }
+            30 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 5,
+                    nonterminal_produced: 13,
+                }
+            }
+            31 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 5,
+                    nonterminal_produced: 13,
+                }
+            }
+            32 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 9,
+                    nonterminal_produced: 13,
+                }
+            }
+            33 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 3,
+                    nonterminal_produced: 13,
+                }
+            }
+            34 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 4,
+                    nonterminal_produced: 13,
+                }
+            }
+            35 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 14,
+                }
+            }
+            36 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 3,
+                    nonterminal_produced: 15,
+                }
+            }
+            37 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 3,
+                    nonterminal_produced: 15,
+                }
+            }
+            38 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 2,
+                    nonterminal_produced: 15,
+                }
+            }
+            39 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 16,
+                }
+            }
+            40 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 3,
+                    nonterminal_produced: 16,
+                }
+            }
+            41 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 17,
+                }
+            }
+            42 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 18,
+                }
+            }
+            43 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 19,
+                }
+            }
+            44 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 20,
+                }
+            }
+            45 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 21,
+                }
+            }
+            46 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 22,
+                }
+            }
+            47 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 23,
+                }
+            }
+            48 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 23,
+                }
+            }
+            49 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 24,
+                }
+            }
+            50 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 25,
+                }
+            }
+            51 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 26,
+                }
+            }
+            52 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 27,
+                }
+            }
+            53 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 27,
+                }
+            }
+            54 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 28,
+                }
+            }
+            55 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 28,
+                }
+            }
+            56 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 28,
+                }
+            }
+            57 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 28,
+                }
+            }
+            58 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 28,
+                }
+            }
+            59 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 28,
+                }
+            }
+            60 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 29,
+                }
+            }
+            61 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 30,
+                }
+            }
+            62 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 0,
+                    nonterminal_produced: 30,
+                }
+            }
+            63 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 2,
+                    nonterminal_produced: 30,
+                }
+            }
+            64 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 30,
+                }
+            }
+            65 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 31,
+                }
+            }
+            66 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 0,
+                    nonterminal_produced: 31,
+                }
+            }
+            67 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 2,
+                    nonterminal_produced: 31,
+                }
+            }
+            68 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 31,
+                }
+            }
+            69 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 32,
+                }
+            }
+            70 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 32,
+                }
+            }
+            71 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 32,
+                }
+            }
+            72 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 3,
+                    nonterminal_produced: 33,
+                }
+            }
+            73 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 4,
+                    nonterminal_produced: 33,
+                }
+            }
+            74 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 7,
+                    nonterminal_produced: 34,
+                }
+            }
+            75 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 7,
+                    nonterminal_produced: 34,
+                }
+            }
+            76 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 6,
+                    nonterminal_produced: 34,
+                }
+            }
+            77 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 7,
+                    nonterminal_produced: 35,
+                }
+            }
+            78 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 36,
+                }
+            }
+            79 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 37,
+                }
+            }
+            80 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 0,
+                    nonterminal_produced: 37,
+                }
+            }
+            81 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 3,
+                    nonterminal_produced: 38,
+                }
+            }
+            82 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 38,
+                }
+            }
+            83 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 3,
+                    nonterminal_produced: 39,
+                }
+            }
+            84 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 39,
+                }
+            }
+            85 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 3,
+                    nonterminal_produced: 40,
+                }
+            }
+            86 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 40,
+                }
+            }
+            87 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 3,
+                    nonterminal_produced: 41,
+                }
+            }
+            88 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 41,
+                }
+            }
+            89 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 3,
+                    nonterminal_produced: 42,
+                }
+            }
+            90 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 42,
+                }
+            }
+            91 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 3,
+                    nonterminal_produced: 43,
+                }
+            }
+            92 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 43,
+                }
+            }
+            93 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 3,
+                    nonterminal_produced: 44,
+                }
+            }
+            94 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 44,
+                }
+            }
+            95 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 4,
+                    nonterminal_produced: 45,
+                }
+            }
+            96 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 46,
+                }
+            }
+            97 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 46,
+                }
+            }
+            98 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 47,
+                }
+            }
+            99 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 0,
+                    nonterminal_produced: 47,
+                }
+            }
+            100 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 48,
+                }
+            }
+            101 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 48,
+                }
+            }
+            102 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 49,
+                }
+            }
+            103 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 2,
+                    nonterminal_produced: 50,
+                }
+            }
+            104 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 2,
+                    nonterminal_produced: 50,
+                }
+            }
+            105 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 50,
+                }
+            }
+            106 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 51,
+                }
+            }
+            107 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 52,
+                }
+            }
+            108 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 52,
+                }
+            }
+            109 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 52,
+                }
+            }
+            110 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 52,
+                }
+            }
+            111 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 53,
+                }
+            }
+            112 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 53,
+                }
+            }
+            113 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 53,
+                }
+            }
+            114 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 54,
+                }
+            }
+            115 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 54,
+                }
+            }
+            116 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 54,
+                }
+            }
+            117 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 54,
+                }
+            }
+            118 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 54,
+                }
+            }
+            119 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 54,
+                }
+            }
+            120 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 54,
+                }
+            }
+            121 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 54,
+                }
+            }
+            122 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 54,
+                }
+            }
+            123 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 54,
+                }
+            }
+            124 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 54,
+                }
+            }
+            125 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 54,
+                }
+            }
+            126 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 55,
+                }
+            }
+            127 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 55,
+                }
+            }
+            128 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 55,
+                }
+            }
+            129 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 56,
+                }
+            }
+            130 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 57,
+                }
+            }
+            131 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 58,
+                }
+            }
+            132 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 3,
+                    nonterminal_produced: 59,
+                }
+            }
+            133 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 59,
+                }
+            }
+            134 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 60,
+                }
+            }
+            135 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 3,
+                    nonterminal_produced: 61,
+                }
+            }
+            136 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 6,
+                    nonterminal_produced: 62,
+                }
+            }
+            137 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 3,
+                    nonterminal_produced: 62,
+                }
+            }
+            138 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 6,
+                    nonterminal_produced: 63,
+                }
+            }
+            139 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 3,
+                    nonterminal_produced: 63,
+                }
+            }
+            140 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 64,
+                }
+            }
+            141 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 64,
+                }
+            }
+            142 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 65,
+                }
+            }
+            143 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 66,
+                }
+            }
+            144 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 67,
+                }
+            }
+            145 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 68,
+                }
+            }
+            146 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 68,
+                }
+            }
+            147 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 68,
+                }
+            }
+            148 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 69,
+                }
+            }
+            149 => __state_machine::SimulatedReduce::Accept,
+            _ => panic!("invalid reduction index {}", __reduce_index)
+        }
+    }
+    pub struct ProgParser {
+        _priv: (),
+    }
+
+    impl ProgParser {
+        pub fn new() -> ProgParser {
+            ProgParser {
+                _priv: (),
+            }
+        }
+
+        #[allow(dead_code)]
+        pub fn parse<
+            'input,
+            'err,
+            __TOKEN: __ToTriple<'input, 'err, >,
+            __TOKENS: IntoIterator<Item=__TOKEN>,
+        >(
+            &self,
+            errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+            __tokens0: __TOKENS,
+        ) -> Result<SurfProg<Span1>, __lalrpop_util::ParseError<usize, Tok<'input>, LexError>>
+        {
+            let __tokens = __tokens0.into_iter();
+            let mut __tokens = __tokens.map(|t| __ToTriple::to_triple(t));
+            __state_machine::Parser::drive(
+                __StateMachine {
+                    errors,
+                    __phantom: core::marker::PhantomData::<(&(), &())>,
+                },
+                __tokens,
+            )
+        }
+    }
+    fn __accepts<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+        __error_state: i16,
+        __states: & [i16],
+        __opt_integer: Option<usize>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> bool
+    {
+        let mut __states = __states.to_vec();
+        __states.push(__error_state);
+        loop {
+            let mut __states_len = __states.len();
+            let __top = __states[__states_len - 1];
+            let __action = match __opt_integer {
+                None => __EOF_ACTION[__top as usize],
+                Some(__integer) => __action(__top, __integer),
+            };
+            if __action == 0 { return false; }
+            if __action > 0 { return true; }
+            let (__to_pop, __nt) = match __simulate_reduce(-(__action + 1), core::marker::PhantomData::<(&(), &())>) {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop, nonterminal_produced
+                } => (states_to_pop, nonterminal_produced),
+                __state_machine::SimulatedReduce::Accept => return true,
+            };
+            __states_len -= __to_pop;
+            __states.truncate(__states_len);
+            let __top = __states[__states_len - 1];
+            let __next_state = __goto(__top, __nt);
+            __states.push(__next_state);
+        }
+    }
+    pub(crate) fn __reduce<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut alloc::vec::Vec<i16>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> Option<Result<SurfProg<Span1>,__lalrpop_util::ParseError<usize, Tok<'input>, LexError>>>
+    {
+        let (__pop_states, __nonterminal) = match __action {
+            0 => {
+                __reduce0(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            1 => {
+                __reduce1(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            2 => {
+                __reduce2(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            3 => {
+                __reduce3(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            4 => {
+                __reduce4(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            5 => {
+                __reduce5(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            6 => {
+                __reduce6(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            7 => {
+                __reduce7(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            8 => {
+                __reduce8(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            9 => {
+                __reduce9(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            10 => {
+                __reduce10(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            11 => {
+                __reduce11(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            12 => {
+                __reduce12(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            13 => {
+                __reduce13(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            14 => {
+                __reduce14(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            15 => {
+                __reduce15(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            16 => {
+                __reduce16(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            17 => {
+                __reduce17(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            18 => {
+                __reduce18(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            19 => {
+                __reduce19(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            20 => {
+                __reduce20(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            21 => {
+                __reduce21(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            22 => {
+                __reduce22(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            23 => {
+                __reduce23(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            24 => {
+                __reduce24(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            25 => {
+                __reduce25(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            26 => {
+                __reduce26(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            27 => {
+                __reduce27(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            28 => {
+                __reduce28(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            29 => {
+                __reduce29(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            30 => {
+                __reduce30(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            31 => {
+                __reduce31(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            32 => {
+                __reduce32(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            33 => {
+                __reduce33(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            34 => {
+                __reduce34(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            35 => {
+                __reduce35(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            36 => {
+                __reduce36(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            37 => {
+                __reduce37(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            38 => {
+                __reduce38(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            39 => {
+                __reduce39(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            40 => {
+                __reduce40(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            41 => {
+                __reduce41(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            42 => {
+                __reduce42(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            43 => {
+                __reduce43(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            44 => {
+                __reduce44(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            45 => {
+                __reduce45(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            46 => {
+                __reduce46(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            47 => {
+                __reduce47(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            48 => {
+                __reduce48(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            49 => {
+                __reduce49(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            50 => {
+                __reduce50(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            51 => {
+                __reduce51(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            52 => {
+                __reduce52(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            53 => {
+                __reduce53(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            54 => {
+                __reduce54(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            55 => {
+                __reduce55(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            56 => {
+                __reduce56(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            57 => {
+                __reduce57(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            58 => {
+                __reduce58(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            59 => {
+                __reduce59(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            60 => {
+                __reduce60(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            61 => {
+                __reduce61(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            62 => {
+                __reduce62(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            63 => {
+                __reduce63(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            64 => {
+                __reduce64(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            65 => {
+                __reduce65(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            66 => {
+                __reduce66(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            67 => {
+                __reduce67(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            68 => {
+                __reduce68(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            69 => {
+                __reduce69(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            70 => {
+                __reduce70(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            71 => {
+                __reduce71(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            72 => {
+                __reduce72(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            73 => {
+                __reduce73(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            74 => {
+                __reduce74(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            75 => {
+                __reduce75(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            76 => {
+                __reduce76(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            77 => {
+                __reduce77(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            78 => {
+                __reduce78(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            79 => {
+                __reduce79(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            80 => {
+                __reduce80(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            81 => {
+                __reduce81(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            82 => {
+                __reduce82(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            83 => {
+                __reduce83(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            84 => {
+                __reduce84(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            85 => {
+                __reduce85(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            86 => {
+                __reduce86(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            87 => {
+                __reduce87(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            88 => {
+                __reduce88(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            89 => {
+                __reduce89(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            90 => {
+                __reduce90(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            91 => {
+                __reduce91(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            92 => {
+                __reduce92(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            93 => {
+                __reduce93(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            94 => {
+                __reduce94(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            95 => {
+                __reduce95(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            96 => {
+                __reduce96(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            97 => {
+                __reduce97(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            98 => {
+                __reduce98(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            99 => {
+                __reduce99(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            100 => {
+                __reduce100(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            101 => {
+                __reduce101(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            102 => {
+                __reduce102(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            103 => {
+                __reduce103(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            104 => {
+                __reduce104(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            105 => {
+                __reduce105(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            106 => {
+                __reduce106(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            107 => {
+                __reduce107(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            108 => {
+                __reduce108(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            109 => {
+                __reduce109(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            110 => {
+                __reduce110(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            111 => {
+                __reduce111(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            112 => {
+                __reduce112(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            113 => {
+                __reduce113(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            114 => {
+                __reduce114(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            115 => {
+                __reduce115(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            116 => {
+                __reduce116(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            117 => {
+                __reduce117(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            118 => {
+                __reduce118(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            119 => {
+                __reduce119(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            120 => {
+                __reduce120(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            121 => {
+                __reduce121(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            122 => {
+                __reduce122(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            123 => {
+                __reduce123(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            124 => {
+                __reduce124(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            125 => {
+                __reduce125(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            126 => {
+                __reduce126(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            127 => {
+                __reduce127(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            128 => {
+                __reduce128(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            129 => {
+                __reduce129(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            130 => {
+                __reduce130(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            131 => {
+                __reduce131(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            132 => {
+                __reduce132(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            133 => {
+                __reduce133(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            134 => {
+                __reduce134(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            135 => {
+                __reduce135(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            136 => {
+                __reduce136(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            137 => {
+                __reduce137(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            138 => {
+                __reduce138(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            139 => {
+                __reduce139(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            140 => {
+                __reduce140(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            141 => {
+                __reduce141(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            142 => {
+                __reduce142(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            143 => {
+                __reduce143(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            144 => {
+                __reduce144(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            145 => {
+                __reduce145(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            146 => {
+                __reduce146(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            147 => {
+                __reduce147(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            148 => {
+                __reduce148(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &())>)
+            }
+            149 => {
+                // __Prog = Prog => ActionFn(0);
+                let __sym0 = __pop_Variant22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action0::<>(errors, __sym0);
+                return Some(Ok(__nt));
+            }
+            _ => panic!("invalid action code {}", __action)
+        };
+        let __states_len = __states.len();
+        __states.truncate(__states_len - __pop_states);
+        let __state = *__states.last().unwrap();
+        let __next_state = __goto(__state, __nonterminal);
+        __states.push(__next_state);
+        None
+    }
+    #[inline(never)]
+    fn __symbol_type_mismatch() -> ! {
+        panic!("symbol type mismatch")
+    }
+    fn __pop_Variant12<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, (String, Exp<Span1>), usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant12(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant9<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, (SurfFunDecl<Span1>, Tok<'input>), usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant9(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant16<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, Box<Exp<Span1>>, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant16(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant7<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, Exp<Span1>, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant7(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant14<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, Prim, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant14(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant5<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, String, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant5(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant19<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, SurfFunDecl<Span1>, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant19(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant22<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, SurfProg<Span1>, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant22(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant0<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, Tok<'input>, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant0(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant13<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, Vec<(String, Exp<Span1>)>, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant13(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant18<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, Vec<Exp<Span1>>, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant18(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant17<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, Vec<String>, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant17(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant3<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, __lalrpop_util::ErrorRecovery<usize, Tok<'input>, LexError>, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant3(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant10<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, alloc::vec::Vec<(SurfFunDecl<Span1>, Tok<'input>)>, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant10(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant8<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, alloc::vec::Vec<Exp<Span1>>, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant8(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant6<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, alloc::vec::Vec<String>, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant6(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant15<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, bool, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant15(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant21<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, core::option::Option<Exp<Span1>>, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant21(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant20<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, core::option::Option<String>, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant20(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant4<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, core::option::Option<Tok<'input>>, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant4(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant2<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, i64, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant2(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant11<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, usize, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant11(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant1<
+      'input,
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant1(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    pub(crate) fn __reduce0<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // ":"? = ":" => ActionFn(115);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action115::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (1, 0)
+    }
+    pub(crate) fn __reduce1<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // ":"? =  => ActionFn(116);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
+        let __end = __start.clone();
+        let __nt = super::__action116::<>(errors, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (0, 0)
+    }
+    pub(crate) fn __reduce2<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // "="? = "=" => ActionFn(91);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action91::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (1, 1)
+    }
+    pub(crate) fn __reduce3<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // "="? =  => ActionFn(92);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
+        let __end = __start.clone();
+        let __nt = super::__action92::<>(errors, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (0, 1)
+    }
+    pub(crate) fn __reduce4<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // (<Id> ",") = Id, "," => ActionFn(130);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant5(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym1.2.clone();
+        let __nt = super::__action130::<>(errors, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
+        (2, 2)
+    }
+    pub(crate) fn __reduce5<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // (<Id> ",")* =  => ActionFn(128);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
+        let __end = __start.clone();
+        let __nt = super::__action128::<>(errors, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant6(__nt), __end));
+        (0, 3)
+    }
+    pub(crate) fn __reduce6<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // (<Id> ",")* = (<Id> ",")+ => ActionFn(129);
+        let __sym0 = __pop_Variant6(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action129::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant6(__nt), __end));
+        (1, 3)
+    }
+    pub(crate) fn __reduce7<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // (<Id> ",")+ = Id, "," => ActionFn(145);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant5(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym1.2.clone();
+        let __nt = super::__action145::<>(errors, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant6(__nt), __end));
+        (2, 4)
+    }
+    pub(crate) fn __reduce8<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // (<Id> ",")+ = (<Id> ",")+, Id, "," => ActionFn(146);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant5(__symbols);
+        let __sym0 = __pop_Variant6(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action146::<>(errors, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant6(__nt), __end));
+        (3, 4)
+    }
+    pub(crate) fn __reduce9<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // (<ListExp> ",") = ListExp, "," => ActionFn(136);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant7(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym1.2.clone();
+        let __nt = super::__action136::<>(errors, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
+        (2, 5)
+    }
+    pub(crate) fn __reduce10<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // (<ListExp> ",")* =  => ActionFn(134);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
+        let __end = __start.clone();
+        let __nt = super::__action134::<>(errors, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant8(__nt), __end));
+        (0, 6)
+    }
+    pub(crate) fn __reduce11<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // (<ListExp> ",")* = (<ListExp> ",")+ => ActionFn(135);
+        let __sym0 = __pop_Variant8(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action135::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant8(__nt), __end));
+        (1, 6)
+    }
+    pub(crate) fn __reduce12<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // (<ListExp> ",")+ = ListExp, "," => ActionFn(149);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant7(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym1.2.clone();
+        let __nt = super::__action149::<>(errors, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant8(__nt), __end));
+        (2, 7)
+    }
+    pub(crate) fn __reduce13<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // (<ListExp> ",")+ = (<ListExp> ",")+, ListExp, "," => ActionFn(150);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant7(__symbols);
+        let __sym0 = __pop_Variant8(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action150::<>(errors, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant8(__nt), __end));
+        (3, 7)
+    }
+    pub(crate) fn __reduce14<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // (FunDecl "and") = FunDecl, "and" => ActionFn(122);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant19(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym1.2.clone();
+        let __nt = super::__action122::<>(errors, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant9(__nt), __end));
+        (2, 8)
+    }
+    pub(crate) fn __reduce15<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // (FunDecl "and")* =  => ActionFn(120);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
+        let __end = __start.clone();
+        let __nt = super::__action120::<>(errors, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant10(__nt), __end));
+        (0, 9)
+    }
+    pub(crate) fn __reduce16<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // (FunDecl "and")* = (FunDecl "and")+ => ActionFn(121);
+        let __sym0 = __pop_Variant10(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action121::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant10(__nt), __end));
+        (1, 9)
+    }
+    pub(crate) fn __reduce17<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // (FunDecl "and")+ = FunDecl, "and" => ActionFn(153);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant19(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym1.2.clone();
+        let __nt = super::__action153::<>(errors, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant10(__nt), __end));
+        (2, 10)
+    }
+    pub(crate) fn __reduce18<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // (FunDecl "and")+ = (FunDecl "and")+, FunDecl, "and" => ActionFn(154);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant19(__symbols);
+        let __sym0 = __pop_Variant10(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action154::<>(errors, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant10(__nt), __end));
+        (3, 10)
+    }
+    pub(crate) fn __reduce19<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // @L =  => ActionFn(123);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
+        let __end = __start.clone();
+        let __nt = super::__action123::<>(errors, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
+        (0, 11)
+    }
+    pub(crate) fn __reduce20<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // @R =  => ActionFn(118);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
+        let __end = __start.clone();
+        let __nt = super::__action118::<>(errors, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
+        (0, 12)
+    }
+    pub(crate) fn __reduce21<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // BaseExp = Id => ActionFn(193);
+        let __sym0 = __pop_Variant5(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action193::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
+        (1, 13)
+    }
+    pub(crate) fn __reduce22<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // BaseExp = Bool => ActionFn(194);
+        let __sym0 = __pop_Variant15(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action194::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
+        (1, 13)
+    }
+    pub(crate) fn __reduce23<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // BaseExp = Num => ActionFn(195);
+        let __sym0 = __pop_Variant2(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action195::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
+        (1, 13)
+    }
+    pub(crate) fn __reduce24<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // BaseExp = Str => ActionFn(196);
+        let __sym0 = __pop_Variant5(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action196::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
+        (1, 13)
+    }
+    pub(crate) fn __reduce25<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // BaseExp = Prim1, "(", Exp, ")" => ActionFn(197);
+        assert!(__symbols.len() >= 4);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant7(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant14(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym3.2.clone();
+        let __nt = super::__action197::<>(errors, __sym0, __sym1, __sym2, __sym3);
+        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
+        (4, 13)
+    }
+    pub(crate) fn __reduce26<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // BaseExp = Prim2, "(", Exp, ",", Exp, ")" => ActionFn(198);
+        assert!(__symbols.len() >= 6);
+        let __sym5 = __pop_Variant0(__symbols);
+        let __sym4 = __pop_Variant7(__symbols);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant7(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant14(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym5.2.clone();
+        let __nt = super::__action198::<>(errors, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5);
+        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
+        (6, 13)
+    }
+    pub(crate) fn __reduce27<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // BaseExp = Prim3, "(", Exp, ",", Exp, ",", Exp, ")" => ActionFn(199);
+        assert!(__symbols.len() >= 8);
+        let __sym7 = __pop_Variant0(__symbols);
+        let __sym6 = __pop_Variant7(__symbols);
+        let __sym5 = __pop_Variant0(__symbols);
+        let __sym4 = __pop_Variant7(__symbols);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant7(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant14(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym7.2.clone();
+        let __nt = super::__action199::<>(errors, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5, __sym6, __sym7);
+        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
+        (8, 13)
+    }
+    pub(crate) fn __reduce28<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // BaseExp = "(", Exp, ")" => ActionFn(57);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant7(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action57::<>(errors, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
+        (3, 13)
+    }
+    pub(crate) fn __reduce29<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // BaseExp = Id, "(", Comma<ListExp>, ")" => ActionFn(200);
+        assert!(__symbols.len() >= 4);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant18(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant5(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym3.2.clone();
+        let __nt = super::__action200::<>(errors, __sym0, __sym1, __sym2, __sym3);
+        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
+        (4, 13)
+    }
+    pub(crate) fn __reduce30<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // BaseExp = "lambda", Comma<Id>, ":", Boxed<Exp>, "end" => ActionFn(201);
+        assert!(__symbols.len() >= 5);
+        let __sym4 = __pop_Variant0(__symbols);
+        let __sym3 = __pop_Variant16(__symbols);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant17(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym4.2.clone();
+        let __nt = super::__action201::<>(errors, __sym0, __sym1, __sym2, __sym3, __sym4);
+        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
+        (5, 13)
+    }
+    pub(crate) fn __reduce31<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // BaseExp = "while", Boxed<Exp>, ":", Boxed<Exp>, "end" => ActionFn(202);
+        assert!(__symbols.len() >= 5);
+        let __sym4 = __pop_Variant0(__symbols);
+        let __sym3 = __pop_Variant16(__symbols);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant16(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym4.2.clone();
+        let __nt = super::__action202::<>(errors, __sym0, __sym1, __sym2, __sym3, __sym4);
+        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
+        (5, 13)
+    }
+    pub(crate) fn __reduce32<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // BaseExp = "for", Id, "=", Boxed<Exp>, "to", Boxed<Exp>, ":", Boxed<Exp>, "end" => ActionFn(203);
+        assert!(__symbols.len() >= 9);
+        let __sym8 = __pop_Variant0(__symbols);
+        let __sym7 = __pop_Variant16(__symbols);
+        let __sym6 = __pop_Variant0(__symbols);
+        let __sym5 = __pop_Variant16(__symbols);
+        let __sym4 = __pop_Variant0(__symbols);
+        let __sym3 = __pop_Variant16(__symbols);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant5(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym8.2.clone();
+        let __nt = super::__action203::<>(errors, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5, __sym6, __sym7, __sym8);
+        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
+        (9, 13)
+    }
+    pub(crate) fn __reduce33<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // BaseExp = "[", Comma<ListExp>, "]" => ActionFn(204);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant18(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action204::<>(errors, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
+        (3, 13)
+    }
+    pub(crate) fn __reduce34<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // BaseExp = BaseExp, "[", Exp, "]" => ActionFn(205);
+        assert!(__symbols.len() >= 4);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant7(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant7(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym3.2.clone();
+        let __nt = super::__action205::<>(errors, __sym0, __sym1, __sym2, __sym3);
+        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
+        (4, 13)
+    }
+    pub(crate) fn __reduce35<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // BinOps = LogExp => ActionFn(19);
+        let __sym0 = __pop_Variant7(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action19::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
+        (1, 14)
+    }
+    pub(crate) fn __reduce36<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // Binding = Id, "=", Exp => ActionFn(84);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant7(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant5(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action84::<>(errors, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant12(__nt), __end));
+        (3, 15)
+    }
+    pub(crate) fn __reduce37<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // Binding = Id, "=", Recovered => ActionFn(143);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant7(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant5(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action143::<>(errors, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant12(__nt), __end));
+        (3, 15)
+    }
+    pub(crate) fn __reduce38<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // Binding = Id, Recovered => ActionFn(144);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant7(__symbols);
+        let __sym0 = __pop_Variant5(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym1.2.clone();
+        let __nt = super::__action144::<>(errors, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant12(__nt), __end));
+        (2, 15)
+    }
+    pub(crate) fn __reduce39<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // Bindings = Binding => ActionFn(82);
+        let __sym0 = __pop_Variant12(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action82::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant13(__nt), __end));
+        (1, 16)
+    }
+    pub(crate) fn __reduce40<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // Bindings = Bindings, ",", Binding => ActionFn(83);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant12(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant13(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action83::<>(errors, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant13(__nt), __end));
+        (3, 16)
+    }
+    pub(crate) fn __reduce41<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // BitAnd = "&" => ActionFn(36);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action36::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant14(__nt), __end));
+        (1, 17)
+    }
+    pub(crate) fn __reduce42<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // BitAndExp = LAssoc<BitAnd, ShiftExp> => ActionFn(24);
+        let __sym0 = __pop_Variant7(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action24::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
+        (1, 18)
+    }
+    pub(crate) fn __reduce43<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // BitOr = "|" => ActionFn(34);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action34::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant14(__nt), __end));
+        (1, 19)
+    }
+    pub(crate) fn __reduce44<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // BitOrExp = LAssoc<BitOr, BitXorExp> => ActionFn(22);
+        let __sym0 = __pop_Variant7(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action22::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
+        (1, 20)
+    }
+    pub(crate) fn __reduce45<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // BitXor = "^" => ActionFn(35);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action35::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant14(__nt), __end));
+        (1, 21)
+    }
+    pub(crate) fn __reduce46<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // BitXorExp = LAssoc<BitXor, BitAndExp> => ActionFn(23);
+        let __sym0 = __pop_Variant7(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action23::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
+        (1, 22)
+    }
+    pub(crate) fn __reduce47<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // Bool = "true" => ActionFn(89);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action89::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant15(__nt), __end));
+        (1, 23)
+    }
+    pub(crate) fn __reduce48<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // Bool = "false" => ActionFn(90);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action90::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant15(__nt), __end));
+        (1, 23)
+    }
+    pub(crate) fn __reduce49<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // Boxed<ClosedExp> = ClosedExp => ActionFn(114);
+        let __sym0 = __pop_Variant7(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action114::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant16(__nt), __end));
+        (1, 24)
+    }
+    pub(crate) fn __reduce50<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // Boxed<Exp> = Exp => ActionFn(119);
+        let __sym0 = __pop_Variant7(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action119::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant16(__nt), __end));
+        (1, 25)
+    }
+    pub(crate) fn __reduce51<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // Boxed<OpenExp> = OpenExp => ActionFn(131);
+        let __sym0 = __pop_Variant7(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action131::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant16(__nt), __end));
+        (1, 26)
+    }
+    pub(crate) fn __reduce52<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // ClosedExp = SET<ClosedExp> => ActionFn(15);
+        let __sym0 = __pop_Variant7(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action15::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
+        (1, 27)
+    }
+    pub(crate) fn __reduce53<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // ClosedExp = BinOps => ActionFn(16);
+        let __sym0 = __pop_Variant7(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action16::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
+        (1, 27)
+    }
+    pub(crate) fn __reduce54<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // Cmp = "<" => ActionFn(41);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action41::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant14(__nt), __end));
+        (1, 28)
+    }
+    pub(crate) fn __reduce55<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // Cmp = "<=" => ActionFn(42);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action42::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant14(__nt), __end));
+        (1, 28)
+    }
+    pub(crate) fn __reduce56<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // Cmp = ">" => ActionFn(43);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action43::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant14(__nt), __end));
+        (1, 28)
+    }
+    pub(crate) fn __reduce57<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // Cmp = ">=" => ActionFn(44);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action44::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant14(__nt), __end));
+        (1, 28)
+    }
+    pub(crate) fn __reduce58<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // Cmp = "==" => ActionFn(45);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action45::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant14(__nt), __end));
+        (1, 28)
+    }
+    pub(crate) fn __reduce59<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // Cmp = "!=" => ActionFn(46);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action46::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant14(__nt), __end));
+        (1, 28)
+    }
+    pub(crate) fn __reduce60<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // CmpExp = LAssoc<Cmp, BitOrExp> => ActionFn(21);
+        let __sym0 = __pop_Variant7(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action21::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
+        (1, 29)
+    }
+    pub(crate) fn __reduce61<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // Comma<Id> = Id => ActionFn(229);
+        let __sym0 = __pop_Variant5(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action229::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant17(__nt), __end));
+        (1, 30)
+    }
+    pub(crate) fn __reduce62<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // Comma<Id> =  => ActionFn(230);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
+        let __end = __start.clone();
+        let __nt = super::__action230::<>(errors, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant17(__nt), __end));
+        (0, 30)
+    }
+    pub(crate) fn __reduce63<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // Comma<Id> = (<Id> ",")+, Id => ActionFn(231);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant5(__symbols);
+        let __sym0 = __pop_Variant6(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym1.2.clone();
+        let __nt = super::__action231::<>(errors, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant17(__nt), __end));
+        (2, 30)
+    }
+    pub(crate) fn __reduce64<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // Comma<Id> = (<Id> ",")+ => ActionFn(232);
+        let __sym0 = __pop_Variant6(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action232::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant17(__nt), __end));
+        (1, 30)
+    }
+    pub(crate) fn __reduce65<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // Comma<ListExp> = ListExp => ActionFn(233);
+        let __sym0 = __pop_Variant7(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action233::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant18(__nt), __end));
+        (1, 31)
+    }
+    pub(crate) fn __reduce66<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // Comma<ListExp> =  => ActionFn(234);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
+        let __end = __start.clone();
+        let __nt = super::__action234::<>(errors, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant18(__nt), __end));
+        (0, 31)
+    }
+    pub(crate) fn __reduce67<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // Comma<ListExp> = (<ListExp> ",")+, ListExp => ActionFn(235);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant7(__symbols);
+        let __sym0 = __pop_Variant8(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym1.2.clone();
+        let __nt = super::__action235::<>(errors, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant18(__nt), __end));
+        (2, 31)
+    }
+    pub(crate) fn __reduce68<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // Comma<ListExp> = (<ListExp> ",")+ => ActionFn(236);
+        let __sym0 = __pop_Variant8(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action236::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant18(__nt), __end));
+        (1, 31)
+    }
+    pub(crate) fn __reduce69<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // Exp = SEQ => ActionFn(7);
+        let __sym0 = __pop_Variant7(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action7::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
+        (1, 32)
+    }
+    pub(crate) fn __reduce70<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // Exp = OpenExp => ActionFn(8);
+        let __sym0 = __pop_Variant7(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action8::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
+        (1, 32)
+    }
+    pub(crate) fn __reduce71<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // Exp = ClosedExp => ActionFn(9);
+        let __sym0 = __pop_Variant7(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action9::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
+        (1, 32)
+    }
+    pub(crate) fn __reduce72<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // FUNDEFS = FunDecl, "in", Boxed<Exp> => ActionFn(206);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant16(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant19(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action206::<>(errors, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
+        (3, 33)
+    }
+    pub(crate) fn __reduce73<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // FUNDEFS = (FunDecl "and")+, FunDecl, "in", Boxed<Exp> => ActionFn(207);
+        assert!(__symbols.len() >= 4);
+        let __sym3 = __pop_Variant16(__symbols);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant19(__symbols);
+        let __sym0 = __pop_Variant10(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym3.2.clone();
+        let __nt = super::__action207::<>(errors, __sym0, __sym1, __sym2, __sym3);
+        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
+        (4, 33)
+    }
+    pub(crate) fn __reduce74<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // FunDecl = "def", Id, "(", Comma<Id>, ")", ":", Exp => ActionFn(208);
+        assert!(__symbols.len() >= 7);
+        let __sym6 = __pop_Variant7(__symbols);
+        let __sym5 = __pop_Variant0(__symbols);
+        let __sym4 = __pop_Variant0(__symbols);
+        let __sym3 = __pop_Variant17(__symbols);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant5(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym6.2.clone();
+        let __nt = super::__action208::<>(errors, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5, __sym6);
+        __symbols.push((__start, __Symbol::Variant19(__nt), __end));
+        (7, 34)
+    }
+    pub(crate) fn __reduce75<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // FunDecl = "def", Id, "(", Comma<Id>, ")", ":", Recovered => ActionFn(209);
+        assert!(__symbols.len() >= 7);
+        let __sym6 = __pop_Variant7(__symbols);
+        let __sym5 = __pop_Variant0(__symbols);
+        let __sym4 = __pop_Variant0(__symbols);
+        let __sym3 = __pop_Variant17(__symbols);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant5(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym6.2.clone();
+        let __nt = super::__action209::<>(errors, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5, __sym6);
+        __symbols.push((__start, __Symbol::Variant19(__nt), __end));
+        (7, 34)
+    }
+    pub(crate) fn __reduce76<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // FunDecl = "def", Id, "(", Comma<Id>, ")", Recovered => ActionFn(210);
+        assert!(__symbols.len() >= 6);
+        let __sym5 = __pop_Variant7(__symbols);
+        let __sym4 = __pop_Variant0(__symbols);
+        let __sym3 = __pop_Variant17(__symbols);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant5(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym5.2.clone();
+        let __nt = super::__action210::<>(errors, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5);
+        __symbols.push((__start, __Symbol::Variant19(__nt), __end));
+        (6, 34)
+    }
+    pub(crate) fn __reduce77<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // IF = "if", Exp, ":", Exp, "else", ":", Exp => ActionFn(211);
+        assert!(__symbols.len() >= 7);
+        let __sym6 = __pop_Variant7(__symbols);
+        let __sym5 = __pop_Variant0(__symbols);
+        let __sym4 = __pop_Variant0(__symbols);
+        let __sym3 = __pop_Variant7(__symbols);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant7(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym6.2.clone();
+        let __nt = super::__action211::<>(errors, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5, __sym6);
+        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
+        (7, 35)
+    }
+    pub(crate) fn __reduce78<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // Id = "identifier" => ActionFn(88);
+        let __sym0 = __pop_Variant1(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action88::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
+        (1, 36)
+    }
+    pub(crate) fn __reduce79<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // Id? = Id => ActionFn(126);
+        let __sym0 = __pop_Variant5(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action126::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant20(__nt), __end));
+        (1, 37)
+    }
+    pub(crate) fn __reduce80<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // Id? =  => ActionFn(127);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
+        let __end = __start.clone();
+        let __nt = super::__action127::<>(errors, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant20(__nt), __end));
+        (0, 37)
+    }
+    pub(crate) fn __reduce81<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // LAssoc<BitAnd, ShiftExp> = LAssoc<BitAnd, ShiftExp>, BitAnd, ShiftExp => ActionFn(212);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant7(__symbols);
+        let __sym1 = __pop_Variant14(__symbols);
+        let __sym0 = __pop_Variant7(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action212::<>(errors, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
+        (3, 38)
+    }
+    pub(crate) fn __reduce82<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // LAssoc<BitAnd, ShiftExp> = ShiftExp => ActionFn(101);
+        let __sym0 = __pop_Variant7(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action101::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
+        (1, 38)
+    }
+    pub(crate) fn __reduce83<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // LAssoc<BitOr, BitXorExp> = LAssoc<BitOr, BitXorExp>, BitOr, BitXorExp => ActionFn(213);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant7(__symbols);
+        let __sym1 = __pop_Variant14(__symbols);
+        let __sym0 = __pop_Variant7(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action213::<>(errors, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
+        (3, 39)
+    }
+    pub(crate) fn __reduce84<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // LAssoc<BitOr, BitXorExp> = BitXorExp => ActionFn(105);
+        let __sym0 = __pop_Variant7(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action105::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
+        (1, 39)
+    }
+    pub(crate) fn __reduce85<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // LAssoc<BitXor, BitAndExp> = LAssoc<BitXor, BitAndExp>, BitXor, BitAndExp => ActionFn(214);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant7(__symbols);
+        let __sym1 = __pop_Variant14(__symbols);
+        let __sym0 = __pop_Variant7(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action214::<>(errors, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
+        (3, 40)
+    }
+    pub(crate) fn __reduce86<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // LAssoc<BitXor, BitAndExp> = BitAndExp => ActionFn(103);
+        let __sym0 = __pop_Variant7(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action103::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
+        (1, 40)
+    }
+    pub(crate) fn __reduce87<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // LAssoc<Cmp, BitOrExp> = LAssoc<Cmp, BitOrExp>, Cmp, BitOrExp => ActionFn(215);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant7(__symbols);
+        let __sym1 = __pop_Variant14(__symbols);
+        let __sym0 = __pop_Variant7(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action215::<>(errors, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
+        (3, 41)
+    }
+    pub(crate) fn __reduce88<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // LAssoc<Cmp, BitOrExp> = BitOrExp => ActionFn(107);
+        let __sym0 = __pop_Variant7(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action107::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
+        (1, 41)
+    }
+    pub(crate) fn __reduce89<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // LAssoc<PlusMinus, Product> = LAssoc<PlusMinus, Product>, PlusMinus, Product => ActionFn(216);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant7(__symbols);
+        let __sym1 = __pop_Variant14(__symbols);
+        let __sym0 = __pop_Variant7(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action216::<>(errors, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
+        (3, 42)
+    }
+    pub(crate) fn __reduce90<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // LAssoc<PlusMinus, Product> = Product => ActionFn(97);
+        let __sym0 = __pop_Variant7(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action97::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
+        (1, 42)
+    }
+    pub(crate) fn __reduce91<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // LAssoc<Shift, Sum> = LAssoc<Shift, Sum>, Shift, Sum => ActionFn(217);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant7(__symbols);
+        let __sym1 = __pop_Variant14(__symbols);
+        let __sym0 = __pop_Variant7(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action217::<>(errors, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
+        (3, 43)
+    }
+    pub(crate) fn __reduce92<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // LAssoc<Shift, Sum> = Sum => ActionFn(99);
+        let __sym0 = __pop_Variant7(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action99::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
+        (1, 43)
+    }
+    pub(crate) fn __reduce93<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // LAssoc<Times, NotExp> = LAssoc<Times, NotExp>, Times, NotExp => ActionFn(218);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant7(__symbols);
+        let __sym1 = __pop_Variant14(__symbols);
+        let __sym0 = __pop_Variant7(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action218::<>(errors, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
+        (3, 44)
+    }
+    pub(crate) fn __reduce94<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // LAssoc<Times, NotExp> = NotExp => ActionFn(95);
+        let __sym0 = __pop_Variant7(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action95::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
+        (1, 44)
+    }
+    pub(crate) fn __reduce95<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // LET = "let", Bindings, "in", Exp => ActionFn(219);
+        assert!(__symbols.len() >= 4);
+        let __sym3 = __pop_Variant7(__symbols);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant13(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym3.2.clone();
+        let __nt = super::__action219::<>(errors, __sym0, __sym1, __sym2, __sym3);
+        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
+        (4, 45)
+    }
+    pub(crate) fn __reduce96<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // ListExp = Exp => ActionFn(64);
+        let __sym0 = __pop_Variant7(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action64::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
+        (1, 46)
+    }
+    pub(crate) fn __reduce97<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // ListExp = Recovered => ActionFn(65);
+        let __sym0 = __pop_Variant7(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action65::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
+        (1, 46)
+    }
+    pub(crate) fn __reduce98<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // ListExp? = ListExp => ActionFn(132);
+        let __sym0 = __pop_Variant7(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action132::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant21(__nt), __end));
+        (1, 47)
+    }
+    pub(crate) fn __reduce99<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // ListExp? =  => ActionFn(133);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
+        let __end = __start.clone();
+        let __nt = super::__action133::<>(errors, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant21(__nt), __end));
+        (0, 47)
+    }
+    pub(crate) fn __reduce100<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // Log = "&&" => ActionFn(39);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action39::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant14(__nt), __end));
+        (1, 48)
+    }
+    pub(crate) fn __reduce101<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // Log = "||" => ActionFn(40);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action40::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant14(__nt), __end));
+        (1, 48)
+    }
+    pub(crate) fn __reduce102<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // LogExp = RAssoc<Log, CmpExp> => ActionFn(20);
+        let __sym0 = __pop_Variant7(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action20::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
+        (1, 49)
+    }
+    pub(crate) fn __reduce103<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // NotExp = "!", BaseExp => ActionFn(220);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant7(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym1.2.clone();
+        let __nt = super::__action220::<>(errors, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
+        (2, 50)
+    }
+    pub(crate) fn __reduce104<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // NotExp = "-", BaseExp => ActionFn(221);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant7(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym1.2.clone();
+        let __nt = super::__action221::<>(errors, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
+        (2, 50)
+    }
+    pub(crate) fn __reduce105<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // NotExp = BaseExp => ActionFn(49);
+        let __sym0 = __pop_Variant7(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action49::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
+        (1, 50)
+    }
+    pub(crate) fn __reduce106<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // Num = "number" => ActionFn(86);
+        let __sym0 = __pop_Variant2(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action86::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant2(__nt), __end));
+        (1, 51)
+    }
+    pub(crate) fn __reduce107<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // OpenExp = IF => ActionFn(11);
+        let __sym0 = __pop_Variant7(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action11::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
+        (1, 52)
+    }
+    pub(crate) fn __reduce108<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // OpenExp = LET => ActionFn(12);
+        let __sym0 = __pop_Variant7(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action12::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
+        (1, 52)
+    }
+    pub(crate) fn __reduce109<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // OpenExp = FUNDEFS => ActionFn(13);
+        let __sym0 = __pop_Variant7(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action13::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
+        (1, 52)
+    }
+    pub(crate) fn __reduce110<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // OpenExp = SET<OpenExp> => ActionFn(14);
+        let __sym0 = __pop_Variant7(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action14::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
+        (1, 52)
+    }
+    pub(crate) fn __reduce111<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // PlusMinus = "+" => ActionFn(28);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action28::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant14(__nt), __end));
+        (1, 53)
+    }
+    pub(crate) fn __reduce112<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // PlusMinus = "-" => ActionFn(29);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action29::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant14(__nt), __end));
+        (1, 53)
+    }
+    pub(crate) fn __reduce113<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // PlusMinus = "++" => ActionFn(30);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action30::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant14(__nt), __end));
+        (1, 53)
+    }
+    pub(crate) fn __reduce114<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // Prim1 = "add1" => ActionFn(66);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action66::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant14(__nt), __end));
+        (1, 54)
+    }
+    pub(crate) fn __reduce115<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // Prim1 = "sub1" => ActionFn(67);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action67::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant14(__nt), __end));
+        (1, 54)
+    }
+    pub(crate) fn __reduce116<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // Prim1 = "print" => ActionFn(68);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action68::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant14(__nt), __end));
+        (1, 54)
+    }
+    pub(crate) fn __reduce117<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // Prim1 = "isnum" => ActionFn(69);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action69::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant14(__nt), __end));
+        (1, 54)
+    }
+    pub(crate) fn __reduce118<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // Prim1 = "isbool" => ActionFn(70);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action70::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant14(__nt), __end));
+        (1, 54)
+    }
+    pub(crate) fn __reduce119<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // Prim1 = "istuple" => ActionFn(71);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action71::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant14(__nt), __end));
+        (1, 54)
+    }
+    pub(crate) fn __reduce120<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // Prim1 = "length" => ActionFn(72);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action72::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant14(__nt), __end));
+        (1, 54)
+    }
+    pub(crate) fn __reduce121<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // Prim1 = "abs" => ActionFn(73);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action73::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant14(__nt), __end));
+        (1, 54)
+    }
+    pub(crate) fn __reduce122<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // Prim1 = "isstring" => ActionFn(74);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action74::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant14(__nt), __end));
+        (1, 54)
+    }
+    pub(crate) fn __reduce123<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // Prim1 = "strlen" => ActionFn(75);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action75::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant14(__nt), __end));
+        (1, 54)
+    }
+    pub(crate) fn __reduce124<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // Prim1 = "num_to_string" => ActionFn(76);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action76::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant14(__nt), __end));
+        (1, 54)
+    }
+    pub(crate) fn __reduce125<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // Prim1 = "string_to_num" => ActionFn(77);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action77::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant14(__nt), __end));
+        (1, 54)
+    }
+    pub(crate) fn __reduce126<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // Prim2 = "min" => ActionFn(78);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action78::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant14(__nt), __end));
+        (1, 55)
+    }
+    pub(crate) fn __reduce127<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // Prim2 = "max" => ActionFn(79);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action79::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant14(__nt), __end));
+        (1, 55)
+    }
+    pub(crate) fn __reduce128<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // Prim2 = "char_at" => ActionFn(80);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action80::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant14(__nt), __end));
+        (1, 55)
+    }
+    pub(crate) fn __reduce129<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // Prim3 = "substr" => ActionFn(81);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action81::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant14(__nt), __end));
+        (1, 56)
+    }
+    pub(crate) fn __reduce130<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // Product = LAssoc<Times, NotExp> => ActionFn(27);
+        let __sym0 = __pop_Variant7(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action27::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
+        (1, 57)
+    }
+    pub(crate) fn __reduce131<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // Prog = Exp => ActionFn(2);
+        let __sym0 = __pop_Variant7(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action2::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant22(__nt), __end));
+        (1, 58)
+    }
+    pub(crate) fn __reduce132<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // RAssoc<Log, CmpExp> = CmpExp, Log, RAssoc<Log, CmpExp> => ActionFn(222);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant7(__symbols);
+        let __sym1 = __pop_Variant14(__symbols);
+        let __sym0 = __pop_Variant7(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action222::<>(errors, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
+        (3, 59)
+    }
+    pub(crate) fn __reduce133<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // RAssoc<Log, CmpExp> = CmpExp => ActionFn(109);
+        let __sym0 = __pop_Variant7(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action109::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
+        (1, 59)
+    }
+    pub(crate) fn __reduce134<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // Recovered = error => ActionFn(223);
+        let __sym0 = __pop_Variant3(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action223::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
+        (1, 60)
+    }
+    pub(crate) fn __reduce135<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // SEQ = Boxed<ClosedExp>, ";", Boxed<Exp> => ActionFn(224);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant16(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant16(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action224::<>(errors, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
+        (3, 61)
+    }
+    pub(crate) fn __reduce136<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // SET<ClosedExp> = BaseExp, "[", Exp, "]", ":=", ClosedExp => ActionFn(225);
+        assert!(__symbols.len() >= 6);
+        let __sym5 = __pop_Variant7(__symbols);
+        let __sym4 = __pop_Variant0(__symbols);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant7(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant7(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym5.2.clone();
+        let __nt = super::__action225::<>(errors, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5);
+        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
+        (6, 62)
+    }
+    pub(crate) fn __reduce137<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // SET<ClosedExp> = Id, ":=", Boxed<ClosedExp> => ActionFn(226);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant16(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant5(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action226::<>(errors, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
+        (3, 62)
+    }
+    pub(crate) fn __reduce138<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // SET<OpenExp> = BaseExp, "[", Exp, "]", ":=", OpenExp => ActionFn(227);
+        assert!(__symbols.len() >= 6);
+        let __sym5 = __pop_Variant7(__symbols);
+        let __sym4 = __pop_Variant0(__symbols);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant7(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant7(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym5.2.clone();
+        let __nt = super::__action227::<>(errors, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5);
+        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
+        (6, 63)
+    }
+    pub(crate) fn __reduce139<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // SET<OpenExp> = Id, ":=", Boxed<OpenExp> => ActionFn(228);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant16(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant5(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action228::<>(errors, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
+        (3, 63)
+    }
+    pub(crate) fn __reduce140<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // Shift = "<<" => ActionFn(37);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action37::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant14(__nt), __end));
+        (1, 64)
+    }
+    pub(crate) fn __reduce141<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // Shift = ">>" => ActionFn(38);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action38::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant14(__nt), __end));
+        (1, 64)
+    }
+    pub(crate) fn __reduce142<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // ShiftExp = LAssoc<Shift, Sum> => ActionFn(25);
+        let __sym0 = __pop_Variant7(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action25::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
+        (1, 65)
+    }
+    pub(crate) fn __reduce143<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // Str = "string" => ActionFn(87);
+        let __sym0 = __pop_Variant1(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action87::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
+        (1, 66)
+    }
+    pub(crate) fn __reduce144<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // Sum = LAssoc<PlusMinus, Product> => ActionFn(26);
+        let __sym0 = __pop_Variant7(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action26::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
+        (1, 67)
+    }
+    pub(crate) fn __reduce145<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // Times = "*" => ActionFn(31);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action31::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant14(__nt), __end));
+        (1, 68)
+    }
+    pub(crate) fn __reduce146<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // Times = "/" => ActionFn(32);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action32::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant14(__nt), __end));
+        (1, 68)
+    }
+    pub(crate) fn __reduce147<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // Times = "%" => ActionFn(33);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action33::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant14(__nt), __end));
+        (1, 68)
+    }
+    pub(crate) fn __reduce148<
+        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: core::marker::PhantomData<(&'input (), &'err ())>,
+    ) -> (usize, usize)
+    {
+        // __Exp = Exp => ActionFn(1);
+        let __sym0 = __pop_Variant7(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action1::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
+        (1, 69)
+    }
+}
+pub use self::__parse__Prog::ProgParser;
+
+#[allow(unused_variables)]
+fn __action0<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+    (_, __0, _): (usize, SurfProg<Span1>, usize),
+) -> SurfProg<Span1>
+{
+    __0
+}
+
+#[allow(unused_variables)]
+fn __action1<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+    (_, __0, _): (usize, Exp<Span1>, usize),
+) -> Exp<Span1>
+{
+    __0
+}
+
+#[allow(unused_variables)]
+fn __action2<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+    (_, __0, _): (usize, Exp<Span1>, usize),
+) -> SurfProg<Span1>
+{
+    __0
+}
+
+#[allow(unused_variables)]
+fn __action3<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+    (_, l, _): (usize, usize, usize),
+    (_, funs, _): (usize, alloc::vec::Vec<(SurfFunDecl<Span1>, Tok<'input>)>, usize),
+    (_, last, _): (usize, SurfFunDecl<Span1>, usize),
+    (_, _, _): (usize, Tok<'input>, usize),
+    (_, body, _): (usize, Box<Exp<Span1>>, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Exp<Span1>
+{
+    {
+      let mut decls: Vec<SurfFunDecl<Span1>> = funs.into_iter().map(|(decl, _)| decl).collect();
+      decls.push(last);
+      Exp::FunDefs { decls, body, ann: Span1 { start_ix: l, end_ix: r} }
+    }
+}
+
+#[allow(unused_variables)]
+fn __action4<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+    (_, l, _): (usize, usize, usize),
+    (_, _, _): (usize, Tok<'input>, usize),
+    (_, name, _): (usize, String, usize),
+    (_, _, _): (usize, Tok<'input>, usize),
+    (_, parameters, _): (usize, Vec<String>, usize),
+    (_, _, _): (usize, Tok<'input>, usize),
+    (_, _, _): (usize, Tok<'input>, usize),
+    (_, body, _): (usize, Exp<Span1>, usize),
+    (_, r, _): (usize, usize, usize),
+) -> SurfFunDecl<Span1>
+{
+    FunDecl { name, parameters, body, ann: Span1 { start_ix: l, end_ix: r}  }
+}
+
+#[allow(unused_variables)]
+fn __action5<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+    (_, l, _): (usize, usize, usize),
+    (_, _, _): (usize, Tok<'input>, usize),
+    (_, name, _): (usize, String, usize),
+    (_, _, _): (usize, Tok<'input>, usize),
+    (_, parameters, _): (usize, Vec<String>, usize),
+    (_, _, _): (usize, Tok<'input>, usize),
+    (_, _, _): (usize, core::option::Option<Tok<'input>>, usize),
+    (_, body, _): (usize, Exp<Span1>, usize),
+    (_, r, _): (usize, usize, usize),
+) -> SurfFunDecl<Span1>
+{
+    FunDecl { name, parameters, body, ann: Span1 { start_ix: l, end_ix: r}  }
+}
+
+#[allow(unused_variables)]
+fn __action6<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+    (_, l, _): (usize, usize, usize),
+    (_, e, _): (usize, __lalrpop_util::ErrorRecovery<usize, Tok<'input>, LexError>, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Exp<Span1>
+{
+    {
+        errors.push(e);
+        Exp::Num(0, Span1 { start_ix: l, end_ix: r })
+    }
+}
+
+#[allow(unused_variables)]
+fn __action7<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+    (_, __0, _): (usize, Exp<Span1>, usize),
+) -> Exp<Span1>
+{
+    __0
+}
+
+#[allow(unused_variables)]
+fn __action8<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+    (_, __0, _): (usize, Exp<Span1>, usize),
+) -> Exp<Span1>
+{
+    __0
+}
+
+#[allow(unused_variables)]
+fn __action9<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+    (_, __0, _): (usize, Exp<Span1>, usize),
+) -> Exp<Span1>
+{
+    __0
+}
+
+#[allow(unused_variables)]
+fn __action10<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+    (_, l, _): (usize, usize, usize),
+    (_, first, _): (usize, Box<Exp<Span1>>, usize),
+    (_, _, _): (usize, Tok<'input>, usize),
+    (_, second, _): (usize, Box<Exp<Span1>>, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Exp<Span1>
+{
+    Exp::Seq { first, second, ann: Span1 { start_ix: l, end_ix: r } }
+}
+
+#[allow(unused_variables)]
+fn __action11<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+    (_, __0, _): (usize, Exp<Span1>, usize),
+) -> Exp<Span1>
+{
+    __0
+}
+
+#[allow(unused_variables)]
+fn __action12<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+    (_, __0, _): (usize, Exp<Span1>, usize),
+) -> Exp<Span1>
+{
+    __0
+}
+
+#[allow(unused_variables)]
+fn __action13<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+    (_, __0, _): (usize, Exp<Span1>, usize),
+) -> Exp<Span1>
+{
+    __0
+}
+
+#[allow(unused_variables)]
+fn __action14<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+    (_, __0, _): (usize, Exp<Span1>, usize),
+) -> Exp<Span1>
+{
+    __0
+}
+
+#[allow(unused_variables)]
+fn __action15<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+    (_, __0, _): (usize, Exp<Span1>, usize),
+) -> Exp<Span1>
+{
+    __0
+}
+
+#[allow(unused_variables)]
+fn __action16<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+    (_, __0, _): (usize, Exp<Span1>, usize),
+) -> Exp<Span1>
+{
+    __0
+}
+
+#[allow(unused_variables)]
+fn __action17<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+    (_, l, _): (usize, usize, usize),
+    (_, _, _): (usize, Tok<'input>, usize),
+    (_, cond, _): (usize, Exp<Span1>, usize),
+    (_, _, _): (usize, Tok<'input>, usize),
+    (_, thn, _): (usize, Exp<Span1>, usize),
+    (_, _, _): (usize, Tok<'input>, usize),
+    (_, _, _): (usize, Tok<'input>, usize),
+    (_, els, _): (usize, Exp<Span1>, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Exp<Span1>
+{
+    Exp::If { cond: Box::new(cond), thn: Box::new(thn), els: Box::new(els), ann: Span1 { start_ix: l, end_ix: r }}
+}
+
+#[allow(unused_variables)]
+fn __action18<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+    (_, l, _): (usize, usize, usize),
+    (_, _, _): (usize, Tok<'input>, usize),
+    (_, bindings, _): (usize, Vec<(String, Exp<Span1>)>, usize),
+    (_, _, _): (usize, Tok<'input>, usize),
+    (_, body, _): (usize, Exp<Span1>, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Exp<Span1>
+{
+    Exp::Let { bindings, body: Box::new(body), ann: Span1 { start_ix: l, end_ix: r }}
+}
+
+#[allow(unused_variables)]
+fn __action19<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+    (_, __0, _): (usize, Exp<Span1>, usize),
+) -> Exp<Span1>
+{
+    __0
+}
+
+#[allow(unused_variables)]
+fn __action20<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+    (_, __0, _): (usize, Exp<Span1>, usize),
+) -> Exp<Span1>
+{
+    __0
+}
+
+#[allow(unused_variables)]
+fn __action21<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+    (_, __0, _): (usize, Exp<Span1>, usize),
+) -> Exp<Span1>
+{
+    __0
+}
+
+#[allow(unused_variables)]
+fn __action22<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+    (_, __0, _): (usize, Exp<Span1>, usize),
+) -> Exp<Span1>
+{
+    __0
+}
+
+#[allow(unused_variables)]
+fn __action23<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+    (_, __0, _): (usize, Exp<Span1>, usize),
+) -> Exp<Span1>
+{
+    __0
+}
+
+#[allow(unused_variables)]
+fn __action24<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+    (_, __0, _): (usize, Exp<Span1>, usize),
+) -> Exp<Span1>
+{
+    __0
+}
+
+#[allow(unused_variables)]
+fn __action25<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+    (_, __0, _): (usize, Exp<Span1>, usize),
+) -> Exp<Span1>
+{
+    __0
+}
+
+#[allow(unused_variables)]
+fn __action26<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+    (_, __0, _): (usize, Exp<Span1>, usize),
+) -> Exp<Span1>
+{
+    __0
+}
+
+#[allow(unused_variables)]
+fn __action27<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+    (_, __0, _): (usize, Exp<Span1>, usize),
+) -> Exp<Span1>
+{
+    __0
+}
+
+#[allow(unused_variables)]
+fn __action28<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+    (_, __0, _): (usize, Tok<'input>, usize),
+) -> Prim
+{
+    Prim::Add
+}
+
+#[allow(unused_variables)]
+fn __action29<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+    (_, __0, _): (usize, Tok<'input>, usize),
+) -> Prim
+{
+    Prim::Sub
+}
+
+#[allow(unused_variables)]
+fn __action30<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+    (_, __0, _): (usize, Tok<'input>, usize),
+) -> Prim
+{
+    Prim::Concat
+}
+
+#[allow(unused_variables)]
+fn __action31<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+    (_, __0, _): (usize, Tok<'input>, usize),
+) -> Prim
+{
+    Prim::Mul
+}
+
+#[allow(unused_variables)]
+fn __action32<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+    (_, __0, _): (usize, Tok<'input>, usize),
+) -> Prim
+{
+    Prim::Div
+}
+
+#[allow(unused_variables)]
+fn __action33<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+    (_, __0, _): (usize, Tok<'input>, usize),
+) -> Prim
+{
+    Prim::Mod
+}
+
+#[allow(unused_variables)]
+fn __action34<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+    (_, __0, _): (usize, Tok<'input>, usize),
+) -> Prim
+{
+    Prim::BitOr
+}
+
+#[allow(unused_variables)]
+fn __action35<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+    (_, __0, _): (usize, Tok<'input>, usize),
+) -> Prim
+{
+    Prim::BitXor
+}
+
+#[allow(unused_variables)]
+fn __action36<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+    (_, __0, _): (usize, Tok<'input>, usize),
+) -> Prim
+{
+    Prim::BitAnd
+}
+
+#[allow(unused_variables)]
+fn __action37<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+    (_, __0, _): (usize, Tok<'input>, usize),
+) -> Prim
+{
+    Prim::Shl
+}
+
+#[allow(unused_variables)]
+fn __action38<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+    (_, __0, _): (usize, Tok<'input>, usize),
+) -> Prim
+{
+    Prim::Shr
+}
+
+#[allow(unused_variables)]
+fn __action39<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+    (_, __0, _): (usize, Tok<'input>, usize),
+) -> Prim
+{
+    Prim::And
+}
+
+#[allow(unused_variables)]
+fn __action40<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+    (_, __0, _): (usize, Tok<'input>, usize),
+) -> Prim
+{
+    Prim::Or
+}
+
+#[allow(unused_variables)]
+fn __action41<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+    (_, __0, _): (usize, Tok<'input>, usize),
+) -> Prim
+{
+    Prim::Lt
+}
+
+#[allow(unused_variables)]
+fn __action42<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+    (_, __0, _): (usize, Tok<'input>, usize),
+) -> Prim
+{
+    Prim::Le
+}
+
+#[allow(unused_variables)]
+fn __action43<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+    (_, __0, _): (usize, Tok<'input>, usize),
+) -> Prim
+{
+    Prim::Gt
+}
+
+#[allow(unused_variables)]
+fn __action44<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+    (_, __0, _): (usize, Tok<'input>, usize),
+) -> Prim
+{
+    Prim::Ge
+}
+
+#[allow(unused_variables)]
+fn __action45<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+    (_, __0, _): (usize, Tok<'input>, usize),
+) -> Prim
+{
+    Prim::Eq
+}
+
+#[allow(unused_variables)]
+fn __action46<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+    (_, __0, _): (usize, Tok<'input>, usize),
+) -> Prim
+{
+    Prim::Neq
+}
+
+#[allow(unused_variables)]
+fn __action47<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+    (_, l, _): (usize, usize, usize),
+    (_, _, _): (usize, Tok<'input>, usize),
+    (_, e, _): (usize, Exp<Span1>, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Exp<Span1>
+{
+    Exp::Prim(Prim::Not, vec![Box::new(e)], Span1 { start_ix: l, end_ix: r })
+}
+
+#[allow(unused_variables)]
+fn __action48<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+    (_, l, _): (usize, usize, usize),
+    (_, _, _): (usize, Tok<'input>, usize),
+    (_, e, _): (usize, Exp<Span1>, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Exp<Span1>
+{
+    Exp::Prim(Prim::Neg, vec![Box::new(e)], Span1 { start_ix: l, end_ix: r })
+}
+
+#[allow(unused_variables)]
+fn __action49<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+    (_, __0, _): (usize, Exp<Span1>, usize),
+) -> Exp<Span1>
+{
+    __0
+}
+
+#[allow(unused_variables)]
+fn __action50<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+    (_, l, _): (usize, usize, usize),
+    (_, x, _): (usize, String, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Exp<Span1>
+{
+    Exp::Var(x, Span1 { start_ix: l, end_ix: r })
+}
+
+#[allow(unused_variables)]
+fn __action51<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+    (_, l, _): (usize, usize, usize),
+    (_, b, _): (usize, bool, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Exp<Span1>
+{
+    Exp::Bool(b, Span1 { start_ix: l, end_ix: r })
+}
+
+#[allow(unused_variables)]
+fn __action52<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+    (_, l, _): (usize, usize, usize),
+    (_, n, _): (usize, i64, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Exp<Span1>
+{
+    Exp::Num(n, Span1 { start_ix: l, end_ix: r })
+}
+
+#[allow(unused_variables)]
+fn __action53<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+    (_, l, _): (usize, usize, usize),
+    (_, s, _): (usize, String, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Exp<Span1>
+{
+    Exp::Str(s, Span1 { start_ix: l, end_ix: r })
+}
+
+#[allow(unused_variables)]
+fn __action54<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+    (_, l, _): (usize, usize, usize),
+    (_, p, _): (usize, Prim, usize),
+    (_, _, _): (usize, Tok<'input>, usize),
+    (_, e, _): (usize, Exp<Span1>, usize),
+    (_, _, _): (usize, Tok<'input>, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Exp<Span1>
+{
+    Exp::Prim(p, vec![Box::new(e)], Span1 { start_ix: l, end_ix: r })
+}
+
+#[allow(unused_variables)]
+fn __action55<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+    (_, l, _): (usize, usize, usize),
+    (_, p, _): (usize, Prim, usize),
+    (_, _, _): (usize, Tok<'input>, usize),
+    (_, e1, _): (usize, Exp<Span1>, usize),
+    (_, _, _): (usize, Tok<'input>, usize),
+    (_, e2, _): (usize, Exp<Span1>, usize),
+    (_, _, _): (usize, Tok<'input>, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Exp<Span1>
+{
+    Exp::Prim(p, vec![Box::new(e1), Box::new(e2)], Span1 { start_ix: l, end_ix: r })
+}
+
+#[allow(unused_variables)]
+fn __action56<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+    (_, l, _): (usize, usize, usize),
+    (_, p, _): (usize, Prim, usize),
+    (_, _, _): (usize, Tok<'input>, usize),
+    (_, e1, _): (usize, Exp<Span1>, usize),
+    (_, _, _): (usize, Tok<'input>, usize),
+    (_, e2, _): (usize, Exp<Span1>, usize),
+    (_, _, _): (usize, Tok<'input>, usize),
+    (_, e3, _): (usize, Exp<Span1>, usize),
+    (_, _, _): (usize, Tok<'input>, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Exp<Span1>
+{
+    Exp::Prim(p, vec![Box::new(e1), Box::new(e2), Box::new(e3)], Span1 { start_ix: l, end_ix: r })
+}
+
+#[allow(unused_variables)]
+fn __action57<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+    (_, _, _): (usize, Tok<'input>, usize),
+    (_, e, _): (usize, Exp<Span1>, usize),
+    (_, _, _): (usize, Tok<'input>, usize),
+) -> Exp<Span1>
+{
+    e
+}
+
+#[allow(unused_variables)]
+fn __action58<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+    (_, l, _): (usize, usize, usize),
+    (_, x, _): (usize, String, usize),
+    (_, _, _): (usize, Tok<'input>, usize),
+    (_, es, _): (usize, Vec<Exp<Span1>>, usize),
+    (_, _, _): (usize, Tok<'input>, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Exp<Span1>
+{
+    Exp::Call(x, es, Span1 { start_ix: l, end_ix: r })
+}
+
+#[allow(unused_variables)]
+fn __action59<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+    (_, l, _): (usize, usize, usize),
+    (_, _, _): (usize, Tok<'input>, usize),
+    (_, parameters, _): (usize, Vec<String>, usize),
+    (_, _, _): (usize, Tok<'input>, usize),
+    (_, body, _): (usize, Box<Exp<Span1>>, usize),
+    (_, _, _): (usize, Tok<'input>, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Exp<Span1>
+{
+    Exp::Lambda { parameters, body, ann: Span1 { start_ix: l, end_ix: r } }
+}
+
+#[allow(unused_variables)]
+fn __action60<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+    (_, l, _): (usize, usize, usize),
+    (_, _, _): (usize, Tok<'input>, usize),
+    (_, cond, _): (usize, Box<Exp<Span1>>, usize),
+    (_, _, _): (usize, Tok<'input>, usize),
+    (_, body, _): (usize, Box<Exp<Span1>>, usize),
+    (_, _, _): (usize, Tok<'input>, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Exp<Span1>
+{
+    Exp::While { cond, body, ann: Span1 { start_ix: l, end_ix: r } }
+}
+
+#[allow(unused_variables)]
+fn __action61<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+    (_, l, _): (usize, usize, usize),
+    (_, _, _): (usize, Tok<'input>, usize),
+    (_, var, _): (usize, String, usize),
+    (_, _, _): (usize, Tok<'input>, usize),
+    (_, from, _): (usize, Box<Exp<Span1>>, usize),
+    (_, _, _): (usize, Tok<'input>, usize),
+    (_, to, _): (usize, Box<Exp<Span1>>, usize),
+    (_, _, _): (usize, Tok<'input>, usize),
+    (_, body, _): (usize, Box<Exp<Span1>>, usize),
+    (_, _, _): (usize, Tok<'input>, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Exp<Span1>
+{
+    Exp::For { var, from, to, body, ann: Span1 { start_ix: l, end_ix: r } }
+}
+
+#[allow(unused_variables)]
+fn __action62<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+    (_, l, _): (usize, usize, usize),
+    (_, _, _): (usize, Tok<'input>, usize),
+    (_, es, _): (usize, Vec<Exp<Span1>>, usize),
+    (_, _, _): (usize, Tok<'input>, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Exp<Span1>
+{
+    Exp::Prim(Prim::MakeTuple, es.into_iter().map(Box::new).collect(), Span1 { start_ix: l, end_ix: r })
+}
+
+#[allow(unused_variables)]
+fn __action63<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+    (_, l, _): (usize, usize, usize),
+    (_, t, _): (usize, Exp<Span1>, usize),
+    (_, _, _): (usize, Tok<'input>, usize),
+    (_, i, _): (usize, Exp<Span1>, usize),
+    (_, _, _): (usize, Tok<'input>, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Exp<Span1>
+{
+    Exp::Prim(Prim::TupleGet, vec![Box::new(t), Box::new(i)], Span1 { start_ix: l, end_ix: r })
+}
+
+#[allow(unused_variables)]
+fn __action64<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+    (_, __0, _): (usize, Exp<Span1>, usize),
+) -> Exp<Span1>
+{
+    __0
+}
+
+#[allow(unused_variables)]
+fn __action65<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+    (_, __0, _): (usize, Exp<Span1>, usize),
+) -> Exp<Span1>
+{
+    __0
+}
+
+#[allow(unused_variables)]
+fn __action66<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+    (_, __0, _): (usize, Tok<'input>, usize),
+) -> Prim
+{
+    Prim::Add1
+}
+
+#[allow(unused_variables)]
+fn __action67<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+    (_, __0, _): (usize, Tok<'input>, usize),
+) -> Prim
+{
+    Prim::Sub1
+}
+
+#[allow(unused_variables)]
+fn __action68<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+    (_, __0, _): (usize, Tok<'input>, usize),
+) -> Prim
+{
+    Prim::Print
+}
+
+#[allow(unused_variables)]
+fn __action69<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+    (_, __0, _): (usize, Tok<'input>, usize),
+) -> Prim
+{
+    Prim::IsNum
+}
+
+#[allow(unused_variables)]
+fn __action70<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+    (_, __0, _): (usize, Tok<'input>, usize),
+) -> Prim
+{
+    Prim::IsBool
+}
+
+#[allow(unused_variables)]
+fn __action71<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+    (_, __0, _): (usize, Tok<'input>, usize),
+) -> Prim
+{
+    Prim::IsTuple
+}
+
+#[allow(unused_variables)]
+fn __action72<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+    (_, __0, _): (usize, Tok<'input>, usize),
+) -> Prim
+{
+    Prim::Length
+}
+
+#[allow(unused_variables)]
+fn __action73<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+    (_, __0, _): (usize, Tok<'input>, usize),
+) -> Prim
+{
+    Prim::Abs
+}
+
+#[allow(unused_variables)]
+fn __action74<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+    (_, __0, _): (usize, Tok<'input>, usize),
+) -> Prim
+{
+    Prim::IsString
+}
+
+#[allow(unused_variables)]
+fn __action75<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+    (_, __0, _): (usize, Tok<'input>, usize),
+) -> Prim
+{
+    Prim::StrLen
+}
+
+#[allow(unused_variables)]
+fn __action76<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+    (_, __0, _): (usize, Tok<'input>, usize),
+) -> Prim
+{
+    Prim::NumToString
+}
+
+#[allow(unused_variables)]
+fn __action77<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+    (_, __0, _): (usize, Tok<'input>, usize),
+) -> Prim
+{
+    Prim::StringToNum
+}
+
+#[allow(unused_variables)]
+fn __action78<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+    (_, __0, _): (usize, Tok<'input>, usize),
+) -> Prim
+{
+    Prim::Min
+}
+
+#[allow(unused_variables)]
+fn __action79<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+    (_, __0, _): (usize, Tok<'input>, usize),
+) -> Prim
+{
+    Prim::Max
+}
+
+#[allow(unused_variables)]
+fn __action80<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+    (_, __0, _): (usize, Tok<'input>, usize),
+) -> Prim
+{
+    Prim::CharAt
+}
+
+#[allow(unused_variables)]
+fn __action81<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+    (_, __0, _): (usize, Tok<'input>, usize),
+) -> Prim
+{
+    Prim::Substr
+}
+
+#[allow(unused_variables)]
+fn __action82<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+    (_, b, _): (usize, (String, Exp<Span1>), usize),
+) -> Vec<(String, Exp<Span1>)>
+{
+    vec![b]
+}
+
+#[allow(unused_variables)]
+fn __action83<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+    (_, mut bs, _): (usize, Vec<(String, Exp<Span1>)>, usize),
+    (_, _, _): (usize, Tok<'input>, usize),
+    (_, b, _): (usize, (String, Exp<Span1>), usize),
+) -> Vec<(String, Exp<Span1>)>
+{
     {
-        // Times = "*" => ActionFn(18);
-        let __sym0 = __pop_Variant0(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action18::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
-        (1, 41)
+        bs.push(b);
+        bs
     }
-    pub(crate) fn __reduce83<
-        'input,
-    >(
-        input: &'input str,
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
-    ) -> (usize, usize)
+}
+
+#[allow(unused_variables)]
+fn __action84<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+    (_, x, _): (usize, String, usize),
+    (_, _, _): (usize, Tok<'input>, usize),
+    (_, e, _): (usize, Exp<Span1>, usize),
+) -> (String, Exp<Span1>)
+{
+    (x, e)
+}
+
+#[allow(unused_variables)]
+fn __action85<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+    (_, x, _): (usize, String, usize),
+    (_, _, _): (usize, core::option::Option<Tok<'input>>, usize),
+    (_, e, _): (usize, Exp<Span1>, usize),
+) -> (String, Exp<Span1>)
+{
+    (x, e)
+}
+
+#[allow(unused_variables)]
+fn __action86<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+    (_, __0, _): (usize, i64, usize),
+) -> i64
+{
+    __0
+}
+
+#[allow(unused_variables)]
+fn __action87<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+    (_, s, _): (usize, &'input str, usize),
+) -> String
+{
+    unescape(s)
+}
+
+#[allow(unused_variables)]
+fn __action88<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+    (_, s, _): (usize, &'input str, usize),
+) -> String
+{
+    String::from(s)
+}
+
+#[allow(unused_variables)]
+fn __action89<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+    (_, __0, _): (usize, Tok<'input>, usize),
+) -> bool
+{
+    true
+}
+
+#[allow(unused_variables)]
+fn __action90<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+    (_, __0, _): (usize, Tok<'input>, usize),
+) -> bool
+{
+    false
+}
+
+#[allow(unused_variables)]
+fn __action91<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+    (_, __0, _): (usize, Tok<'input>, usize),
+) -> core::option::Option<Tok<'input>>
+{
+    Some(__0)
+}
+
+#[allow(unused_variables)]
+fn __action92<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> core::option::Option<Tok<'input>>
+{
+    None
+}
+
+#[allow(unused_variables)]
+fn __action93<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+    (_, mut v, _): (usize, alloc::vec::Vec<Exp<Span1>>, usize),
+    (_, last, _): (usize, core::option::Option<Exp<Span1>>, usize),
+) -> Vec<Exp<Span1>>
+{
     {
-        // __Exp = Exp => ActionFn(1);
-        let __sym0 = __pop_Variant1(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action1::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 42)
+        match last {
+            None => { },
+            Some(t) => { v.push(t); }
+        };
+        v
     }
 }
-pub use self::__parse__Prog::ProgParser;
-#[cfg_attr(rustfmt, rustfmt_skip)]
-mod __intern_token {
-    #![allow(unused_imports)]
-    use std::str::FromStr;
-    use crate::syntax::{FunDecl, SurfProg, SurfFunDecl, Exp, Prim};
-    use crate::span::Span1;
-    #[allow(unused_extern_crates)]
-    extern crate lalrpop_util as __lalrpop_util;
-    #[allow(unused_imports)]
-    use self::__lalrpop_util::state_machine as __state_machine;
-    extern crate core;
-    extern crate alloc;
-    pub fn new_builder() -> __lalrpop_util::lexer::MatcherBuilder {
-        let __strs: &[(&str, bool)] = &[
-            ("^([\\+\\-]?[0-9]+)", false),
-            ("^([A-Za-z][0-9A-Z_a-z]*)", false),
-            ("^(!)", false),
-            ("^(!=)", false),
-            ("^(\\&\\&)", false),
-            ("^(\\()", false),
-            ("^(\\))", false),
-            ("^(\\*)", false),
-            ("^(\\+)", false),
-            ("^(,)", false),
-            ("^(\\-)", false),
-            ("^(:)", false),
-            ("^(<)", false),
-            ("^(<=)", false),
-            ("^(=)", false),
-            ("^(==)", false),
-            ("^(>)", false),
-            ("^(>=)", false),
-            ("^(add1)", false),
-            ("^(and)", false),
-            ("^(def)", false),
-            ("^(else)", false),
-            ("^(false)", false),
-            ("^(if)", false),
-            ("^(in)", false),
-            ("^(isbool)", false),
-            ("^(isnum)", false),
-            ("^(let)", false),
-            ("^(print)", false),
-            ("^(sub1)", false),
-            ("^(true)", false),
-            ("^(\\|\\|)", false),
-            (r"^(\s*)", true),
-        ];
-        __lalrpop_util::lexer::MatcherBuilder::new(__strs.iter().copied()).unwrap()
-    }
+
+#[allow(unused_variables)]
+fn __action94<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+    (_, l, _): (usize, usize, usize),
+    (_, e1, _): (usize, Exp<Span1>, usize),
+    (_, op, _): (usize, Prim, usize),
+    (_, e2, _): (usize, Exp<Span1>, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Exp<Span1>
+{
+    Exp::Prim(op, vec![Box::new(e1), Box::new(e2)], Span1 { start_ix: l, end_ix: r })
+}
+
+#[allow(unused_variables)]
+fn __action95<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+    (_, __0, _): (usize, Exp<Span1>, usize),
+) -> Exp<Span1>
+{
+    __0
+}
+
+#[allow(unused_variables)]
+fn __action96<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+    (_, l, _): (usize, usize, usize),
+    (_, e1, _): (usize, Exp<Span1>, usize),
+    (_, op, _): (usize, Prim, usize),
+    (_, e2, _): (usize, Exp<Span1>, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Exp<Span1>
+{
+    Exp::Prim(op, vec![Box::new(e1), Box::new(e2)], Span1 { start_ix: l, end_ix: r })
+}
+
+#[allow(unused_variables)]
+fn __action97<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+    (_, __0, _): (usize, Exp<Span1>, usize),
+) -> Exp<Span1>
+{
+    __0
+}
+
+#[allow(unused_variables)]
+fn __action98<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+    (_, l, _): (usize, usize, usize),
+    (_, e1, _): (usize, Exp<Span1>, usize),
+    (_, op, _): (usize, Prim, usize),
+    (_, e2, _): (usize, Exp<Span1>, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Exp<Span1>
+{
+    Exp::Prim(op, vec![Box::new(e1), Box::new(e2)], Span1 { start_ix: l, end_ix: r })
 }
-pub(crate) use self::__lalrpop_util::lexer::Token;
 
 #[allow(unused_variables)]
-fn __action0<
+fn __action99<
     'input,
+    'err,
 >(
-    input: &'input str,
-    (_, __0, _): (usize, SurfProg<Span1>, usize),
-) -> SurfProg<Span1>
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+    (_, __0, _): (usize, Exp<Span1>, usize),
+) -> Exp<Span1>
 {
     __0
 }
 
 #[allow(unused_variables)]
-fn __action1<
+fn __action100<
     'input,
+    'err,
 >(
-    input: &'input str,
-    (_, __0, _): (usize, Exp<Span1>, usize),
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+    (_, l, _): (usize, usize, usize),
+    (_, e1, _): (usize, Exp<Span1>, usize),
+    (_, op, _): (usize, Prim, usize),
+    (_, e2, _): (usize, Exp<Span1>, usize),
+    (_, r, _): (usize, usize, usize),
 ) -> Exp<Span1>
 {
-    __0
+    Exp::Prim(op, vec![Box::new(e1), Box::new(e2)], Span1 { start_ix: l, end_ix: r })
 }
 
 #[allow(unused_variables)]
-fn __action2<
+fn __action101<
     'input,
+    'err,
 >(
-    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
     (_, __0, _): (usize, Exp<Span1>, usize),
-) -> SurfProg<Span1>
+) -> Exp<Span1>
 {
     __0
 }
 
 #[allow(unused_variables)]
-fn __action3<
+fn __action102<
     'input,
+    'err,
 >(
-    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
     (_, l, _): (usize, usize, usize),
-    (_, funs, _): (usize, alloc::vec::Vec<(SurfFunDecl<Span1>, &'input str)>, usize),
-    (_, last, _): (usize, SurfFunDecl<Span1>, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, body, _): (usize, Box<Exp<Span1>>, usize),
+    (_, e1, _): (usize, Exp<Span1>, usize),
+    (_, op, _): (usize, Prim, usize),
+    (_, e2, _): (usize, Exp<Span1>, usize),
     (_, r, _): (usize, usize, usize),
 ) -> Exp<Span1>
 {
-    {
-      let mut decls: Vec<SurfFunDecl<Span1>> = funs.into_iter().map(|(decl, _)| decl).collect();
-      decls.push(last);
-      Exp::FunDefs { decls, body, ann: Span1 { start_ix: l, end_ix: r} }
-    }
+    Exp::Prim(op, vec![Box::new(e1), Box::new(e2)], Span1 { start_ix: l, end_ix: r })
 }
 
 #[allow(unused_variables)]
-fn __action4<
+fn __action103<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+    (_, __0, _): (usize, Exp<Span1>, usize),
+) -> Exp<Span1>
+{
+    __0
+}
+
+#[allow(unused_variables)]
+fn __action104<
     'input,
+    'err,
 >(
-    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
     (_, l, _): (usize, usize, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, name, _): (usize, String, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, parameters, _): (usize, Vec<String>, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, body, _): (usize, Exp<Span1>, usize),
+    (_, e1, _): (usize, Exp<Span1>, usize),
+    (_, op, _): (usize, Prim, usize),
+    (_, e2, _): (usize, Exp<Span1>, usize),
     (_, r, _): (usize, usize, usize),
-) -> SurfFunDecl<Span1>
+) -> Exp<Span1>
 {
-    FunDecl { name, parameters, body, ann: Span1 { start_ix: l, end_ix: r}  }
+    Exp::Prim(op, vec![Box::new(e1), Box::new(e2)], Span1 { start_ix: l, end_ix: r })
 }
 
 #[allow(unused_variables)]
-fn __action5<
+fn __action105<
     'input,
+    'err,
 >(
-    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
     (_, __0, _): (usize, Exp<Span1>, usize),
 ) -> Exp<Span1>
 {
@@ -5689,21 +13188,27 @@ fn __action5<
 }
 
 #[allow(unused_variables)]
-fn __action6<
+fn __action106<
     'input,
+    'err,
 >(
-    input: &'input str,
-    (_, __0, _): (usize, Exp<Span1>, usize),
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+    (_, l, _): (usize, usize, usize),
+    (_, e1, _): (usize, Exp<Span1>, usize),
+    (_, op, _): (usize, Prim, usize),
+    (_, e2, _): (usize, Exp<Span1>, usize),
+    (_, r, _): (usize, usize, usize),
 ) -> Exp<Span1>
 {
-    __0
+    Exp::Prim(op, vec![Box::new(e1), Box::new(e2)], Span1 { start_ix: l, end_ix: r })
 }
 
 #[allow(unused_variables)]
-fn __action7<
+fn __action107<
     'input,
+    'err,
 >(
-    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
     (_, __0, _): (usize, Exp<Span1>, usize),
 ) -> Exp<Span1>
 {
@@ -5711,10 +13216,27 @@ fn __action7<
 }
 
 #[allow(unused_variables)]
-fn __action8<
+fn __action108<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+    (_, l, _): (usize, usize, usize),
+    (_, e1, _): (usize, Exp<Span1>, usize),
+    (_, op, _): (usize, Prim, usize),
+    (_, e2, _): (usize, Exp<Span1>, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Exp<Span1>
+{
+    Exp::Prim(op, vec![Box::new(e1), Box::new(e2)], Span1 { start_ix: l, end_ix: r })
+}
+
+#[allow(unused_variables)]
+fn __action109<
     'input,
+    'err,
 >(
-    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
     (_, __0, _): (usize, Exp<Span1>, usize),
 ) -> Exp<Span1>
 {
@@ -5722,1371 +13244,2321 @@ fn __action8<
 }
 
 #[allow(unused_variables)]
-fn __action9<
+fn __action110<
     'input,
+    'err,
 >(
-    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
     (_, l, _): (usize, usize, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, cond, _): (usize, Exp<Span1>, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, thn, _): (usize, Exp<Span1>, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, els, _): (usize, Exp<Span1>, usize),
+    (_, t, _): (usize, Exp<Span1>, usize),
+    (_, _, _): (usize, Tok<'input>, usize),
+    (_, i, _): (usize, Exp<Span1>, usize),
+    (_, _, _): (usize, Tok<'input>, usize),
+    (_, _, _): (usize, Tok<'input>, usize),
+    (_, v, _): (usize, Exp<Span1>, usize),
     (_, r, _): (usize, usize, usize),
 ) -> Exp<Span1>
 {
-    Exp::If { cond: Box::new(cond), thn: Box::new(thn), els: Box::new(els), ann: Span1 { start_ix: l, end_ix: r }}
+    Exp::Prim(Prim::TupleSet, vec![Box::new(t), Box::new(i), Box::new(v)], Span1 { start_ix: l, end_ix: r })
 }
 
 #[allow(unused_variables)]
-fn __action10<
+fn __action111<
     'input,
+    'err,
 >(
-    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
     (_, l, _): (usize, usize, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, bindings, _): (usize, Vec<(String, Exp<Span1>)>, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, body, _): (usize, Exp<Span1>, usize),
+    (_, var, _): (usize, String, usize),
+    (_, _, _): (usize, Tok<'input>, usize),
+    (_, v, _): (usize, Box<Exp<Span1>>, usize),
     (_, r, _): (usize, usize, usize),
 ) -> Exp<Span1>
 {
-    Exp::Let { bindings, body: Box::new(body), ann: Span1 { start_ix: l, end_ix: r }}
+    Exp::Assign { var, value: v, ann: Span1 { start_ix: l, end_ix: r } }
 }
 
 #[allow(unused_variables)]
-fn __action11<
+fn __action112<
     'input,
+    'err,
 >(
-    input: &'input str,
-    (_, __0, _): (usize, Exp<Span1>, usize),
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+    (_, l, _): (usize, usize, usize),
+    (_, t, _): (usize, Exp<Span1>, usize),
+    (_, _, _): (usize, Tok<'input>, usize),
+    (_, i, _): (usize, Exp<Span1>, usize),
+    (_, _, _): (usize, Tok<'input>, usize),
+    (_, _, _): (usize, Tok<'input>, usize),
+    (_, v, _): (usize, Exp<Span1>, usize),
+    (_, r, _): (usize, usize, usize),
 ) -> Exp<Span1>
 {
-    __0
+    Exp::Prim(Prim::TupleSet, vec![Box::new(t), Box::new(i), Box::new(v)], Span1 { start_ix: l, end_ix: r })
 }
 
 #[allow(unused_variables)]
-fn __action12<
+fn __action113<
     'input,
+    'err,
 >(
-    input: &'input str,
-    (_, __0, _): (usize, Exp<Span1>, usize),
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+    (_, l, _): (usize, usize, usize),
+    (_, var, _): (usize, String, usize),
+    (_, _, _): (usize, Tok<'input>, usize),
+    (_, v, _): (usize, Box<Exp<Span1>>, usize),
+    (_, r, _): (usize, usize, usize),
 ) -> Exp<Span1>
 {
-    __0
+    Exp::Assign { var, value: v, ann: Span1 { start_ix: l, end_ix: r } }
 }
 
 #[allow(unused_variables)]
-fn __action13<
+fn __action114<
     'input,
+    'err,
 >(
-    input: &'input str,
-    (_, __0, _): (usize, Exp<Span1>, usize),
-) -> Exp<Span1>
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+    (_, t, _): (usize, Exp<Span1>, usize),
+) -> Box<Exp<Span1>>
 {
-    __0
+    Box::new(t)
 }
 
 #[allow(unused_variables)]
-fn __action14<
+fn __action115<
     'input,
+    'err,
 >(
-    input: &'input str,
-    (_, __0, _): (usize, Exp<Span1>, usize),
-) -> Exp<Span1>
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+    (_, __0, _): (usize, Tok<'input>, usize),
+) -> core::option::Option<Tok<'input>>
 {
-    __0
+    Some(__0)
 }
 
 #[allow(unused_variables)]
-fn __action15<
+fn __action116<
     'input,
+    'err,
 >(
-    input: &'input str,
-    (_, __0, _): (usize, Exp<Span1>, usize),
-) -> Exp<Span1>
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> core::option::Option<Tok<'input>>
 {
-    __0
+    None
 }
 
 #[allow(unused_variables)]
-fn __action16<
+fn __action117<
     'input,
+    'err,
 >(
-    input: &'input str,
-    (_, __0, _): (usize, &'input str, usize),
-) -> Prim
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+    (_, mut v, _): (usize, alloc::vec::Vec<String>, usize),
+    (_, last, _): (usize, core::option::Option<String>, usize),
+) -> Vec<String>
 {
-    Prim::Add
+    {
+        match last {
+            None => { },
+            Some(t) => { v.push(t); }
+        };
+        v
+    }
 }
 
 #[allow(unused_variables)]
-fn __action17<
+fn __action118<
     'input,
+    'err,
 >(
-    input: &'input str,
-    (_, __0, _): (usize, &'input str, usize),
-) -> Prim
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> usize
 {
-    Prim::Sub
+    __lookbehind.clone()
 }
 
 #[allow(unused_variables)]
-fn __action18<
+fn __action119<
     'input,
+    'err,
 >(
-    input: &'input str,
-    (_, __0, _): (usize, &'input str, usize),
-) -> Prim
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+    (_, t, _): (usize, Exp<Span1>, usize),
+) -> Box<Exp<Span1>>
 {
-    Prim::Mul
+    Box::new(t)
 }
 
 #[allow(unused_variables)]
-fn __action19<
+fn __action120<
     'input,
+    'err,
 >(
-    input: &'input str,
-    (_, __0, _): (usize, &'input str, usize),
-) -> Prim
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> alloc::vec::Vec<(SurfFunDecl<Span1>, Tok<'input>)>
 {
-    Prim::And
+    alloc::vec![]
 }
 
 #[allow(unused_variables)]
-fn __action20<
+fn __action121<
     'input,
+    'err,
 >(
-    input: &'input str,
-    (_, __0, _): (usize, &'input str, usize),
-) -> Prim
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+    (_, v, _): (usize, alloc::vec::Vec<(SurfFunDecl<Span1>, Tok<'input>)>, usize),
+) -> alloc::vec::Vec<(SurfFunDecl<Span1>, Tok<'input>)>
 {
-    Prim::Or
+    v
 }
 
 #[allow(unused_variables)]
-fn __action21<
+fn __action122<
     'input,
+    'err,
 >(
-    input: &'input str,
-    (_, __0, _): (usize, &'input str, usize),
-) -> Prim
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+    (_, __0, _): (usize, SurfFunDecl<Span1>, usize),
+    (_, __1, _): (usize, Tok<'input>, usize),
+) -> (SurfFunDecl<Span1>, Tok<'input>)
 {
-    Prim::Lt
+    (__0, __1)
 }
 
 #[allow(unused_variables)]
-fn __action22<
+fn __action123<
     'input,
+    'err,
 >(
-    input: &'input str,
-    (_, __0, _): (usize, &'input str, usize),
-) -> Prim
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> usize
 {
-    Prim::Le
+    __lookahead.clone()
 }
 
 #[allow(unused_variables)]
-fn __action23<
+fn __action124<
     'input,
+    'err,
 >(
-    input: &'input str,
-    (_, __0, _): (usize, &'input str, usize),
-) -> Prim
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+    (_, __0, _): (usize, (SurfFunDecl<Span1>, Tok<'input>), usize),
+) -> alloc::vec::Vec<(SurfFunDecl<Span1>, Tok<'input>)>
 {
-    Prim::Gt
+    alloc::vec![__0]
 }
 
 #[allow(unused_variables)]
-fn __action24<
+fn __action125<
     'input,
+    'err,
 >(
-    input: &'input str,
-    (_, __0, _): (usize, &'input str, usize),
-) -> Prim
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+    (_, v, _): (usize, alloc::vec::Vec<(SurfFunDecl<Span1>, Tok<'input>)>, usize),
+    (_, e, _): (usize, (SurfFunDecl<Span1>, Tok<'input>), usize),
+) -> alloc::vec::Vec<(SurfFunDecl<Span1>, Tok<'input>)>
 {
-    Prim::Ge
+    { let mut v = v; v.push(e); v }
 }
 
 #[allow(unused_variables)]
-fn __action25<
+fn __action126<
     'input,
+    'err,
 >(
-    input: &'input str,
-    (_, __0, _): (usize, &'input str, usize),
-) -> Prim
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+    (_, __0, _): (usize, String, usize),
+) -> core::option::Option<String>
 {
-    Prim::Eq
+    Some(__0)
 }
 
 #[allow(unused_variables)]
-fn __action26<
+fn __action127<
     'input,
+    'err,
 >(
-    input: &'input str,
-    (_, __0, _): (usize, &'input str, usize),
-) -> Prim
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> core::option::Option<String>
 {
-    Prim::Neq
+    None
 }
 
 #[allow(unused_variables)]
-fn __action27<
+fn __action128<
     'input,
+    'err,
 >(
-    input: &'input str,
-    (_, l, _): (usize, usize, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, e, _): (usize, Exp<Span1>, usize),
-    (_, r, _): (usize, usize, usize),
-) -> Exp<Span1>
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> alloc::vec::Vec<String>
+{
+    alloc::vec![]
+}
+
+#[allow(unused_variables)]
+fn __action129<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+    (_, v, _): (usize, alloc::vec::Vec<String>, usize),
+) -> alloc::vec::Vec<String>
+{
+    v
+}
+
+#[allow(unused_variables)]
+fn __action130<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+    (_, __0, _): (usize, String, usize),
+    (_, _, _): (usize, Tok<'input>, usize),
+) -> String
+{
+    __0
+}
+
+#[allow(unused_variables)]
+fn __action131<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+    (_, t, _): (usize, Exp<Span1>, usize),
+) -> Box<Exp<Span1>>
+{
+    Box::new(t)
+}
+
+#[allow(unused_variables)]
+fn __action132<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+    (_, __0, _): (usize, Exp<Span1>, usize),
+) -> core::option::Option<Exp<Span1>>
 {
-    Exp::Prim(Prim::Not, vec![Box::new(e)], Span1 { start_ix: l, end_ix: r })
+    Some(__0)
 }
 
 #[allow(unused_variables)]
-fn __action28<
+fn __action133<
     'input,
+    'err,
 >(
-    input: &'input str,
-    (_, __0, _): (usize, Exp<Span1>, usize),
-) -> Exp<Span1>
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> core::option::Option<Exp<Span1>>
 {
-    __0
+    None
 }
 
 #[allow(unused_variables)]
-fn __action29<
+fn __action134<
     'input,
+    'err,
 >(
-    input: &'input str,
-    (_, l, _): (usize, usize, usize),
-    (_, x, _): (usize, String, usize),
-    (_, r, _): (usize, usize, usize),
-) -> Exp<Span1>
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> alloc::vec::Vec<Exp<Span1>>
 {
-    Exp::Var(x, Span1 { start_ix: l, end_ix: r })
+    alloc::vec![]
 }
 
 #[allow(unused_variables)]
-fn __action30<
+fn __action135<
     'input,
+    'err,
 >(
-    input: &'input str,
-    (_, l, _): (usize, usize, usize),
-    (_, b, _): (usize, bool, usize),
-    (_, r, _): (usize, usize, usize),
-) -> Exp<Span1>
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+    (_, v, _): (usize, alloc::vec::Vec<Exp<Span1>>, usize),
+) -> alloc::vec::Vec<Exp<Span1>>
 {
-    Exp::Bool(b, Span1 { start_ix: l, end_ix: r })
+    v
 }
 
 #[allow(unused_variables)]
-fn __action31<
+fn __action136<
     'input,
+    'err,
 >(
-    input: &'input str,
-    (_, l, _): (usize, usize, usize),
-    (_, n, _): (usize, i64, usize),
-    (_, r, _): (usize, usize, usize),
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+    (_, __0, _): (usize, Exp<Span1>, usize),
+    (_, _, _): (usize, Tok<'input>, usize),
 ) -> Exp<Span1>
 {
-    Exp::Num(n, Span1 { start_ix: l, end_ix: r })
+    __0
 }
 
 #[allow(unused_variables)]
-fn __action32<
+fn __action137<
     'input,
+    'err,
 >(
-    input: &'input str,
-    (_, l, _): (usize, usize, usize),
-    (_, p, _): (usize, Prim, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, e, _): (usize, Exp<Span1>, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, r, _): (usize, usize, usize),
-) -> Exp<Span1>
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+    (_, __0, _): (usize, Exp<Span1>, usize),
+) -> alloc::vec::Vec<Exp<Span1>>
 {
-    Exp::Prim(p, vec![Box::new(e)], Span1 { start_ix: l, end_ix: r })
+    alloc::vec![__0]
 }
 
 #[allow(unused_variables)]
-fn __action33<
+fn __action138<
     'input,
+    'err,
 >(
-    input: &'input str,
-    (_, _, _): (usize, &'input str, usize),
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+    (_, v, _): (usize, alloc::vec::Vec<Exp<Span1>>, usize),
     (_, e, _): (usize, Exp<Span1>, usize),
-    (_, _, _): (usize, &'input str, usize),
-) -> Exp<Span1>
+) -> alloc::vec::Vec<Exp<Span1>>
 {
-    e
+    { let mut v = v; v.push(e); v }
 }
 
 #[allow(unused_variables)]
-fn __action34<
+fn __action139<
     'input,
+    'err,
 >(
-    input: &'input str,
-    (_, l, _): (usize, usize, usize),
-    (_, x, _): (usize, String, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, es, _): (usize, Vec<Exp<Span1>>, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, r, _): (usize, usize, usize),
-) -> Exp<Span1>
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+    (_, __0, _): (usize, String, usize),
+) -> alloc::vec::Vec<String>
 {
-    Exp::Call(x, es, Span1 { start_ix: l, end_ix: r })
+    alloc::vec![__0]
 }
 
 #[allow(unused_variables)]
-fn __action35<
+fn __action140<
     'input,
+    'err,
 >(
-    input: &'input str,
-    (_, __0, _): (usize, &'input str, usize),
-) -> Prim
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+    (_, v, _): (usize, alloc::vec::Vec<String>, usize),
+    (_, e, _): (usize, String, usize),
+) -> alloc::vec::Vec<String>
 {
-    Prim::Add1
+    { let mut v = v; v.push(e); v }
 }
 
 #[allow(unused_variables)]
-fn __action36<
+fn __action141<
     'input,
+    'err,
 >(
-    input: &'input str,
-    (_, __0, _): (usize, &'input str, usize),
-) -> Prim
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+    __0: (usize, usize, usize),
+    __1: (usize, Tok<'input>, usize),
+    __2: (usize, String, usize),
+    __3: (usize, Tok<'input>, usize),
+    __4: (usize, Vec<String>, usize),
+    __5: (usize, Tok<'input>, usize),
+    __6: (usize, Tok<'input>, usize),
+    __7: (usize, Exp<Span1>, usize),
+    __8: (usize, usize, usize),
+) -> SurfFunDecl<Span1>
 {
-    Prim::Sub1
+    let __start0 = __6.0.clone();
+    let __end0 = __6.2.clone();
+    let __temp0 = __action115(
+        errors,
+        __6,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action5(
+        errors,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+        __temp0,
+        __7,
+        __8,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action37<
+fn __action142<
     'input,
+    'err,
 >(
-    input: &'input str,
-    (_, __0, _): (usize, &'input str, usize),
-) -> Prim
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+    __0: (usize, usize, usize),
+    __1: (usize, Tok<'input>, usize),
+    __2: (usize, String, usize),
+    __3: (usize, Tok<'input>, usize),
+    __4: (usize, Vec<String>, usize),
+    __5: (usize, Tok<'input>, usize),
+    __6: (usize, Exp<Span1>, usize),
+    __7: (usize, usize, usize),
+) -> SurfFunDecl<Span1>
 {
-    Prim::Print
+    let __start0 = __5.2.clone();
+    let __end0 = __6.0.clone();
+    let __temp0 = __action116(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action5(
+        errors,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+        __temp0,
+        __6,
+        __7,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action38<
+fn __action143<
     'input,
+    'err,
 >(
-    input: &'input str,
-    (_, __0, _): (usize, &'input str, usize),
-) -> Prim
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+    __0: (usize, String, usize),
+    __1: (usize, Tok<'input>, usize),
+    __2: (usize, Exp<Span1>, usize),
+) -> (String, Exp<Span1>)
 {
-    Prim::IsNum
+    let __start0 = __1.0.clone();
+    let __end0 = __1.2.clone();
+    let __temp0 = __action91(
+        errors,
+        __1,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action85(
+        errors,
+        __0,
+        __temp0,
+        __2,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action39<
+fn __action144<
     'input,
+    'err,
 >(
-    input: &'input str,
-    (_, __0, _): (usize, &'input str, usize),
-) -> Prim
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+    __0: (usize, String, usize),
+    __1: (usize, Exp<Span1>, usize),
+) -> (String, Exp<Span1>)
 {
-    Prim::IsBool
+    let __start0 = __0.2.clone();
+    let __end0 = __1.0.clone();
+    let __temp0 = __action92(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action85(
+        errors,
+        __0,
+        __temp0,
+        __1,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action40<
+fn __action145<
     'input,
+    'err,
 >(
-    input: &'input str,
-    (_, x, _): (usize, String, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, e, _): (usize, Exp<Span1>, usize),
-) -> Vec<(String, Exp<Span1>)>
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+    __0: (usize, String, usize),
+    __1: (usize, Tok<'input>, usize),
+) -> alloc::vec::Vec<String>
 {
-    vec![(x, e)]
+    let __start0 = __0.0.clone();
+    let __end0 = __1.2.clone();
+    let __temp0 = __action130(
+        errors,
+        __0,
+        __1,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action139(
+        errors,
+        __temp0,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action41<
+fn __action146<
     'input,
+    'err,
 >(
-    input: &'input str,
-    (_, mut bs, _): (usize, Vec<(String, Exp<Span1>)>, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, x, _): (usize, String, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, e, _): (usize, Exp<Span1>, usize),
-) -> Vec<(String, Exp<Span1>)>
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+    __0: (usize, alloc::vec::Vec<String>, usize),
+    __1: (usize, String, usize),
+    __2: (usize, Tok<'input>, usize),
+) -> alloc::vec::Vec<String>
 {
-    {
-        bs.push((x, e));
-        bs
-    }
+    let __start0 = __1.0.clone();
+    let __end0 = __2.2.clone();
+    let __temp0 = __action130(
+        errors,
+        __1,
+        __2,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action140(
+        errors,
+        __0,
+        __temp0,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action42<
+fn __action147<
     'input,
+    'err,
 >(
-    input: &'input str,
-    (_, s, _): (usize, &'input str, usize),
-) -> i64
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+    __0: (usize, core::option::Option<String>, usize),
+) -> Vec<String>
 {
-    i64::from_str(s).unwrap()
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action128(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action117(
+        errors,
+        __temp0,
+        __0,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action43<
+fn __action148<
     'input,
+    'err,
 >(
-    input: &'input str,
-    (_, s, _): (usize, &'input str, usize),
-) -> String
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+    __0: (usize, alloc::vec::Vec<String>, usize),
+    __1: (usize, core::option::Option<String>, usize),
+) -> Vec<String>
 {
-    String::from(s)
+    let __start0 = __0.0.clone();
+    let __end0 = __0.2.clone();
+    let __temp0 = __action129(
+        errors,
+        __0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action117(
+        errors,
+        __temp0,
+        __1,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action44<
+fn __action149<
     'input,
+    'err,
 >(
-    input: &'input str,
-    (_, __0, _): (usize, &'input str, usize),
-) -> bool
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+    __0: (usize, Exp<Span1>, usize),
+    __1: (usize, Tok<'input>, usize),
+) -> alloc::vec::Vec<Exp<Span1>>
 {
-    true
+    let __start0 = __0.0.clone();
+    let __end0 = __1.2.clone();
+    let __temp0 = __action136(
+        errors,
+        __0,
+        __1,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action137(
+        errors,
+        __temp0,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action45<
+fn __action150<
     'input,
+    'err,
 >(
-    input: &'input str,
-    (_, __0, _): (usize, &'input str, usize),
-) -> bool
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+    __0: (usize, alloc::vec::Vec<Exp<Span1>>, usize),
+    __1: (usize, Exp<Span1>, usize),
+    __2: (usize, Tok<'input>, usize),
+) -> alloc::vec::Vec<Exp<Span1>>
 {
-    false
+    let __start0 = __1.0.clone();
+    let __end0 = __2.2.clone();
+    let __temp0 = __action136(
+        errors,
+        __1,
+        __2,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action138(
+        errors,
+        __0,
+        __temp0,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action46<
+fn __action151<
     'input,
+    'err,
 >(
-    input: &'input str,
-    (_, mut v, _): (usize, alloc::vec::Vec<Exp<Span1>>, usize),
-    (_, last, _): (usize, core::option::Option<Exp<Span1>>, usize),
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+    __0: (usize, core::option::Option<Exp<Span1>>, usize),
 ) -> Vec<Exp<Span1>>
 {
-    {
-        match last {
-            None => { },
-            Some(t) => { v.push(t); }
-        };
-        v
-    }
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action134(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action93(
+        errors,
+        __temp0,
+        __0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action152<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+    __0: (usize, alloc::vec::Vec<Exp<Span1>>, usize),
+    __1: (usize, core::option::Option<Exp<Span1>>, usize),
+) -> Vec<Exp<Span1>>
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.2.clone();
+    let __temp0 = __action135(
+        errors,
+        __0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action93(
+        errors,
+        __temp0,
+        __1,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action47<
+fn __action153<
     'input,
+    'err,
 >(
-    input: &'input str,
-    (_, l, _): (usize, usize, usize),
-    (_, e1, _): (usize, Exp<Span1>, usize),
-    (_, op, _): (usize, Prim, usize),
-    (_, e2, _): (usize, Exp<Span1>, usize),
-    (_, r, _): (usize, usize, usize),
-) -> Exp<Span1>
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+    __0: (usize, SurfFunDecl<Span1>, usize),
+    __1: (usize, Tok<'input>, usize),
+) -> alloc::vec::Vec<(SurfFunDecl<Span1>, Tok<'input>)>
 {
-    Exp::Prim(op, vec![Box::new(e1), Box::new(e2)], Span1 { start_ix: l, end_ix: r })
+    let __start0 = __0.0.clone();
+    let __end0 = __1.2.clone();
+    let __temp0 = __action122(
+        errors,
+        __0,
+        __1,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action124(
+        errors,
+        __temp0,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action48<
+fn __action154<
     'input,
+    'err,
 >(
-    input: &'input str,
-    (_, __0, _): (usize, Exp<Span1>, usize),
-) -> Exp<Span1>
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+    __0: (usize, alloc::vec::Vec<(SurfFunDecl<Span1>, Tok<'input>)>, usize),
+    __1: (usize, SurfFunDecl<Span1>, usize),
+    __2: (usize, Tok<'input>, usize),
+) -> alloc::vec::Vec<(SurfFunDecl<Span1>, Tok<'input>)>
 {
-    __0
+    let __start0 = __1.0.clone();
+    let __end0 = __2.2.clone();
+    let __temp0 = __action122(
+        errors,
+        __1,
+        __2,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action125(
+        errors,
+        __0,
+        __temp0,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action49<
+fn __action155<
     'input,
+    'err,
 >(
-    input: &'input str,
-    (_, l, _): (usize, usize, usize),
-    (_, e1, _): (usize, Exp<Span1>, usize),
-    (_, op, _): (usize, Prim, usize),
-    (_, e2, _): (usize, Exp<Span1>, usize),
-    (_, r, _): (usize, usize, usize),
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+    __0: (usize, usize, usize),
+    __1: (usize, SurfFunDecl<Span1>, usize),
+    __2: (usize, Tok<'input>, usize),
+    __3: (usize, Box<Exp<Span1>>, usize),
+    __4: (usize, usize, usize),
 ) -> Exp<Span1>
 {
-    Exp::Prim(op, vec![Box::new(e1), Box::new(e2)], Span1 { start_ix: l, end_ix: r })
+    let __start0 = __0.2.clone();
+    let __end0 = __1.0.clone();
+    let __temp0 = __action120(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action3(
+        errors,
+        __0,
+        __temp0,
+        __1,
+        __2,
+        __3,
+        __4,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action50<
+fn __action156<
     'input,
+    'err,
 >(
-    input: &'input str,
-    (_, __0, _): (usize, Exp<Span1>, usize),
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+    __0: (usize, usize, usize),
+    __1: (usize, alloc::vec::Vec<(SurfFunDecl<Span1>, Tok<'input>)>, usize),
+    __2: (usize, SurfFunDecl<Span1>, usize),
+    __3: (usize, Tok<'input>, usize),
+    __4: (usize, Box<Exp<Span1>>, usize),
+    __5: (usize, usize, usize),
 ) -> Exp<Span1>
 {
-    __0
+    let __start0 = __1.0.clone();
+    let __end0 = __1.2.clone();
+    let __temp0 = __action121(
+        errors,
+        __1,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action3(
+        errors,
+        __0,
+        __temp0,
+        __2,
+        __3,
+        __4,
+        __5,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action51<
+fn __action157<
     'input,
+    'err,
 >(
-    input: &'input str,
-    (_, l, _): (usize, usize, usize),
-    (_, e1, _): (usize, Exp<Span1>, usize),
-    (_, op, _): (usize, Prim, usize),
-    (_, e2, _): (usize, Exp<Span1>, usize),
-    (_, r, _): (usize, usize, usize),
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+    __0: (usize, String, usize),
+    __1: (usize, usize, usize),
 ) -> Exp<Span1>
 {
-    Exp::Prim(op, vec![Box::new(e1), Box::new(e2)], Span1 { start_ix: l, end_ix: r })
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action123(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action50(
+        errors,
+        __temp0,
+        __0,
+        __1,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action52<
+fn __action158<
     'input,
+    'err,
 >(
-    input: &'input str,
-    (_, __0, _): (usize, Exp<Span1>, usize),
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+    __0: (usize, bool, usize),
+    __1: (usize, usize, usize),
 ) -> Exp<Span1>
 {
-    __0
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action123(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action51(
+        errors,
+        __temp0,
+        __0,
+        __1,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action53<
+fn __action159<
     'input,
+    'err,
 >(
-    input: &'input str,
-    (_, l, _): (usize, usize, usize),
-    (_, e1, _): (usize, Exp<Span1>, usize),
-    (_, op, _): (usize, Prim, usize),
-    (_, e2, _): (usize, Exp<Span1>, usize),
-    (_, r, _): (usize, usize, usize),
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+    __0: (usize, i64, usize),
+    __1: (usize, usize, usize),
 ) -> Exp<Span1>
 {
-    Exp::Prim(op, vec![Box::new(e1), Box::new(e2)], Span1 { start_ix: l, end_ix: r })
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action123(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action52(
+        errors,
+        __temp0,
+        __0,
+        __1,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action54<
+fn __action160<
     'input,
+    'err,
 >(
-    input: &'input str,
-    (_, __0, _): (usize, Exp<Span1>, usize),
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+    __0: (usize, String, usize),
+    __1: (usize, usize, usize),
 ) -> Exp<Span1>
 {
-    __0
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action123(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action53(
+        errors,
+        __temp0,
+        __0,
+        __1,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action55<
+fn __action161<
     'input,
+    'err,
 >(
-    input: &'input str,
-    (_, mut v, _): (usize, alloc::vec::Vec<String>, usize),
-    (_, last, _): (usize, core::option::Option<String>, usize),
-) -> Vec<String>
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+    __0: (usize, Prim, usize),
+    __1: (usize, Tok<'input>, usize),
+    __2: (usize, Exp<Span1>, usize),
+    __3: (usize, Tok<'input>, usize),
+    __4: (usize, usize, usize),
+) -> Exp<Span1>
 {
-    {
-        match last {
-            None => { },
-            Some(t) => { v.push(t); }
-        };
-        v
-    }
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action123(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action54(
+        errors,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action56<
+fn __action162<
     'input,
+    'err,
 >(
-    input: &'input str,
-    __lookbehind: &usize,
-    __lookahead: &usize,
-) -> usize
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+    __0: (usize, Prim, usize),
+    __1: (usize, Tok<'input>, usize),
+    __2: (usize, Exp<Span1>, usize),
+    __3: (usize, Tok<'input>, usize),
+    __4: (usize, Exp<Span1>, usize),
+    __5: (usize, Tok<'input>, usize),
+    __6: (usize, usize, usize),
+) -> Exp<Span1>
 {
-    __lookbehind.clone()
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action123(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action55(
+        errors,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+        __6,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action57<
+fn __action163<
     'input,
+    'err,
 >(
-    input: &'input str,
-    (_, t, _): (usize, Exp<Span1>, usize),
-) -> Box<Exp<Span1>>
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+    __0: (usize, Prim, usize),
+    __1: (usize, Tok<'input>, usize),
+    __2: (usize, Exp<Span1>, usize),
+    __3: (usize, Tok<'input>, usize),
+    __4: (usize, Exp<Span1>, usize),
+    __5: (usize, Tok<'input>, usize),
+    __6: (usize, Exp<Span1>, usize),
+    __7: (usize, Tok<'input>, usize),
+    __8: (usize, usize, usize),
+) -> Exp<Span1>
 {
-    Box::new(t)
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action123(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action56(
+        errors,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+        __6,
+        __7,
+        __8,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action58<
+fn __action164<
     'input,
+    'err,
 >(
-    input: &'input str,
-    __lookbehind: &usize,
-    __lookahead: &usize,
-) -> alloc::vec::Vec<(SurfFunDecl<Span1>, &'input str)>
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+    __0: (usize, String, usize),
+    __1: (usize, Tok<'input>, usize),
+    __2: (usize, Vec<Exp<Span1>>, usize),
+    __3: (usize, Tok<'input>, usize),
+    __4: (usize, usize, usize),
+) -> Exp<Span1>
 {
-    alloc::vec![]
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action123(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action58(
+        errors,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action59<
+fn __action165<
     'input,
+    'err,
 >(
-    input: &'input str,
-    (_, v, _): (usize, alloc::vec::Vec<(SurfFunDecl<Span1>, &'input str)>, usize),
-) -> alloc::vec::Vec<(SurfFunDecl<Span1>, &'input str)>
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+    __0: (usize, Tok<'input>, usize),
+    __1: (usize, Vec<String>, usize),
+    __2: (usize, Tok<'input>, usize),
+    __3: (usize, Box<Exp<Span1>>, usize),
+    __4: (usize, Tok<'input>, usize),
+    __5: (usize, usize, usize),
+) -> Exp<Span1>
 {
-    v
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action123(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action59(
+        errors,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action60<
+fn __action166<
     'input,
+    'err,
 >(
-    input: &'input str,
-    (_, __0, _): (usize, SurfFunDecl<Span1>, usize),
-    (_, __1, _): (usize, &'input str, usize),
-) -> (SurfFunDecl<Span1>, &'input str)
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+    __0: (usize, Tok<'input>, usize),
+    __1: (usize, Box<Exp<Span1>>, usize),
+    __2: (usize, Tok<'input>, usize),
+    __3: (usize, Box<Exp<Span1>>, usize),
+    __4: (usize, Tok<'input>, usize),
+    __5: (usize, usize, usize),
+) -> Exp<Span1>
 {
-    (__0, __1)
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action123(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action60(
+        errors,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action61<
+fn __action167<
     'input,
+    'err,
 >(
-    input: &'input str,
-    __lookbehind: &usize,
-    __lookahead: &usize,
-) -> usize
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+    __0: (usize, Tok<'input>, usize),
+    __1: (usize, String, usize),
+    __2: (usize, Tok<'input>, usize),
+    __3: (usize, Box<Exp<Span1>>, usize),
+    __4: (usize, Tok<'input>, usize),
+    __5: (usize, Box<Exp<Span1>>, usize),
+    __6: (usize, Tok<'input>, usize),
+    __7: (usize, Box<Exp<Span1>>, usize),
+    __8: (usize, Tok<'input>, usize),
+    __9: (usize, usize, usize),
+) -> Exp<Span1>
 {
-    __lookahead.clone()
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action123(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action61(
+        errors,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+        __6,
+        __7,
+        __8,
+        __9,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action62<
+fn __action168<
     'input,
+    'err,
 >(
-    input: &'input str,
-    (_, __0, _): (usize, (SurfFunDecl<Span1>, &'input str), usize),
-) -> alloc::vec::Vec<(SurfFunDecl<Span1>, &'input str)>
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+    __0: (usize, Tok<'input>, usize),
+    __1: (usize, Vec<Exp<Span1>>, usize),
+    __2: (usize, Tok<'input>, usize),
+    __3: (usize, usize, usize),
+) -> Exp<Span1>
 {
-    alloc::vec![__0]
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action123(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action62(
+        errors,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action63<
+fn __action169<
     'input,
+    'err,
 >(
-    input: &'input str,
-    (_, v, _): (usize, alloc::vec::Vec<(SurfFunDecl<Span1>, &'input str)>, usize),
-    (_, e, _): (usize, (SurfFunDecl<Span1>, &'input str), usize),
-) -> alloc::vec::Vec<(SurfFunDecl<Span1>, &'input str)>
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+    __0: (usize, Exp<Span1>, usize),
+    __1: (usize, Tok<'input>, usize),
+    __2: (usize, Exp<Span1>, usize),
+    __3: (usize, Tok<'input>, usize),
+    __4: (usize, usize, usize),
+) -> Exp<Span1>
 {
-    { let mut v = v; v.push(e); v }
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action123(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action63(
+        errors,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action64<
+fn __action170<
     'input,
+    'err,
 >(
-    input: &'input str,
-    (_, __0, _): (usize, String, usize),
-) -> core::option::Option<String>
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+    __0: (usize, SurfFunDecl<Span1>, usize),
+    __1: (usize, Tok<'input>, usize),
+    __2: (usize, Box<Exp<Span1>>, usize),
+    __3: (usize, usize, usize),
+) -> Exp<Span1>
 {
-    Some(__0)
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action123(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action155(
+        errors,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action65<
+fn __action171<
     'input,
+    'err,
 >(
-    input: &'input str,
-    __lookbehind: &usize,
-    __lookahead: &usize,
-) -> core::option::Option<String>
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+    __0: (usize, alloc::vec::Vec<(SurfFunDecl<Span1>, Tok<'input>)>, usize),
+    __1: (usize, SurfFunDecl<Span1>, usize),
+    __2: (usize, Tok<'input>, usize),
+    __3: (usize, Box<Exp<Span1>>, usize),
+    __4: (usize, usize, usize),
+) -> Exp<Span1>
 {
-    None
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action123(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action156(
+        errors,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action66<
+fn __action172<
     'input,
+    'err,
 >(
-    input: &'input str,
-    __lookbehind: &usize,
-    __lookahead: &usize,
-) -> alloc::vec::Vec<String>
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+    __0: (usize, Tok<'input>, usize),
+    __1: (usize, String, usize),
+    __2: (usize, Tok<'input>, usize),
+    __3: (usize, Vec<String>, usize),
+    __4: (usize, Tok<'input>, usize),
+    __5: (usize, Tok<'input>, usize),
+    __6: (usize, Exp<Span1>, usize),
+    __7: (usize, usize, usize),
+) -> SurfFunDecl<Span1>
 {
-    alloc::vec![]
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action123(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action4(
+        errors,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+        __6,
+        __7,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action67<
+fn __action173<
     'input,
+    'err,
 >(
-    input: &'input str,
-    (_, v, _): (usize, alloc::vec::Vec<String>, usize),
-) -> alloc::vec::Vec<String>
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+    __0: (usize, Tok<'input>, usize),
+    __1: (usize, String, usize),
+    __2: (usize, Tok<'input>, usize),
+    __3: (usize, Vec<String>, usize),
+    __4: (usize, Tok<'input>, usize),
+    __5: (usize, Tok<'input>, usize),
+    __6: (usize, Exp<Span1>, usize),
+    __7: (usize, usize, usize),
+) -> SurfFunDecl<Span1>
 {
-    v
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action123(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action141(
+        errors,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+        __6,
+        __7,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action68<
+fn __action174<
     'input,
+    'err,
 >(
-    input: &'input str,
-    (_, __0, _): (usize, String, usize),
-    (_, _, _): (usize, &'input str, usize),
-) -> String
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+    __0: (usize, Tok<'input>, usize),
+    __1: (usize, String, usize),
+    __2: (usize, Tok<'input>, usize),
+    __3: (usize, Vec<String>, usize),
+    __4: (usize, Tok<'input>, usize),
+    __5: (usize, Exp<Span1>, usize),
+    __6: (usize, usize, usize),
+) -> SurfFunDecl<Span1>
 {
-    __0
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action123(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action142(
+        errors,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+        __6,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action69<
+fn __action175<
     'input,
+    'err,
 >(
-    input: &'input str,
-    (_, __0, _): (usize, Exp<Span1>, usize),
-) -> core::option::Option<Exp<Span1>>
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+    __0: (usize, Tok<'input>, usize),
+    __1: (usize, Exp<Span1>, usize),
+    __2: (usize, Tok<'input>, usize),
+    __3: (usize, Exp<Span1>, usize),
+    __4: (usize, Tok<'input>, usize),
+    __5: (usize, Tok<'input>, usize),
+    __6: (usize, Exp<Span1>, usize),
+    __7: (usize, usize, usize),
+) -> Exp<Span1>
 {
-    Some(__0)
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action123(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action17(
+        errors,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+        __6,
+        __7,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action70<
+fn __action176<
     'input,
+    'err,
 >(
-    input: &'input str,
-    __lookbehind: &usize,
-    __lookahead: &usize,
-) -> core::option::Option<Exp<Span1>>
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+    __0: (usize, Exp<Span1>, usize),
+    __1: (usize, Prim, usize),
+    __2: (usize, Exp<Span1>, usize),
+    __3: (usize, usize, usize),
+) -> Exp<Span1>
 {
-    None
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action123(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action100(
+        errors,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action71<
+fn __action177<
     'input,
+    'err,
 >(
-    input: &'input str,
-    __lookbehind: &usize,
-    __lookahead: &usize,
-) -> alloc::vec::Vec<Exp<Span1>>
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+    __0: (usize, Exp<Span1>, usize),
+    __1: (usize, Prim, usize),
+    __2: (usize, Exp<Span1>, usize),
+    __3: (usize, usize, usize),
+) -> Exp<Span1>
 {
-    alloc::vec![]
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action123(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action104(
+        errors,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action72<
+fn __action178<
     'input,
+    'err,
 >(
-    input: &'input str,
-    (_, v, _): (usize, alloc::vec::Vec<Exp<Span1>>, usize),
-) -> alloc::vec::Vec<Exp<Span1>>
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+    __0: (usize, Exp<Span1>, usize),
+    __1: (usize, Prim, usize),
+    __2: (usize, Exp<Span1>, usize),
+    __3: (usize, usize, usize),
+) -> Exp<Span1>
 {
-    v
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action123(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action102(
+        errors,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action73<
+fn __action179<
     'input,
+    'err,
 >(
-    input: &'input str,
-    (_, __0, _): (usize, Exp<Span1>, usize),
-    (_, _, _): (usize, &'input str, usize),
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+    __0: (usize, Exp<Span1>, usize),
+    __1: (usize, Prim, usize),
+    __2: (usize, Exp<Span1>, usize),
+    __3: (usize, usize, usize),
 ) -> Exp<Span1>
 {
-    __0
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action123(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action106(
+        errors,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action74<
+fn __action180<
     'input,
+    'err,
 >(
-    input: &'input str,
-    (_, __0, _): (usize, Exp<Span1>, usize),
-) -> alloc::vec::Vec<Exp<Span1>>
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+    __0: (usize, Exp<Span1>, usize),
+    __1: (usize, Prim, usize),
+    __2: (usize, Exp<Span1>, usize),
+    __3: (usize, usize, usize),
+) -> Exp<Span1>
 {
-    alloc::vec![__0]
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action123(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action96(
+        errors,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action75<
+fn __action181<
     'input,
+    'err,
 >(
-    input: &'input str,
-    (_, v, _): (usize, alloc::vec::Vec<Exp<Span1>>, usize),
-    (_, e, _): (usize, Exp<Span1>, usize),
-) -> alloc::vec::Vec<Exp<Span1>>
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+    __0: (usize, Exp<Span1>, usize),
+    __1: (usize, Prim, usize),
+    __2: (usize, Exp<Span1>, usize),
+    __3: (usize, usize, usize),
+) -> Exp<Span1>
 {
-    { let mut v = v; v.push(e); v }
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action123(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action98(
+        errors,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action76<
+fn __action182<
     'input,
+    'err,
 >(
-    input: &'input str,
-    (_, __0, _): (usize, String, usize),
-) -> alloc::vec::Vec<String>
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+    __0: (usize, Exp<Span1>, usize),
+    __1: (usize, Prim, usize),
+    __2: (usize, Exp<Span1>, usize),
+    __3: (usize, usize, usize),
+) -> Exp<Span1>
 {
-    alloc::vec![__0]
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action123(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action94(
+        errors,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action77<
+fn __action183<
     'input,
+    'err,
 >(
-    input: &'input str,
-    (_, v, _): (usize, alloc::vec::Vec<String>, usize),
-    (_, e, _): (usize, String, usize),
-) -> alloc::vec::Vec<String>
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+    __0: (usize, Tok<'input>, usize),
+    __1: (usize, Vec<(String, Exp<Span1>)>, usize),
+    __2: (usize, Tok<'input>, usize),
+    __3: (usize, Exp<Span1>, usize),
+    __4: (usize, usize, usize),
+) -> Exp<Span1>
 {
-    { let mut v = v; v.push(e); v }
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action123(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action18(
+        errors,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action78<
+fn __action184<
     'input,
+    'err,
 >(
-    input: &'input str,
-    __0: (usize, Exp<Span1>, usize),
-    __1: (usize, &'input str, usize),
-) -> alloc::vec::Vec<Exp<Span1>>
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+    __0: (usize, Tok<'input>, usize),
+    __1: (usize, Exp<Span1>, usize),
+    __2: (usize, usize, usize),
+) -> Exp<Span1>
 {
     let __start0 = __0.0.clone();
-    let __end0 = __1.2.clone();
-    let __temp0 = __action73(
-        input,
-        __0,
-        __1,
+    let __end0 = __0.0.clone();
+    let __temp0 = __action123(
+        errors,
+        &__start0,
+        &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action74(
-        input,
+    __action47(
+        errors,
         __temp0,
+        __0,
+        __1,
+        __2,
     )
 }
 
 #[allow(unused_variables)]
-fn __action79<
+fn __action185<
     'input,
+    'err,
 >(
-    input: &'input str,
-    __0: (usize, alloc::vec::Vec<Exp<Span1>>, usize),
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+    __0: (usize, Tok<'input>, usize),
     __1: (usize, Exp<Span1>, usize),
-    __2: (usize, &'input str, usize),
-) -> alloc::vec::Vec<Exp<Span1>>
+    __2: (usize, usize, usize),
+) -> Exp<Span1>
 {
-    let __start0 = __1.0.clone();
-    let __end0 = __2.2.clone();
-    let __temp0 = __action73(
-        input,
-        __1,
-        __2,
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action123(
+        errors,
+        &__start0,
+        &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action75(
-        input,
-        __0,
+    __action48(
+        errors,
         __temp0,
+        __0,
+        __1,
+        __2,
     )
 }
 
 #[allow(unused_variables)]
-fn __action80<
+fn __action186<
     'input,
+    'err,
 >(
-    input: &'input str,
-    __0: (usize, core::option::Option<Exp<Span1>>, usize),
-) -> Vec<Exp<Span1>>
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+    __0: (usize, Exp<Span1>, usize),
+    __1: (usize, Prim, usize),
+    __2: (usize, Exp<Span1>, usize),
+    __3: (usize, usize, usize),
+) -> Exp<Span1>
 {
     let __start0 = __0.0.clone();
     let __end0 = __0.0.clone();
-    let __temp0 = __action71(
-        input,
+    let __temp0 = __action123(
+        errors,
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action46(
-        input,
+    __action108(
+        errors,
         __temp0,
         __0,
+        __1,
+        __2,
+        __3,
     )
 }
 
 #[allow(unused_variables)]
-fn __action81<
+fn __action187<
     'input,
+    'err,
 >(
-    input: &'input str,
-    __0: (usize, alloc::vec::Vec<Exp<Span1>>, usize),
-    __1: (usize, core::option::Option<Exp<Span1>>, usize),
-) -> Vec<Exp<Span1>>
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+    __0: (usize, __lalrpop_util::ErrorRecovery<usize, Tok<'input>, LexError>, usize),
+    __1: (usize, usize, usize),
+) -> Exp<Span1>
 {
     let __start0 = __0.0.clone();
-    let __end0 = __0.2.clone();
-    let __temp0 = __action72(
-        input,
-        __0,
+    let __end0 = __0.0.clone();
+    let __temp0 = __action123(
+        errors,
+        &__start0,
+        &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action46(
-        input,
+    __action6(
+        errors,
         __temp0,
+        __0,
         __1,
     )
 }
 
 #[allow(unused_variables)]
-fn __action82<
+fn __action188<
     'input,
+    'err,
 >(
-    input: &'input str,
-    __0: (usize, String, usize),
-    __1: (usize, &'input str, usize),
-) -> alloc::vec::Vec<String>
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+    __0: (usize, Box<Exp<Span1>>, usize),
+    __1: (usize, Tok<'input>, usize),
+    __2: (usize, Box<Exp<Span1>>, usize),
+    __3: (usize, usize, usize),
+) -> Exp<Span1>
 {
     let __start0 = __0.0.clone();
-    let __end0 = __1.2.clone();
-    let __temp0 = __action68(
-        input,
-        __0,
-        __1,
+    let __end0 = __0.0.clone();
+    let __temp0 = __action123(
+        errors,
+        &__start0,
+        &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action76(
-        input,
+    __action10(
+        errors,
         __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
     )
 }
 
 #[allow(unused_variables)]
-fn __action83<
+fn __action189<
     'input,
+    'err,
 >(
-    input: &'input str,
-    __0: (usize, alloc::vec::Vec<String>, usize),
-    __1: (usize, String, usize),
-    __2: (usize, &'input str, usize),
-) -> alloc::vec::Vec<String>
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+    __0: (usize, Exp<Span1>, usize),
+    __1: (usize, Tok<'input>, usize),
+    __2: (usize, Exp<Span1>, usize),
+    __3: (usize, Tok<'input>, usize),
+    __4: (usize, Tok<'input>, usize),
+    __5: (usize, Exp<Span1>, usize),
+    __6: (usize, usize, usize),
+) -> Exp<Span1>
 {
-    let __start0 = __1.0.clone();
-    let __end0 = __2.2.clone();
-    let __temp0 = __action68(
-        input,
-        __1,
-        __2,
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action123(
+        errors,
+        &__start0,
+        &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action77(
-        input,
-        __0,
+    __action110(
+        errors,
         __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+        __6,
     )
 }
 
 #[allow(unused_variables)]
-fn __action84<
+fn __action190<
     'input,
+    'err,
 >(
-    input: &'input str,
-    __0: (usize, core::option::Option<String>, usize),
-) -> Vec<String>
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+    __0: (usize, String, usize),
+    __1: (usize, Tok<'input>, usize),
+    __2: (usize, Box<Exp<Span1>>, usize),
+    __3: (usize, usize, usize),
+) -> Exp<Span1>
 {
     let __start0 = __0.0.clone();
     let __end0 = __0.0.clone();
-    let __temp0 = __action66(
-        input,
+    let __temp0 = __action123(
+        errors,
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action55(
-        input,
+    __action111(
+        errors,
         __temp0,
         __0,
+        __1,
+        __2,
+        __3,
     )
 }
 
 #[allow(unused_variables)]
-fn __action85<
+fn __action191<
     'input,
+    'err,
 >(
-    input: &'input str,
-    __0: (usize, alloc::vec::Vec<String>, usize),
-    __1: (usize, core::option::Option<String>, usize),
-) -> Vec<String>
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+    __0: (usize, Exp<Span1>, usize),
+    __1: (usize, Tok<'input>, usize),
+    __2: (usize, Exp<Span1>, usize),
+    __3: (usize, Tok<'input>, usize),
+    __4: (usize, Tok<'input>, usize),
+    __5: (usize, Exp<Span1>, usize),
+    __6: (usize, usize, usize),
+) -> Exp<Span1>
 {
     let __start0 = __0.0.clone();
-    let __end0 = __0.2.clone();
-    let __temp0 = __action67(
-        input,
-        __0,
+    let __end0 = __0.0.clone();
+    let __temp0 = __action123(
+        errors,
+        &__start0,
+        &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action55(
-        input,
+    __action112(
+        errors,
         __temp0,
+        __0,
         __1,
+        __2,
+        __3,
+        __4,
+        __5,
+        __6,
     )
 }
 
 #[allow(unused_variables)]
-fn __action86<
+fn __action192<
     'input,
+    'err,
 >(
-    input: &'input str,
-    __0: (usize, SurfFunDecl<Span1>, usize),
-    __1: (usize, &'input str, usize),
-) -> alloc::vec::Vec<(SurfFunDecl<Span1>, &'input str)>
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+    __0: (usize, String, usize),
+    __1: (usize, Tok<'input>, usize),
+    __2: (usize, Box<Exp<Span1>>, usize),
+    __3: (usize, usize, usize),
+) -> Exp<Span1>
 {
     let __start0 = __0.0.clone();
-    let __end0 = __1.2.clone();
-    let __temp0 = __action60(
-        input,
-        __0,
-        __1,
+    let __end0 = __0.0.clone();
+    let __temp0 = __action123(
+        errors,
+        &__start0,
+        &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action62(
-        input,
+    __action113(
+        errors,
         __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
     )
 }
 
 #[allow(unused_variables)]
-fn __action87<
+fn __action193<
     'input,
+    'err,
 >(
-    input: &'input str,
-    __0: (usize, alloc::vec::Vec<(SurfFunDecl<Span1>, &'input str)>, usize),
-    __1: (usize, SurfFunDecl<Span1>, usize),
-    __2: (usize, &'input str, usize),
-) -> alloc::vec::Vec<(SurfFunDecl<Span1>, &'input str)>
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+    __0: (usize, String, usize),
+) -> Exp<Span1>
 {
-    let __start0 = __1.0.clone();
-    let __end0 = __2.2.clone();
-    let __temp0 = __action60(
-        input,
-        __1,
-        __2,
+    let __start0 = __0.2.clone();
+    let __end0 = __0.2.clone();
+    let __temp0 = __action118(
+        errors,
+        &__start0,
+        &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action63(
-        input,
+    __action157(
+        errors,
         __0,
         __temp0,
     )
 }
 
 #[allow(unused_variables)]
-fn __action88<
+fn __action194<
     'input,
+    'err,
 >(
-    input: &'input str,
-    __0: (usize, usize, usize),
-    __1: (usize, SurfFunDecl<Span1>, usize),
-    __2: (usize, &'input str, usize),
-    __3: (usize, Box<Exp<Span1>>, usize),
-    __4: (usize, usize, usize),
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+    __0: (usize, bool, usize),
 ) -> Exp<Span1>
 {
     let __start0 = __0.2.clone();
-    let __end0 = __1.0.clone();
-    let __temp0 = __action58(
-        input,
+    let __end0 = __0.2.clone();
+    let __temp0 = __action118(
+        errors,
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action3(
-        input,
+    __action158(
+        errors,
         __0,
         __temp0,
-        __1,
-        __2,
-        __3,
-        __4,
     )
 }
 
 #[allow(unused_variables)]
-fn __action89<
+fn __action195<
     'input,
+    'err,
 >(
-    input: &'input str,
-    __0: (usize, usize, usize),
-    __1: (usize, alloc::vec::Vec<(SurfFunDecl<Span1>, &'input str)>, usize),
-    __2: (usize, SurfFunDecl<Span1>, usize),
-    __3: (usize, &'input str, usize),
-    __4: (usize, Box<Exp<Span1>>, usize),
-    __5: (usize, usize, usize),
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+    __0: (usize, i64, usize),
 ) -> Exp<Span1>
 {
-    let __start0 = __1.0.clone();
-    let __end0 = __1.2.clone();
-    let __temp0 = __action59(
-        input,
-        __1,
+    let __start0 = __0.2.clone();
+    let __end0 = __0.2.clone();
+    let __temp0 = __action118(
+        errors,
+        &__start0,
+        &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action3(
-        input,
+    __action159(
+        errors,
         __0,
         __temp0,
-        __2,
-        __3,
-        __4,
-        __5,
     )
 }
 
 #[allow(unused_variables)]
-fn __action90<
+fn __action196<
     'input,
+    'err,
 >(
-    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
     __0: (usize, String, usize),
-    __1: (usize, usize, usize),
 ) -> Exp<Span1>
 {
-    let __start0 = __0.0.clone();
-    let __end0 = __0.0.clone();
-    let __temp0 = __action61(
-        input,
+    let __start0 = __0.2.clone();
+    let __end0 = __0.2.clone();
+    let __temp0 = __action118(
+        errors,
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action29(
-        input,
-        __temp0,
+    __action160(
+        errors,
         __0,
-        __1,
+        __temp0,
     )
 }
 
 #[allow(unused_variables)]
-fn __action91<
+fn __action197<
     'input,
+    'err,
 >(
-    input: &'input str,
-    __0: (usize, bool, usize),
-    __1: (usize, usize, usize),
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+    __0: (usize, Prim, usize),
+    __1: (usize, Tok<'input>, usize),
+    __2: (usize, Exp<Span1>, usize),
+    __3: (usize, Tok<'input>, usize),
 ) -> Exp<Span1>
 {
-    let __start0 = __0.0.clone();
-    let __end0 = __0.0.clone();
-    let __temp0 = __action61(
-        input,
+    let __start0 = __3.2.clone();
+    let __end0 = __3.2.clone();
+    let __temp0 = __action118(
+        errors,
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action30(
-        input,
-        __temp0,
+    __action161(
+        errors,
         __0,
         __1,
+        __2,
+        __3,
+        __temp0,
     )
 }
 
 #[allow(unused_variables)]
-fn __action92<
+fn __action198<
     'input,
+    'err,
 >(
-    input: &'input str,
-    __0: (usize, i64, usize),
-    __1: (usize, usize, usize),
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+    __0: (usize, Prim, usize),
+    __1: (usize, Tok<'input>, usize),
+    __2: (usize, Exp<Span1>, usize),
+    __3: (usize, Tok<'input>, usize),
+    __4: (usize, Exp<Span1>, usize),
+    __5: (usize, Tok<'input>, usize),
 ) -> Exp<Span1>
 {
-    let __start0 = __0.0.clone();
-    let __end0 = __0.0.clone();
-    let __temp0 = __action61(
-        input,
+    let __start0 = __5.2.clone();
+    let __end0 = __5.2.clone();
+    let __temp0 = __action118(
+        errors,
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action31(
-        input,
-        __temp0,
+    __action162(
+        errors,
         __0,
         __1,
+        __2,
+        __3,
+        __4,
+        __5,
+        __temp0,
     )
 }
 
 #[allow(unused_variables)]
-fn __action93<
+fn __action199<
     'input,
+    'err,
 >(
-    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
     __0: (usize, Prim, usize),
-    __1: (usize, &'input str, usize),
+    __1: (usize, Tok<'input>, usize),
     __2: (usize, Exp<Span1>, usize),
-    __3: (usize, &'input str, usize),
-    __4: (usize, usize, usize),
+    __3: (usize, Tok<'input>, usize),
+    __4: (usize, Exp<Span1>, usize),
+    __5: (usize, Tok<'input>, usize),
+    __6: (usize, Exp<Span1>, usize),
+    __7: (usize, Tok<'input>, usize),
 ) -> Exp<Span1>
 {
-    let __start0 = __0.0.clone();
-    let __end0 = __0.0.clone();
-    let __temp0 = __action61(
-        input,
+    let __start0 = __7.2.clone();
+    let __end0 = __7.2.clone();
+    let __temp0 = __action118(
+        errors,
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action32(
-        input,
-        __temp0,
+    __action163(
+        errors,
         __0,
         __1,
         __2,
         __3,
         __4,
+        __5,
+        __6,
+        __7,
+        __temp0,
     )
 }
 
 #[allow(unused_variables)]
-fn __action94<
+fn __action200<
     'input,
+    'err,
 >(
-    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
     __0: (usize, String, usize),
-    __1: (usize, &'input str, usize),
+    __1: (usize, Tok<'input>, usize),
     __2: (usize, Vec<Exp<Span1>>, usize),
-    __3: (usize, &'input str, usize),
-    __4: (usize, usize, usize),
+    __3: (usize, Tok<'input>, usize),
 ) -> Exp<Span1>
 {
-    let __start0 = __0.0.clone();
-    let __end0 = __0.0.clone();
-    let __temp0 = __action61(
-        input,
+    let __start0 = __3.2.clone();
+    let __end0 = __3.2.clone();
+    let __temp0 = __action118(
+        errors,
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action34(
-        input,
-        __temp0,
+    __action164(
+        errors,
         __0,
         __1,
         __2,
         __3,
-        __4,
+        __temp0,
     )
 }
 
 #[allow(unused_variables)]
-fn __action95<
+fn __action201<
     'input,
+    'err,
 >(
-    input: &'input str,
-    __0: (usize, SurfFunDecl<Span1>, usize),
-    __1: (usize, &'input str, usize),
-    __2: (usize, Box<Exp<Span1>>, usize),
-    __3: (usize, usize, usize),
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+    __0: (usize, Tok<'input>, usize),
+    __1: (usize, Vec<String>, usize),
+    __2: (usize, Tok<'input>, usize),
+    __3: (usize, Box<Exp<Span1>>, usize),
+    __4: (usize, Tok<'input>, usize),
 ) -> Exp<Span1>
 {
-    let __start0 = __0.0.clone();
-    let __end0 = __0.0.clone();
-    let __temp0 = __action61(
-        input,
+    let __start0 = __4.2.clone();
+    let __end0 = __4.2.clone();
+    let __temp0 = __action118(
+        errors,
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action88(
-        input,
-        __temp0,
+    __action165(
+        errors,
         __0,
         __1,
         __2,
         __3,
+        __4,
+        __temp0,
     )
 }
 
 #[allow(unused_variables)]
-fn __action96<
+fn __action202<
     'input,
+    'err,
 >(
-    input: &'input str,
-    __0: (usize, alloc::vec::Vec<(SurfFunDecl<Span1>, &'input str)>, usize),
-    __1: (usize, SurfFunDecl<Span1>, usize),
-    __2: (usize, &'input str, usize),
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+    __0: (usize, Tok<'input>, usize),
+    __1: (usize, Box<Exp<Span1>>, usize),
+    __2: (usize, Tok<'input>, usize),
     __3: (usize, Box<Exp<Span1>>, usize),
-    __4: (usize, usize, usize),
+    __4: (usize, Tok<'input>, usize),
 ) -> Exp<Span1>
 {
-    let __start0 = __0.0.clone();
-    let __end0 = __0.0.clone();
-    let __temp0 = __action61(
-        input,
+    let __start0 = __4.2.clone();
+    let __end0 = __4.2.clone();
+    let __temp0 = __action118(
+        errors,
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action89(
-        input,
-        __temp0,
+    __action166(
+        errors,
         __0,
         __1,
         __2,
         __3,
         __4,
+        __temp0,
     )
 }
 
 #[allow(unused_variables)]
-fn __action97<
+fn __action203<
     'input,
+    'err,
 >(
-    input: &'input str,
-    __0: (usize, &'input str, usize),
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+    __0: (usize, Tok<'input>, usize),
     __1: (usize, String, usize),
-    __2: (usize, &'input str, usize),
-    __3: (usize, Vec<String>, usize),
-    __4: (usize, &'input str, usize),
-    __5: (usize, &'input str, usize),
-    __6: (usize, Exp<Span1>, usize),
-    __7: (usize, usize, usize),
-) -> SurfFunDecl<Span1>
+    __2: (usize, Tok<'input>, usize),
+    __3: (usize, Box<Exp<Span1>>, usize),
+    __4: (usize, Tok<'input>, usize),
+    __5: (usize, Box<Exp<Span1>>, usize),
+    __6: (usize, Tok<'input>, usize),
+    __7: (usize, Box<Exp<Span1>>, usize),
+    __8: (usize, Tok<'input>, usize),
+) -> Exp<Span1>
 {
-    let __start0 = __0.0.clone();
-    let __end0 = __0.0.clone();
-    let __temp0 = __action61(
-        input,
+    let __start0 = __8.2.clone();
+    let __end0 = __8.2.clone();
+    let __temp0 = __action118(
+        errors,
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action4(
-        input,
-        __temp0,
+    __action167(
+        errors,
         __0,
         __1,
         __2,
@@ -7095,367 +15567,458 @@ fn __action97<
         __5,
         __6,
         __7,
+        __8,
+        __temp0,
     )
 }
 
 #[allow(unused_variables)]
-fn __action98<
+fn __action204<
     'input,
+    'err,
 >(
-    input: &'input str,
-    __0: (usize, &'input str, usize),
-    __1: (usize, Exp<Span1>, usize),
-    __2: (usize, &'input str, usize),
-    __3: (usize, Exp<Span1>, usize),
-    __4: (usize, &'input str, usize),
-    __5: (usize, &'input str, usize),
-    __6: (usize, Exp<Span1>, usize),
-    __7: (usize, usize, usize),
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+    __0: (usize, Tok<'input>, usize),
+    __1: (usize, Vec<Exp<Span1>>, usize),
+    __2: (usize, Tok<'input>, usize),
 ) -> Exp<Span1>
 {
-    let __start0 = __0.0.clone();
-    let __end0 = __0.0.clone();
-    let __temp0 = __action61(
-        input,
+    let __start0 = __2.2.clone();
+    let __end0 = __2.2.clone();
+    let __temp0 = __action118(
+        errors,
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action9(
-        input,
-        __temp0,
+    __action168(
+        errors,
         __0,
         __1,
         __2,
-        __3,
-        __4,
-        __5,
-        __6,
-        __7,
+        __temp0,
     )
 }
 
 #[allow(unused_variables)]
-fn __action99<
+fn __action205<
     'input,
+    'err,
 >(
-    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
     __0: (usize, Exp<Span1>, usize),
-    __1: (usize, Prim, usize),
+    __1: (usize, Tok<'input>, usize),
     __2: (usize, Exp<Span1>, usize),
-    __3: (usize, usize, usize),
+    __3: (usize, Tok<'input>, usize),
 ) -> Exp<Span1>
 {
-    let __start0 = __0.0.clone();
-    let __end0 = __0.0.clone();
-    let __temp0 = __action61(
-        input,
+    let __start0 = __3.2.clone();
+    let __end0 = __3.2.clone();
+    let __temp0 = __action118(
+        errors,
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action51(
-        input,
-        __temp0,
+    __action169(
+        errors,
         __0,
         __1,
         __2,
         __3,
+        __temp0,
     )
 }
 
 #[allow(unused_variables)]
-fn __action100<
+fn __action206<
     'input,
+    'err,
 >(
-    input: &'input str,
-    __0: (usize, Exp<Span1>, usize),
-    __1: (usize, Prim, usize),
-    __2: (usize, Exp<Span1>, usize),
-    __3: (usize, usize, usize),
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+    __0: (usize, SurfFunDecl<Span1>, usize),
+    __1: (usize, Tok<'input>, usize),
+    __2: (usize, Box<Exp<Span1>>, usize),
 ) -> Exp<Span1>
 {
-    let __start0 = __0.0.clone();
-    let __end0 = __0.0.clone();
-    let __temp0 = __action61(
-        input,
+    let __start0 = __2.2.clone();
+    let __end0 = __2.2.clone();
+    let __temp0 = __action118(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action170(
+        errors,
+        __0,
+        __1,
+        __2,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action207<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+    __0: (usize, alloc::vec::Vec<(SurfFunDecl<Span1>, Tok<'input>)>, usize),
+    __1: (usize, SurfFunDecl<Span1>, usize),
+    __2: (usize, Tok<'input>, usize),
+    __3: (usize, Box<Exp<Span1>>, usize),
+) -> Exp<Span1>
+{
+    let __start0 = __3.2.clone();
+    let __end0 = __3.2.clone();
+    let __temp0 = __action118(
+        errors,
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action49(
-        input,
+    __action171(
+        errors,
+        __0,
+        __1,
+        __2,
+        __3,
         __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action208<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+    __0: (usize, Tok<'input>, usize),
+    __1: (usize, String, usize),
+    __2: (usize, Tok<'input>, usize),
+    __3: (usize, Vec<String>, usize),
+    __4: (usize, Tok<'input>, usize),
+    __5: (usize, Tok<'input>, usize),
+    __6: (usize, Exp<Span1>, usize),
+) -> SurfFunDecl<Span1>
+{
+    let __start0 = __6.2.clone();
+    let __end0 = __6.2.clone();
+    let __temp0 = __action118(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action172(
+        errors,
         __0,
         __1,
         __2,
         __3,
+        __4,
+        __5,
+        __6,
+        __temp0,
     )
 }
 
 #[allow(unused_variables)]
-fn __action101<
+fn __action209<
     'input,
+    'err,
 >(
-    input: &'input str,
-    __0: (usize, Exp<Span1>, usize),
-    __1: (usize, Prim, usize),
-    __2: (usize, Exp<Span1>, usize),
-    __3: (usize, usize, usize),
-) -> Exp<Span1>
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+    __0: (usize, Tok<'input>, usize),
+    __1: (usize, String, usize),
+    __2: (usize, Tok<'input>, usize),
+    __3: (usize, Vec<String>, usize),
+    __4: (usize, Tok<'input>, usize),
+    __5: (usize, Tok<'input>, usize),
+    __6: (usize, Exp<Span1>, usize),
+) -> SurfFunDecl<Span1>
 {
-    let __start0 = __0.0.clone();
-    let __end0 = __0.0.clone();
-    let __temp0 = __action61(
-        input,
+    let __start0 = __6.2.clone();
+    let __end0 = __6.2.clone();
+    let __temp0 = __action118(
+        errors,
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action47(
-        input,
-        __temp0,
+    __action173(
+        errors,
         __0,
         __1,
         __2,
         __3,
+        __4,
+        __5,
+        __6,
+        __temp0,
     )
 }
 
 #[allow(unused_variables)]
-fn __action102<
+fn __action210<
     'input,
+    'err,
 >(
-    input: &'input str,
-    __0: (usize, &'input str, usize),
-    __1: (usize, Vec<(String, Exp<Span1>)>, usize),
-    __2: (usize, &'input str, usize),
-    __3: (usize, Exp<Span1>, usize),
-    __4: (usize, usize, usize),
-) -> Exp<Span1>
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+    __0: (usize, Tok<'input>, usize),
+    __1: (usize, String, usize),
+    __2: (usize, Tok<'input>, usize),
+    __3: (usize, Vec<String>, usize),
+    __4: (usize, Tok<'input>, usize),
+    __5: (usize, Exp<Span1>, usize),
+) -> SurfFunDecl<Span1>
 {
-    let __start0 = __0.0.clone();
-    let __end0 = __0.0.clone();
-    let __temp0 = __action61(
-        input,
+    let __start0 = __5.2.clone();
+    let __end0 = __5.2.clone();
+    let __temp0 = __action118(
+        errors,
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action10(
-        input,
-        __temp0,
+    __action174(
+        errors,
         __0,
         __1,
         __2,
         __3,
         __4,
+        __5,
+        __temp0,
     )
 }
 
 #[allow(unused_variables)]
-fn __action103<
+fn __action211<
     'input,
+    'err,
 >(
-    input: &'input str,
-    __0: (usize, &'input str, usize),
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+    __0: (usize, Tok<'input>, usize),
     __1: (usize, Exp<Span1>, usize),
-    __2: (usize, usize, usize),
+    __2: (usize, Tok<'input>, usize),
+    __3: (usize, Exp<Span1>, usize),
+    __4: (usize, Tok<'input>, usize),
+    __5: (usize, Tok<'input>, usize),
+    __6: (usize, Exp<Span1>, usize),
 ) -> Exp<Span1>
 {
-    let __start0 = __0.0.clone();
-    let __end0 = __0.0.clone();
-    let __temp0 = __action61(
-        input,
+    let __start0 = __6.2.clone();
+    let __end0 = __6.2.clone();
+    let __temp0 = __action118(
+        errors,
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action27(
-        input,
-        __temp0,
+    __action175(
+        errors,
         __0,
         __1,
         __2,
+        __3,
+        __4,
+        __5,
+        __6,
+        __temp0,
     )
 }
 
 #[allow(unused_variables)]
-fn __action104<
+fn __action212<
     'input,
+    'err,
 >(
-    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
     __0: (usize, Exp<Span1>, usize),
     __1: (usize, Prim, usize),
     __2: (usize, Exp<Span1>, usize),
-    __3: (usize, usize, usize),
 ) -> Exp<Span1>
 {
-    let __start0 = __0.0.clone();
-    let __end0 = __0.0.clone();
-    let __temp0 = __action61(
-        input,
+    let __start0 = __2.2.clone();
+    let __end0 = __2.2.clone();
+    let __temp0 = __action118(
+        errors,
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action53(
-        input,
-        __temp0,
+    __action176(
+        errors,
         __0,
         __1,
         __2,
-        __3,
+        __temp0,
     )
 }
 
 #[allow(unused_variables)]
-fn __action105<
+fn __action213<
     'input,
+    'err,
 >(
-    input: &'input str,
-    __0: (usize, String, usize),
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+    __0: (usize, Exp<Span1>, usize),
+    __1: (usize, Prim, usize),
+    __2: (usize, Exp<Span1>, usize),
 ) -> Exp<Span1>
 {
-    let __start0 = __0.2.clone();
-    let __end0 = __0.2.clone();
-    let __temp0 = __action56(
-        input,
+    let __start0 = __2.2.clone();
+    let __end0 = __2.2.clone();
+    let __temp0 = __action118(
+        errors,
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action90(
-        input,
+    __action177(
+        errors,
         __0,
+        __1,
+        __2,
         __temp0,
     )
 }
 
 #[allow(unused_variables)]
-fn __action106<
+fn __action214<
     'input,
+    'err,
 >(
-    input: &'input str,
-    __0: (usize, bool, usize),
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+    __0: (usize, Exp<Span1>, usize),
+    __1: (usize, Prim, usize),
+    __2: (usize, Exp<Span1>, usize),
 ) -> Exp<Span1>
 {
-    let __start0 = __0.2.clone();
-    let __end0 = __0.2.clone();
-    let __temp0 = __action56(
-        input,
+    let __start0 = __2.2.clone();
+    let __end0 = __2.2.clone();
+    let __temp0 = __action118(
+        errors,
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action91(
-        input,
+    __action178(
+        errors,
         __0,
+        __1,
+        __2,
         __temp0,
     )
 }
 
 #[allow(unused_variables)]
-fn __action107<
+fn __action215<
     'input,
+    'err,
 >(
-    input: &'input str,
-    __0: (usize, i64, usize),
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+    __0: (usize, Exp<Span1>, usize),
+    __1: (usize, Prim, usize),
+    __2: (usize, Exp<Span1>, usize),
 ) -> Exp<Span1>
 {
-    let __start0 = __0.2.clone();
-    let __end0 = __0.2.clone();
-    let __temp0 = __action56(
-        input,
+    let __start0 = __2.2.clone();
+    let __end0 = __2.2.clone();
+    let __temp0 = __action118(
+        errors,
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action92(
-        input,
+    __action179(
+        errors,
         __0,
+        __1,
+        __2,
         __temp0,
     )
 }
 
 #[allow(unused_variables)]
-fn __action108<
+fn __action216<
     'input,
+    'err,
 >(
-    input: &'input str,
-    __0: (usize, Prim, usize),
-    __1: (usize, &'input str, usize),
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+    __0: (usize, Exp<Span1>, usize),
+    __1: (usize, Prim, usize),
     __2: (usize, Exp<Span1>, usize),
-    __3: (usize, &'input str, usize),
 ) -> Exp<Span1>
 {
-    let __start0 = __3.2.clone();
-    let __end0 = __3.2.clone();
-    let __temp0 = __action56(
-        input,
+    let __start0 = __2.2.clone();
+    let __end0 = __2.2.clone();
+    let __temp0 = __action118(
+        errors,
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action93(
-        input,
+    __action180(
+        errors,
         __0,
         __1,
         __2,
-        __3,
         __temp0,
     )
 }
 
 #[allow(unused_variables)]
-fn __action109<
+fn __action217<
     'input,
+    'err,
 >(
-    input: &'input str,
-    __0: (usize, String, usize),
-    __1: (usize, &'input str, usize),
-    __2: (usize, Vec<Exp<Span1>>, usize),
-    __3: (usize, &'input str, usize),
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+    __0: (usize, Exp<Span1>, usize),
+    __1: (usize, Prim, usize),
+    __2: (usize, Exp<Span1>, usize),
 ) -> Exp<Span1>
 {
-    let __start0 = __3.2.clone();
-    let __end0 = __3.2.clone();
-    let __temp0 = __action56(
-        input,
+    let __start0 = __2.2.clone();
+    let __end0 = __2.2.clone();
+    let __temp0 = __action118(
+        errors,
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action94(
-        input,
+    __action181(
+        errors,
         __0,
         __1,
         __2,
-        __3,
         __temp0,
     )
 }
 
 #[allow(unused_variables)]
-fn __action110<
+fn __action218<
     'input,
+    'err,
 >(
-    input: &'input str,
-    __0: (usize, SurfFunDecl<Span1>, usize),
-    __1: (usize, &'input str, usize),
-    __2: (usize, Box<Exp<Span1>>, usize),
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+    __0: (usize, Exp<Span1>, usize),
+    __1: (usize, Prim, usize),
+    __2: (usize, Exp<Span1>, usize),
 ) -> Exp<Span1>
 {
     let __start0 = __2.2.clone();
     let __end0 = __2.2.clone();
-    let __temp0 = __action56(
-        input,
+    let __temp0 = __action118(
+        errors,
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action95(
-        input,
+    __action182(
+        errors,
         __0,
         __1,
         __2,
@@ -7464,26 +16027,27 @@ fn __action110<
 }
 
 #[allow(unused_variables)]
-fn __action111<
+fn __action219<
     'input,
+    'err,
 >(
-    input: &'input str,
-    __0: (usize, alloc::vec::Vec<(SurfFunDecl<Span1>, &'input str)>, usize),
-    __1: (usize, SurfFunDecl<Span1>, usize),
-    __2: (usize, &'input str, usize),
-    __3: (usize, Box<Exp<Span1>>, usize),
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+    __0: (usize, Tok<'input>, usize),
+    __1: (usize, Vec<(String, Exp<Span1>)>, usize),
+    __2: (usize, Tok<'input>, usize),
+    __3: (usize, Exp<Span1>, usize),
 ) -> Exp<Span1>
 {
     let __start0 = __3.2.clone();
     let __end0 = __3.2.clone();
-    let __temp0 = __action56(
-        input,
+    let __temp0 = __action118(
+        errors,
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action96(
-        input,
+    __action183(
+        errors,
         __0,
         __1,
         __2,
@@ -7493,80 +16057,63 @@ fn __action111<
 }
 
 #[allow(unused_variables)]
-fn __action112<
+fn __action220<
     'input,
+    'err,
 >(
-    input: &'input str,
-    __0: (usize, &'input str, usize),
-    __1: (usize, String, usize),
-    __2: (usize, &'input str, usize),
-    __3: (usize, Vec<String>, usize),
-    __4: (usize, &'input str, usize),
-    __5: (usize, &'input str, usize),
-    __6: (usize, Exp<Span1>, usize),
-) -> SurfFunDecl<Span1>
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+    __0: (usize, Tok<'input>, usize),
+    __1: (usize, Exp<Span1>, usize),
+) -> Exp<Span1>
 {
-    let __start0 = __6.2.clone();
-    let __end0 = __6.2.clone();
-    let __temp0 = __action56(
-        input,
+    let __start0 = __1.2.clone();
+    let __end0 = __1.2.clone();
+    let __temp0 = __action118(
+        errors,
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action97(
-        input,
+    __action184(
+        errors,
         __0,
         __1,
-        __2,
-        __3,
-        __4,
-        __5,
-        __6,
         __temp0,
     )
 }
 
 #[allow(unused_variables)]
-fn __action113<
+fn __action221<
     'input,
+    'err,
 >(
-    input: &'input str,
-    __0: (usize, &'input str, usize),
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+    __0: (usize, Tok<'input>, usize),
     __1: (usize, Exp<Span1>, usize),
-    __2: (usize, &'input str, usize),
-    __3: (usize, Exp<Span1>, usize),
-    __4: (usize, &'input str, usize),
-    __5: (usize, &'input str, usize),
-    __6: (usize, Exp<Span1>, usize),
 ) -> Exp<Span1>
 {
-    let __start0 = __6.2.clone();
-    let __end0 = __6.2.clone();
-    let __temp0 = __action56(
-        input,
+    let __start0 = __1.2.clone();
+    let __end0 = __1.2.clone();
+    let __temp0 = __action118(
+        errors,
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action98(
-        input,
+    __action185(
+        errors,
         __0,
         __1,
-        __2,
-        __3,
-        __4,
-        __5,
-        __6,
         __temp0,
     )
 }
 
 #[allow(unused_variables)]
-fn __action114<
+fn __action222<
     'input,
+    'err,
 >(
-    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
     __0: (usize, Exp<Span1>, usize),
     __1: (usize, Prim, usize),
     __2: (usize, Exp<Span1>, usize),
@@ -7574,14 +16121,14 @@ fn __action114<
 {
     let __start0 = __2.2.clone();
     let __end0 = __2.2.clone();
-    let __temp0 = __action56(
-        input,
+    let __temp0 = __action118(
+        errors,
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action99(
-        input,
+    __action186(
+        errors,
         __0,
         __1,
         __2,
@@ -7590,25 +16137,50 @@ fn __action114<
 }
 
 #[allow(unused_variables)]
-fn __action115<
+fn __action223<
     'input,
+    'err,
 >(
-    input: &'input str,
-    __0: (usize, Exp<Span1>, usize),
-    __1: (usize, Prim, usize),
-    __2: (usize, Exp<Span1>, usize),
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+    __0: (usize, __lalrpop_util::ErrorRecovery<usize, Tok<'input>, LexError>, usize),
+) -> Exp<Span1>
+{
+    let __start0 = __0.2.clone();
+    let __end0 = __0.2.clone();
+    let __temp0 = __action118(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action187(
+        errors,
+        __0,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action224<
+    'input,
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+    __0: (usize, Box<Exp<Span1>>, usize),
+    __1: (usize, Tok<'input>, usize),
+    __2: (usize, Box<Exp<Span1>>, usize),
 ) -> Exp<Span1>
 {
     let __start0 = __2.2.clone();
     let __end0 = __2.2.clone();
-    let __temp0 = __action56(
-        input,
+    let __temp0 = __action118(
+        errors,
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action100(
-        input,
+    __action188(
+        errors,
         __0,
         __1,
         __2,
@@ -7617,106 +16189,122 @@ fn __action115<
 }
 
 #[allow(unused_variables)]
-fn __action116<
+fn __action225<
     'input,
+    'err,
 >(
-    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
     __0: (usize, Exp<Span1>, usize),
-    __1: (usize, Prim, usize),
+    __1: (usize, Tok<'input>, usize),
     __2: (usize, Exp<Span1>, usize),
+    __3: (usize, Tok<'input>, usize),
+    __4: (usize, Tok<'input>, usize),
+    __5: (usize, Exp<Span1>, usize),
 ) -> Exp<Span1>
 {
-    let __start0 = __2.2.clone();
-    let __end0 = __2.2.clone();
-    let __temp0 = __action56(
-        input,
+    let __start0 = __5.2.clone();
+    let __end0 = __5.2.clone();
+    let __temp0 = __action118(
+        errors,
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action101(
-        input,
+    __action189(
+        errors,
         __0,
         __1,
         __2,
+        __3,
+        __4,
+        __5,
         __temp0,
     )
 }
 
 #[allow(unused_variables)]
-fn __action117<
+fn __action226<
     'input,
+    'err,
 >(
-    input: &'input str,
-    __0: (usize, &'input str, usize),
-    __1: (usize, Vec<(String, Exp<Span1>)>, usize),
-    __2: (usize, &'input str, usize),
-    __3: (usize, Exp<Span1>, usize),
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+    __0: (usize, String, usize),
+    __1: (usize, Tok<'input>, usize),
+    __2: (usize, Box<Exp<Span1>>, usize),
 ) -> Exp<Span1>
 {
-    let __start0 = __3.2.clone();
-    let __end0 = __3.2.clone();
-    let __temp0 = __action56(
-        input,
+    let __start0 = __2.2.clone();
+    let __end0 = __2.2.clone();
+    let __temp0 = __action118(
+        errors,
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action102(
-        input,
+    __action190(
+        errors,
         __0,
         __1,
         __2,
-        __3,
         __temp0,
     )
 }
 
 #[allow(unused_variables)]
-fn __action118<
+fn __action227<
     'input,
+    'err,
 >(
-    input: &'input str,
-    __0: (usize, &'input str, usize),
-    __1: (usize, Exp<Span1>, usize),
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+    __0: (usize, Exp<Span1>, usize),
+    __1: (usize, Tok<'input>, usize),
+    __2: (usize, Exp<Span1>, usize),
+    __3: (usize, Tok<'input>, usize),
+    __4: (usize, Tok<'input>, usize),
+    __5: (usize, Exp<Span1>, usize),
 ) -> Exp<Span1>
 {
-    let __start0 = __1.2.clone();
-    let __end0 = __1.2.clone();
-    let __temp0 = __action56(
-        input,
+    let __start0 = __5.2.clone();
+    let __end0 = __5.2.clone();
+    let __temp0 = __action118(
+        errors,
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action103(
-        input,
+    __action191(
+        errors,
         __0,
         __1,
+        __2,
+        __3,
+        __4,
+        __5,
         __temp0,
     )
 }
 
 #[allow(unused_variables)]
-fn __action119<
+fn __action228<
     'input,
+    'err,
 >(
-    input: &'input str,
-    __0: (usize, Exp<Span1>, usize),
-    __1: (usize, Prim, usize),
-    __2: (usize, Exp<Span1>, usize),
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+    __0: (usize, String, usize),
+    __1: (usize, Tok<'input>, usize),
+    __2: (usize, Box<Exp<Span1>>, usize),
 ) -> Exp<Span1>
 {
     let __start0 = __2.2.clone();
     let __end0 = __2.2.clone();
-    let __temp0 = __action56(
-        input,
+    let __temp0 = __action118(
+        errors,
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action104(
-        input,
+    __action192(
+        errors,
         __0,
         __1,
         __2,
@@ -7725,199 +16313,207 @@ fn __action119<
 }
 
 #[allow(unused_variables)]
-fn __action120<
+fn __action229<
     'input,
+    'err,
 >(
-    input: &'input str,
-    __0: (usize, Exp<Span1>, usize),
-) -> Vec<Exp<Span1>>
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+    __0: (usize, String, usize),
+) -> Vec<String>
 {
     let __start0 = __0.0.clone();
     let __end0 = __0.2.clone();
-    let __temp0 = __action69(
-        input,
+    let __temp0 = __action126(
+        errors,
         __0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action80(
-        input,
+    __action147(
+        errors,
         __temp0,
     )
 }
 
 #[allow(unused_variables)]
-fn __action121<
+fn __action230<
     'input,
+    'err,
 >(
-    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
     __lookbehind: &usize,
     __lookahead: &usize,
-) -> Vec<Exp<Span1>>
+) -> Vec<String>
 {
     let __start0 = __lookbehind.clone();
     let __end0 = __lookahead.clone();
-    let __temp0 = __action70(
-        input,
+    let __temp0 = __action127(
+        errors,
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action80(
-        input,
+    __action147(
+        errors,
         __temp0,
     )
 }
 
 #[allow(unused_variables)]
-fn __action122<
+fn __action231<
     'input,
+    'err,
 >(
-    input: &'input str,
-    __0: (usize, alloc::vec::Vec<Exp<Span1>>, usize),
-    __1: (usize, Exp<Span1>, usize),
-) -> Vec<Exp<Span1>>
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+    __0: (usize, alloc::vec::Vec<String>, usize),
+    __1: (usize, String, usize),
+) -> Vec<String>
 {
     let __start0 = __1.0.clone();
     let __end0 = __1.2.clone();
-    let __temp0 = __action69(
-        input,
+    let __temp0 = __action126(
+        errors,
         __1,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action81(
-        input,
+    __action148(
+        errors,
         __0,
         __temp0,
     )
 }
 
 #[allow(unused_variables)]
-fn __action123<
+fn __action232<
     'input,
+    'err,
 >(
-    input: &'input str,
-    __0: (usize, alloc::vec::Vec<Exp<Span1>>, usize),
-) -> Vec<Exp<Span1>>
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+    __0: (usize, alloc::vec::Vec<String>, usize),
+) -> Vec<String>
 {
     let __start0 = __0.2.clone();
     let __end0 = __0.2.clone();
-    let __temp0 = __action70(
-        input,
+    let __temp0 = __action127(
+        errors,
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action81(
-        input,
+    __action148(
+        errors,
         __0,
         __temp0,
     )
 }
 
 #[allow(unused_variables)]
-fn __action124<
+fn __action233<
     'input,
+    'err,
 >(
-    input: &'input str,
-    __0: (usize, String, usize),
-) -> Vec<String>
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+    __0: (usize, Exp<Span1>, usize),
+) -> Vec<Exp<Span1>>
 {
     let __start0 = __0.0.clone();
     let __end0 = __0.2.clone();
-    let __temp0 = __action64(
-        input,
+    let __temp0 = __action132(
+        errors,
         __0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action84(
-        input,
+    __action151(
+        errors,
         __temp0,
     )
 }
 
 #[allow(unused_variables)]
-fn __action125<
+fn __action234<
     'input,
+    'err,
 >(
-    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
     __lookbehind: &usize,
     __lookahead: &usize,
-) -> Vec<String>
+) -> Vec<Exp<Span1>>
 {
     let __start0 = __lookbehind.clone();
     let __end0 = __lookahead.clone();
-    let __temp0 = __action65(
-        input,
+    let __temp0 = __action133(
+        errors,
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action84(
-        input,
+    __action151(
+        errors,
         __temp0,
     )
 }
 
 #[allow(unused_variables)]
-fn __action126<
+fn __action235<
     'input,
+    'err,
 >(
-    input: &'input str,
-    __0: (usize, alloc::vec::Vec<String>, usize),
-    __1: (usize, String, usize),
-) -> Vec<String>
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+    __0: (usize, alloc::vec::Vec<Exp<Span1>>, usize),
+    __1: (usize, Exp<Span1>, usize),
+) -> Vec<Exp<Span1>>
 {
     let __start0 = __1.0.clone();
     let __end0 = __1.2.clone();
-    let __temp0 = __action64(
-        input,
+    let __temp0 = __action132(
+        errors,
         __1,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action85(
-        input,
+    __action152(
+        errors,
         __0,
         __temp0,
     )
 }
 
 #[allow(unused_variables)]
-fn __action127<
+fn __action236<
     'input,
+    'err,
 >(
-    input: &'input str,
-    __0: (usize, alloc::vec::Vec<String>, usize),
-) -> Vec<String>
+    errors: &'err mut Vec<ErrorRecovery<usize, Tok<'input>, LexError>>,
+    __0: (usize, alloc::vec::Vec<Exp<Span1>>, usize),
+) -> Vec<Exp<Span1>>
 {
     let __start0 = __0.2.clone();
     let __end0 = __0.2.clone();
-    let __temp0 = __action65(
-        input,
+    let __temp0 = __action133(
+        errors,
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action85(
-        input,
+    __action152(
+        errors,
         __0,
         __temp0,
     )
 }
 
-pub trait __ToTriple<'input, >
+pub trait __ToTriple<'input, 'err, >
 {
-    fn to_triple(value: Self) -> Result<(usize,Token<'input>,usize), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>;
+    fn to_triple(value: Self) -> Result<(usize,Tok<'input>,usize), __lalrpop_util::ParseError<usize, Tok<'input>, LexError>>;
 }
 
-impl<'input, > __ToTriple<'input, > for (usize, Token<'input>, usize)
+impl<'input, 'err, > __ToTriple<'input, 'err, > for (usize, Tok<'input>, usize)
 {
-    fn to_triple(value: Self) -> Result<(usize,Token<'input>,usize), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>> {
+    fn to_triple(value: Self) -> Result<(usize,Tok<'input>,usize), __lalrpop_util::ParseError<usize, Tok<'input>, LexError>> {
         Ok(value)
     }
 }
-impl<'input, > __ToTriple<'input, > for Result<(usize, Token<'input>, usize), &'static str>
+impl<'input, 'err, > __ToTriple<'input, 'err, > for Result<(usize, Tok<'input>, usize), LexError>
 {
-    fn to_triple(value: Self) -> Result<(usize,Token<'input>,usize), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>> {
+    fn to_triple(value: Self) -> Result<(usize,Tok<'input>,usize), __lalrpop_util::ParseError<usize, Tok<'input>, LexError>> {
         match value {
             Ok(v) => Ok(v),
             Err(error) => Err(__lalrpop_util::ParseError::User { error }),
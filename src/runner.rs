@@ -1,21 +1,28 @@
+use std::collections::HashSet;
 use std::fs::File;
 use std::io::prelude::*;
 use std::io::{BufReader, Read, Write};
 use std::path::Path;
 use std::process::{Command, Stdio};
+use std::str::FromStr;
+use std::sync::OnceLock;
 
 use std::fmt::{Display, Formatter};
 
 use crate::compile;
-use crate::compile::{compile_to_string, CompileErr};
+use crate::compile::{emit_stage, CompileErr, CompileWarning, Stage};
+use crate::diagnostic::{stderr_is_colored, Diagnostic, Severity};
+use crate::explain;
+use crate::formatter;
 use crate::interp;
 use crate::interp::InterpErr;
-use crate::parser::ProgParser;
+use crate::parse::{parse_prog, ParseErr};
 use crate::syntax::SurfProg;
 
 mod span {
     use crate::span::{Span1, Span2};
     use std::fmt::Display;
+    use std::path::Path;
 
     #[derive(Clone, Debug)]
     pub struct FileInfo {
@@ -56,6 +63,12 @@ mod span {
                 return (line + 1, offset - start);
             }
         }
+        // the end of the file is on the last line
+        if let [.., start, end] = newlines {
+            if offset == *end {
+                return (newlines.len() - 1, offset - start);
+            }
+        }
         panic!("internal error: offset_to_line_col. Send this to the professor");
     }
 
@@ -63,19 +76,26 @@ mod span {
     impl<Span> CompileErr<Span> {
         pub fn map_span<F, SpanPrime>(self, f: F) -> CompileErr<SpanPrime>
         where
-            F: FnOnce(&Span) -> SpanPrime,
+            F: Fn(&Span) -> SpanPrime,
         {
             match self {
-                CompileErr::UnboundVariable { unbound, location } => CompileErr::UnboundVariable {
+                CompileErr::UnboundVariable {
+                    unbound,
+                    location,
+                    suggestions,
+                } => CompileErr::UnboundVariable {
                     unbound,
                     location: f(&location),
+                    suggestions,
                 },
                 CompileErr::DuplicateBinding {
                     duplicated_name,
                     location,
+                    first_location,
                 } => CompileErr::DuplicateBinding {
                     duplicated_name,
                     location: f(&location),
+                    first_location: f(&first_location),
                 },
                 CompileErr::Overflow { num, location } => CompileErr::Overflow {
                     num,
@@ -85,9 +105,11 @@ mod span {
                 CompileErr::UndefinedFunction {
                     undefined,
                     location,
+                    suggestions,
                 } => CompileErr::UndefinedFunction {
                     undefined: undefined.clone(),
                     location: f(&location),
+                    suggestions,
                 },
                 CompileErr::DuplicateArgName {
                     duplicated_name,
@@ -100,36 +122,167 @@ mod span {
                 CompileErr::DuplicateFunName {
                     duplicated_name,
                     location,
+                    first_location,
                 } => CompileErr::DuplicateFunName {
                     duplicated_name: duplicated_name.clone(),
                     location: f(&location),
+                    first_location: f(&first_location),
                 },
 
-                CompileErr::FunctionUsedAsValue {
+                CompileErr::FunctionCalledWrongArity {
                     function_name,
+                    correct_arity,
+                    arity_used,
                     location,
-                } => CompileErr::FunctionUsedAsValue {
+                    parameters,
+                    definition,
+                } => CompileErr::FunctionCalledWrongArity {
                     function_name: function_name.clone(),
+                    correct_arity,
+                    arity_used,
+                    location: f(&location),
+                    parameters,
+                    definition: f(&definition),
+                },
+                CompileErr::InvalidAssignment {
+                    name,
+                    what,
+                    location,
+                    definition,
+                } => CompileErr::InvalidAssignment {
+                    name,
+                    what,
+                    location: f(&location),
+                    definition: f(&definition),
+                },
+                CompileErr::CapturedAssignment {
+                    name,
+                    location,
+                    function,
+                } => CompileErr::CapturedAssignment {
+                    name,
                     location: f(&location),
+                    function: f(&function),
                 },
+            }
+        }
+    }
 
-                CompileErr::ValueUsedAsFunction {
-                    variable_name,
+    use crate::compile::CompileWarning;
+    impl<Span> CompileWarning<Span> {
+        pub fn map_span<F, SpanPrime>(self, f: F) -> CompileWarning<SpanPrime>
+        where
+            F: FnOnce(&Span) -> SpanPrime,
+        {
+            match self {
+                CompileWarning::UnusedVariable { name, location } => {
+                    CompileWarning::UnusedVariable {
+                        name,
+                        location: f(&location),
+                    }
+                }
+                CompileWarning::UnusedParameter { name, location } => {
+                    CompileWarning::UnusedParameter {
+                        name,
+                        location: f(&location),
+                    }
+                }
+                CompileWarning::UnusedFunction { name, location } => {
+                    CompileWarning::UnusedFunction {
+                        name,
+                        location: f(&location),
+                    }
+                }
+                CompileWarning::Shadowing { name, location } => CompileWarning::Shadowing {
+                    name,
+                    location: f(&location),
+                },
+                CompileWarning::ConstantCondition {
+                    who,
+                    value,
                     location,
-                } => CompileErr::ValueUsedAsFunction {
-                    variable_name: variable_name.clone(),
+                } => CompileWarning::ConstantCondition {
+                    who,
+                    value,
                     location: f(&location),
                 },
+            }
+        }
+    }
 
-                CompileErr::FunctionCalledWrongArity {
-                    function_name,
-                    correct_arity,
-                    arity_used,
+    use crate::interp::InterpErr;
+    impl<Span> InterpErr<Span> {
+        pub fn map_span<F, SpanPrime>(self, f: F) -> InterpErr<SpanPrime>
+        where
+            F: FnOnce(&Span) -> SpanPrime,
+        {
+            InterpErr {
+                kind: self.kind,
+                location: self.location.as_ref().map(f),
+            }
+        }
+    }
+
+    use crate::parse::ParseErr;
+    impl<Span> ParseErr<Span> {
+        pub fn map_span<F, SpanPrime>(self, f: F) -> ParseErr<SpanPrime>
+        where
+            F: Fn(&Span) -> SpanPrime,
+        {
+            match self {
+                ParseErr::InvalidToken { found, location } => ParseErr::InvalidToken {
+                    found,
+                    location: f(&location),
+                },
+                ParseErr::InvalidNumber { found, location } => ParseErr::InvalidNumber {
+                    found,
+                    location: f(&location),
+                },
+                ParseErr::NumberOutOfRange { found, location } => ParseErr::NumberOutOfRange {
+                    found,
+                    location: f(&location),
+                },
+                ParseErr::UnterminatedComment { location } => ParseErr::UnterminatedComment {
+                    location: f(&location),
+                },
+                ParseErr::UnterminatedString { location } => ParseErr::UnterminatedString {
+                    location: f(&location),
+                },
+                ParseErr::InvalidEscape { found, location } => ParseErr::InvalidEscape {
+                    found,
+                    location: f(&location),
+                },
+                ParseErr::UnexpectedToken {
+                    found,
+                    expected,
+                    context,
                     location,
-                } => CompileErr::FunctionCalledWrongArity {
-                    function_name: function_name.clone(),
-                    correct_arity: correct_arity.clone(),
-                    arity_used: arity_used.clone(),
+                } => ParseErr::UnexpectedToken {
+                    found,
+                    expected,
+                    context,
+                    location: f(&location),
+                },
+                ParseErr::Unterminated {
+                    keyword,
+                    closing,
+                    expected,
+                    context,
+                    location,
+                } => ParseErr::Unterminated {
+                    keyword,
+                    closing,
+                    expected,
+                    context,
+                    location: f(&location),
+                },
+                ParseErr::UnexpectedEof {
+                    expected,
+                    context,
+                    location,
+                } => ParseErr::UnexpectedEof {
+                    expected,
+                    context,
                     location: f(&location),
                 },
             }
@@ -145,97 +298,349 @@ mod span {
             )
         }
     }
+
+    // Where in which file a runtime check is, the compiled code prints it
+    // like the `--> file:line:col` of diagnostics
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+    pub struct SiteLocation<'a> {
+        pub path: &'a Path,
+        pub span: Span2,
+    }
+
+    impl Display for SiteLocation<'_> {
+        fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+            write!(
+                f,
+                "{}:{}:{}",
+                self.path.display(),
+                self.span.start_line,
+                self.span.start_col + 1
+            )
+        }
+    }
 }
 use crate::span::{Span1, Span2};
-use span::{file_info, span1_to_span2, FileInfo};
+use span::{file_info, span1_to_span2, FileInfo, SiteLocation};
 
 #[derive(Debug, PartialEq, Eq)]
 pub enum RunnerErr<Span> {
     FileOpen(String),
     Lex(String),
-    Parse(String),
-    CodeGen(CompileErr<Span>),
+    // Every syntax error, and the checker's errors in the program recovered
+    // from them
+    Parse {
+        errors: Vec<ParseErr<Span>>,
+        checked: Vec<CompileErr<Span>>,
+    },
+    // Every error found by the checker, sorted by location
+    CodeGen(Vec<CompileErr<Span>>),
     Link(String),
-    Interp(InterpErr),
+    Interp(InterpErr<Span>),
+    // A runtime error the compiled code reported, with its code
+    Runtime {
+        code: &'static str,
+        msg: String,
+        location: Span,
+    },
     Run(String),
 }
 
-impl<Span> Display for CompileErr<Span>
-where
-    Span: Display,
-{
-    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+impl<Span> CompileErr<Span> {
+    /// What is wrong, without saying where
+    pub fn message(&self) -> String {
         match self {
-            CompileErr::UnboundVariable { unbound, location } => {
-                write!(f, "Unbound variable {} at {}", unbound, location)
-            }
-            CompileErr::UndefinedFunction {
-                undefined,
-                location,
-            } => {
-                write!(f, "Undefined function {} called at {}", undefined, location)
+            CompileErr::UnboundVariable { unbound, .. } => format!("Unbound variable {}", unbound),
+            CompileErr::UndefinedFunction { undefined, .. } => {
+                format!("Undefined function {} called", undefined)
             }
             CompileErr::DuplicateBinding {
-                duplicated_name,
-                location,
-            } => write!(
-                f,
-                "Variable {} defined twice in let-expression at {}",
-                duplicated_name, location
+                duplicated_name, ..
+            } => format!(
+                "Variable {} defined twice in let-expression",
+                duplicated_name
             ),
-
-            CompileErr::Overflow { num, location } => write!(
-                f,
-                "Number literal {} doesn't fit into 63-bit integer at {}",
-                num, location
+            CompileErr::Overflow { num, .. } => format!(
+                "Number literal {} doesn't fit into 63-bit integer",
+                num
             ),
-
             CompileErr::DuplicateArgName {
-                duplicated_name,
-                location,
-            } => write!(
-                f,
-                "multiple arguments named \"{}\" at {}",
-                duplicated_name, location,
+                duplicated_name, ..
+            } => format!("multiple arguments named \"{}\"", duplicated_name),
+            CompileErr::DuplicateFunName {
+                duplicated_name, ..
+            } => format!("multiple defined functions named \"{}\"", duplicated_name),
+            CompileErr::FunctionCalledWrongArity {
+                function_name,
+                correct_arity,
+                arity_used,
+                ..
+            } => format!(
+                "function {} of arity {} called with {} arguments",
+                function_name, correct_arity, arity_used
+            ),
+            CompileErr::InvalidAssignment { name, what, .. } => {
+                format!("cannot assign to {} {}", what, name)
+            }
+            CompileErr::CapturedAssignment { name, .. } => format!(
+                "variable {} is assigned and referred to by a function in its scope",
+                name
             ),
+        }
+    }
 
-            CompileErr::DuplicateFunName {
+    // The places to point at in the source, starting with the location of
+    // the error
+    fn labels(&self) -> Vec<(&Span, String)> {
+        match self {
+            CompileErr::UnboundVariable { location, .. } => {
+                vec![(location, "not found in this scope".to_string())]
+            }
+            CompileErr::UndefinedFunction { location, .. } => {
+                vec![(location, "no function of this name in scope".to_string())]
+            }
+            CompileErr::DuplicateBinding {
                 duplicated_name,
                 location,
-            } => write!(
-                f,
-                "multiple defined functions named \"{}\" at {}",
-                duplicated_name, location
-            ),
-
-            CompileErr::FunctionUsedAsValue {
-                function_name,
+                first_location,
+            } => vec![
+                (location, format!("{} bound again here", duplicated_name)),
+                (first_location, format!("{} first bound here", duplicated_name)),
+            ],
+            CompileErr::Overflow { location, .. } => vec![(
                 location,
-            } => write!(
-                f,
-                "function {} used in non-function call position at {}",
-                function_name, location
-            ),
-
-            CompileErr::ValueUsedAsFunction {
-                variable_name,
+                "must be between -2^62 and 2^62 - 1".to_string(),
+            )],
+            CompileErr::DuplicateArgName { location, .. } => {
+                vec![(location, "in this function".to_string())]
+            }
+            CompileErr::DuplicateFunName {
                 location,
-            } => write!(
-                f,
-                "value variable {} used in function call position at {}",
-                variable_name, location
-            ),
-
+                first_location,
+                ..
+            } => vec![
+                (location, "defined again here".to_string()),
+                (first_location, "first defined here".to_string()),
+            ],
             CompileErr::FunctionCalledWrongArity {
                 function_name,
-                correct_arity,
                 arity_used,
                 location,
-            } => write!(
+                definition,
+                ..
+            } => vec![
+                (location, format!("called with {} arguments", arity_used)),
+                (definition, format!("{} defined here", function_name)),
+            ],
+            CompileErr::InvalidAssignment {
+                name,
+                location,
+                definition,
+                ..
+            } => vec![
+                (location, "assigned here".to_string()),
+                (definition, format!("{} defined here", name)),
+            ],
+            CompileErr::CapturedAssignment {
+                name,
+                location,
+                function,
+            } => vec![
+                (location, "assigned here".to_string()),
+                (function, format!("this function refers to {}", name)),
+            ],
+        }
+    }
+}
+
+impl<Span> CompileErr<Span> {
+    // More about the error, after the source lines
+    fn notes(&self) -> Vec<String> {
+        match self {
+            CompileErr::FunctionCalledWrongArity {
+                function_name,
+                parameters,
+                ..
+            } => vec![format!(
+                "{} is declared as `def {}({})`",
+                function_name,
+                function_name,
+                parameters.join(", ")
+            )],
+            CompileErr::CapturedAssignment { .. } => vec![
+                "functions copy the values of the variables they refer to when they are created"
+                    .to_string(),
+            ],
+            _ => vec![],
+        }
+    }
+
+    // What could be meant instead, e.g. a name with a typo fixed
+    fn help(&self) -> Vec<String> {
+        let suggestions = match self {
+            CompileErr::UnboundVariable { suggestions, .. }
+            | CompileErr::UndefinedFunction { suggestions, .. } => suggestions,
+            _ => return vec![],
+        };
+        let quoted: Vec<String> = suggestions.iter().map(|s| format!("`{}`", s)).collect();
+        match quoted.as_slice() {
+            [] => vec![],
+            [one] => vec![format!("did you mean {}?", one)],
+            many => vec![format!("did you mean one of {}?", many.join(", "))],
+        }
+    }
+}
+
+impl CompileErr<Span2> {
+    pub fn diagnostic(&self) -> Diagnostic {
+        let mut d = Diagnostic::new(self.code(), Severity::Error, self.message(), self.labels());
+        d.notes = self.notes();
+        d.help = self.help();
+        d
+    }
+}
+
+impl<Span> Display for CompileErr<Span>
+where
+    Span: Display,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{} at {}", self.message(), self.location())
+    }
+}
+
+impl<Span> ParseErr<Span> {
+    /// What is wrong, without saying where
+    pub fn message(&self) -> String {
+        let expectation = self.expectation();
+        match self {
+            ParseErr::InvalidToken { found, .. } => format!("unrecognized character `{}`", found),
+            ParseErr::InvalidNumber { found, .. } => format!("invalid number literal `{}`", found),
+            ParseErr::NumberOutOfRange { found, .. } => {
+                format!("Number literal {} doesn't fit into 63-bit integer", found)
+            }
+            ParseErr::UnterminatedComment { .. } => "unterminated block comment".to_string(),
+            ParseErr::UnterminatedString { .. } => "unterminated string".to_string(),
+            ParseErr::InvalidEscape { found, .. } => format!("unknown escape `{}` in string", found),
+            ParseErr::UnexpectedToken { found, .. } => {
+                format!("expected {}, found `{}`", expectation.unwrap(), found)
+            }
+            ParseErr::Unterminated { keyword, .. } => {
+                format!("unterminated `{}`: expected {}", keyword, expectation.unwrap())
+            }
+            ParseErr::UnexpectedEof { .. } => {
+                format!("unexpected end of file: expected {}", expectation.unwrap())
+            }
+        }
+    }
+
+    fn label(&self) -> String {
+        match self {
+            ParseErr::InvalidToken { .. } => "not part of any token".to_string(),
+            ParseErr::InvalidNumber { .. } => {
+                "not a decimal, 0x hex or 0b binary number".to_string()
+            }
+            ParseErr::NumberOutOfRange { .. } => "must be between -2^62 and 2^62 - 1".to_string(),
+            ParseErr::UnterminatedComment { .. } => "this `(#` has no matching `#)`".to_string(),
+            ParseErr::UnterminatedString { .. } => {
+                "this `\"` has no matching `\"` on its line".to_string()
+            }
+            ParseErr::InvalidEscape { .. } => {
+                "not one of \\n, \\t, \\r, \\0, \\\\ or \\\"".to_string()
+            }
+            ParseErr::UnexpectedToken { found, .. } => format!("unexpected `{}`", found),
+            ParseErr::Unterminated {
+                keyword, closing, ..
+            } => format!("this `{}` has no matching `{}`", keyword, closing),
+            ParseErr::UnexpectedEof { .. } => "the file ends after this".to_string(),
+        }
+    }
+}
+
+impl ParseErr<Span2> {
+    pub fn diagnostic(&self) -> Diagnostic {
+        Diagnostic::new(
+            self.code(),
+            Severity::Error,
+            self.message(),
+            vec![(self.location(), self.label())],
+        )
+    }
+}
+
+impl<Span> Display for ParseErr<Span>
+where
+    Span: Display,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{} at {}", self.message(), self.location())
+    }
+}
+
+impl<Span> CompileWarning<Span> {
+    /// What is suspicious, without saying where
+    pub fn message(&self) -> String {
+        match self {
+            CompileWarning::UnusedVariable { name, .. } => format!("unused variable {}", name),
+            CompileWarning::UnusedParameter { name, .. } => format!("unused parameter {}", name),
+            CompileWarning::UnusedFunction { name, .. } => {
+                format!("function {} is never called", name)
+            }
+            CompileWarning::Shadowing { name, .. } => {
+                format!("variable {} shadows an outer binding", name)
+            }
+            CompileWarning::ConstantCondition { who, value, .. } => {
+                format!("{} condition is always {}", who, value)
+            }
+        }
+    }
+
+    fn label(&self) -> String {
+        match self {
+            CompileWarning::UnusedVariable { .. } => "bound to this but never used".to_string(),
+            CompileWarning::UnusedParameter { .. } => "not used in this function".to_string(),
+            CompileWarning::UnusedFunction { .. } => "defined here".to_string(),
+            CompileWarning::Shadowing { name, .. } => {
+                format!("the earlier {} can't be used from here on", name)
+            }
+            CompileWarning::ConstantCondition { who, value, .. } => match (*who, *value) {
+                ("while", true) => "the loop only stops on an error".to_string(),
+                ("while", false) => "the body never runs".to_string(),
+                (_, true) => "the else branch never runs".to_string(),
+                (_, false) => "the then branch never runs".to_string(),
+            },
+        }
+    }
+}
+
+impl CompileWarning<Span2> {
+    pub fn diagnostic(&self) -> Diagnostic {
+        let mut d = Diagnostic::new(
+            self.code(),
+            Severity::Warning,
+            self.message(),
+            vec![(self.location(), self.label())],
+        );
+        d.notes
+            .push(format!("silence this warning with -Wno-{}", self.name()));
+        d
+    }
+}
+
+impl<Span> Display for CompileWarning<Span>
+where
+    Span: Display,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            CompileWarning::UnusedVariable { name, location } => {
+                write!(f, "unused variable {} bound at {}", name, location)
+            }
+            CompileWarning::UnusedParameter { name, location } => write!(
                 f,
-                "function {} of arity {} called with {} arguments at {}",
-                function_name, correct_arity, arity_used, location
+                "unused parameter {} of the function at {}",
+                name, location
             ),
+            _ => write!(f, "{} at {}", self.message(), self.location()),
         }
     }
 }
@@ -248,52 +653,268 @@ where
         match self {
             RunnerErr::FileOpen(s) => write!(f, "Error reading file: {}", s),
             RunnerErr::Lex(s) => write!(f, "Error lexing input: {}", s),
-            RunnerErr::Parse(s) => write!(f, "Error parsing input: {}", s),
-            RunnerErr::CodeGen(ce) => write!(f, "Error generating assembly: {}", ce),
+            RunnerErr::Parse { errors, checked } => {
+                let lines: Vec<String> = errors
+                    .iter()
+                    .map(|pe| format!("Error parsing input: {}", pe))
+                    .chain(
+                        checked
+                            .iter()
+                            .map(|ce| format!("Error generating assembly: {}", ce)),
+                    )
+                    .collect();
+                write!(f, "{}", lines.join("\n"))
+            }
+            RunnerErr::CodeGen(errs) => {
+                let lines: Vec<String> = errs
+                    .iter()
+                    .map(|ce| format!("Error generating assembly: {}", ce))
+                    .collect();
+                write!(f, "{}", lines.join("\n"))
+            }
             RunnerErr::Link(s) => write!(f, "Error linking generated assembly with runtime: {}", s),
             RunnerErr::Interp(s) => write!(f, "Error in interpreter: {}", s),
+            RunnerErr::Runtime { msg, location, .. } => {
+                write!(f, "Error running your compiled output: {} at {}", msg, location)
+            }
             RunnerErr::Run(s) => write!(f, "Error running your compiled output: {}", s),
         }
     }
 }
 
-fn fail<Span>(e: RunnerErr<Span>)
-where
-    Span: Display,
-{
-    eprintln!("{}", e);
+impl RunnerErr<Span2> {
+    /// The diagnostics to report for the error. Errors other than compile
+    /// errors become a single diagnostic with a code for their kind.
+    pub fn diagnostics(&self) -> Vec<Diagnostic> {
+        let code = match self {
+            RunnerErr::CodeGen(errs) => return errs.iter().map(|ce| ce.diagnostic()).collect(),
+            RunnerErr::Parse { errors, checked } => {
+                let mut diagnostics: Vec<Diagnostic> = errors
+                    .iter()
+                    .map(|pe| pe.diagnostic())
+                    .chain(checked.iter().map(|ce| ce.diagnostic()))
+                    .collect();
+                diagnostics.sort_by_key(|d| d.primary.as_ref().map(|l| l.span));
+                return diagnostics;
+            }
+            RunnerErr::FileOpen(_) => "E0010",
+            RunnerErr::Lex(_) => "E0011",
+            RunnerErr::Link(_) => "E0013",
+            RunnerErr::Interp(e) => {
+                let labels = match &e.location {
+                    Some(location) => vec![(location, "the program failed here".to_string())],
+                    None => vec![],
+                };
+                return vec![Diagnostic::new(e.code(), Severity::Error, e.kind.to_string(), labels)];
+            }
+            RunnerErr::Runtime {
+                code,
+                msg,
+                location,
+            } => {
+                return vec![Diagnostic::new(
+                    code,
+                    Severity::Error,
+                    msg.clone(),
+                    vec![(location, "the program failed here".to_string())],
+                )]
+            }
+            // e.g. killed by a signal
+            RunnerErr::Run(_) => "E0015",
+        };
+        vec![Diagnostic::new(code, Severity::Error, self.to_string(), vec![])]
+    }
+}
+
+// The compiled code reports runtime errors as `snake-error CODE SITE MSG`
+// on stderr, [sites] has the location of each site
+fn runtime_error(stderr: &str, sites: &[Span2]) -> Option<RunnerErr<Span2>> {
+    let record = stderr.strip_prefix("snake-error ")?;
+    let mut parts = record.splitn(3, ' ');
+    let code = parts.next()?;
+    let site: usize = parts.next()?.parse().ok()?;
+    let code = explain::EXPLANATIONS
+        .iter()
+        .find(|explanation| explanation.code == code)?
+        .code;
+    Some(RunnerErr::Runtime {
+        code,
+        msg: parts.next()?.to_string(),
+        location: *sites.get(site)?,
+    })
+}
+
+/// How the functions below report errors and warnings on stderr
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ErrorFormat {
+    // Source snippets for people to read
+    Human,
+    // One JSON object per line for tools to read
+    Json,
+}
+
+impl FromStr for ErrorFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "human" => Ok(ErrorFormat::Human),
+            "json" => Ok(ErrorFormat::Json),
+            _ => Err(format!("unknown error format {}", s)),
+        }
+    }
+}
+
+static ERROR_FORMAT: OnceLock<ErrorFormat> = OnceLock::new();
+
+/// Sets the error format for the rest of the process, it is Human otherwise
+pub fn set_error_format(format: ErrorFormat) {
+    let _ = ERROR_FORMAT.set(format);
+}
+
+fn error_format() -> ErrorFormat {
+    *ERROR_FORMAT.get().unwrap_or(&ErrorFormat::Human)
+}
+
+fn render_diagnostic(p: &Path, src: &str, d: &Diagnostic) -> String {
+    let file = p.display().to_string();
+    match error_format() {
+        ErrorFormat::Human => d.render(&file, src, stderr_is_colored()),
+        ErrorFormat::Json => d.to_json(&file),
+    }
+}
+
+fn render_err(p: &Path, e: &RunnerErr<Span2>) -> String {
+    let src = std::fs::read_to_string(p).unwrap_or_default();
+    let rendered: Vec<String> = e
+        .diagnostics()
+        .iter()
+        .map(|d| render_diagnostic(p, &src, d))
+        .collect();
+    rendered.join("\n")
+}
+
+fn fail(p: &Path, e: RunnerErr<Span2>) {
+    eprintln!("{}", render_err(p, &e));
     std::process::exit(1);
 }
 
-fn handle_errs<Span>(r: Result<String, RunnerErr<Span>>)
-where
-    Span: Display,
-{
+fn handle_errs(p: &Path, r: Result<String, RunnerErr<Span2>>) {
     match r {
         Ok(s) => println!("{}", s),
-        Err(e) => {
-            eprintln!("{}", e);
-            std::process::exit(1);
+        Err(e) => fail(p, e),
+    }
+}
+
+/// Which warnings the command line asked for
+#[derive(Clone, Debug, Default)]
+pub struct WarningConfig {
+    // -Werror
+    pub deny: bool,
+    // the names given to -Wno-NAME
+    pub silenced: HashSet<String>,
+}
+
+/// Prints the warnings for the file that aren't silenced, and with -Werror
+/// fails if there are any
+pub fn report_warnings(p: &Path, config: &WarningConfig) {
+    // errors are left to be reported by the compiler or interpreter
+    let warnings = match warnings_file(p) {
+        Ok(warnings) => warnings,
+        Err(_) => return,
+    };
+    let src = std::fs::read_to_string(p).unwrap_or_default();
+    let mut reported = 0;
+    for w in warnings.iter().filter(|w| !config.silenced.contains(w.name())) {
+        let mut d = w.diagnostic();
+        if config.deny {
+            d.severity = Severity::Error;
         }
+        eprintln!("{}", render_diagnostic(p, &src, &d));
+        reported += 1;
+    }
+    if config.deny && reported > 0 {
+        if error_format() == ErrorFormat::Human {
+            let d = Diagnostic {
+                code: None,
+                severity: Severity::Error,
+                message: format!("{} warnings treated as errors because of -Werror", reported),
+                primary: None,
+                secondary: vec![],
+                notes: vec![],
+                help: vec![],
+            };
+            eprintln!("{}", render_diagnostic(p, &src, &d));
+        }
+        std::process::exit(1);
     }
 }
 
+/// Returns the warnings for the file, sorted by location. A program with
+/// errors has none, they would only repeat what the errors say.
+pub fn warnings_file(p: &Path) -> Result<Vec<CompileWarning<Span2>>, RunnerErr<Span2>> {
+    let (info, prog) = parse_file(p)?;
+    compile::check_prog(&prog).map_err(|errs| {
+        codegen_err(
+            errs.into_iter()
+                .map(|e| e.map_span(|s| span1_to_span2(&info, *s)))
+                .collect(),
+        )
+    })?;
+    let mut warnings: Vec<CompileWarning<Span2>> = compile::warnings(&prog)
+        .into_iter()
+        .map(|w| w.map_span(|s| span1_to_span2(&info, *s)))
+        .collect();
+    warnings.sort_by_key(|w| *w.location());
+    Ok(warnings)
+}
+
 pub fn emit_assembly(p: &Path) {
-    handle_errs(compile_file(p))
+    handle_errs(p, compile_file(p))
+}
+
+pub fn emit(p: &Path, stage: Stage) {
+    handle_errs(p, emit_file(p, stage))
 }
 
 pub fn run(p: &Path) {
     if let Err(e) = compile_and_run_file(p, Path::new("runtime"), &mut std::io::stdout()) {
-        fail(e)
+        fail(p, e)
     }
 }
 
+/// Rewrites the file in the canonical layout. With [check] the file is left
+/// as is and the process fails if it isn't already formatted.
+pub fn format(p: &Path, check: bool) {
+    match format_file(p) {
+        Ok((src, formatted)) if src == formatted => {}
+        Ok(_) if check => {
+            eprintln!("{} is not formatted", p.display());
+            std::process::exit(1);
+        }
+        Ok((_, formatted)) => {
+            if let Err(e) = std::fs::write(p, formatted) {
+                fail(p, RunnerErr::FileOpen(e.to_string()))
+            }
+        }
+        Err(e) => fail(p, e),
+    }
+}
+
+/// Returns the contents of the file and the same program formatted
+pub fn format_file(p: &Path) -> Result<(String, String), RunnerErr<Span2>> {
+    let s = read_file(p)?;
+    let e = parse_source(&s)?;
+    let formatted = formatter::format_prog(&s, &e);
+    Ok((s, formatted))
+}
+
 pub fn interp<W>(p: &Path, w: &mut W)
 where
     W: std::io::Write,
 {
     if let Err(e) = interpret_file(p, w) {
-        fail(e)
+        fail(p, e)
     }
 }
 
@@ -302,10 +923,16 @@ where
     W: std::io::Write,
 {
     let (info, prog) = parse_file(p)?;
-    let () = compile::check_prog(&prog)
-        .map_err(|e| RunnerErr::CodeGen(e.map_span(|s| span1_to_span2(&info, *s))))?;
+    let () = compile::check_prog(&prog).map_err(|errs| {
+        codegen_err(
+            errs.into_iter()
+                .map(|e| e.map_span(|s| span1_to_span2(&info, *s)))
+                .collect(),
+        )
+    })?;
 
-    interp::prog(&prog, w).map_err(|e| RunnerErr::Interp(e))?;
+    interp::prog(&prog, w)
+        .map_err(|e| RunnerErr::Interp(e.map_span(|s| span1_to_span2(&info, *s))))?;
     Ok(())
 }
 
@@ -313,14 +940,38 @@ pub fn compile_and_run_file<W>(p: &Path, dir: &Path, out: &mut W) -> Result<(),
 where
     W: std::io::Write,
 {
-    let asm = compile_file(p)?;
-    link_and_run(&asm, dir, out)
+    let (info, prog) = parse_file(p)?;
+    let prog = prog.map_ann(&|s| SiteLocation {
+        path: p,
+        span: span1_to_span2(&info, *s),
+    });
+    let compiled = compile::compile_prog(&prog).map_err(|errs| {
+        codegen_err(errs.into_iter().map(|e| e.map_span(|l| l.span)).collect())
+    })?;
+    let sites: Vec<Span2> = compiled.sites.iter().map(|l| l.span).collect();
+    link_and_run(&compiled.asm, &sites, dir, out)
 }
 
 fn compile_file(p: &Path) -> Result<String, RunnerErr<Span2>> {
+    emit_file(p, Stage::Asm)
+}
+
+fn emit_file(p: &Path, stage: Stage) -> Result<String, RunnerErr<Span2>> {
     let (info, prog) = parse_file(p)?;
-    compile_to_string(&prog)
-        .map_err(|e| RunnerErr::CodeGen(e.map_span(|s| span1_to_span2(&info, *s))))
+    // converted up front so that the compiled code reports runtime errors
+    // with the same locations as compile errors
+    let prog = prog.map_ann(&|s| SiteLocation {
+        path: p,
+        span: span1_to_span2(&info, *s),
+    });
+    emit_stage(&prog, stage).map_err(|errs| {
+        codegen_err(errs.into_iter().map(|e| e.map_span(|l| l.span)).collect())
+    })
+}
+
+fn codegen_err(mut errs: Vec<CompileErr<Span2>>) -> RunnerErr<Span2> {
+    errs.sort_by_key(|e| *e.location());
+    RunnerErr::CodeGen(errs)
 }
 
 fn read_file<Span>(p: &Path) -> Result<String, RunnerErr<Span>> {
@@ -333,13 +984,41 @@ fn read_file<Span>(p: &Path) -> Result<String, RunnerErr<Span>> {
 
 fn parse_file(p: &Path) -> Result<(FileInfo, SurfProg<Span1>), RunnerErr<Span2>> {
     let s = read_file(p)?;
-    let e = ProgParser::new()
-        .parse(&s)
-        .map_err(|e| RunnerErr::Parse(e.to_string()))?;
+    let e = parse_source(&s)?;
     Ok((file_info(&s), e))
 }
 
-fn link_and_run<W>(assembly: &str, dir: &Path, out: &mut W) -> Result<(), RunnerErr<Span2>>
+fn parse_source(s: &str) -> Result<SurfProg<Span1>, RunnerErr<Span2>> {
+    let info = file_info(s);
+    parse_prog(s).map_err(|failure| {
+        // recovering keeps every name and arity, so the checker's errors
+        // in the recovered program are still right
+        let checked = match &failure.recovered {
+            Some(prog) => compile::check_prog(prog).err().unwrap_or_default(),
+            None => vec![],
+        };
+        let mut checked: Vec<CompileErr<Span2>> = checked
+            .into_iter()
+            .map(|e| e.map_span(|s| span1_to_span2(&info, *s)))
+            .collect();
+        checked.sort_by_key(|e| *e.location());
+        RunnerErr::Parse {
+            errors: failure
+                .errors
+                .into_iter()
+                .map(|e| e.map_span(|s| span1_to_span2(&info, *s)))
+                .collect(),
+            checked,
+        }
+    })
+}
+
+fn link_and_run<W>(
+    assembly: &str,
+    sites: &[Span2],
+    dir: &Path,
+    out: &mut W,
+) -> Result<(), RunnerErr<Span2>>
 where
     W: std::io::Write,
 {
@@ -430,6 +1109,7 @@ where
     }
 
     let mut child = Command::new(&exe_fname)
+        .env("SNAKE_ERROR_RECORD", "1")
         .stdout(Stdio::piped())
         .stderr(Stdio::piped())
         .spawn()
@@ -460,6 +1140,9 @@ where
         for line in compiled_err.lines() {
             stderr.push_str(&format!("{}\n", line.unwrap()));
         }
+        if let Some(e) = runtime_error(stderr.trim_end(), sites) {
+            return Err(e);
+        }
         return Err(RunnerErr::Run(format!(
             "Error code {} when running compiled code Stderr:\n{}",
             status, stderr
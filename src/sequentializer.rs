@@ -1,26 +1,68 @@
 use crate::syntax::*;
-fn parse_param_exps(
-    params: &[Exp<()>],
+
+// Whether evaluating e can assign var. Lifted functions only assign their
+// own variables, so calls don't need to be looked into.
+fn assigns<Ann>(e: &Exp<Ann>, var: &str) -> bool {
+    match e {
+        Exp::Num(_, _) | Exp::Bool(_, _) | Exp::Str(_, _) | Exp::Var(_, _) => false,
+        Exp::Prim(_, exps, _) => exps.iter().any(|e| assigns(e, var)),
+        Exp::Let { bindings, body, .. } => {
+            bindings.iter().any(|(_, e)| assigns(e, var)) || assigns(body, var)
+        }
+        Exp::If { cond, thn, els, .. } => {
+            assigns(cond, var) || assigns(thn, var) || assigns(els, var)
+        }
+        Exp::Seq { first, second, .. } => assigns(first, var) || assigns(second, var),
+        Exp::While { cond, body, .. } => assigns(cond, var) || assigns(body, var),
+        Exp::For { from, to, body, .. } => {
+            assigns(from, var) || assigns(to, var) || assigns(body, var)
+        }
+        Exp::Assign { var: x, value, .. } => x == var || assigns(value, var),
+        // the local functions that are left are jumped to in this frame
+        Exp::FunDefs { decls, body, .. } => {
+            decls.iter().any(|decl| assigns(&decl.body, var)) || assigns(body, var)
+        }
+        Exp::Lambda { body, .. } => assigns(body, var),
+        Exp::Call(_, args, _)
+        | Exp::InternalTailCall(_, args, _)
+        | Exp::ExternalCall { args, .. }
+        | Exp::ClosureCall { args, .. }
+        | Exp::MakeClosure { captured: args, .. } => args.iter().any(|e| assigns(e, var)),
+    }
+}
+
+// A variable is read before the parameters after it are evaluated, so it is
+// copied if one of them assigns it
+fn parse_param_exps<Ann: Clone>(
+    params: &[Exp<Ann>],
     counter: &mut u32,
-) -> (Vec<ImmExp>, Vec<(String, SeqExp<()>)>) {
+) -> (Vec<ImmExp>, Vec<(String, SeqExp<Ann>)>) {
     let mut let_bindings = vec![];
     let imm_params = params
         .iter()
-        .map(|param| {
+        .enumerate()
+        .map(|(i, param)| {
             let seq_param = sequentialize(param, counter);
-            if let SeqExp::Imm(i, _) = seq_param {
-                return i;
+            match &seq_param {
+                SeqExp::Imm(ImmExp::Var(x), _)
+                    if params[i + 1..].iter().any(|later| assigns(later, x)) => {}
+                SeqExp::Imm(imm, _) => return imm.clone(),
+                _ => {}
             }
             let var = format!("#var_{}", counter);
             let_bindings.push((var.clone(), seq_param));
             *counter += 1;
-            return ImmExp::Var(var);
+            ImmExp::Var(var)
         })
         .collect();
     (imm_params, let_bindings)
 }
 
-fn generate_nested_let(bindings: &[(String, SeqExp<()>)], body: SeqExp<()>) -> SeqExp<()> {
+// Each generated let takes the location of the expression it binds
+fn generate_nested_let<Ann: Clone>(
+    bindings: &[(String, SeqExp<Ann>)],
+    body: SeqExp<Ann>,
+) -> SeqExp<Ann> {
     if bindings.is_empty() {
         return body;
     }
@@ -28,39 +70,73 @@ fn generate_nested_let(bindings: &[(String, SeqExp<()>)], body: SeqExp<()>) -> S
         var: bindings[0].0.clone(),
         bound_exp: Box::new(bindings[0].1.clone()),
         body: Box::new(generate_nested_let(&bindings[1..], body)),
-        ann: (),
+        ann: bindings[0].1.ann().clone(),
     }
 }
 
-fn sequentialize(e: &Exp<()>, counter: &mut u32) -> SeqExp<()> {
+fn sequentialize<Ann: Clone>(e: &Exp<Ann>, counter: &mut u32) -> SeqExp<Ann> {
     match e {
-        Exp::Bool(b, _) => SeqExp::Imm(ImmExp::Bool(*b), ()),
-        Exp::Num(i, _) => SeqExp::Imm(ImmExp::Num(*i), ()),
-        Exp::Var(s, _) => SeqExp::Imm(ImmExp::Var(s.clone()), ()),
+        Exp::Bool(b, ann) => SeqExp::Imm(ImmExp::Bool(*b), ann.clone()),
+        Exp::Num(i, ann) => SeqExp::Imm(ImmExp::Num(*i), ann.clone()),
+        Exp::Str(s, ann) => SeqExp::Str(s.clone(), ann.clone()),
+        Exp::Var(s, ann) => SeqExp::Imm(ImmExp::Var(s.clone()), ann.clone()),
+        // The right operand is only evaluated when the left one doesn't
+        // decide the result, and each is checked to be a boolean once:
+        //   let #logic = l in
+        //   if #logic: (if r: true else: false) else: false
+        // for &&, with the branches swapped for ||
+        Exp::Prim(p @ (Prim::And | Prim::Or), exps, ann) => {
+            *counter += 1;
+            let left = format!("#logic_{}", counter);
+            let decided = SeqExp::Imm(ImmExp::Bool(*p == Prim::Or), ann.clone());
+            let (imm_right, right_bindings) = parse_param_exps(&[*exps[1].clone()], counter);
+            let evaluated = generate_nested_let(
+                &right_bindings,
+                SeqExp::If {
+                    cond: imm_right[0].clone(),
+                    thn: Box::new(SeqExp::Imm(ImmExp::Bool(true), ann.clone())),
+                    els: Box::new(SeqExp::Imm(ImmExp::Bool(false), ann.clone())),
+                    kind: IfKind::Logic,
+                    ann: ann.clone(),
+                },
+            );
+            let (thn, els) = match p {
+                Prim::And => (evaluated, decided),
+                _ => (decided, evaluated),
+            };
+            SeqExp::Let {
+                var: left.clone(),
+                bound_exp: Box::new(sequentialize(&exps[0], counter)),
+                body: Box::new(SeqExp::If {
+                    cond: ImmExp::Var(left),
+                    thn: Box::new(thn),
+                    els: Box::new(els),
+                    kind: IfKind::Logic,
+                    ann: ann.clone(),
+                }),
+                ann: exps[0].ann().clone(),
+            }
+        }
         Exp::Prim(p, exps, ann) => {
-            let params: Vec<Exp<()>> = exps.iter().map(|exp| (*exp.clone())).collect();
+            let params: Vec<Exp<Ann>> = exps.iter().map(|exp| *exp.clone()).collect();
             let (imm_params, let_bindings) = parse_param_exps(&params, counter);
-            generate_nested_let(&let_bindings, SeqExp::Prim(*p, imm_params, ()))
+            generate_nested_let(&let_bindings, SeqExp::Prim(*p, imm_params, ann.clone()))
         }
         Exp::Let {
             bindings,
             body,
-            ann: _,
+            ann,
         } => {
-            let mut optionRes: Option<SeqExp<()>> = None;
+            let mut res = sequentialize(body, counter);
             for (var, exp) in bindings.iter().rev() {
-                optionRes = Some(SeqExp::Let {
+                res = SeqExp::Let {
                     var: var.clone(),
-                    bound_exp: Box::new(sequentialize(&exp, counter)),
-                    body: if optionRes.is_some() {
-                        Box::new(optionRes.unwrap())
-                    } else {
-                        Box::new(sequentialize(body, counter))
-                    },
-                    ann: (),
-                })
+                    bound_exp: Box::new(sequentialize(exp, counter)),
+                    body: Box::new(res),
+                    ann: ann.clone(),
+                }
             }
-            optionRes.unwrap()
+            res
         }
         Exp::If {
             cond,
@@ -77,10 +153,64 @@ fn sequentialize(e: &Exp<()>, counter: &mut u32) -> SeqExp<()> {
                     cond: ImmExp::Var(var_name),
                     thn: Box::new(sequentialize(thn, counter)),
                     els: Box::new(sequentialize(els, counter)),
-                    ann: (),
+                    kind: IfKind::If,
+                    ann: ann.clone(),
                 }),
-                ann: (),
+                // the temporary holding the condition is located at it
+                ann: cond.ann().clone(),
+            }
+        }
+        // first is evaluated into rax and then overwritten, so unlike a let
+        // it doesn't need a variable
+        Exp::Seq { first, second, ann } => SeqExp::Seq {
+            first: Box::new(sequentialize(first, counter)),
+            second: Box::new(sequentialize(second, counter)),
+            ann: ann.clone(),
+        },
+        Exp::While { cond, body, ann } => SeqExp::While {
+            cond: Box::new(sequentialize(cond, counter)),
+            body: Box::new(sequentialize(body, counter)),
+            ann: ann.clone(),
+        },
+        Exp::For {
+            var,
+            from,
+            to,
+            body,
+            ann,
+        } => {
+            let (mut imm_bounds, mut let_bindings) =
+                parse_param_exps(&[*from.clone(), *to.clone()], counter);
+            // the upper bound is read again before every iteration
+            if let ImmExp::Var(x) = &imm_bounds[1] {
+                if assigns(body, x) {
+                    let var = format!("#var_{}", counter);
+                    *counter += 1;
+                    let_bindings.push((var.clone(), SeqExp::Imm(imm_bounds[1].clone(), to.ann().clone())));
+                    imm_bounds[1] = ImmExp::Var(var);
+                }
             }
+            generate_nested_let(
+                &let_bindings,
+                SeqExp::For {
+                    var: var.clone(),
+                    from: imm_bounds[0].clone(),
+                    to: imm_bounds[1].clone(),
+                    body: Box::new(sequentialize(body, counter)),
+                    ann: ann.clone(),
+                },
+            )
+        }
+        Exp::Assign { var, value, ann } => {
+            let (imm_value, let_bindings) = parse_param_exps(&[*value.clone()], counter);
+            generate_nested_let(
+                &let_bindings,
+                SeqExp::Assign {
+                    var: var.clone(),
+                    value: imm_value[0].clone(),
+                    ann: ann.clone(),
+                },
+            )
         }
         Exp::FunDefs { decls, body, ann } => {
             let seq_decls = decls
@@ -89,23 +219,24 @@ fn sequentialize(e: &Exp<()>, counter: &mut u32) -> SeqExp<()> {
                     name: decl.name.clone(),
                     parameters: decl.parameters.clone(),
                     body: sequentialize(&decl.body, counter),
-                    ann: (),
+                    ann: decl.ann.clone(),
                 })
                 .collect();
             SeqExp::FunDefs {
                 decls: seq_decls,
-                body: Box::new(sequentialize(&body, counter)),
-                ann: (),
+                body: Box::new(sequentialize(body, counter)),
+                ann: ann.clone(),
             }
         }
-        Exp::Call(func, args, _) => {
-            unimplemented!("called function = {}, arg size = {}", func, args.len())
-        },
-        Exp::InternalTailCall(func, params, _) => {
+        // lambda_lift turns every call into one of the calls below and every
+        // lambda into a lifted function
+        Exp::Call(func, _, _) => unreachable!("call to {} left after lambda lifting", func),
+        Exp::Lambda { .. } => unreachable!("lambda left after lambda lifting"),
+        Exp::InternalTailCall(func, params, ann) => {
             let (imm_params, let_bindings) = parse_param_exps(params, counter);
             generate_nested_let(
                 &let_bindings,
-                SeqExp::InternalTailCall(func.clone(), imm_params, ()),
+                SeqExp::InternalTailCall(func.clone(), imm_params, ann.clone()),
             )
         }
         Exp::ExternalCall {
@@ -120,15 +251,51 @@ fn sequentialize(e: &Exp<()>, counter: &mut u32) -> SeqExp<()> {
                 SeqExp::ExternalCall {
                     fun_name: fun_name.clone(),
                     args: imm_params,
-                    is_tail: is_tail.clone(),
-                    ann: (),
+                    is_tail: *is_tail,
+                    ann: ann.clone(),
+                },
+            )
+        }
+        Exp::MakeClosure {
+            fun_name,
+            arity,
+            captured,
+            ann,
+        } => {
+            let (imm_captured, let_bindings) = parse_param_exps(captured, counter);
+            generate_nested_let(
+                &let_bindings,
+                SeqExp::MakeClosure {
+                    fun_name: fun_name.clone(),
+                    arity: *arity,
+                    captured: imm_captured,
+                    ann: ann.clone(),
+                },
+            )
+        }
+        Exp::ClosureCall {
+            closure,
+            args,
+            is_tail,
+            ann,
+        } => {
+            // the closure is looked up before the arguments are evaluated
+            let closure_and_args = [vec![Exp::Var(closure.clone(), ann.clone())], args.clone()].concat();
+            let (mut imm_params, let_bindings) = parse_param_exps(&closure_and_args, counter);
+            generate_nested_let(
+                &let_bindings,
+                SeqExp::ClosureCall {
+                    closure: imm_params.remove(0),
+                    args: imm_params,
+                    is_tail: *is_tail,
+                    ann: ann.clone(),
                 },
             )
         }
     }
 }
 
-pub fn seq_prog(decls: &[FunDecl<Exp<()>, ()>], p: &Exp<()>) -> SeqProg<()> {
+pub fn seq_prog<Ann: Clone>(decls: &[FunDecl<Exp<Ann>, Ann>], p: &Exp<Ann>) -> SeqProg<Ann> {
     let mut counter = 0;
     SeqProg {
         funs: decls
@@ -139,11 +306,11 @@ pub fn seq_prog(decls: &[FunDecl<Exp<()>, ()>], p: &Exp<()>) -> SeqProg<()> {
                     name: decl.name.clone(),
                     parameters: decl.parameters.clone(),
                     body: seq_body,
-                    ann: (),
+                    ann: decl.ann.clone(),
                 }
             })
             .collect(),
         main: sequentialize(p, &mut counter),
-        ann: (),
+        ann: p.ann().clone(),
     }
 }
@@ -10,7 +10,8 @@ pub struct Span1 {
 
 // 2-dimensional span of source locations
 // This is what we use in error messages.
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+// Ordered by where they start, then by where they end
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct Span2 {
     pub start_line: usize,
     pub start_col: usize,
@@ -1,3 +1,5 @@
+use crate::lexer::escape;
+
 pub type SurfProg<Ann> = Exp<Ann>;
 pub type SurfFunDecl<Ann> = FunDecl<Exp<Ann>, Ann>;
 
@@ -14,6 +16,7 @@ pub struct FunDecl<E, Ann> {
 pub enum Exp<Ann> {
     Num(i64, Ann),
     Bool(bool, Ann),
+    Str(String, Ann),
     Var(String, Ann),
     Prim(Prim, Vec<Box<Exp<Ann>>>, Ann),
     Let {
@@ -27,11 +30,43 @@ pub enum Exp<Ann> {
         els: Box<Exp<Ann>>,
         ann: Ann,
     },
+    // first; second, the value of first is thrown away
+    Seq {
+        first: Box<Exp<Ann>>,
+        second: Box<Exp<Ann>>,
+        ann: Ann,
+    },
+    // Loops are run for what their bodies do and are false
+    While {
+        cond: Box<Exp<Ann>>,
+        body: Box<Exp<Ann>>,
+        ann: Ann,
+    },
+    // Runs body with var bound to every number from from up to to, both
+    // included
+    For {
+        var: String,
+        from: Box<Exp<Ann>>,
+        to: Box<Exp<Ann>>,
+        body: Box<Exp<Ann>>,
+        ann: Ann,
+    },
+    // Stores value in var, which must be in scope, and evaluates to it
+    Assign {
+        var: String,
+        value: Box<Exp<Ann>>,
+        ann: Ann,
+    },
     FunDefs {
         decls: Vec<FunDecl<Exp<Ann>, Ann>>,
         body: Box<Exp<Ann>>,
         ann: Ann,
     },
+    Lambda {
+        parameters: Vec<String>,
+        body: Box<Exp<Ann>>,
+        ann: Ann,
+    },
 
     // Source program calls will be parsed as a call.
     // In your lambda_lift function you should
@@ -48,6 +83,21 @@ pub enum Exp<Ann> {
         is_tail: bool,
         ann: Ann,
     },
+    // Allocates a closure for a lifted function, capturing the values
+    // passed after its own parameters
+    MakeClosure {
+        fun_name: String,
+        arity: usize,
+        captured: Vec<Exp<Ann>>,
+        ann: Ann,
+    },
+    // A call to a closure stored in a variable
+    ClosureCall {
+        closure: String,
+        args: Vec<Exp<Ann>>,
+        is_tail: bool,
+        ann: Ann,
+    },
 }
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
@@ -59,11 +109,28 @@ pub enum Prim {
     Print,
     IsBool,
     IsNum,
+    IsTuple,
+    Length,
+    Neg,
+    Abs,
+    IsString,
+    StrLen,
+    NumToString,
+    StringToNum,
 
     // binary
     Add,
     Sub,
     Mul,
+    Div,
+    Mod,
+    BitAnd,
+    BitOr,
+    BitXor,
+    Shl,
+    Shr,
+    Min,
+    Max,
     And,
     Or,
     Lt,
@@ -72,6 +139,171 @@ pub enum Prim {
     Ge,
     Eq,
     Neq,
+    TupleGet,
+    Concat,
+    CharAt,
+
+    // ternary
+    TupleSet,
+    Substr,
+
+    // variadic
+    MakeTuple,
+}
+
+impl<Ann> Exp<Ann> {
+    pub fn ann(&self) -> &Ann {
+        match self {
+            Exp::Num(_, ann)
+            | Exp::Bool(_, ann)
+            | Exp::Str(_, ann)
+            | Exp::Var(_, ann)
+            | Exp::Prim(_, _, ann)
+            | Exp::Let { ann, .. }
+            | Exp::If { ann, .. }
+            | Exp::Seq { ann, .. }
+            | Exp::While { ann, .. }
+            | Exp::For { ann, .. }
+            | Exp::Assign { ann, .. }
+            | Exp::FunDefs { ann, .. }
+            | Exp::Lambda { ann, .. }
+            | Exp::Call(_, _, ann)
+            | Exp::InternalTailCall(_, _, ann)
+            | Exp::ExternalCall { ann, .. }
+            | Exp::MakeClosure { ann, .. }
+            | Exp::ClosureCall { ann, .. } => ann,
+        }
+    }
+
+    /// Rebuilds the expression with every annotation passed through `f`,
+    /// e.g. to turn byte offsets into line/column spans.
+    pub fn map_ann<B>(&self, f: &impl Fn(&Ann) -> B) -> Exp<B> {
+        let map_decl = |decl: &FunDecl<Exp<Ann>, Ann>| FunDecl {
+            name: decl.name.clone(),
+            parameters: decl.parameters.clone(),
+            body: decl.body.map_ann(f),
+            ann: f(&decl.ann),
+        };
+        match self {
+            Exp::Num(n, ann) => Exp::Num(*n, f(ann)),
+            Exp::Bool(b, ann) => Exp::Bool(*b, f(ann)),
+            Exp::Str(s, ann) => Exp::Str(s.clone(), f(ann)),
+            Exp::Var(x, ann) => Exp::Var(x.clone(), f(ann)),
+            Exp::Prim(p, es, ann) => Exp::Prim(
+                *p,
+                es.iter().map(|e| Box::new(e.map_ann(f))).collect(),
+                f(ann),
+            ),
+            Exp::Let {
+                bindings,
+                body,
+                ann,
+            } => Exp::Let {
+                bindings: bindings
+                    .iter()
+                    .map(|(x, e)| (x.clone(), e.map_ann(f)))
+                    .collect(),
+                body: Box::new(body.map_ann(f)),
+                ann: f(ann),
+            },
+            Exp::If {
+                cond,
+                thn,
+                els,
+                ann,
+            } => Exp::If {
+                cond: Box::new(cond.map_ann(f)),
+                thn: Box::new(thn.map_ann(f)),
+                els: Box::new(els.map_ann(f)),
+                ann: f(ann),
+            },
+            Exp::Seq { first, second, ann } => Exp::Seq {
+                first: Box::new(first.map_ann(f)),
+                second: Box::new(second.map_ann(f)),
+                ann: f(ann),
+            },
+            Exp::While { cond, body, ann } => Exp::While {
+                cond: Box::new(cond.map_ann(f)),
+                body: Box::new(body.map_ann(f)),
+                ann: f(ann),
+            },
+            Exp::For {
+                var,
+                from,
+                to,
+                body,
+                ann,
+            } => Exp::For {
+                var: var.clone(),
+                from: Box::new(from.map_ann(f)),
+                to: Box::new(to.map_ann(f)),
+                body: Box::new(body.map_ann(f)),
+                ann: f(ann),
+            },
+            Exp::Assign { var, value, ann } => Exp::Assign {
+                var: var.clone(),
+                value: Box::new(value.map_ann(f)),
+                ann: f(ann),
+            },
+            Exp::FunDefs { decls, body, ann } => Exp::FunDefs {
+                decls: decls.iter().map(map_decl).collect(),
+                body: Box::new(body.map_ann(f)),
+                ann: f(ann),
+            },
+            Exp::Lambda {
+                parameters,
+                body,
+                ann,
+            } => Exp::Lambda {
+                parameters: parameters.clone(),
+                body: Box::new(body.map_ann(f)),
+                ann: f(ann),
+            },
+            Exp::Call(fun, args, ann) => Exp::Call(
+                fun.clone(),
+                args.iter().map(|e| e.map_ann(f)).collect(),
+                f(ann),
+            ),
+            Exp::InternalTailCall(fun, args, ann) => Exp::InternalTailCall(
+                fun.clone(),
+                args.iter().map(|e| e.map_ann(f)).collect(),
+                f(ann),
+            ),
+            Exp::ExternalCall {
+                fun_name,
+                args,
+                is_tail,
+                ann,
+            } => Exp::ExternalCall {
+                fun_name: fun_name.clone(),
+                args: args.iter().map(|e| e.map_ann(f)).collect(),
+                is_tail: *is_tail,
+                ann: f(ann),
+            },
+            Exp::MakeClosure {
+                fun_name,
+                arity,
+                captured,
+                ann,
+            } => Exp::MakeClosure {
+                fun_name: fun_name.clone(),
+                arity: *arity,
+                captured: captured.iter().map(|e| e.map_ann(f)).collect(),
+                ann: f(ann),
+            },
+            Exp::ClosureCall {
+                closure,
+                args,
+                is_tail,
+                ann,
+            } => Exp::ClosureCall {
+                closure: closure.clone(),
+                args: args.iter().map(|e| e.map_ann(f)).collect(),
+                is_tail: *is_tail,
+                ann: f(ann),
+            },
+        }
+    }
 }
 
 /* Sequential Expressions */
@@ -91,9 +323,20 @@ pub enum ImmExp {
     Var(String),
 }
 
+// What a sequential if branches on, which decides how it fails on a
+// condition that isn't a boolean
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum IfKind {
+    If,
+    // An operand of && or ||
+    Logic,
+}
+
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub enum SeqExp<Ann> {
     Imm(ImmExp, Ann),
+    // Strings live on the heap, so a literal allocates a new one
+    Str(String, Ann),
     Prim(Prim, Vec<ImmExp>, Ann),
     Let {
         var: String,
@@ -101,6 +344,35 @@ pub enum SeqExp<Ann> {
         body: Box<SeqExp<Ann>>,
         ann: Ann,
     },
+    // Like a let whose variable is never used, so the value of first
+    // doesn't need a place on the stack
+    Seq {
+        first: Box<SeqExp<Ann>>,
+        second: Box<SeqExp<Ann>>,
+        ann: Ann,
+    },
+    // The condition is evaluated again before every iteration, so unlike
+    // that of an if it isn't an immediate
+    While {
+        cond: Box<SeqExp<Ann>>,
+        body: Box<SeqExp<Ann>>,
+        ann: Ann,
+    },
+    // The bounds are evaluated once, before the first iteration. var has a
+    // single place on the stack that is counted up.
+    For {
+        var: String,
+        from: ImmExp,
+        to: ImmExp,
+        body: Box<SeqExp<Ann>>,
+        ann: Ann,
+    },
+    // Overwrites the place of var on the stack
+    Assign {
+        var: String,
+        value: ImmExp,
+        ann: Ann,
+    },
     // Local function definitions
     // These should only be called using InternalTailCall
     FunDefs {
@@ -112,6 +384,7 @@ pub enum SeqExp<Ann> {
         cond: ImmExp,
         thn: Box<SeqExp<Ann>>,
         els: Box<SeqExp<Ann>>,
+        kind: IfKind,
         ann: Ann,
     },
     // An internal tail call to a locally defined function.
@@ -126,7 +399,467 @@ pub enum SeqExp<Ann> {
         is_tail: bool,
         ann: Ann,
     },
+    MakeClosure {
+        fun_name: String,
+        arity: usize,
+        captured: Vec<ImmExp>,
+        ann: Ann,
+    },
+    // Checks that closure is a function of the right arity, then calls it
+    // with args followed by the values it captured
+    ClosureCall {
+        closure: ImmExp,
+        args: Vec<ImmExp>,
+        is_tail: bool,
+        ann: Ann,
+    },
+}
+
+impl<Ann> SeqExp<Ann> {
+    pub fn ann(&self) -> &Ann {
+        match self {
+            SeqExp::Imm(_, ann)
+            | SeqExp::Str(_, ann)
+            | SeqExp::Prim(_, _, ann)
+            | SeqExp::Let { ann, .. }
+            | SeqExp::Seq { ann, .. }
+            | SeqExp::While { ann, .. }
+            | SeqExp::For { ann, .. }
+            | SeqExp::Assign { ann, .. }
+            | SeqExp::FunDefs { ann, .. }
+            | SeqExp::If { ann, .. }
+            | SeqExp::InternalTailCall(_, _, ann)
+            | SeqExp::ExternalCall { ann, .. }
+            | SeqExp::MakeClosure { ann, .. }
+            | SeqExp::ClosureCall { ann, .. } => ann,
+        }
+    }
+}
+
+/* Pretty printing */
+
+// Precedence levels of the grammar in parser.lalrpop, loosest first. The
+// forms that extend as far right as they can (if, let, def and :=) are at
+// the EXP level and only appear unparenthesized where an Exp is expected.
+// Sequences are looser still, so they are parenthesized even there, except
+// in the bodies of those forms.
+pub(crate) const SEQ: u8 = 0;
+pub(crate) const EXP: u8 = 1;
+pub(crate) const LOG: u8 = 2;
+pub(crate) const CMP: u8 = 3;
+pub(crate) const BIT_OR: u8 = 4;
+pub(crate) const BIT_XOR: u8 = 5;
+pub(crate) const BIT_AND: u8 = 6;
+pub(crate) const SHIFT: u8 = 7;
+pub(crate) const SUM: u8 = 8;
+pub(crate) const PRODUCT: u8 = 9;
+pub(crate) const NOT: u8 = 10;
+pub(crate) const BASE: u8 = 11;
+
+pub(crate) trait Pretty {
+    fn level(&self) -> u8;
+    // Whether the printed form ends with an Exp, e.g. the body of a let,
+    // that would take in a ; written after it
+    fn open_ended(&self) -> bool;
+    // Continuation lines are indented by `indent` spaces
+    fn pretty(&self, indent: usize) -> String;
+}
+
+fn pretty_at<E: Pretty>(e: &E, level: u8, indent: usize) -> String {
+    if e.level() < level {
+        format!("({})", e.pretty(indent))
+    } else {
+        e.pretty(indent)
+    }
+}
+
+// The first expression of a sequence can't be open ended
+pub(crate) fn needs_parens_before_semicolon<E: Pretty>(e: &E) -> bool {
+    e.level() < EXP || e.open_ended()
+}
+
+fn pretty_seq<E: Pretty>(first: &E, second: &E, indent: usize) -> String {
+    let first = if needs_parens_before_semicolon(first) {
+        format!("({})", first.pretty(indent + 1))
+    } else {
+        first.pretty(indent)
+    };
+    format!("{};{}{}", first, newline(indent), second.pretty(indent))
+}
+
+fn newline(indent: usize) -> String {
+    format!("\n{}", " ".repeat(indent))
+}
+
+fn pretty_list<E: Pretty>(es: &[E], indent: usize) -> String {
+    es.iter()
+        .map(|e| pretty_at(e, EXP, indent))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+// The name of a prim written like a call, e.g. add1(e) or min(e1, e2)
+pub(crate) fn call_name(p: Prim) -> Option<&'static str> {
+    match p {
+        Prim::Add1 => Some("add1"),
+        Prim::Sub1 => Some("sub1"),
+        Prim::Print => Some("print"),
+        Prim::IsBool => Some("isbool"),
+        Prim::IsNum => Some("isnum"),
+        Prim::IsTuple => Some("istuple"),
+        Prim::Length => Some("length"),
+        Prim::Abs => Some("abs"),
+        Prim::Min => Some("min"),
+        Prim::Max => Some("max"),
+        Prim::IsString => Some("isstring"),
+        Prim::StrLen => Some("strlen"),
+        Prim::NumToString => Some("num_to_string"),
+        Prim::StringToNum => Some("string_to_num"),
+        Prim::CharAt => Some("char_at"),
+        Prim::Substr => Some("substr"),
+        _ => None,
+    }
+}
+
+// The symbol and precedence level of an infix operator
+pub(crate) fn binop(p: Prim) -> Option<(&'static str, u8)> {
+    match p {
+        Prim::Add => Some(("+", SUM)),
+        Prim::Sub => Some(("-", SUM)),
+        Prim::Concat => Some(("++", SUM)),
+        Prim::Mul => Some(("*", PRODUCT)),
+        Prim::Div => Some(("/", PRODUCT)),
+        Prim::Mod => Some(("%", PRODUCT)),
+        Prim::Shl => Some(("<<", SHIFT)),
+        Prim::Shr => Some((">>", SHIFT)),
+        Prim::BitAnd => Some(("&", BIT_AND)),
+        Prim::BitXor => Some(("^", BIT_XOR)),
+        Prim::BitOr => Some(("|", BIT_OR)),
+        Prim::And => Some(("&&", LOG)),
+        Prim::Or => Some(("||", LOG)),
+        Prim::Lt => Some(("<", CMP)),
+        Prim::Gt => Some((">", CMP)),
+        Prim::Le => Some(("<=", CMP)),
+        Prim::Ge => Some((">=", CMP)),
+        Prim::Eq => Some(("==", CMP)),
+        Prim::Neq => Some(("!=", CMP)),
+        _ => None,
+    }
+}
+
+// The levels of the left and right operands of an infix operator. They are
+// left associative, except for && and ||
+pub(crate) fn operand_levels(level: u8) -> (u8, u8) {
+    if level == LOG {
+        (level + 1, level)
+    } else {
+        (level, level + 1)
+    }
+}
+
+pub(crate) fn prim_level(p: Prim) -> u8 {
+    match p {
+        Prim::Not | Prim::Neg => NOT,
+        Prim::TupleSet => EXP,
+        _ => match binop(p) {
+            Some((_, level)) => level,
+            None => BASE,
+        },
+    }
+}
+
+fn pretty_prim<E: Pretty>(p: Prim, args: &[&E], indent: usize) -> String {
+    let at = |i: usize, level: u8| pretty_at(args[i], level, indent);
+    if let Some(name) = call_name(p) {
+        let es: Vec<String> = (0..args.len()).map(|i| at(i, EXP)).collect();
+        return format!("{}({})", name, es.join(", "));
+    }
+    if let Some((op, level)) = binop(p) {
+        let (left, right) = operand_levels(level);
+        return format!("{} {} {}", at(0, left), op, at(1, right));
+    }
+    match p {
+        Prim::Not => format!("!{}", at(0, BASE)),
+        Prim::Neg => {
+            // -5 would be read back as a number literal
+            let e = at(0, BASE);
+            if e.starts_with(|c: char| c.is_ascii_digit()) {
+                format!("-({})", e)
+            } else {
+                format!("-{}", e)
+            }
+        }
+        Prim::TupleGet => format!("{}[{}]", at(0, BASE), at(1, EXP)),
+        Prim::TupleSet => format!("{}[{}] := {}", at(0, BASE), at(1, EXP), at(2, EXP)),
+        Prim::MakeTuple => {
+            let es: Vec<String> = (0..args.len()).map(|i| at(i, EXP)).collect();
+            format!("[{}]", es.join(", "))
+        }
+        _ => unreachable!("{:?} is neither a call-like prim nor an operator", p),
+    }
+}
+
+fn pretty_if<C: Pretty, E: Pretty>(cond: &C, thn: &E, els: &E, indent: usize) -> String {
+    format!(
+        "if {}:{}{}{}else:{}{}",
+        cond.pretty(indent + 2),
+        newline(indent + 2),
+        thn.pretty(indent + 2),
+        newline(indent),
+        newline(indent + 2),
+        els.pretty(indent + 2)
+    )
+}
+
+fn pretty_fundefs<E: Pretty, Ann>(decls: &[FunDecl<E, Ann>], body: &E, indent: usize) -> String {
+    let decls: Vec<String> = decls
+        .iter()
+        .map(|decl| {
+            format!(
+                "def {}({}):{}{}",
+                decl.name,
+                decl.parameters.join(", "),
+                newline(indent + 2),
+                decl.body.pretty(indent + 2)
+            )
+        })
+        .collect();
+    format!(
+        "{}{}in{}{}",
+        decls.join(&format!("{}and ", newline(indent))),
+        newline(indent),
+        newline(indent),
+        body.pretty(indent)
+    )
+}
+
+fn pretty_while<C: Pretty, E: Pretty>(cond: &C, body: &E, indent: usize) -> String {
+    format!(
+        "while {}:{}{}{}end",
+        cond.pretty(indent + 2),
+        newline(indent + 2),
+        body.pretty(indent + 2),
+        newline(indent)
+    )
+}
+
+fn pretty_for<I: Pretty, E: Pretty>(var: &str, from: &I, to: &I, body: &E, indent: usize) -> String {
+    format!(
+        "for {} = {} to {}:{}{}{}end",
+        var,
+        from.pretty(indent + 2),
+        to.pretty(indent + 2),
+        newline(indent + 2),
+        body.pretty(indent + 2),
+        newline(indent)
+    )
+}
+
+fn pretty_call<E: Pretty>(fun: &str, args: &[E], indent: usize) -> String {
+    format!("{}({})", fun, pretty_list(args, indent))
+}
+
+impl<Ann> Pretty for Exp<Ann> {
+    fn level(&self) -> u8 {
+        match self {
+            Exp::Prim(p, _, _) => prim_level(*p),
+            Exp::Let { .. } | Exp::If { .. } | Exp::FunDefs { .. } | Exp::Assign { .. } => EXP,
+            Exp::Seq { .. } => SEQ,
+            _ => BASE,
+        }
+    }
+
+    fn open_ended(&self) -> bool {
+        match self {
+            Exp::Let { .. } | Exp::If { .. } | Exp::FunDefs { .. } => true,
+            Exp::Prim(Prim::TupleSet, es, _) => es[2].level() >= EXP && es[2].open_ended(),
+            Exp::Assign { value, .. } => value.level() >= EXP && value.open_ended(),
+            _ => false,
+        }
+    }
+
+    fn pretty(&self, indent: usize) -> String {
+        match self {
+            Exp::Num(n, _) => n.to_string(),
+            Exp::Bool(b, _) => b.to_string(),
+            Exp::Str(s, _) => escape(s),
+            Exp::Var(x, _) => x.clone(),
+            Exp::Prim(p, es, _) => {
+                let args: Vec<&Exp<Ann>> = es.iter().map(|e| &**e).collect();
+                pretty_prim(*p, &args, indent)
+            }
+            Exp::Let { bindings, body, .. } => {
+                let bindings: Vec<String> = bindings
+                    .iter()
+                    .map(|(x, e)| format!("{} = {}", x, e.pretty(indent + 4)))
+                    .collect();
+                format!(
+                    "let {} in{}{}",
+                    bindings.join(&format!(",{}", newline(indent + 4))),
+                    newline(indent),
+                    body.pretty(indent)
+                )
+            }
+            Exp::If { cond, thn, els, .. } => pretty_if(&**cond, &**thn, &**els, indent),
+            Exp::Seq { first, second, .. } => pretty_seq(&**first, &**second, indent),
+            Exp::While { cond, body, .. } => pretty_while(&**cond, &**body, indent),
+            Exp::For {
+                var,
+                from,
+                to,
+                body,
+                ..
+            } => pretty_for(var, &**from, &**to, &**body, indent),
+            Exp::Assign { var, value, .. } => {
+                format!("{} := {}", var, pretty_at(&**value, EXP, indent))
+            }
+            Exp::FunDefs { decls, body, .. } => pretty_fundefs(decls, &**body, indent),
+            Exp::Lambda {
+                parameters, body, ..
+            } => format!(
+                "lambda {}: {} end",
+                parameters.join(", "),
+                body.pretty(indent + 2)
+            ),
+            Exp::Call(fun, args, _)
+            | Exp::InternalTailCall(fun, args, _)
+            | Exp::ExternalCall {
+                fun_name: fun,
+                args,
+                ..
+            }
+            | Exp::ClosureCall {
+                closure: fun, args, ..
+            } => pretty_call(fun, args, indent),
+            // There is no surface syntax for closure allocation, so it is
+            // shown as a call to a pseudo-function
+            Exp::MakeClosure {
+                fun_name, captured, ..
+            } => format!("make_closure({})", {
+                let mut args = vec![fun_name.clone()];
+                args.extend(captured.iter().map(|e| pretty_at(e, EXP, indent)));
+                args.join(", ")
+            }),
+        }
+    }
 }
 
+impl Pretty for ImmExp {
+    fn level(&self) -> u8 {
+        BASE
+    }
 
+    fn open_ended(&self) -> bool {
+        false
+    }
 
+    fn pretty(&self, _indent: usize) -> String {
+        match self {
+            ImmExp::Num(n) => n.to_string(),
+            ImmExp::Bool(b) => b.to_string(),
+            ImmExp::Var(x) => x.clone(),
+        }
+    }
+}
+
+impl<Ann> Pretty for SeqExp<Ann> {
+    fn level(&self) -> u8 {
+        match self {
+            SeqExp::Prim(p, _, _) => prim_level(*p),
+            SeqExp::Let { .. }
+            | SeqExp::If { .. }
+            | SeqExp::FunDefs { .. }
+            | SeqExp::Assign { .. } => EXP,
+            SeqExp::Seq { .. } => SEQ,
+            _ => BASE,
+        }
+    }
+
+    // The arguments of a := are immediates
+    fn open_ended(&self) -> bool {
+        matches!(
+            self,
+            SeqExp::Let { .. } | SeqExp::If { .. } | SeqExp::FunDefs { .. }
+        )
+    }
+
+    fn pretty(&self, indent: usize) -> String {
+        match self {
+            SeqExp::Imm(imm, _) => imm.pretty(indent),
+            SeqExp::Str(s, _) => escape(s),
+            SeqExp::Prim(p, imms, _) => {
+                let args: Vec<&ImmExp> = imms.iter().collect();
+                pretty_prim(*p, &args, indent)
+            }
+            SeqExp::Let {
+                var,
+                bound_exp,
+                body,
+                ..
+            } => format!(
+                "let {} = {} in{}{}",
+                var,
+                bound_exp.pretty(indent + 4),
+                newline(indent),
+                body.pretty(indent)
+            ),
+            SeqExp::If { cond, thn, els, .. } => pretty_if(cond, &**thn, &**els, indent),
+            SeqExp::Seq { first, second, .. } => pretty_seq(&**first, &**second, indent),
+            SeqExp::While { cond, body, .. } => pretty_while(&**cond, &**body, indent),
+            SeqExp::For {
+                var,
+                from,
+                to,
+                body,
+                ..
+            } => pretty_for(var, from, to, &**body, indent),
+            SeqExp::Assign { var, value, .. } => format!("{} := {}", var, value.pretty(indent)),
+            SeqExp::FunDefs { decls, body, .. } => pretty_fundefs(decls, &**body, indent),
+            SeqExp::InternalTailCall(fun, args, _)
+            | SeqExp::ExternalCall {
+                fun_name: fun,
+                args,
+                ..
+            } => pretty_call(fun, args, indent),
+            SeqExp::ClosureCall { closure, args, .. } => {
+                pretty_call(&closure.pretty(indent), args, indent)
+            }
+            SeqExp::MakeClosure {
+                fun_name, captured, ..
+            } => format!("make_closure({})", {
+                let mut args = vec![fun_name.clone()];
+                args.extend(captured.iter().map(|imm| imm.pretty(indent)));
+                args.join(", ")
+            }),
+        }
+    }
+}
+
+impl<Ann> std::fmt::Display for Exp<Ann> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.pretty(0))
+    }
+}
+
+impl std::fmt::Display for ImmExp {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.pretty(0))
+    }
+}
+
+impl<Ann> std::fmt::Display for SeqExp<Ann> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.pretty(0))
+    }
+}
+
+// The top-level functions are shown as one group of definitions around main
+impl<Ann> std::fmt::Display for SeqProg<Ann> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.funs.is_empty() {
+            write!(f, "{}", self.main.pretty(0))
+        } else {
+            write!(f, "{}", pretty_fundefs(&self.funs, &self.main, 0))
+        }
+    }
+}
@@ -9,6 +9,16 @@ macro_rules! mk_test {
     };
 }
 
+// Like mk_test, also checking that the output is the expected one
+macro_rules! mk_output_test {
+    ($test_name:ident, $file_name:expr, $expected_output:expr) => {
+        #[test]
+        fn $test_name() -> std::io::Result<()> {
+            test_example_output($file_name, $expected_output)
+        }
+    };
+}
+
 macro_rules! mk_fail_test {
     ($test_name:ident, $file_name:expr, $expected_output:expr) => {
         #[test]
@@ -18,16 +28,45 @@ macro_rules! mk_fail_test {
     };
 }
 
+macro_rules! mk_warn_test {
+    ($test_name:ident, $file_name:expr, $expected_warnings:expr) => {
+        #[test]
+        fn $test_name() -> std::io::Result<()> {
+            test_example_warnings($file_name, $expected_warnings)
+        }
+    };
+}
+
 /*
  * YOUR TESTS GO HERE
  */
 mk_fail_test!(
     parse_error_example,
     "parse_error.adder",
-    "Unrecognized token `)`"
+    "expected an expression, found `)`"
+);
+mk_fail_test!(
+    parse_error_2,
+    "parse_error_2",
+    "unterminated `let`: expected one of `in`, `,` after let binding at line 1, column 0 to line 1, column 3"
+);
+mk_fail_test!(
+    parse_error_3,
+    "parse_error_3",
+    "expected one of `in`, `,` after let binding, found `4` at line 5, column 13 to line 5, column 14"
 );
 mk_fail_test!(err_1, "err_1", "Error generating assembly");
 mk_fail_test!(err_2, "err_2", "overflow");
+mk_fail_test!(
+    overflow_2,
+    "overflow_2",
+    "Number literal 99999999999999999999 doesn't fit into 63-bit integer at line 1, column 8 to line 1, column 28"
+);
+mk_fail_test!(
+    overflow_3,
+    "overflow_3",
+    "Number literal 9223372036854775807 doesn't fit into 63-bit integer at line 1, column 8 to line 1, column 27"
+);
 mk_fail_test!(err_3, "err_3", "arithmetic expected a number");
 mk_fail_test!(err_4, "err_4", "arithmetic expected a number");
 mk_fail_test!(err_5, "err_5", "overflow");
@@ -39,6 +78,72 @@ mk_fail_test!(err_10, "err_10", "if expected a boolean");
 mk_fail_test!(logic_error_1, "logic_error_1", "logic expected a boolean");
 mk_fail_test!(logic_error_2, "logic_error_2", "logic expected a boolean");
 mk_fail_test!(logic_error_3, "logic_error_3", "logic expected a boolean");
+mk_output_test!(
+    short_circuit_1,
+    "short_circuit_1",
+    "1\n2\n[false, true, true, true, false, true]"
+);
+mk_output_test!(short_circuit_2, "short_circuit_2", "[false, true, true]");
+mk_fail_test!(short_circuit_3, "short_circuit_3", "logic expected a boolean");
+mk_fail_test!(short_circuit_4, "short_circuit_4", "logic expected a boolean");
+mk_output_test!(
+    arith_ops_1,
+    "arith_ops_1",
+    "[-3, 2, -3, -2, 1, 0, -17, -3, 4, 4, 5, 17, 0, 0, 29, 29, 3, -6, 1024, 3, -48, 2305843009213693952, 0, 4, -3, -1, 0, 128, -5, 17, 3, true, -4611686018427387903]"
+);
+mk_output_test!(arith_ops_2, "arith_ops_2", "[-5, 7, -15, -5, -3, 5, 2, 6]");
+mk_fail_test!(div_zero_1, "div_zero_1", "division by zero");
+mk_output_test!(
+    string_1,
+    "string_1",
+    "Hello, world!\nworld\nHw\n-42 is 9 long\n[13, 3, true, false]"
+);
+mk_output_test!(
+    string_2,
+    "string_2",
+    "say \"hi\"\tthen\\leave\n[\"say \\\"hi\\\"\\tthen\\\\leave\", \"line\\nbreak\", \"\"]\n[true, false, false, true, false]"
+);
+mk_output_test!(string_3, "string_3", "[15000, \"snake\", 2288895]");
+mk_output_test!(string_4, "string_4", "éö✓\néllo\n[14, 1, true]");
+mk_output_test!(seq_1, "seq_1", "2\n1\n0\n[1, \"liftoff\"]");
+mk_output_test!(seq_2, "seq_2", "21\n500000500042");
+mk_output_test!(seq_3, "seq_3", "one\n3\n[3, 2]");
+mk_output_test!(loop_1, "loop_1", "55\n55");
+mk_output_test!(loop_2, "loop_2", "[1, 9, false, false]");
+mk_output_test!(loop_3, "loop_3", "[1000000, \"1000th\"]");
+mk_fail_test!(loop_err_1, "loop_err_1", "while expected a boolean 3");
+mk_fail_test!(loop_err_2, "loop_err_2", "arithmetic expected a number \"ten\"");
+mk_fail_test!(loop_err_3, "loop_err_3", "arithmetic expected a number true");
+mk_output_test!(loop_4, "loop_4", "0\n1\n2\n[3, 111]");
+mk_output_test!(assign_1, "assign_1", "6\n[5, 7, 7]\n5\n4\n10\nabc");
+mk_fail_test!(assign_err_1, "assign_err_1", "Unbound variable count");
+mk_fail_test!(
+    assign_err_2,
+    "assign_err_2",
+    "variable x is assigned and referred to by a function in its scope"
+);
+mk_warn_test!(
+    loop_warn_1,
+    "loop_warn_1",
+    "variable i shadows an outer binding at line 3, column 8 to line 3, column 9
+unused variable i bound at line 3, column 8 to line 3, column 9"
+);
+mk_warn_test!(
+    loop_warn_2,
+    "loop_warn_2",
+    "while condition is always false at line 2, column 6 to line 2, column 11"
+);
+mk_fail_test!(string_err_1, "string_err_1", "string operation expected a string 5");
+mk_fail_test!(string_err_2, "string_err_2", "index out of bounds 6");
+mk_fail_test!(
+    string_err_3,
+    "string_err_3",
+    "string_to_num expected the digits of a number \"0x10\""
+);
+mk_fail_test!(string_err_4, "string_err_4", "index out of bounds -1");
+mk_fail_test!(string_err_5, "string_err_5", "substr end 1 is before start 2");
+mk_fail_test!(shift_overflow_1, "shift_overflow_1", "overflow");
+mk_fail_test!(shift_overflow_2, "shift_overflow_2", "overflow");
 mk_fail_test!(cmp_err_1, "cmp_err_1", "comparison expected a number");
 mk_fail_test!(cmp_err_2, "cmp_err_2", "comparison expected a number");
 mk_fail_test!(cmp_err_3, "cmp_err_3", "comparison expected a number");
@@ -91,6 +196,256 @@ mk_test!(lambda_1, "lambda_1", "5");
 mk_test!(lambda_2, "lambda_2", "6");
 mk_test!(lambda_3, "lambda_3", "7");
 mk_test!(lambda_4, "lambda_4", "5");
+mk_output_test!(closure_1, "closure_1", "23");
+mk_output_test!(closure_2, "closure_2", "27\n42\ntrue\n120");
+mk_output_test!(closure_3, "closure_3", "<closure>\n[7, 14, 42, <closure>]");
+mk_output_test!(
+    closure_eq_1,
+    "closure_eq_1",
+    "true\ntrue\ntrue\ntrue\ntrue\nfalse\nfalse\nfalse\nfalse"
+);
+mk_fail_test!(closure_err_1, "closure_err_1", "expected a function");
+mk_fail_test!(closure_err_2, "closure_err_2", "wrong number of arguments");
+mk_fail_test!(closure_err_3, "closure_err_3", "Undefined function f called");
+mk_output_test!(gc_1, "gc_1", "[1, 2000000]");
+mk_output_test!(gc_2, "gc_2", "500555");
+mk_output_test!(gc_3, "gc_3", "1001000");
+// Only the compiled code has a fixed heap, the interpreter prints 2
+mk_fail_test!(gc_err_1, "gc_err_1", "out of memory");
+mk_fail_test!(
+    loc_err_1,
+    "loc_err_1",
+    "if expected a boolean 6 at line 3, column 0 to line 3, column 15"
+);
+mk_fail_test!(
+    loc_err_2,
+    "loc_err_2",
+    "index out of bounds 5 at line 2, column 2 to line 2, column 6"
+);
+mk_fail_test!(loc_err_3, "loc_err_3", "at line 1, column 22 to line 1, column 27");
+mk_fail_test!(
+    loc_err_4,
+    "loc_err_4",
+    "arithmetic expected a number true at line 3, column 4 to line 3, column 9"
+);
+mk_fail_test!(
+    loc_err_5,
+    "loc_err_5",
+    "index out of bounds 4 at line 2, column 18 to line 2, column 28"
+);
+mk_output_test!(free_vars_1, "free_vars_1", "10\n20");
+mk_output_test!(local_fun_1, "local_fun_1", "3");
+mk_output_test!(local_fun_2, "local_fun_2", "5");
+mk_output_test!(local_fun_3, "local_fun_3", "10");
+mk_output_test!(local_fun_4, "local_fun_4", "2\n6");
+mk_output_test!(local_fun_5, "local_fun_5", "4");
+mk_fail_test!(let_scope_1, "let_scope_1", "Unbound variable x");
+mk_output_test!(mangle_1, "mangle_1", "121");
+mk_output_test!(
+    pretty_1,
+    "pretty_1",
+    "[[1, 7, -6], 10, true, 14, 12, 9, true]\n[[1, 7, -6], 10, true, 14, 12, 9, true]"
+);
+mk_output_test!(fmt_1, "fmt_1", "1500000000000\n1500000000000");
+mk_output_test!(fmt_2, "fmt_2", "1500000000000\n1500000000000");
+mk_output_test!(lex_1, "lex_1", "[255, 170, 1000000, -16, 999999, 171]");
+mk_output_test!(warn_1, "warn_1", "2");
+mk_warn_test!(
+    warn_1_warnings,
+    "warn_1",
+    "unused parameter y of the function at line 1, column 0 to line 2, column 7
+function g is never called at line 3, column 4 to line 7, column 12
+unused parameter z of the function at line 11, column 13 to line 11, column 28
+unused variable unused bound at line 11, column 13 to line 11, column 28
+variable a shadows an outer binding at line 12, column 8 to line 12, column 26
+if condition is always true at line 12, column 11 to line 12, column 15"
+);
+mk_warn_test!(no_warnings_1, "func_test_7", "");
+
+#[test]
+fn render_duplicate_binding() -> std::io::Result<()> {
+    let tmp_dir = tempfile::TempDir::new()?;
+    let path = std::path::Path::new("examples/dup_let_1");
+    let errs = match runner::compile_and_run_file(path, tmp_dir.path(), &mut Vec::new()) {
+        Err(runner::RunnerErr::CodeGen(errs)) => errs,
+        _ => panic!("expected a compile error"),
+    };
+    let src = std::fs::read_to_string(path)?;
+    assert_eq!(
+        errs[0].diagnostic().render("examples/dup_let_1", &src, false),
+        "error[E0003]: Variable x defined twice in let-expression
+ --> examples/dup_let_1:3:9
+  |
+1 | let x = 1,
+  |         - x first bound here
+...
+3 |     x = 3 in
+  |         ^ x bound again here
+"
+    );
+    Ok(())
+}
+#[test]
+fn json_duplicate_binding() -> std::io::Result<()> {
+    let tmp_dir = tempfile::TempDir::new()?;
+    let path = std::path::Path::new("examples/dup_let_1");
+    let err = runner::compile_and_run_file(path, tmp_dir.path(), &mut Vec::new())
+        .expect_err("expected a compile error");
+    let json: Vec<String> = err
+        .diagnostics()
+        .iter()
+        .map(|d| d.to_json("examples/dup_let_1"))
+        .collect();
+    assert_eq!(
+        json,
+        vec![concat!(
+            r#"{"code":"E0003","severity":"error","message":"Variable x defined twice in let-expression","#,
+            r#""file":"examples/dup_let_1","span":{"start_line":3,"start_col":9,"end_line":3,"end_col":10},"#,
+            r#""label":"x bound again here","secondary":[{"span":{"start_line":1,"start_col":9,"end_line":1,"end_col":10},"#,
+            r#""text":"x first bound here"}],"notes":[],"help":[]}"#
+        )]
+    );
+    Ok(())
+}
+
+// The duplicated x is unused too, but that only repeats the error
+#[test]
+fn no_warnings_with_errors() -> std::io::Result<()> {
+    let path = std::path::Path::new("examples/dup_let_1");
+    let err = runner::warnings_file(path).expect_err("expected the duplicate binding");
+    assert_eq!(err.diagnostics()[0].code, Some("E0003"));
+    Ok(())
+}
+
+#[test]
+fn json_runtime_error() -> std::io::Result<()> {
+    let tmp_dir = tempfile::TempDir::new()?;
+    let path = std::path::Path::new("examples/err_3");
+    let err = runner::compile_and_run_file(path, tmp_dir.path(), &mut Vec::new())
+        .expect_err("expected a runtime error");
+    let diagnostics = err.diagnostics();
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0].code, Some("E0200"));
+    assert!(diagnostics[0].primary.is_some());
+    // the interpreter points at the same expression
+    let interp_err =
+        runner::interpret_file(path, &mut Vec::new()).expect_err("expected a runtime error");
+    let interp_diagnostics = interp_err.diagnostics();
+    assert_eq!(interp_diagnostics[0].code, Some("E0200"));
+    assert_eq!(
+        interp_diagnostics[0].primary.as_ref().map(|l| l.span),
+        diagnostics[0].primary.as_ref().map(|l| l.span)
+    );
+    Ok(())
+}
+mk_fail_test!(
+    parse_error_4,
+    "parse_error_4",
+    "Error parsing input: expected `:` before the body, found `x` at line 1, column 12 to line 1, column 13
+Error parsing input: expected one of `in`, `,` after let binding, found `2` at line 5, column 10 to line 5, column 11
+Error parsing input: expected one of `)`, `,`, found `2` at line 6, column 12 to line 6, column 13
+Error parsing input: expected an expression, found `+` at line 7, column 12 to line 7, column 13
+Error parsing input: expected `=` after let-bound name, found `4` at line 8, column 6 to line 8, column 7
+Error generating assembly: Unbound variable w at line 3, column 6 to line 3, column 7
+Error generating assembly: Variable a defined twice in let-expression at line 9, column 8 to line 9, column 9
+Error generating assembly: function g of arity 1 called with 2 arguments at line 11, column 0 to line 11, column 7"
+);
+#[test]
+fn render_suggestions() -> std::io::Result<()> {
+    let tmp_dir = tempfile::TempDir::new()?;
+    let path = std::path::Path::new("examples/suggest_1");
+    let errs = match runner::compile_and_run_file(path, tmp_dir.path(), &mut Vec::new()) {
+        Err(runner::RunnerErr::CodeGen(errs)) => errs,
+        _ => panic!("expected compile errors"),
+    };
+    let src = std::fs::read_to_string(path)?;
+    let rendered: Vec<String> = errs
+        .iter()
+        .map(|e| e.diagnostic().render("examples/suggest_1", &src, false))
+        .collect();
+    assert_eq!(
+        rendered.join("\n"),
+        "error[E0001]: Unbound variable totla
+ --> examples/suggest_1:9:2
+  |
+9 | [totla + 1, cont(false, 0), count(false), m + n]
+  |  ^^^^^ not found in this scope
+  = help: did you mean `totl`?
+
+error[E0002]: Undefined function cont called
+ --> examples/suggest_1:9:13
+  |
+9 | [totla + 1, cont(false, 0), count(false), m + n]
+  |             ^^^^^^^^^^^^^^ no function of this name in scope
+  = help: did you mean `count`?
+
+error[E0007]: function count of arity 2 called with 1 arguments
+ --> examples/suggest_1:9:29
+  |
+1 | def count(lst, acc):
+  | -------------------- count defined here
+...
+9 | [totla + 1, cont(false, 0), count(false), m + n]
+  |                             ^^^^^^^^^^^^ called with 1 arguments
+  = note: count is declared as `def count(lst, acc)`
+
+error[E0001]: Unbound variable m
+ --> examples/suggest_1:9:43
+  |
+9 | [totla + 1, cont(false, 0), count(false), m + n]
+  |                                           ^ not found in this scope
+"
+    );
+    Ok(())
+}
+#[test]
+fn explain_examples() -> std::io::Result<()> {
+    use snake::explain::EXPLANATIONS;
+    use std::path::Path;
+    for explanation in EXPLANATIONS {
+        let examples = match &explanation.examples {
+            Some(examples) => examples,
+            None => continue,
+        };
+        let wrong = format!("examples/{}_wrong", examples.name);
+        let fixed = format!("examples/{}_fixed", examples.name);
+        let tmp_dir = tempfile::TempDir::new()?;
+        if explanation.code.starts_with('W') {
+            let has_warning = |p: &str| {
+                runner::warnings_file(Path::new(p))
+                    .unwrap()
+                    .iter()
+                    .any(|w| w.code() == explanation.code)
+            };
+            assert!(has_warning(&wrong), "{} has no {}", wrong, explanation.code);
+            assert!(!has_warning(&fixed), "{} has {}", fixed, explanation.code);
+        } else {
+            let err = runner::compile_and_run_file(Path::new(&wrong), tmp_dir.path(), &mut Vec::new())
+                .expect_err(&format!("{} should fail", wrong));
+            assert!(
+                err.diagnostics().iter().any(|d| d.code == Some(explanation.code)),
+                "{} fails without {}: {}",
+                wrong,
+                explanation.code,
+                err
+            );
+        }
+        if let Err(e) = runner::compile_and_run_file(Path::new(&fixed), tmp_dir.path(), &mut Vec::new()) {
+            panic!("{} fails: {}", fixed, e)
+        }
+    }
+    Ok(())
+}
+mk_fail_test!(
+    multi_err_1,
+    "multi_err_1",
+    "Error generating assembly: Unbound variable z at line 2, column 6 to line 2, column 7
+Error generating assembly: Undefined function g called at line 4, column 8 to line 4, column 12
+Error generating assembly: function f of arity 2 called with 1 arguments at line 5, column 8 to line 5, column 12
+Error generating assembly: Variable a defined twice in let-expression at line 6, column 8 to line 6, column 9
+Error generating assembly: Unbound variable w at line 7, column 3 to line 7, column 4
+Error generating assembly: Number literal 4611686018427387904 doesn't fit into 63-bit integer at line 7, column 14 to line 7, column 33"
+);
 
 mk_test!(list_1, "list_1", "3");
 mk_test!(list_2, "list_2", "10");
@@ -99,6 +454,84 @@ mk_test!(list_4, "list_4", "true");
 mk_test!(list_5, "list_5", "true");
 mk_test!(list_6, "list_6", "[4, [true, 3]]");
 mk_test!(list_7, "list_7", "[0, [0, 1]]");
+mk_output_test!(list_8, "list_8", "3\ntrue\nfalse\nfalse");
+mk_output_test!(list_9, "list_9", "[1, <loop>]\ntrue");
+mk_fail_test!(list_err_1, "list_err_1", "index out of bounds");
+mk_fail_test!(list_err_2, "list_err_2", "expected a tuple");
+mk_fail_test!(list_err_3, "list_err_3", "index expected a number");
+mk_fail_test!(list_err_4, "list_err_4", "expected a tuple");
+
+// Printing any example that parses gives back the same program
+#[test]
+fn pretty_print_round_trip() -> std::io::Result<()> {
+    for entry in std::fs::read_dir("examples")? {
+        test_round_trip(&entry?.path())?;
+    }
+    Ok(())
+}
+
+#[test]
+fn format_layout() -> std::io::Result<()> {
+    let (formatted, _) = runner::format_file(std::path::Path::new("examples/fmt_1")).unwrap();
+    let (_, reformatted) = runner::format_file(std::path::Path::new("examples/fmt_2")).unwrap();
+    assert_eq!(reformatted, formatted);
+    Ok(())
+}
+
+#[test]
+fn format_keeps_literal_spelling() -> std::io::Result<()> {
+    let (_, formatted) = runner::format_file(std::path::Path::new("examples/lex_1")).unwrap();
+    assert!(formatted.contains("mask = 0xFF,"));
+    assert!(formatted.contains("bits = 0b1010_1010,"));
+    assert!(formatted.contains("big = 1_000_000,"));
+    assert!(formatted.contains("neg = -0x10 in"));
+    assert!(formatted.contains("(# Block comments nest #)"));
+    Ok(())
+}
+
+#[test]
+fn format_sequence() -> std::io::Result<()> {
+    let (_, formatted) = runner::format_file(std::path::Path::new("examples/seq_3")).unwrap();
+    assert!(formatted.contains("(if t[0] == 1:"));
+    assert!(formatted.contains("print(x);\nt[0] := x;\nt\n"));
+    Ok(())
+}
+
+#[test]
+fn format_keeps_string_escapes() -> std::io::Result<()> {
+    let (_, formatted) = runner::format_file(std::path::Path::new("examples/string_2")).unwrap();
+    assert!(formatted.contains(r#"quoted = "say \"hi\"\tthen\\leave","#));
+    assert!(formatted.contains(r#""line\nbreak""#));
+    Ok(())
+}
+
+// Formatting any example that parses keeps the program and its comments,
+// and formatting again changes nothing
+#[test]
+fn format_round_trip() -> std::io::Result<()> {
+    for entry in std::fs::read_dir("examples")? {
+        test_format_round_trip(&entry?.path())?;
+    }
+    Ok(())
+}
+
+// Both backends print the same before failing the same way
+#[test]
+fn loop_bounds_order() -> std::io::Result<()> {
+    let tmp_dir = tempfile::TempDir::new()?;
+    let path = std::path::Path::new("examples/loop_err_3");
+    let mut compiled_w = Vec::new();
+    let compiled_err = runner::compile_and_run_file(path, tmp_dir.path(), &mut compiled_w)
+        .expect_err("expected a runtime error");
+    let mut interp_w = Vec::new();
+    let interp_err =
+        runner::interpret_file(path, &mut interp_w).expect_err("expected a runtime error");
+    assert_eq!(std::str::from_utf8(&compiled_w).unwrap(), "5\n");
+    assert_eq!(compiled_w, interp_w);
+    assert_eq!(compiled_err.diagnostics()[0].code, Some("E0200"));
+    assert_eq!(interp_err.diagnostics()[0].code, Some("E0200"));
+    Ok(())
+}
 
 // IMPLEMENTATION
 fn test_example_file(f: &str, expected_str: &str) -> std::io::Result<()> {
@@ -109,7 +542,7 @@ fn test_example_file(f: &str, expected_str: &str) -> std::io::Result<()> {
     // Test the compiler
     let tmp_dir = tempfile::TempDir::new()?;
     let mut w = Vec::new();
-    match runner::compile_and_run_file(&path, tmp_dir.path(), &mut w) {
+    match runner::compile_and_run_file(path, tmp_dir.path(), &mut w) {
         Ok(()) => {
             let stdout = std::str::from_utf8(&w).unwrap();
             let mut interp_w = Vec::new();
@@ -118,13 +551,31 @@ fn test_example_file(f: &str, expected_str: &str) -> std::io::Result<()> {
             // assert_eq!(stdout.trim(), expected_str)
         }
         Err(e) => {
-            assert!(false, "Expected {}, got an error: {}", expected_str, e)
+            panic!("Expected {}, got an error: {}", expected_str, e)
         }
     }
 
     Ok(())
 }
 
+fn test_example_output(f: &str, expected_str: &str) -> std::io::Result<()> {
+    use std::path::Path;
+    let p_name = format!("examples/{}", f);
+    let path = Path::new(&p_name);
+
+    let tmp_dir = tempfile::TempDir::new()?;
+    let mut w = Vec::new();
+    if let Err(e) = runner::compile_and_run_file(path, tmp_dir.path(), &mut w) {
+        panic!("Expected {}, got an error: {}", expected_str, e)
+    }
+    let stdout = std::str::from_utf8(&w).unwrap();
+    let mut interp_w = Vec::new();
+    runner::interp(path, &mut interp_w);
+    assert_eq!(std::str::from_utf8(&interp_w).unwrap(), stdout);
+    assert_eq!(stdout.trim(), expected_str);
+    Ok(())
+}
+
 fn test_example_fail(f: &str, includes: &str) -> std::io::Result<()> {
     use std::path::Path;
     let p_name = format!("examples/{}", f);
@@ -133,14 +584,10 @@ fn test_example_fail(f: &str, includes: &str) -> std::io::Result<()> {
     // Test the compiler
     let tmp_dir = tempfile::TempDir::new()?;
     let mut w_run = Vec::new();
-    match runner::compile_and_run_file(
-        &Path::new(&format!("examples/{}", f)),
-        tmp_dir.path(),
-        &mut w_run,
-    ) {
+    match runner::compile_and_run_file(path, tmp_dir.path(), &mut w_run) {
         Ok(()) => {
             let stdout = std::str::from_utf8(&w_run).unwrap();
-            assert!(false, "Expected a failure but got: {}", stdout.trim())
+            panic!("Expected a failure but got: {}", stdout.trim())
         }
         Err(e) => {
             let msg = format!("{}", e);
@@ -155,3 +602,63 @@ fn test_example_fail(f: &str, includes: &str) -> std::io::Result<()> {
 
     Ok(())
 }
+
+fn test_round_trip(path: &std::path::Path) -> std::io::Result<()> {
+    use snake::parse::parse_prog;
+    let src = std::fs::read_to_string(path)?;
+    let prog = match parse_prog(&src) {
+        Ok(prog) => prog.map_ann(&|_| ()),
+        Err(_) => return Ok(()),
+    };
+    let printed = format!("{}", prog);
+    match parse_prog(&printed) {
+        Ok(reparsed) => assert_eq!(
+            reparsed.map_ann(&|_| ()),
+            prog,
+            "{} printed differently as:\n{}",
+            path.display(),
+            printed
+        ),
+        Err(e) => panic!(
+            "{} printed as unparseable:\n{}\n{:?}",
+            path.display(),
+            printed,
+            e
+        ),
+    }
+    Ok(())
+}
+
+fn test_format_round_trip(path: &std::path::Path) -> std::io::Result<()> {
+    use snake::formatter::format_prog;
+    use snake::parse::parse_prog;
+    let src = std::fs::read_to_string(path)?;
+    let prog = match parse_prog(&src) {
+        Ok(prog) => prog,
+        Err(_) => return Ok(()),
+    };
+    let formatted = format_prog(&src, &prog);
+    let reparsed = parse_prog(&formatted).unwrap();
+    assert_eq!(
+        reparsed.map_ann(&|_| ()),
+        prog.map_ann(&|_| ()),
+        "{} formatted differently as:\n{}",
+        path.display(),
+        formatted
+    );
+    assert_eq!(formatted.matches('#').count(), src.matches('#').count());
+    assert_eq!(format_prog(&formatted, &reparsed), formatted);
+    Ok(())
+}
+
+fn test_example_warnings(f: &str, expected: &str) -> std::io::Result<()> {
+    use std::path::Path;
+    let p_name = format!("examples/{}", f);
+    let warnings: Vec<String> = runner::warnings_file(Path::new(&p_name))
+        .unwrap()
+        .iter()
+        .map(|w| w.to_string())
+        .collect();
+    assert_eq!(warnings.join("\n"), expected);
+    Ok(())
+}